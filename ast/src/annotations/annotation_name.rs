@@ -24,6 +24,10 @@ use serde::Serialize;
 #[pest_ast(rule(Rule::annotation_name))]
 pub enum AnnotationName<'ast> {
     Context(Context<'ast>),
+    Fuzz(Fuzz<'ast>),
+    Ignore(Ignore<'ast>),
+    Inputs(Inputs<'ast>),
+    ShouldFail(ShouldFail<'ast>),
 }
 
 #[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
@@ -33,3 +37,35 @@ pub struct Context<'ast> {
     #[serde(with = "SpanDef")]
     pub span: Span<'ast>,
 }
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::fuzz))]
+pub struct Fuzz<'ast> {
+    #[pest_ast(outer())]
+    #[serde(with = "SpanDef")]
+    pub span: Span<'ast>,
+}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::ignore))]
+pub struct Ignore<'ast> {
+    #[pest_ast(outer())]
+    #[serde(with = "SpanDef")]
+    pub span: Span<'ast>,
+}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::inputs))]
+pub struct Inputs<'ast> {
+    #[pest_ast(outer())]
+    #[serde(with = "SpanDef")]
+    pub span: Span<'ast>,
+}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::should_fail))]
+pub struct ShouldFail<'ast> {
+    #[pest_ast(outer())]
+    #[serde(with = "SpanDef")]
+    pub span: Span<'ast>,
+}
@@ -22,6 +22,7 @@ use crate::{
         ArrayInlineExpression,
         CircuitInlineExpression,
         Expression,
+        MatchExpression,
         PostfixExpression,
         TernaryExpression,
         UnaryExpression,
@@ -96,6 +97,9 @@ fn parse_term(pair: Pair<Rule>) -> Box<Expression> {
                 Rule::expression_conditional => {
                     Expression::Ternary(TernaryExpression::from_pest(&mut pair.into_inner()).unwrap())
                 }
+                Rule::expression_match => {
+                    Expression::Match(MatchExpression::from_pest(&mut pair.into_inner()).unwrap())
+                }
                 Rule::expression_unary => {
                     // The following is necessary to match with the unary operator and its unary expression
                     let span = next.as_span();
@@ -14,7 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ast::Rule, circuits::CircuitMember, common::Identifier, SpanDef};
+use crate::{
+    ast::Rule,
+    circuits::CircuitMember,
+    common::{ConstParameter, Identifier},
+    SpanDef,
+};
 
 use pest::Span;
 use pest_ast::FromPest;
@@ -24,6 +29,7 @@ use serde::Serialize;
 #[pest_ast(rule(Rule::circuit))]
 pub struct Circuit<'ast> {
     pub identifier: Identifier<'ast>,
+    pub const_parameters: Vec<ConstParameter<'ast>>,
     pub members: Vec<CircuitMember<'ast>>,
     #[pest_ast(outer())]
     #[serde(with = "SpanDef")]
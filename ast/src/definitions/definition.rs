@@ -18,6 +18,7 @@ use crate::{
     ast::Rule,
     circuits::Circuit,
     definitions::AnnotatedDefinition,
+    enums::Enum,
     functions::{Function, TestFunction},
     imports::Import,
 };
@@ -31,6 +32,7 @@ pub enum Definition<'ast> {
     Annotated(AnnotatedDefinition<'ast>),
     Import(Import<'ast>),
     Circuit(Circuit<'ast>),
+    Enum(Enum<'ast>),
     Function(Function<'ast>),
     TestFunction(TestFunction<'ast>),
 }
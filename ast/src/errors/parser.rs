@@ -33,28 +33,57 @@ pub enum ParserError {
     #[error("{}", _0)]
     SyntaxError(#[from] SyntaxError),
 
+    #[error("aborting due to {} syntax errors", _0.len())]
+    SyntaxErrors(Vec<SyntaxError>),
+
     #[error("Unable to construct program abstract syntax tree")]
     SyntaxTreeError,
 }
 
 impl ParserError {
-    pub fn set_path(&mut self, path: PathBuf) {
+    pub fn code(&self) -> &'static str {
         match self {
-            ParserError::SyntaxError(error) => {
-                let new_error: Error<Rule> = match error {
-                    SyntaxError::Error(error) => {
-                        let new_error = error.clone();
-                        new_error.with_path(path.to_str().unwrap())
-                    }
-                };
-
-                tracing::error!("{}", new_error);
+            ParserError::Crate(_, _) => "E0005",
+            ParserError::FileReadError(_) => "E0002",
+            ParserError::JsonError(_) => "E0003",
+            ParserError::SyntaxError(_) | ParserError::SyntaxErrors(_) => "E0001",
+            ParserError::SyntaxTreeError => "E0004",
+        }
+    }
 
-                *error = SyntaxError::Error(new_error);
+    pub fn set_path(&mut self, path: PathBuf) {
+        match self {
+            ParserError::SyntaxError(error) => Self::set_syntax_error_path(error, &path),
+            ParserError::SyntaxErrors(errors) => {
+                for error in errors {
+                    Self::set_syntax_error_path(error, &path);
+                }
             }
             _ => {}
         }
     }
+
+    fn set_syntax_error_path(error: &mut SyntaxError, path: &PathBuf) {
+        let new_error: Error<Rule> = match error {
+            SyntaxError::Error(error) => {
+                let new_error = error.clone();
+                new_error.with_path(path.to_str().unwrap())
+            }
+        };
+
+        tracing::error!("{}", new_error);
+
+        *error = SyntaxError::Error(new_error);
+    }
+}
+
+impl From<Vec<SyntaxError>> for ParserError {
+    fn from(mut errors: Vec<SyntaxError>) -> Self {
+        match errors.len() {
+            1 => ParserError::SyntaxError(errors.remove(0)),
+            _ => ParserError::SyntaxErrors(errors),
+        }
+    }
 }
 
 impl From<Error<Rule>> for ParserError {
@@ -27,6 +27,7 @@ pub enum Expression<'ast> {
     Unary(UnaryExpression<'ast>),
     Binary(BinaryExpression<'ast>),
     Ternary(TernaryExpression<'ast>),
+    Match(MatchExpression<'ast>),
     ArrayInline(ArrayInlineExpression<'ast>),
     ArrayInitializer(ArrayInitializerExpression<'ast>),
     CircuitInline(CircuitInlineExpression<'ast>),
@@ -70,6 +71,7 @@ impl<'ast> Expression<'ast> {
             Expression::Unary(expression) => &expression.span,
             Expression::Binary(expression) => &expression.span,
             Expression::Ternary(expression) => &expression.span,
+            Expression::Match(expression) => &expression.span,
             Expression::ArrayInline(expression) => &expression.span,
             Expression::ArrayInitializer(expression) => &expression.span,
             Expression::CircuitInline(expression) => &expression.span,
@@ -91,6 +93,7 @@ impl<'ast> fmt::Display for Expression<'ast> {
                 "if {} ? {} : {}",
                 expression.first, expression.second, expression.third
             ),
+            Expression::Match(ref expression) => write!(f, "{}", expression.span.as_str()),
             Expression::ArrayInline(ref expression) => {
                 for (i, spread_or_expression) in expression.expressions.iter().enumerate() {
                     write!(f, "{}", spread_or_expression)?;
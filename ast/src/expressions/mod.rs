@@ -32,6 +32,9 @@ pub use expression::*;
 pub mod unary_expression;
 pub use unary_expression::*;
 
+pub mod match_expression;
+pub use match_expression::*;
+
 pub mod postfix_expression;
 pub use postfix_expression::*;
 
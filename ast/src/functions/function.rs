@@ -14,7 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ast::Rule, common::Identifier, functions::input::Input, statements::Statement, types::Type, SpanDef};
+use crate::{
+    ast::Rule,
+    common::{ConstParameter, Identifier},
+    functions::input::Input,
+    statements::Statement,
+    types::Type,
+    SpanDef,
+};
 
 use pest::Span;
 use pest_ast::FromPest;
@@ -24,6 +31,7 @@ use serde::Serialize;
 #[pest_ast(rule(Rule::function))]
 pub struct Function<'ast> {
     pub identifier: Identifier<'ast>,
+    pub const_parameters: Vec<ConstParameter<'ast>>,
     pub parameters: Vec<Input<'ast>>,
     pub returns: Option<Type<'ast>>,
     pub statements: Vec<Statement<'ast>>,
@@ -29,11 +29,13 @@ pub mod circuits;
 pub mod common;
 pub mod console;
 pub mod definitions;
+pub mod enums;
 pub mod expressions;
 pub mod files;
 pub mod functions;
 pub mod imports;
 pub mod operations;
+pub mod printer;
 pub mod statements;
 pub mod types;
 pub mod values;
@@ -41,10 +43,14 @@ pub mod values;
 pub mod errors;
 pub use errors::*;
 
+pub mod recovery;
+pub use recovery::*;
+
 pub(crate) mod span;
 pub(crate) use span::*;
 
 use from_pest::FromPest;
+use pest::iterators::Pairs;
 use std::{fs, path::PathBuf};
 
 pub struct LeoAst<'ast> {
@@ -58,10 +64,27 @@ impl<'ast> LeoAst<'ast> {
         // assert_eq!(program_string, fs::read_to_string(file_path).map_err(|_| ParserError::FileReadError(file_path.clone()))?);
 
         // Parse the file using leo.pest
+        // On failure, recover from each syntax error to report all of them at once
         let file = &mut ast::parse(&program_string)
-            .map_err(|error| ParserError::from(error.with_path(file_path.to_str().unwrap())))?;
+            .map_err(|_| ParserError::from(RecoveredProgram::new(file_path, program_string).errors))?;
+
+        Self::from_pairs(file)
+    }
+
+    /// Creates a new abstract syntax tree of a recovered program, and returns it with the syntax errors of the program.
+    ///
+    /// The statements and definitions that contain a syntax error are missing from the syntax tree,
+    /// the remaining code keeps the lines and columns of the original source.
+    /// If the program has no syntax errors, the syntax tree is complete and no errors are returned.
+    pub fn new_partial(recovered: &'ast RecoveredProgram) -> Result<(Self, &'ast [SyntaxError]), ParserError> {
+        // The recovered program always parses, an error would be a bug in the recovery
+        let file = &mut ast::parse(&recovered.program_string).map_err(|_| ParserError::SyntaxTreeError)?;
 
-        // Builds the abstract syntax tree using pest derivation.
+        Ok((Self::from_pairs(file)?, &recovered.errors))
+    }
+
+    /// Builds the abstract syntax tree from the parsed file using pest derivation.
+    fn from_pairs(file: &mut Pairs<'ast, ast::Rule>) -> Result<Self, ParserError> {
         let ast = files::File::<'ast>::from_pest(file).map_err(|_| ParserError::SyntaxTreeError)?;
         tracing::debug!("{:#?}", ast);
 
@@ -80,6 +103,11 @@ impl<'ast> LeoAst<'ast> {
         &self.ast
     }
 
+    /// Prints the abstract syntax tree as canonical Leo source code, preserving comments.
+    pub fn to_formatted_string(&self) -> String {
+        printer::format_file(&self.ast)
+    }
+
     /// Serializes the abstract syntax tree into a JSON string.
     pub fn to_json_string(&self) -> Result<String, ParserError> {
         Ok(serde_json::to_string_pretty(&self.ast)?)
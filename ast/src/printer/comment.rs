@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Comments of a Leo program which are restored by the printer.
+
+/// A comment in a Leo program string.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comment {
+    /// The comment including its `//` or `/* */` delimiters.
+    pub text: String,
+    /// The byte offset of the first character of the comment.
+    pub start: usize,
+    /// The byte offset after the last character of the comment.
+    pub end: usize,
+}
+
+impl Comment {
+    /// Returns `true` if the comment runs until the end of its line.
+    pub fn is_line_comment(&self) -> bool {
+        self.text.starts_with("//")
+    }
+}
+
+/// Returns all comments in the given program string in order of appearance.
+///
+/// Comments are never part of a string, so the contents of string literals are skipped.
+pub fn parse_comments(program_string: &str) -> Vec<Comment> {
+    let bytes = program_string.as_bytes();
+    let mut comments = vec![];
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match (bytes[index], bytes.get(index + 1)) {
+            (b'"', _) => {
+                index += 1;
+                while index < bytes.len() && bytes[index] != b'"' {
+                    index += 1;
+                }
+                index += 1;
+            }
+            (b'/', Some(b'/')) => {
+                let start = index;
+                while index < bytes.len() && bytes[index] != b'\n' {
+                    index += 1;
+                }
+                let text = program_string[start..index].trim_end().to_string();
+
+                comments.push(Comment {
+                    end: start + text.len(),
+                    text,
+                    start,
+                });
+            }
+            (b'/', Some(b'*')) => {
+                let start = index;
+                index = match program_string[start + 2..].find("*/") {
+                    Some(offset) => start + 2 + offset + 2,
+                    None => bytes.len(),
+                };
+
+                comments.push(Comment {
+                    text: program_string[start..index].to_string(),
+                    start,
+                    end: index,
+                });
+            }
+            _ => index += 1,
+        }
+    }
+
+    comments
+}
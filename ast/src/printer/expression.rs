@@ -0,0 +1,361 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Prints Leo expressions, types and values.
+
+use crate::{
+    access::{Access, AssigneeAccess},
+    common::{Assignee, ConstParameter, RangeOrExpression, SpreadOrExpression},
+    expressions::{CircuitName, Expression},
+    operations::{AssignOperation, BinaryOperation, UnaryOperation},
+    printer::{match_pattern, Printer, INDENT},
+    types::{ArrayDimensions, ArrayTypeDimensions, DataType, IntegerType, Type},
+    values::{GroupRepresentation, Value},
+};
+
+/// Inline arrays and circuits which are longer than this are printed on multiple lines.
+const MAX_INLINE_WIDTH: usize = 80;
+
+impl<'a> Printer<'a> {
+    /// Returns the source code of an expression starting at the current indentation.
+    pub fn expression(&self, expression: &Expression) -> String {
+        self.expression_at(expression, self.indentation())
+    }
+
+    fn expression_at(&self, expression: &Expression, indent: usize) -> String {
+        match expression {
+            Expression::Value(value) => self.value(value),
+            Expression::Identifier(identifier) => identifier.to_string(),
+            Expression::Unary(unary) => {
+                let operation = match unary.operation {
+                    UnaryOperation::Negate(_) => "-",
+                    UnaryOperation::Not(_) => "!",
+                };
+                let operand = self.expression_at(&unary.expression, indent);
+
+                match *unary.expression {
+                    Expression::Binary(_) | Expression::Ternary(_) | Expression::Match(_) => {
+                        format!("{}({})", operation, operand)
+                    }
+                    _ => format!("{}{}", operation, operand),
+                }
+            }
+            Expression::Binary(binary) => {
+                let precedence = precedence(&binary.operation);
+                let mut left = self.expression_at(&binary.left, indent);
+                let mut right = self.expression_at(&binary.right, indent);
+
+                // Binary operations are left associative
+                if operand_precedence(&binary.left) < precedence {
+                    left = format!("({})", left);
+                }
+                if operand_precedence(&binary.right) <= precedence {
+                    right = format!("({})", right);
+                }
+
+                format!("{} {} {}", left, binary_operation(&binary.operation), right)
+            }
+            Expression::Ternary(ternary) => format!(
+                "if {} ? {} : {}",
+                self.expression_at(&ternary.first, indent),
+                self.expression_at(&ternary.second, indent),
+                self.expression_at(&ternary.third, indent)
+            ),
+            Expression::Match(match_) => {
+                let arms = match_
+                    .arms
+                    .iter()
+                    .map(|arm| {
+                        format!(
+                            "{}{} => {},\n",
+                            INDENT.repeat(indent + 1),
+                            match_pattern(&arm.pattern),
+                            self.expression_at(&arm.expression, indent + 1)
+                        )
+                    })
+                    .collect::<String>();
+
+                format!(
+                    "match {} {{\n{}{}}}",
+                    self.expression_at(&match_.expression, indent),
+                    arms,
+                    INDENT.repeat(indent)
+                )
+            }
+            Expression::ArrayInline(array) => {
+                let single_line = array
+                    .expressions
+                    .iter()
+                    .map(|element| self.spread_or_expression(element, indent))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                if single_line.len() <= MAX_INLINE_WIDTH && !single_line.contains('\n') {
+                    return format!("[{}]", single_line);
+                }
+
+                let elements = array
+                    .expressions
+                    .iter()
+                    .map(|element| {
+                        format!(
+                            "{}{}",
+                            INDENT.repeat(indent + 1),
+                            self.spread_or_expression(element, indent + 1)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                format!("[\n{}\n{}]", elements.join(",\n"), INDENT.repeat(indent))
+            }
+            Expression::ArrayInitializer(array) => format!(
+                "[{}; {}]",
+                self.expression_at(&array.expression, indent),
+                array_type_dimensions(&array.dimensions)
+            ),
+            Expression::CircuitInline(circuit) => {
+                let name = match &circuit.name {
+                    CircuitName::SelfType(self_type) => self_type.keyword.clone(),
+                    CircuitName::Identifier(identifier) => identifier.value.clone(),
+                };
+                if circuit.members.is_empty() {
+                    return format!("{} {{}}", name);
+                }
+
+                let single_line = circuit
+                    .members
+                    .iter()
+                    .map(|member| format!("{}: {}", member.identifier, self.expression_at(&member.expression, indent)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                if single_line.len() <= MAX_INLINE_WIDTH && !single_line.contains('\n') {
+                    return format!("{} {{ {} }}", name, single_line);
+                }
+
+                let members = circuit
+                    .members
+                    .iter()
+                    .map(|member| {
+                        format!(
+                            "{}{}: {},\n",
+                            INDENT.repeat(indent + 1),
+                            member.identifier,
+                            self.expression_at(&member.expression, indent + 1)
+                        )
+                    })
+                    .collect::<String>();
+
+                format!("{} {{\n{}{}}}", name, members, INDENT.repeat(indent))
+            }
+            Expression::Postfix(postfix) => {
+                let mut result = postfix.name.to_string();
+
+                for access in postfix.accesses.iter() {
+                    match access {
+                        Access::Array(array) => {
+                            result.push_str(&format!("[{}]", self.range_or_expression(&array.expression, indent)))
+                        }
+                        Access::Tuple(tuple) => result.push_str(&format!(".{}", tuple.number)),
+                        Access::Call(call) => {
+                            result.push_str(&self.tuple(&call.expressions.expressions, indent));
+                        }
+                        Access::Object(member) => result.push_str(&format!(".{}", member.identifier)),
+                        Access::StaticObject(member) => result.push_str(&format!("::{}", member.identifier)),
+                    }
+                }
+
+                result
+            }
+            Expression::Tuple(tuple) => self.tuple(&tuple.expressions, indent),
+        }
+    }
+
+    fn tuple(&self, expressions: &[Expression], indent: usize) -> String {
+        let expressions = expressions
+            .iter()
+            .map(|expression| self.expression_at(expression, indent))
+            .collect::<Vec<_>>();
+
+        format!("({})", expressions.join(", "))
+    }
+
+    fn spread_or_expression(&self, element: &SpreadOrExpression, indent: usize) -> String {
+        match element {
+            SpreadOrExpression::Spread(spread) => format!("...{}", self.expression_at(&spread.expression, indent)),
+            SpreadOrExpression::Expression(expression) => self.expression_at(expression, indent),
+        }
+    }
+
+    fn range_or_expression(&self, range: &RangeOrExpression, indent: usize) -> String {
+        match range {
+            RangeOrExpression::Range(range) => {
+                let from = range.from.as_ref().map(|from| self.expression_at(from, indent));
+                let to = range.to.as_ref().map(|to| self.expression_at(to, indent));
+
+                format!("{}..{}", from.unwrap_or_default(), to.unwrap_or_default())
+            }
+            RangeOrExpression::Expression(expression) => self.expression_at(expression, indent),
+        }
+    }
+
+    pub fn assignee(&self, assignee: &Assignee) -> String {
+        let mut result = assignee.name.to_string();
+
+        for access in assignee.accesses.iter() {
+            match access {
+                AssigneeAccess::Array(array) => result.push_str(&format!(
+                    "[{}]",
+                    self.range_or_expression(&array.expression, self.indentation())
+                )),
+                AssigneeAccess::Tuple(tuple) => result.push_str(&format!(".{}", tuple.number)),
+                AssigneeAccess::Member(member) => result.push_str(&format!(".{}", member.identifier)),
+            }
+        }
+
+        result
+    }
+
+    fn value(&self, value: &Value) -> String {
+        match value {
+            Value::Address(address) => format!("address({})", address.address.value),
+            Value::Boolean(boolean) => boolean.value.clone(),
+            Value::Field(field) => format!("{}field", field.number),
+            Value::Group(group) => match &group.value {
+                GroupRepresentation::Single(number) => format!("{}group", number),
+                GroupRepresentation::Tuple(tuple) => format!("({}, {})group", tuple.x, tuple.y),
+            },
+            Value::Implicit(number) => number.to_string(),
+            Value::Integer(integer) => integer.to_string(),
+        }
+    }
+
+    /// Returns the source code of a type.
+    pub fn type_(&self, type_: &Type) -> String {
+        match type_ {
+            Type::Basic(DataType::Address(_)) => "address".to_string(),
+            Type::Basic(DataType::Boolean(_)) => "bool".to_string(),
+            Type::Basic(DataType::Field(_)) => "field".to_string(),
+            Type::Basic(DataType::Group(_)) => "group".to_string(),
+            Type::Basic(DataType::Integer(integer)) => integer_type(integer),
+            Type::Array(array) => format!(
+                "[{}; {}]",
+                self.type_(&array.type_),
+                array_type_dimensions(&array.dimensions)
+            ),
+            Type::Tuple(tuple) => {
+                let types = tuple.types.iter().map(|type_| self.type_(type_)).collect::<Vec<_>>();
+                format!("({})", types.join(", "))
+            }
+            Type::Circuit(circuit) => circuit.identifier.to_string(),
+            Type::SelfType(self_type) => self_type.keyword.clone(),
+        }
+    }
+
+    pub fn const_parameters(&self, parameters: &[ConstParameter]) -> String {
+        if parameters.is_empty() {
+            return String::new();
+        }
+
+        let parameters = parameters
+            .iter()
+            .map(|parameter| format!("const {}: {}", parameter.identifier, integer_type(&parameter.type_)))
+            .collect::<Vec<_>>();
+
+        format!("<{}>", parameters.join(", "))
+    }
+}
+
+fn array_type_dimensions(dimensions: &ArrayTypeDimensions) -> String {
+    match dimensions {
+        ArrayTypeDimensions::Dimensions(dimensions) => array_dimensions(dimensions),
+        ArrayTypeDimensions::Const(dimension) => dimension.identifier.to_string(),
+    }
+}
+
+fn array_dimensions(dimensions: &ArrayDimensions) -> String {
+    match dimensions {
+        ArrayDimensions::Single(single) => single.number.to_string(),
+        ArrayDimensions::Multiple(multiple) => {
+            let numbers = multiple.numbers.iter().map(|number| number.to_string()).collect::<Vec<_>>();
+            format!("({})", numbers.join(", "))
+        }
+    }
+}
+
+fn integer_type(type_: &IntegerType) -> String {
+    match type_ {
+        IntegerType::Signed(signed) => signed.to_string(),
+        IntegerType::Unsigned(unsigned) => unsigned.to_string(),
+    }
+}
+
+pub fn assign_operation(operation: &AssignOperation) -> &'static str {
+    match operation {
+        AssignOperation::Assign(_) => "=",
+        AssignOperation::AddAssign(_) => "+=",
+        AssignOperation::SubAssign(_) => "-=",
+        AssignOperation::MulAssign(_) => "*=",
+        AssignOperation::DivAssign(_) => "/=",
+        AssignOperation::PowAssign(_) => "**=",
+    }
+}
+
+fn binary_operation(operation: &BinaryOperation) -> &'static str {
+    match operation {
+        BinaryOperation::Or => "||",
+        BinaryOperation::And => "&&",
+        BinaryOperation::Eq => "==",
+        BinaryOperation::Ne => "!=",
+        BinaryOperation::Ge => ">=",
+        BinaryOperation::Gt => ">",
+        BinaryOperation::Le => "<=",
+        BinaryOperation::Lt => "<",
+        BinaryOperation::Add => "+",
+        BinaryOperation::Sub => "-",
+        BinaryOperation::Mul => "*",
+        BinaryOperation::Div => "/",
+        BinaryOperation::Pow => "**",
+    }
+}
+
+/// Returns the binding strength of a binary operation, matching the precedence climber of the parser.
+fn precedence(operation: &BinaryOperation) -> u8 {
+    match operation {
+        BinaryOperation::Or => 1,
+        BinaryOperation::And => 2,
+        BinaryOperation::Eq
+        | BinaryOperation::Ne
+        | BinaryOperation::Ge
+        | BinaryOperation::Gt
+        | BinaryOperation::Le
+        | BinaryOperation::Lt => 3,
+        BinaryOperation::Add | BinaryOperation::Sub => 4,
+        BinaryOperation::Mul | BinaryOperation::Div => 5,
+        BinaryOperation::Pow => 6,
+    }
+}
+
+/// Returns the binding strength of an operand of a binary operation.
+///
+/// Conditional and match expressions extend as far as possible and always need parentheses.
+fn operand_precedence(expression: &Expression) -> u8 {
+    match expression {
+        Expression::Binary(binary) => precedence(&binary.operation),
+        Expression::Ternary(_) | Expression::Match(_) => 0,
+        _ => u8::MAX,
+    }
+}
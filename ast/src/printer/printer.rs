@@ -0,0 +1,557 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Prints a Leo abstract syntax tree as canonical Leo source code.
+
+use crate::{
+    annotations::AnnotationName,
+    circuits::{Circuit, CircuitFunction, CircuitMember},
+    common::{Declare, VariableName},
+    console::{ConsoleFunction, ConsoleFunctionCall, FormattedString},
+    definitions::Definition,
+    enums::{Enum, EnumVariant, MatchPattern},
+    files::File,
+    functions::{Function, Input},
+    imports::{Import, Package, PackageAccess},
+    printer::{assign_operation, parse_comments, Comment},
+    statements::{
+        ConditionalNestedOrEndStatement,
+        ConditionalStatement,
+        MatchStatement,
+        MatchStatementArm,
+        Statement,
+    },
+};
+
+pub(crate) const INDENT: &str = "    ";
+
+/// Returns the canonical source code of the given file.
+///
+/// Comments in the file are preserved. Formatting the returned source code again yields the same string.
+pub fn format_file(file: &File) -> String {
+    let mut printer = Printer::new(file.span.as_str());
+    printer.file(file);
+    printer.output
+}
+
+/// Prints the definitions and statements of a Leo file line by line.
+///
+/// Expressions are printed on a single line unless they contain a match expression or an inline
+/// array or circuit that is too long. Comments are reattached to the gaps between definitions,
+/// circuit members, enum variants, statements and match arms.
+pub struct Printer<'a> {
+    program_string: &'a str,
+    comments: Vec<Comment>,
+    printed: Vec<bool>,
+    output: String,
+    indent: usize,
+    last_line_comment: bool,
+}
+
+impl<'a> Printer<'a> {
+    pub fn new(program_string: &'a str) -> Self {
+        let comments = parse_comments(program_string);
+        let printed = vec![false; comments.len()];
+
+        Self {
+            program_string,
+            comments,
+            printed,
+            output: String::new(),
+            indent: 0,
+            last_line_comment: false,
+        }
+    }
+
+    /// Prints all definitions of the file separated by blank lines.
+    pub fn file(&mut self, file: &File) {
+        let mut end = 0;
+        let mut previous_import = None;
+
+        for definition in file.definitions.iter() {
+            let is_import = matches!(definition, Definition::Import(_));
+            let force_blank = previous_import.map(|previous| !(previous && is_import)).unwrap_or(false);
+
+            self.gap(end, definition_start(definition), previous_import.is_none(), false, force_blank);
+            end = self.definition(definition);
+            self.inner_comments(end);
+
+            previous_import = Some(is_import);
+        }
+
+        self.gap(end, self.program_string.len(), file.definitions.is_empty(), true, false);
+    }
+
+    fn definition(&mut self, definition: &Definition) -> usize {
+        match definition {
+            Definition::Annotated(annotated) => {
+                let name = match annotated.annotation.name {
+                    AnnotationName::Context(_) => "context",
+                    AnnotationName::Fuzz(_) => "fuzz",
+                    AnnotationName::Ignore(_) => "ignore",
+                    AnnotationName::Inputs(_) => "inputs",
+                    AnnotationName::ShouldFail(_) => "should_fail",
+                };
+
+                match &annotated.annotation.arguments {
+                    Some(arguments) => {
+                        let arguments = arguments
+                            .arguments
+                            .iter()
+                            .map(|argument| {
+                                // Arguments are printed as `name` or `name = value`
+                                argument
+                                    .value
+                                    .split('=')
+                                    .map(|part| part.trim())
+                                    .collect::<Vec<_>>()
+                                    .join(" = ")
+                            })
+                            .collect::<Vec<_>>();
+
+                        self.line(&format!("@{}({})", name, arguments.join(", ")));
+                    }
+                    None => self.line(&format!("@{}", name)),
+                }
+
+                self.definition(&annotated.definition)
+            }
+            Definition::Import(import) => self.import(import),
+            Definition::Circuit(circuit) => self.circuit(circuit),
+            Definition::Enum(enum_) => self.enum_(enum_),
+            Definition::Function(function) => self.function(function, ""),
+            Definition::TestFunction(test_function) => self.function(&test_function.function, "test "),
+        }
+    }
+
+    fn import(&mut self, import: &Import) -> usize {
+        self.line(&format!("import {};", package(&import.package)));
+        self.find(import.package.span.end(), b';') + 1
+    }
+
+    fn circuit(&mut self, circuit: &Circuit) -> usize {
+        self.line(&format!(
+            "circuit {}{} {{",
+            circuit.identifier,
+            self.const_parameters(&circuit.const_parameters)
+        ));
+        let open = self.find(circuit.identifier.span.end(), b'{') + 1;
+
+        self.block(open, &circuit.members, circuit_member_start, Self::circuit_member)
+    }
+
+    fn circuit_member(&mut self, member: &CircuitMember) -> usize {
+        match member {
+            CircuitMember::CircuitVariableDefinition(definition) => {
+                let mutable = if definition.mutable.is_some() { "mut " } else { "" };
+                self.line(&format!(
+                    "{}{}: {},",
+                    mutable,
+                    definition.identifier,
+                    self.type_(&definition.type_)
+                ));
+
+                definition.span.end()
+            }
+            CircuitMember::CircuitFunction(CircuitFunction { _static, function, .. }) => {
+                let prefix = if _static.is_some() { "static " } else { "" };
+                self.function(function, prefix)
+            }
+        }
+    }
+
+    fn enum_(&mut self, enum_: &Enum) -> usize {
+        self.line(&format!("enum {} {{", enum_.identifier));
+        let open = self.find(enum_.identifier.span.end(), b'{') + 1;
+
+        self.block(open, &enum_.variants, |variant| variant.span.start(), Self::enum_variant)
+    }
+
+    fn enum_variant(&mut self, variant: &EnumVariant) -> usize {
+        if variant.types.is_empty() {
+            self.line(&format!("{},", variant.identifier));
+        } else {
+            let types = variant.types.iter().map(|type_| self.type_(type_)).collect::<Vec<_>>();
+            self.line(&format!("{}({}),", variant.identifier, types.join(", ")));
+        }
+
+        variant.span.end()
+    }
+
+    fn function(&mut self, function: &Function, prefix: &str) -> usize {
+        let parameters = function
+            .parameters
+            .iter()
+            .map(|input| match input {
+                Input::InputKeyword(keyword) => keyword.keyword.clone(),
+                Input::FunctionInput(input) => {
+                    let mutable = if input.mutable.is_some() { "mut " } else { "" };
+                    format!("{}{}: {}", mutable, input.identifier, self.type_(&input.type_))
+                }
+            })
+            .collect::<Vec<_>>();
+        let returns = match &function.returns {
+            Some(type_) => format!(" -> {}", self.type_(type_)),
+            None => String::new(),
+        };
+
+        self.line(&format!(
+            "{}function {}{}({}){} {{",
+            prefix,
+            function.identifier,
+            self.const_parameters(&function.const_parameters),
+            parameters.join(", "),
+            returns
+        ));
+
+        // Function headers never contain braces
+        let open = self.find(function.identifier.span.end(), b'{') + 1;
+
+        self.block(open, &function.statements, statement_start, Self::statement)
+    }
+
+    fn statement(&mut self, statement: &Statement) -> usize {
+        match statement {
+            Statement::Return(statement) => {
+                let expression = self.expression(&statement.expression);
+                self.line(&format!("return {}", expression));
+
+                statement.expression.span().end()
+            }
+            Statement::Definition(statement) => {
+                let declare = match statement.declare {
+                    Declare::Const(_) => "const",
+                    Declare::Let(_) => "let",
+                };
+                let names = statement.variables.names.iter().map(variable_name).collect::<Vec<_>>();
+                let names = if names.len() == 1 {
+                    names[0].clone()
+                } else {
+                    format!("({})", names.join(", "))
+                };
+                let type_ = match &statement.variables.type_ {
+                    Some(type_) => format!(": {}", self.type_(type_)),
+                    None => String::new(),
+                };
+                let expressions = statement
+                    .expressions
+                    .iter()
+                    .map(|expression| self.expression(expression))
+                    .collect::<Vec<_>>();
+
+                self.line(&format!("{} {}{} = {};", declare, names, type_, expressions.join(", ")));
+
+                let last = statement.expressions.last().map(|expression| expression.span().end());
+                self.find(last.unwrap_or_else(|| statement.variables.span.end()), b';') + 1
+            }
+            Statement::Assign(statement) => {
+                let assignee = self.assignee(&statement.assignee);
+                let expression = self.expression(&statement.expression);
+                self.line(&format!("{} {} {};", assignee, assign_operation(&statement.assign), expression));
+
+                self.find(statement.expression.span().end(), b';') + 1
+            }
+            Statement::Conditional(statement) => {
+                let expression = self.expression(&statement.condition);
+                self.line(&format!("if {} {{", expression));
+
+                self.conditional(statement)
+            }
+            Statement::Iteration(statement) => {
+                let start = self.expression(&statement.start);
+                let stop = self.expression(&statement.stop);
+                self.line(&format!("for {} in {}..{} {{", statement.index, start, stop));
+                let open = self.find(statement.stop.span().end(), b'{') + 1;
+
+                self.block(open, &statement.statements, statement_start, Self::statement)
+            }
+            Statement::Match(statement) => self.match_statement(statement),
+            Statement::Console(console) => self.console(console),
+            Statement::Expression(statement) => {
+                let expression = self.expression(&statement.expression);
+                self.line(&format!("{};", expression));
+
+                self.find(statement.expression.span().end(), b';') + 1
+            }
+        }
+    }
+
+    /// Prints the block and the `else` branches of a conditional statement after its header line.
+    fn conditional(&mut self, statement: &ConditionalStatement) -> usize {
+        let open = self.find(statement.condition.span().end(), b'{') + 1;
+        let end = self.block(open, &statement.statements, statement_start, Self::statement);
+
+        match &statement.next {
+            Some(ConditionalNestedOrEndStatement::Nested(nested)) => {
+                let expression = self.expression(&nested.condition);
+                self.append(&format!(" else if {} {{", expression));
+
+                self.conditional(nested)
+            }
+            Some(ConditionalNestedOrEndStatement::End(statements)) => {
+                self.append(" else {");
+                let open = self.find(end, b'{') + 1;
+
+                self.block(open, statements, statement_start, Self::statement)
+            }
+            None => end,
+        }
+    }
+
+    fn match_statement(&mut self, statement: &MatchStatement) -> usize {
+        let expression = self.expression(&statement.expression);
+        self.line(&format!("match {} {{", expression));
+        let open = self.find(statement.expression.span().end(), b'{') + 1;
+
+        self.block(open, &statement.arms, |arm| arm.span.start(), Self::match_statement_arm)
+    }
+
+    fn match_statement_arm(&mut self, arm: &MatchStatementArm) -> usize {
+        self.line(&format!("{} => {{", match_pattern(&arm.pattern)));
+        let open = self.find(match_pattern_end(&arm.pattern), b'{') + 1;
+
+        self.block(open, &arm.statements, statement_start, Self::statement)
+    }
+
+    fn console(&mut self, console: &ConsoleFunctionCall) -> usize {
+        let (function, span) = match &console.function {
+            ConsoleFunction::Assert(assert) => {
+                let expression = self.expression(&assert.expression);
+                (format!("assert({})", expression), &assert.span)
+            }
+            ConsoleFunction::Debug(debug) => (format!("debug({})", self.formatted_string(&debug.string)), &debug.span),
+            ConsoleFunction::Error(error) => (format!("error({})", self.formatted_string(&error.string)), &error.span),
+            ConsoleFunction::Log(log) => (format!("log({})", self.formatted_string(&log.string)), &log.span),
+        };
+        self.line(&format!("console.{};", function));
+
+        self.find(span.end(), b';') + 1
+    }
+
+    fn formatted_string(&self, string: &FormattedString) -> String {
+        // The string literal is followed by the formatted parameters in the same span
+        let source = string.span.as_str();
+        let close = source[1..].find('"').map(|index| index + 2).unwrap_or_else(|| source.len());
+
+        let mut result = source[..close].to_string();
+        for parameter in string.parameters.iter() {
+            result.push_str(", ");
+            result.push_str(&self.expression(&parameter.expression));
+        }
+
+        result
+    }
+
+    /// Prints a list of items followed by a closing brace.
+    ///
+    /// The header line containing the opening brace must already be printed.
+    /// Returns the position after the closing brace in the program string.
+    fn block<T>(
+        &mut self,
+        open: usize,
+        items: &[T],
+        start: fn(&T) -> usize,
+        print: fn(&mut Self, &T) -> usize,
+    ) -> usize {
+        self.indent += 1;
+
+        let mut end = open;
+        for (index, item) in items.iter().enumerate() {
+            self.gap(end, start(item), index == 0, false, false);
+            end = print(self, item);
+            self.inner_comments(end);
+        }
+
+        let close = self.find(end, b'}');
+        self.gap(end, close, items.is_empty(), true, false);
+
+        self.indent -= 1;
+        self.line("}");
+
+        close + 1
+    }
+
+    /// Prints the comments and blank lines between `start` and `end` in the program string.
+    ///
+    /// A comment on the same line as the previous item is appended to its last line.
+    /// Consecutive blank lines are collapsed into one and are removed at the start and end of a block.
+    fn gap(&mut self, start: usize, end: usize, first: bool, last: bool, force_blank: bool) {
+        let mut position = start;
+        let mut leading = true;
+
+        for index in 0..self.comments.len() {
+            let comment = &self.comments[index];
+            if self.printed[index] || comment.start < start || comment.start >= end {
+                continue;
+            }
+            let (text, comment_start, comment_end) = (comment.text.clone(), comment.start, comment.end);
+            let is_line_comment = comment.is_line_comment();
+            let newlines = self.newlines(position, comment_start);
+
+            if newlines == 0 && position == start && !self.output.is_empty() && !self.last_line_comment {
+                self.append(&format!(" {}", text));
+            } else {
+                if (force_blank && leading) || (newlines > 1 && !(first && leading)) {
+                    self.blank_line();
+                }
+                self.line(&text);
+                leading = false;
+            }
+
+            self.last_line_comment = is_line_comment;
+            self.printed[index] = true;
+            position = comment_end;
+        }
+
+        if (force_blank && leading) || (self.newlines(position, end) > 1 && !last && !(first && leading)) {
+            self.blank_line();
+        }
+    }
+
+    /// Prints the remaining comments before `end` in the program string on their own lines.
+    fn inner_comments(&mut self, end: usize) {
+        for index in 0..self.comments.len() {
+            if !self.printed[index] && self.comments[index].start < end {
+                let comment = self.comments[index].clone();
+                self.line(&comment.text);
+
+                self.last_line_comment = comment.is_line_comment();
+                self.printed[index] = true;
+            }
+        }
+    }
+
+    /// Returns the position of the given token after `start` in the program string, skipping comments.
+    fn find(&self, start: usize, token: u8) -> usize {
+        let bytes = self.program_string.as_bytes();
+        let mut position = start;
+
+        while position < bytes.len() && bytes[position] != token {
+            position = match self.comments.iter().find(|comment| comment.start == position) {
+                Some(comment) => comment.end,
+                None => position + 1,
+            };
+        }
+
+        position
+    }
+
+    fn newlines(&self, start: usize, end: usize) -> usize {
+        self.program_string[start..end].matches('\n').count()
+    }
+
+    fn line(&mut self, text: &str) {
+        for _ in 0..self.indent {
+            self.output.push_str(INDENT);
+        }
+        self.output.push_str(text);
+        self.output.push('\n');
+
+        self.last_line_comment = false;
+    }
+
+    fn append(&mut self, text: &str) {
+        self.output.pop();
+        self.output.push_str(text);
+        self.output.push('\n');
+    }
+
+    fn blank_line(&mut self) {
+        if !self.output.is_empty() && !self.output.ends_with("\n\n") {
+            self.output.push('\n');
+        }
+    }
+
+    pub(crate) fn indentation(&self) -> usize {
+        self.indent
+    }
+}
+
+fn definition_start(definition: &Definition) -> usize {
+    match definition {
+        Definition::Annotated(annotated) => annotated.span.start(),
+        Definition::Import(import) => import.span.start(),
+        Definition::Circuit(circuit) => circuit.span.start(),
+        Definition::Enum(enum_) => enum_.span.start(),
+        Definition::Function(function) => function.span.start(),
+        Definition::TestFunction(test_function) => test_function.span.start(),
+    }
+}
+
+fn statement_start(statement: &Statement) -> usize {
+    match statement {
+        Statement::Return(statement) => statement.span.start(),
+        Statement::Definition(statement) => statement.span.start(),
+        Statement::Assign(statement) => statement.span.start(),
+        Statement::Conditional(statement) => statement.span.start(),
+        Statement::Iteration(statement) => statement.span.start(),
+        Statement::Match(statement) => statement.span.start(),
+        Statement::Console(statement) => statement.span.start(),
+        Statement::Expression(statement) => statement.span.start(),
+    }
+}
+
+fn circuit_member_start(member: &CircuitMember) -> usize {
+    match member {
+        CircuitMember::CircuitVariableDefinition(definition) => definition.span.start(),
+        CircuitMember::CircuitFunction(function) => function.span.start(),
+    }
+}
+
+fn package(package: &Package) -> String {
+    format!("{}.{}", package.name.value, package_access(&package.access))
+}
+
+fn package_access(access: &PackageAccess) -> String {
+    match access {
+        PackageAccess::Star(_) => "*".to_string(),
+        PackageAccess::SubPackage(sub_package) => package(sub_package),
+        PackageAccess::Symbol(symbol) => match &symbol.alias {
+            Some(alias) => format!("{} as {}", symbol.value, alias),
+            None => symbol.value.to_string(),
+        },
+        PackageAccess::Multiple(accesses) => {
+            let accesses = accesses.iter().map(package_access).collect::<Vec<_>>();
+            format!("({})", accesses.join(", "))
+        }
+    }
+}
+
+fn variable_name(name: &VariableName) -> String {
+    let mutable = if name.mutable.is_some() { "mut " } else { "" };
+    format!("{}{}", mutable, name.identifier)
+}
+
+pub(crate) fn match_pattern(pattern: &MatchPattern) -> String {
+    match pattern {
+        MatchPattern::Wildcard(_) => "_".to_string(),
+        MatchPattern::Variant(variant) => {
+            let bindings = variant.bindings.iter().map(|binding| binding.value.clone()).collect::<Vec<_>>();
+            if bindings.is_empty() {
+                format!("{}::{}", variant.enum_name, variant.variant)
+            } else {
+                format!("{}::{}({})", variant.enum_name, variant.variant, bindings.join(", "))
+            }
+        }
+    }
+}
+
+fn match_pattern_end(pattern: &MatchPattern) -> usize {
+    match pattern {
+        MatchPattern::Wildcard(wildcard) => wildcard.span.end(),
+        MatchPattern::Variant(variant) => variant.span.end(),
+    }
+}
@@ -0,0 +1,275 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Recovers from syntax errors to report every syntax error of a program in one run.
+//!
+//! The parser stops at the first syntax error. To find the next one, the statement or definition
+//! that contains the error is replaced by whitespace and the program is parsed again.
+//! Replacing code by whitespace of the same length keeps the lines and columns of the remaining code,
+//! so the errors and the partial syntax tree point to the original source.
+
+use crate::{ast, errors::SyntaxError};
+
+use pest::{
+    error::{Error, InputLocation},
+    Position,
+};
+use std::path::PathBuf;
+
+/// The maximum number of syntax errors that are reported for a program.
+const MAX_ERRORS: usize = 100;
+
+/// Keywords that start a definition when they begin a line without indentation.
+const DEFINITION_KEYWORDS: &[&str] = &["import ", "circuit ", "enum ", "function ", "test ", "@"];
+
+/// The syntax errors of a program, and the program without the code that contains them.
+#[derive(Debug)]
+pub struct RecoveredProgram {
+    /// The program with every statement and definition that contains a syntax error replaced by whitespace.
+    /// It always parses, so it can be used to build a partial abstract syntax tree.
+    pub program_string: String,
+    /// The syntax errors in the order they appear in the program.
+    pub errors: Vec<SyntaxError>,
+}
+
+impl RecoveredProgram {
+    /// Parses the program and recovers from every syntax error at the next statement or definition boundary.
+    pub fn new(file_path: &PathBuf, program_string: &str) -> Self {
+        let mut program = program_string.as_bytes().to_vec();
+        let mut removed = vec![false; program.len()];
+        let mut errors: Vec<(usize, Error<ast::Rule>)> = vec![];
+
+        loop {
+            // Only ascii whitespace replaces code, so the program is always valid utf-8
+            let current = String::from_utf8(program.clone()).expect("recovered program is valid utf-8");
+
+            let error = match ast::parse(&current) {
+                Ok(_) => break,
+                Err(error) => error,
+            };
+
+            let position = match error.location {
+                InputLocation::Pos(position) => position,
+                InputLocation::Span((start, _)) => start,
+            };
+
+            let (start, end, caused_by_removal) = match errors.len() < MAX_ERRORS {
+                true => recovery_range(&program, &removed, position),
+                false => (0, program.len(), true),
+            };
+
+            if !caused_by_removal {
+                // Report the error on the original source, as the current line may contain removed code
+                let error = match Position::new(program_string, position) {
+                    Some(original_position) => Error::new_from_pos(error.variant, original_position),
+                    None => error,
+                };
+
+                errors.push((position, error));
+            }
+
+            remove_code(&mut program, &mut removed, start, end);
+        }
+
+        errors.sort_by_key(|(position, _)| *position);
+
+        let errors = errors
+            .into_iter()
+            .map(|(_, error)| SyntaxError::from(error.with_path(file_path.to_str().unwrap())))
+            .collect();
+
+        Self {
+            program_string: String::from_utf8(program).expect("recovered program is valid utf-8"),
+            errors,
+        }
+    }
+}
+
+/// Returns the range of the statement or definition to remove for the syntax error at the given position.
+/// Falls back to the enclosing statement or definition if the range does not contain any code.
+/// Also returns `true` if the error is caused by code that was removed before.
+fn recovery_range(program: &[u8], removed: &[bool], position: usize) -> (usize, usize, bool) {
+    let code = code_mask(program);
+    let ends = statement_ends(program, &code);
+    let mut position = position.min(program.len());
+
+    // A statement that only contained removed code cannot be parsed either
+    let (start, end) = statement_range(program, &code, &ends, position);
+    let caused_by_removal = !contains_code(&program[start..end]) && removed[start..end].iter().any(|removed| *removed);
+
+    loop {
+        let (start, end) = statement_range(program, &code, &ends, position);
+
+        if contains_code(&program[start..end]) {
+            return (start, end, caused_by_removal);
+        }
+
+        // Recover at the statement or definition that contains the innermost open block
+        match innermost_open_brace(program, &code, position) {
+            Some(brace) => position = brace,
+            None => return (0, program.len(), caused_by_removal),
+        }
+    }
+}
+
+/// Returns the range from the end of the previous statement to the end of the statement at the given position.
+/// A statement ends after a `;` or a block, and before a `}` that closes the enclosing block.
+fn statement_range(program: &[u8], code: &[bool], ends: &[bool], position: usize) -> (usize, usize) {
+    let start = (0..position)
+        .rev()
+        .find(|&i| ends[i] || (code[i] && matches!(program[i], b'{' | b'}')))
+        .map(|i| i + 1)
+        .unwrap_or(0);
+
+    let mut depth = 0usize;
+    let mut i = start;
+
+    while i < program.len() {
+        // Never remove the next definition
+        if i > start && i >= position && starts_definition(program, code, i) {
+            return (start, i);
+        }
+
+        if code[i] {
+            match program[i] {
+                b'{' => depth += 1,
+                b'}' if depth == 0 => return (start, i),
+                b'}' => {
+                    depth -= 1;
+
+                    if depth == 0 && i >= position && !followed_by_else(program, code, i + 1) {
+                        return (start, i + 1);
+                    }
+                }
+                b';' if depth == 0 && ends[i] && i >= position => return (start, i + 1),
+                _ => {}
+            }
+        }
+
+        i += 1;
+    }
+
+    (start, program.len())
+}
+
+/// Returns `true` for every `;` that ends a statement.
+/// Semicolons inside brackets or parentheses are part of an expression, such as the length of an array.
+fn statement_ends(program: &[u8], code: &[bool]) -> Vec<bool> {
+    let mut ends = vec![false; program.len()];
+    let mut depth = 0usize;
+
+    for i in 0..program.len() {
+        if code[i] {
+            match program[i] {
+                b'(' | b'[' => depth += 1,
+                b')' | b']' => depth = depth.saturating_sub(1),
+                // Unclosed brackets never continue into another block
+                b'{' | b'}' => depth = 0,
+                b';' => ends[i] = depth == 0,
+                _ => {}
+            }
+        }
+    }
+
+    ends
+}
+
+/// Returns the position of the innermost `{` that is still open at the given position.
+fn innermost_open_brace(program: &[u8], code: &[bool], position: usize) -> Option<usize> {
+    let mut open = vec![];
+
+    for i in 0..position {
+        if code[i] {
+            match program[i] {
+                b'{' => open.push(i),
+                b'}' => {
+                    open.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    open.pop()
+}
+
+/// Returns `true` if a definition keyword begins an unindented line at the given position.
+fn starts_definition(program: &[u8], code: &[bool], position: usize) -> bool {
+    let line_start = position == 0 || program[position - 1] == b'\n';
+
+    line_start
+        && code[position]
+        && DEFINITION_KEYWORDS
+            .iter()
+            .any(|keyword| program[position..].starts_with(keyword.as_bytes()))
+}
+
+/// Returns `true` if the next code after the given position is an `else` branch.
+fn followed_by_else(program: &[u8], code: &[bool], position: usize) -> bool {
+    (position..program.len())
+        .find(|&i| code[i] && !program[i].is_ascii_whitespace())
+        .map(|i| program[i..].starts_with(b"else"))
+        .unwrap_or(false)
+}
+
+fn contains_code(bytes: &[u8]) -> bool {
+    bytes.iter().any(|byte| !byte.is_ascii_whitespace())
+}
+
+/// Returns `true` for every byte of the program that is not part of a comment or a string.
+fn code_mask(program: &[u8]) -> Vec<bool> {
+    let mut code = vec![true; program.len()];
+    let mut i = 0;
+
+    while i < program.len() {
+        let end = if program[i..].starts_with(b"//") {
+            (i..program.len())
+                .find(|&j| program[j] == b'\n')
+                .unwrap_or(program.len())
+        } else if program[i..].starts_with(b"/*") {
+            (i + 2..program.len())
+                .find(|&j| program[j..].starts_with(b"*/"))
+                .map(|j| j + 2)
+                .unwrap_or(program.len())
+        } else if program[i] == b'"' {
+            (i + 1..program.len())
+                .find(|&j| program[j] == b'"')
+                .map(|j| j + 1)
+                .unwrap_or(program.len())
+        } else {
+            i += 1;
+            continue;
+        };
+
+        for masked in code.iter_mut().take(end).skip(i) {
+            *masked = false;
+        }
+
+        i = end;
+    }
+
+    code
+}
+
+/// Replaces the code in the given range by spaces, keeping line breaks.
+fn remove_code(program: &mut [u8], removed: &mut [bool], start: usize, end: usize) {
+    for (byte, removed) in program[start..end].iter_mut().zip(removed[start..end].iter_mut()) {
+        if !byte.is_ascii_whitespace() {
+            *byte = b' ';
+            *removed = true;
+        }
+    }
+}
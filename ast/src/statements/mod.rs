@@ -32,6 +32,9 @@ pub use expression_statement::*;
 pub mod for_statement;
 pub use for_statement::*;
 
+pub mod match_statement;
+pub use match_statement::*;
+
 pub mod return_statement;
 pub use return_statement::*;
 
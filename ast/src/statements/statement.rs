@@ -28,6 +28,7 @@ pub enum Statement<'ast> {
     Assign(AssignStatement<'ast>),
     Conditional(ConditionalStatement<'ast>),
     Iteration(ForStatement<'ast>),
+    Match(MatchStatement<'ast>),
     Console(ConsoleFunctionCall<'ast>),
     Expression(ExpressionStatement<'ast>),
 }
@@ -40,6 +41,7 @@ impl<'ast> fmt::Display for Statement<'ast> {
             Statement::Assign(ref statement) => write!(f, "{}", statement),
             Statement::Conditional(ref statement) => write!(f, "{}", statement),
             Statement::Iteration(ref statement) => write!(f, "{}", statement),
+            Statement::Match(ref statement) => write!(f, "{}", statement),
             Statement::Console(ref statement) => write!(f, "{}", statement),
             Statement::Expression(ref statement) => write!(f, "{}", statement.expression),
         }
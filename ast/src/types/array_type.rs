@@ -16,7 +16,7 @@
 
 use crate::{
     ast::Rule,
-    types::{ArrayDimensions, Type},
+    types::{ArrayDimensions, ConstDimension, Type},
     SpanDef,
 };
 
@@ -28,8 +28,24 @@ use serde::Serialize;
 #[pest_ast(rule(Rule::type_array))]
 pub struct ArrayType<'ast> {
     pub type_: Box<Type<'ast>>,
-    pub dimensions: ArrayDimensions<'ast>,
+    pub dimensions: ArrayTypeDimensions<'ast>,
     #[pest_ast(outer())]
     #[serde(with = "SpanDef")]
     pub span: Span<'ast>,
 }
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::type_array_dimensions))]
+pub enum ArrayTypeDimensions<'ast> {
+    Dimensions(ArrayDimensions<'ast>),
+    Const(ConstDimension<'ast>),
+}
+
+impl<'ast> std::fmt::Display for ArrayTypeDimensions<'ast> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            ArrayTypeDimensions::Dimensions(ref dimensions) => write!(f, "{}", dimensions),
+            ArrayTypeDimensions::Const(ref dimension) => write!(f, "{}", dimension.identifier),
+        }
+    }
+}
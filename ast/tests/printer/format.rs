@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{printer::parse_comments, LeoAst};
+
+use serde_json::Value;
+use std::{fs, path::PathBuf};
+
+/// Returns the abstract syntax tree as JSON without spans and whitespace in the original source code.
+fn normalized_json(program_string: &str) -> Value {
+    fn normalize(value: Value) -> Value {
+        match value {
+            Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .filter(|(key, _)| key != "span")
+                    .map(|(key, value)| (key, normalize(value)))
+                    .collect(),
+            ),
+            Value::Array(values) => Value::Array(values.into_iter().map(normalize).collect()),
+            Value::String(string) => Value::String(string.split_whitespace().collect()),
+            value => value,
+        }
+    }
+
+    let path = PathBuf::from("main.leo");
+    let ast = LeoAst::new(&path, program_string).unwrap();
+
+    normalize(serde_json::from_str(&ast.to_json_string().unwrap()).unwrap())
+}
+
+fn comments(program_string: &str) -> Vec<String> {
+    let mut comments = parse_comments(program_string)
+        .into_iter()
+        .map(|comment| comment.text)
+        .collect::<Vec<_>>();
+    comments.sort();
+
+    comments
+}
+
+fn format(program_string: &str) -> String {
+    let path = PathBuf::from("main.leo");
+    let ast = LeoAst::new(&path, program_string).unwrap();
+
+    ast.to_formatted_string()
+}
+
+fn leo_files(path: PathBuf, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(path).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+
+        if path.is_dir() && !name.starts_with('.') && name != "target" {
+            leo_files(path, files);
+        } else if name.ends_with(".leo") {
+            files.push(path);
+        }
+    }
+}
+
+#[test]
+fn test_format_comments() {
+    let program_string = include_str!("comments.leo");
+    let expected = include_str!("comments_formatted.leo");
+
+    assert_eq!(expected, format(program_string));
+}
+
+#[test]
+fn test_format_repository_files() {
+    let mut files = vec![];
+    leo_files(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".."), &mut files);
+
+    let mut formatted_files = 0;
+    for file in files {
+        let program_string = fs::read_to_string(&file).unwrap();
+
+        // Some test files contain syntax errors on purpose
+        if LeoAst::new(&file, &program_string).is_err() {
+            continue;
+        }
+
+        let formatted = format(&program_string);
+        let message = format!("{}\n{}", file.display(), formatted);
+
+        assert_eq!(normalized_json(&program_string), normalized_json(&formatted), "{}", message);
+        assert_eq!(comments(&program_string), comments(&formatted), "{}", message);
+        assert_eq!(formatted, format(&formatted), "{}", message);
+
+        formatted_files += 1;
+    }
+
+    assert!(formatted_files > 0);
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{LeoAst, ParserError, RecoveredProgram, SyntaxError};
+
+use pest::error::LineColLocation;
+use std::path::PathBuf;
+
+/// Returns the line and column of each syntax error.
+fn error_positions(errors: &[SyntaxError]) -> Vec<(usize, usize)> {
+    errors
+        .iter()
+        .map(|error| match error {
+            SyntaxError::Error(error) => match error.line_col {
+                LineColLocation::Pos(position) => position,
+                LineColLocation::Span(start, _) => start,
+            },
+        })
+        .collect()
+}
+
+#[test]
+fn test_recover_syntax_errors() {
+    let path = PathBuf::from("syntax_errors.leo");
+    let program_string = include_str!("syntax_errors.leo");
+
+    let recovered = RecoveredProgram::new(&path, program_string);
+    let (ast, errors) = LeoAst::new_partial(&recovered).unwrap();
+
+    assert_eq!(error_positions(errors), vec![
+        (9, 17),
+        (14, 17),
+        (16, 17),
+        (20, 1),
+        (29, 14)
+    ]);
+
+    // The partial syntax tree keeps every statement and definition without errors
+
+    assert_eq!(include_str!("syntax_errors_recovered.leo"), ast.to_formatted_string());
+}
+
+#[test]
+fn test_recover_missing_brace() {
+    let path = PathBuf::from("missing_brace.leo");
+    let program_string = include_str!("missing_brace.leo");
+
+    let recovered = RecoveredProgram::new(&path, program_string);
+    let (ast, errors) = LeoAst::new_partial(&recovered).unwrap();
+
+    assert_eq!(error_positions(errors), vec![(4, 1)]);
+
+    assert_eq!("function b() {\n    let y = 2u32;\n}\n", ast.to_formatted_string());
+}
+
+#[test]
+fn test_parser_error_reports_all_syntax_errors() {
+    let path = PathBuf::from("syntax_errors.leo");
+    let program_string = include_str!("syntax_errors.leo");
+
+    match LeoAst::new(&path, program_string) {
+        Err(ParserError::SyntaxErrors(errors)) => assert_eq!(errors.len(), 5),
+        _ => panic!("expected every syntax error of the program"),
+    }
+}
+
+#[test]
+fn test_partial_ast_without_syntax_errors() {
+    let path = PathBuf::from("syntax_errors_recovered.leo");
+    let program_string = include_str!("syntax_errors_recovered.leo");
+
+    let recovered = RecoveredProgram::new(&path, program_string);
+    let (ast, errors) = LeoAst::new_partial(&recovered).unwrap();
+
+    assert!(errors.is_empty());
+    assert_eq!(program_string, ast.to_formatted_string());
+}
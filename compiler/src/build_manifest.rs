@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A record of everything that determines the shape of a compiled Leo circuit.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    env::current_dir,
+    path::{Path, PathBuf},
+};
+
+/// The version of the compiler that generated the build manifest.
+pub static COMPILER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A source file that was compiled into the circuit.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SourceFile {
+    pub path: PathBuf,
+    pub sha256: String,
+}
+
+impl SourceFile {
+    pub fn new(path: &Path, source: &str) -> Self {
+        // Record paths relative to the current directory so that moving a package keeps its checksum.
+        let path = current_dir()
+            .ok()
+            .and_then(|directory| path.strip_prefix(directory).ok().map(|path| path.to_path_buf()))
+            .unwrap_or_else(|| path.to_path_buf());
+
+        Self {
+            path,
+            sha256: sha256(source.as_bytes()),
+        }
+    }
+}
+
+/// Lists the compiler version, sources, core packages, input declarations, and circuit options of a build.
+/// The build checksum is the hash of this manifest.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct BuildManifest {
+    pub compiler_version: String,
+    pub main_file: SourceFile,
+    pub imports: Vec<SourceFile>,
+    pub core_packages: Vec<String>,
+    pub inputs: Vec<String>,
+    /// Omitted unless set, so that existing build checksums are unchanged.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub enforce_local_data: bool,
+}
+
+impl BuildManifest {
+    /// Returns the hex encoded SHA-256 hash of the manifest.
+    pub fn checksum(&self) -> String {
+        sha256(self.to_json_string().as_bytes())
+    }
+
+    pub fn to_json_string(&self) -> String {
+        // Serializing a struct of strings and sequences cannot fail
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+fn sha256(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    let hash = hasher.finalize();
+
+    hex::encode(hash)
+}
@@ -18,16 +18,22 @@
 
 use crate::{
     constraints::{generate_constraints, generate_test_constraints},
-    errors::CompilerError,
+    errors::{CompilerError, InputSignatureError},
+    interpreter::Interpreter,
+    optimize_program,
+    BuildManifest,
     GroupType,
     ImportParser,
+    MainSignature,
     OutputBytes,
     OutputFile,
+    SourceFile,
+    COMPILER_VERSION,
 };
 use leo_ast::LeoAst;
 use leo_input::LeoInputParser;
 use leo_package::inputs::InputPairs;
-use leo_state::verify_local_data_commitment;
+use leo_state::{verify_local_data_commitment, LocalDataVerificationError, StateValues};
 use leo_typed::{Input, LeoTypedAst, MainInput, Program};
 
 use snarkos_dpc::{base_dpc::instantiated::Components, SystemParameters};
@@ -37,8 +43,7 @@ use snarkos_models::{
     gadgets::r1cs::{ConstraintSynthesizer, ConstraintSystem},
 };
 
-use sha2::{Digest, Sha256};
-use std::{fs, marker::PhantomData, path::PathBuf};
+use std::{convert::TryFrom, fs, marker::PhantomData, path::PathBuf};
 
 #[derive(Clone)]
 pub struct Compiler<F: Field + PrimeField, G: GroupType<F>> {
@@ -48,6 +53,7 @@ pub struct Compiler<F: Field + PrimeField, G: GroupType<F>> {
     program: Program,
     program_input: Input,
     imported_programs: ImportParser,
+    enforce_local_data: bool,
     _engine: PhantomData<F>,
     _group: PhantomData<G>,
 }
@@ -61,6 +67,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
             program: Program::new(package_name),
             program_input: Input::new(),
             imported_programs: ImportParser::new(),
+            enforce_local_data: false,
             _engine: PhantomData,
             _group: PhantomData,
         }
@@ -134,6 +141,27 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
         Ok(compiler)
     }
 
+    /// Returns a copy of the parsed program that runs on the given input and state files.
+    /// The program output is written to the output file named after the input files.
+    pub fn with_input(
+        &self,
+        input_name: &str,
+        input_string: &str,
+        input_path: PathBuf,
+        state_string: &str,
+        state_path: PathBuf,
+    ) -> Result<Self, CompilerError> {
+        let mut compiler = Self {
+            package_name: input_name.to_owned(),
+            program_input: Input::new(),
+            ..self.clone()
+        };
+
+        compiler.parse_input(input_string, input_path, state_string, state_path)?;
+
+        Ok(compiler)
+    }
+
     /// Parses the Leo program file, constructs a syntax tree, and generates a program.
     pub(crate) fn parse_program(&mut self) -> Result<(), CompilerError> {
         // Use the parser to construct the abstract syntax tree.
@@ -167,11 +195,65 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
         Ok(())
     }
 
+    /// Folds constant expressions and removes dead branches in the program and its imports.
+    /// Reduces the number of constraints generated for the program without changing its outputs.
+    pub fn optimize(&mut self) {
+        self.program = optimize_program(self.program.clone());
+        self.imported_programs.optimize();
+    }
+
+    /// Returns the signature of the `main` function of the program.
+    pub fn main_signature(&self) -> Result<MainSignature, CompilerError> {
+        MainSignature::new(&self.program, &self.imported_programs).ok_or(CompilerError::NoMain)
+    }
+
+    /// Compares the `[main]` section of a program input file with the parameters of `main`.
+    pub fn check_input_file(
+        &self,
+        input_string: &str,
+        input_path: &PathBuf,
+    ) -> Result<Vec<InputSignatureError>, CompilerError> {
+        let signature = self.main_signature()?;
+
+        Ok(signature.check_input_file(input_string, input_path, &self.main_file_path)?)
+    }
+
     /// Manually sets main function input
     pub fn set_main_input(&mut self, input: MainInput) {
         self.program_input.set_main_input(input);
     }
 
+    /// Enforces the record commitment and local data Merkle path of the program state in the circuit.
+    /// The local data root becomes a public input of the circuit.
+    pub fn enforce_local_data(&mut self) {
+        self.enforce_local_data = true;
+    }
+
+    /// Returns the public inputs of the circuit.
+    /// Contains the local data root of the program state if the local data commitment is enforced.
+    pub fn public_inputs(&self) -> Result<Vec<F>, CompilerError> {
+        if !self.enforce_local_data {
+            return Ok(vec![]);
+        }
+
+        let state = StateValues::try_from(self.program_input.get_state()).map_err(LocalDataVerificationError::from)?;
+        let root = F::read(&state.root[..]).map_err(LocalDataVerificationError::from)?;
+
+        Ok(vec![root])
+    }
+
+    /// Loads the system parameters if the local data commitment is enforced.
+    fn system_parameters(&self) -> Result<Option<SystemParameters<Components>>, CompilerError> {
+        if !self.enforce_local_data {
+            return Ok(None);
+        }
+
+        let system_parameters =
+            SystemParameters::<Components>::load().map_err(CompilerError::SystemParametersError)?;
+
+        Ok(Some(system_parameters))
+    }
+
     /// Verifies the input to the program
     pub fn verify_local_data_commitment(
         &self,
@@ -182,30 +264,78 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
         Ok(result)
     }
 
-    pub fn checksum(&self) -> Result<String, CompilerError> {
+    /// Returns the compiler version, sources, core packages, and input declarations
+    /// that determine the shape of the compiled circuit.
+    pub fn build_manifest(&self) -> Result<BuildManifest, CompilerError> {
         // Read in the main file as string
         let unparsed_file = fs::read_to_string(&self.main_file_path)
             .map_err(|_| CompilerError::FileReadError(self.main_file_path.clone()))?;
 
-        // Hash the file contents
-        let mut hasher = Sha256::new();
-        hasher.update(unparsed_file.as_bytes());
-        let hash = hasher.finalize();
+        let imports = self
+            .imported_programs
+            .sources()
+            .iter()
+            .map(|(path, source)| SourceFile::new(path, source))
+            .collect();
+
+        let mut core_packages = self
+            .imported_programs
+            .core_packages()
+            .iter()
+            .map(|package| package.to_string())
+            .collect::<Vec<_>>();
+        core_packages.sort();
+
+        Ok(BuildManifest {
+            compiler_version: COMPILER_VERSION.to_string(),
+            main_file: SourceFile::new(&self.main_file_path, &unparsed_file),
+            imports,
+            core_packages,
+            inputs: self.program_input.shape(),
+            enforce_local_data: self.enforce_local_data,
+        })
+    }
 
-        Ok(hex::encode(hash))
+    /// Returns the hash of the build manifest.
+    /// Changes whenever a source file, the compiler version, or the shape of the input changes.
+    pub fn checksum(&self) -> Result<String, CompilerError> {
+        Ok(self.build_manifest()?.checksum())
     }
 
     /// Synthesizes the circuit without program input to verify correctness.
     pub fn compile_constraints<CS: ConstraintSystem<F>>(self, cs: &mut CS) -> Result<OutputBytes, CompilerError> {
+        let system_parameters = self.system_parameters()?;
         let path = self.main_file_path;
 
-        generate_constraints::<F, G, CS>(cs, self.program, self.program_input, &self.imported_programs).map_err(
-            |mut error| {
-                error.set_path(path);
-
-                error
-            },
+        generate_constraints::<F, G, CS>(
+            cs,
+            self.program,
+            self.program_input,
+            &self.imported_programs,
+            system_parameters.as_ref(),
         )
+        .map_err(|mut error| {
+            error.set_path(path);
+
+            error
+        })
+    }
+
+    /// Runs the main function natively with the reference interpreter instead of synthesizing the circuit.
+    pub fn interpret(&self) -> Result<OutputBytes, CompilerError> {
+        let result = Interpreter::new(&self.program, &self.imported_programs)
+            .and_then(|interpreter| interpreter.run_main(&self.program, &self.program_input));
+
+        match result {
+            Ok(Some(output)) => Ok(output),
+            Ok(None) => Err(CompilerError::NoMain),
+            Err(error) => {
+                let mut error = CompilerError::from(error);
+                error.set_path(self.main_file_path.clone());
+
+                Err(error)
+            }
+        }
     }
 
     /// Synthesizes the circuit for test functions with program input.
@@ -224,13 +354,20 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
         self,
         cs: &mut CS,
     ) -> Result<OutputBytes, CompilerError> {
+        let system_parameters = self.system_parameters()?;
         let path = self.main_file_path;
-        generate_constraints::<_, G, _>(cs, self.program, self.program_input, &self.imported_programs).map_err(
-            |mut error| {
-                error.set_path(path);
-                error
-            },
+
+        generate_constraints::<_, G, _>(
+            cs,
+            self.program,
+            self.program_input,
+            &self.imported_programs,
+            system_parameters.as_ref(),
         )
+        .map_err(|mut error| {
+            error.set_path(path);
+            error
+        })
     }
 
     pub fn to_bytes(&self) -> Result<Vec<u8>, CompilerError> {
@@ -248,6 +385,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
             program,
             program_input,
             imported_programs: ImportParser::new(),
+            enforce_local_data: false,
             _engine: PhantomData,
             _group: PhantomData,
         })
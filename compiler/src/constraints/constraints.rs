@@ -17,30 +17,44 @@
 //! Generates R1CS constraints for a compiled Leo program.
 
 use crate::{
-    errors::CompilerError,
+    errors::{CompilerError, OutputFileError},
+    fuzz_test_function,
     new_scope,
     ConstrainedProgram,
     ConstrainedValue,
+    Coverage,
+    CoverageFile,
     GroupType,
     ImportParser,
     OutputBytes,
     OutputFile,
 };
-use leo_typed::{Input, Program};
+use leo_typed::{Diagnostic, DiagnosticRange, Input, MainInput, Program, RelatedSpan, Span};
 
 use leo_input::LeoInputParser;
-use leo_package::inputs::InputPairs;
+use leo_package::inputs::{InputPairs, INPUT_FILE_EXTENSION};
+use snarkos_dpc::base_dpc::{instantiated::Components, parameters::SystemParameters};
 use snarkos_models::{
     curves::{Field, PrimeField},
     gadgets::r1cs::{ConstraintSystem, TestConstraintSystem},
 };
-use std::path::PathBuf;
+use rand::thread_rng;
+use std::{fs, path::PathBuf};
+
+/// Error codes of failures that a circuit can reach at runtime: failed assertions,
+/// out-of-bounds indices, and synthesis errors such as integer overflow.
+/// A `@should_fail` test passes only if it fails with one of these errors or leaves its constraints unsatisfied.
+const EXPECTED_FAILURE_CODES: &[&str] = &[
+    "E0212", "E0245", "E0301", "E0309", "E0402", "E0410", "E0420", "E0421", "E0425", "E0430", "E0431", "E0434",
+    "E0450", "E0451", "E0452", "E0453",
+];
 
 pub fn generate_constraints<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
     cs: &mut CS,
     program: Program,
     input: Input,
     imported_programs: &ImportParser,
+    system_parameters: Option<&SystemParameters<Components>>,
 ) -> Result<OutputBytes, CompilerError> {
     let mut resolved_program = ConstrainedProgram::<F, G>::new();
     let program_name = program.get_name();
@@ -54,7 +68,14 @@ pub fn generate_constraints<F: Field + PrimeField, G: GroupType<F>, CS: Constrai
 
     match main.clone() {
         ConstrainedValue::Function(_circuit_identifier, function) => {
-            let result = resolved_program.enforce_main_function(cs, program_name, function, input)?;
+            let result =
+                resolved_program.enforce_main_function(cs, program_name.clone(), function.clone(), input.clone())?;
+
+            // Enforce the local data commitment of the program state
+            if let Some(system_parameters) = system_parameters {
+                resolved_program.enforce_local_data(cs, program_name, &function, &input, system_parameters)?;
+            }
+
             Ok(result)
         }
         _ => Err(CompilerError::NoMainFunction),
@@ -85,9 +106,20 @@ pub fn generate_test_constraints<F: Field + PrimeField, G: GroupType<F>>(
     let mut passed = 0;
     let mut failed = 0;
 
+    // Record executed statements and branches of all tests
+    let mut coverage = Coverage::new();
+
     for (test_name, test) in tests.into_iter() {
-        let cs = &mut TestConstraintSystem::<F>::new();
         let full_test_name = format!("{}::{}", program_name.clone(), test_name.to_string());
+
+        if test.ignore {
+            tracing::info!("{} ... ignored\n", full_test_name);
+
+            continue;
+        }
+
+        let cs = &mut TestConstraintSystem::<F>::new();
+        let test_span = test.function.span.clone();
         let mut output_file_name = program_name.clone();
 
         // get input file name from annotation or use test_name
@@ -98,33 +130,158 @@ pub fn generate_test_constraints<F: Field + PrimeField, G: GroupType<F>>(
                 output_file_name = file_name.clone();
 
                 match input.pairs.get(&file_name) {
-                    Some(pair) => pair.to_owned(),
+                    Some(pair) => Some(pair),
                     None => return Err(CompilerError::InvalidTestContext(file_name)),
                 }
             }
-            None => default.ok_or(CompilerError::NoTestInput)?,
+            // fuzz tests generate their own main function input
+            None if test.fuzz_iterations.is_some() => default,
+            // inline inputs replace the main function input
+            None if test.inputs.is_some() => default,
+            None => Some(default.ok_or(CompilerError::NoTestInput)?),
         };
 
-        // parse input files to abstract syntax trees
-        let input_file = &input_pair.input_file;
-        let state_file = &input_pair.state_file;
-
-        let input_ast = LeoInputParser::parse_file(input_file)?;
-        let state_ast = LeoInputParser::parse_file(state_file)?;
-
         // parse input files into input struct
         let mut input = Input::new();
-        input.parse_input(input_ast)?;
-        input.parse_state(state_ast)?;
+
+        if let Some(input_pair) = input_pair {
+            // parse input files to abstract syntax trees
+            let input_ast = LeoInputParser::parse_file(&input_pair.input_file)?;
+            let state_ast = LeoInputParser::parse_file(&input_pair.state_file)?;
+
+            input.parse_input(input_ast)?;
+            input.parse_state(state_ast)?;
+        }
+
+        // parse inline inputs from annotation
+        if let Some(inputs) = &test.inputs {
+            let inputs_string = inline_input_file_string(inputs);
+
+            input.set_main_input(MainInput::new());
+
+            // a malformed annotation only fails its own test
+            if let Err(error) =
+                LeoInputParser::parse_file(&inputs_string).and_then(|inputs_ast| input.parse_input(inputs_ast))
+            {
+                tracing::error!("{} has invalid inline inputs\n\n{}\n", full_test_name, error);
+
+                let diagnostic = Diagnostic::error(format!("invalid inline inputs: {}", error));
+                test_failure_diagnostic(diagnostic, &full_test_name, &test_span, main_file_path).emit();
+
+                // increment failed tests
+                failed += 1;
+
+                continue;
+            }
+        }
+
+        // run test function on randomly generated input
+        if let Some(iterations) = test.fuzz_iterations {
+            let failure = fuzz_test_function(
+                &resolved_program,
+                &program_name,
+                &test.function,
+                &input,
+                iterations,
+                &mut thread_rng(),
+                &mut coverage,
+            )?;
+
+            match failure {
+                None => {
+                    tracing::info!("{} ... ok ({} cases)\n", full_test_name, iterations);
+
+                    // increment passed tests
+                    passed += 1;
+                }
+                Some(failure) => {
+                    // write the shrunk failing case to an input file
+                    let mut input_file_path = output_directory.clone();
+                    input_file_path.push(format!("{}{}", test_name, INPUT_FILE_EXTENSION));
+
+                    fs::write(&input_file_path, failure.input_file_string(&full_test_name, &input))
+                        .map_err(OutputFileError::from)?;
+
+                    let inputs = failure
+                        .inputs
+                        .iter()
+                        .map(|(name, _type, value)| format!("{} = {}", name, value))
+                        .collect::<Vec<_>>();
+
+                    tracing::error!(
+                        "{} failed for input ({}) written to {:?}\n\n{}\n",
+                        full_test_name,
+                        inputs.join(", "),
+                        input_file_path,
+                        failure.reason
+                    );
+
+                    let message = format!("failed for input ({}): {}", inputs.join(", "), failure.reason);
+                    test_failure_diagnostic(Diagnostic::error(message), &full_test_name, &test_span, main_file_path)
+                        .emit();
+
+                    // increment failed tests
+                    failed += 1;
+                }
+            }
+
+            continue;
+        }
 
         // run test function on new program with input
-        let result = resolved_program.clone().enforce_main_function(
+        let mut test_program = resolved_program.clone();
+        let result = test_program.enforce_main_function(
             cs,
             program_name.clone(),
             test.function,
             input, // pass program input into every test
         );
 
+        coverage.merge(test_program.coverage);
+
+        if test.should_fail {
+            match (result, cs.is_satisfied()) {
+                (Ok(_), true) => {
+                    tracing::error!("{} succeeded but was expected to fail\n", full_test_name);
+
+                    let diagnostic = Diagnostic::error("succeeded but was expected to fail".to_owned());
+                    test_failure_diagnostic(diagnostic, &full_test_name, &test_span, main_file_path).emit();
+
+                    // increment failed tests
+                    failed += 1;
+                }
+                (Ok(_), false) => {
+                    tracing::info!("{} ... ok (constraint system not satisfied)\n", full_test_name);
+
+                    // increment passed tests
+                    passed += 1;
+                }
+                (Err(error), _) if EXPECTED_FAILURE_CODES.contains(&error.code()) => {
+                    tracing::info!("{} ... ok (failed due to error)\n\n{}\n", full_test_name, error);
+
+                    // increment passed tests
+                    passed += 1;
+                }
+                (Err(mut error), _) => {
+                    error.set_path(main_file_path.clone());
+
+                    tracing::error!(
+                        "{} failed due to an error that is not a circuit failure\n\n{}\n",
+                        full_test_name,
+                        error
+                    );
+
+                    test_failure_diagnostic(Diagnostic::from(&error), &full_test_name, &test_span, main_file_path)
+                        .emit();
+
+                    // increment failed tests
+                    failed += 1;
+                }
+            }
+
+            continue;
+        }
+
         match (result.is_ok(), cs.is_satisfied()) {
             (true, true) => {
                 tracing::info!("{} ... ok\n", full_test_name);
@@ -141,6 +298,9 @@ pub fn generate_test_constraints<F: Field + PrimeField, G: GroupType<F>>(
             (true, false) => {
                 tracing::error!("{} constraint system not satisfied\n", full_test_name);
 
+                let diagnostic = Diagnostic::error("constraint system not satisfied".to_owned());
+                test_failure_diagnostic(diagnostic, &full_test_name, &test_span, main_file_path).emit();
+
                 // increment failed tests
                 failed += 1;
             }
@@ -151,11 +311,66 @@ pub fn generate_test_constraints<F: Field + PrimeField, G: GroupType<F>>(
 
                 tracing::error!("{} failed due to error\n\n{}\n", full_test_name, error);
 
+                test_failure_diagnostic(Diagnostic::from(&error), &full_test_name, &test_span, main_file_path).emit();
+
                 // increment failed tests
                 failed += 1;
             }
         }
     }
 
+    // write coverage report to file
+    let (lines_found, lines_hit) = coverage.lines_found_hit(&program_name);
+    let (branches_found, branches_hit) = coverage.branches_found_hit(&program_name);
+    let coverage_file = CoverageFile::new(&program_name);
+
+    coverage_file.write(output_directory, &coverage.to_lcov(&program_name, main_file_path))?;
+
+    tracing::info!(
+        "Coverage: {}/{} lines, {}/{} branches\n",
+        lines_hit,
+        lines_found,
+        branches_hit,
+        branches_found
+    );
+
     Ok((passed, failed))
 }
+
+/// Returns a diagnostic for a failed test.
+/// Failures without a location of their own point to the test function.
+fn test_failure_diagnostic(
+    mut diagnostic: Diagnostic,
+    test_name: &str,
+    test_span: &Span,
+    main_file_path: &PathBuf,
+) -> Diagnostic {
+    let file = Some(main_file_path.to_string_lossy().to_string());
+    let range = DiagnosticRange::from(test_span);
+
+    match diagnostic.range {
+        Some(_) => diagnostic.add_related(RelatedSpan {
+            file,
+            range,
+            message: format!("test `{}` failed", test_name),
+        }),
+        None => {
+            diagnostic.message = format!("test `{}` {}", test_name, diagnostic.message);
+            diagnostic.file = file;
+            diagnostic.range = Some(range);
+        }
+    }
+
+    diagnostic
+}
+
+/// Returns an input file with a `[main]` section containing the given input declarations.
+fn inline_input_file_string(inputs: &[String]) -> String {
+    let mut input_file = String::from("[main]\n");
+
+    for input in inputs {
+        input_file.push_str(&format!("{};\n", input));
+    }
+
+    input_file
+}
@@ -0,0 +1,364 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Generates random inputs for test functions with a `@fuzz` annotation.
+
+use crate::{
+    errors::CompilerError,
+    field_to_decimal,
+    optimizer::{signed_range, unsigned_max},
+    ConstrainedProgram,
+    Coverage,
+    GroupType,
+};
+use leo_input::types::{
+    I128Type,
+    I16Type,
+    I32Type,
+    I64Type,
+    I8Type,
+    IntegerType as InputIntegerType,
+    SignedIntegerType,
+    U128Type,
+    U16Type,
+    U32Type,
+    U64Type,
+    U8Type,
+    UnsignedIntegerType,
+};
+use leo_typed::{Function, GroupValue, Input, InputValue, InputVariable, IntegerType, MainInput, Span, Type};
+
+use rand::Rng;
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::r1cs::TestConstraintSystem,
+};
+
+/// The maximum number of inputs that are run while shrinking a failing case.
+const MAX_SHRINK_RUNS: usize = 1024;
+
+/// A failing case of a fuzzed test function.
+#[derive(Clone)]
+pub struct FuzzFailure {
+    /// The names, types, and shrunk values of the test function inputs.
+    pub inputs: Vec<(String, Type, InputValue)>,
+    /// The reason the shrunk case failed.
+    pub reason: String,
+}
+
+impl FuzzFailure {
+    /// Returns an input file that reproduces the failing case.
+    pub fn input_file_string(&self, test_name: &str, input: &Input) -> String {
+        let mut string = format!("// A failing input for `{}` found by `@fuzz`\n[main]\n", test_name);
+
+        for (name, type_, value) in self.inputs.iter() {
+            string.push_str(&format!("{}: {} = {};\n", name, type_, input_value_string(value)));
+        }
+
+        string.push_str("\n[registers]\n");
+
+        let mut registers = input.get_registers().values().into_iter().collect::<Vec<_>>();
+        registers.sort_by(|a, b| a.0.variable.name.cmp(&b.0.variable.name));
+
+        for (parameter, value) in registers {
+            if let Some(value) = value {
+                string.push_str(&format!(
+                    "{}: {} = {};\n",
+                    parameter.variable,
+                    parameter.type_,
+                    input_value_string(&value)
+                ));
+            }
+        }
+
+        string
+    }
+}
+
+/// Runs a test function on randomly generated values for its inputs.
+/// Returns the shrunk inputs of the first failing case, or `None` if every case passed.
+pub fn fuzz_test_function<F: Field + PrimeField, G: GroupType<F>, R: Rng>(
+    program: &ConstrainedProgram<F, G>,
+    program_name: &str,
+    function: &Function,
+    input: &Input,
+    iterations: usize,
+    rng: &mut R,
+    coverage: &mut Coverage,
+) -> Result<Option<FuzzFailure>, CompilerError> {
+    // The input keyword is read from the input file, other inputs are generated
+    let types = function
+        .input
+        .iter()
+        .filter_map(|input_model| match input_model {
+            InputVariable::InputKeyword(_) => None,
+            InputVariable::FunctionInput(input_model) => {
+                Some((input_model.identifier.name.clone(), input_model.type_.clone()))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for _ in 0..iterations {
+        let mut inputs = vec![];
+
+        for (name, type_) in types.iter() {
+            let value = fuzz_input_value::<F, R>(type_, &function.span, rng)?;
+
+            inputs.push((name.clone(), type_.clone(), value));
+        }
+
+        if let Some(reason) = run_case(program, program_name, function, input, &inputs, coverage) {
+            return Ok(Some(shrink_case(program, program_name, function, input, inputs, reason)));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Runs a test function on the given inputs and records the executed statements and branches.
+/// Returns the reason the case failed, or `None` if it passed.
+fn run_case<F: Field + PrimeField, G: GroupType<F>>(
+    program: &ConstrainedProgram<F, G>,
+    program_name: &str,
+    function: &Function,
+    input: &Input,
+    inputs: &[(String, Type, InputValue)],
+    coverage: &mut Coverage,
+) -> Option<String> {
+    let mut main_input = MainInput::new();
+
+    for (name, _type, value) in inputs.iter() {
+        main_input.insert(name.clone(), Some(value.clone()));
+    }
+
+    let mut input = input.clone();
+    input.set_main_input(main_input);
+
+    let cs = &mut TestConstraintSystem::<F>::new();
+    let mut program = program.clone();
+    let result = program.enforce_main_function(cs, program_name.to_owned(), function.clone(), input);
+
+    coverage.merge(program.coverage);
+
+    match result {
+        Ok(_) if cs.is_satisfied() => None,
+        Ok(_) => Some("constraint system not satisfied".to_owned()),
+        Err(error) => Some(error.to_string()),
+    }
+}
+
+/// Replaces input values with simpler values for as long as the case keeps failing.
+fn shrink_case<F: Field + PrimeField, G: GroupType<F>>(
+    program: &ConstrainedProgram<F, G>,
+    program_name: &str,
+    function: &Function,
+    input: &Input,
+    mut inputs: Vec<(String, Type, InputValue)>,
+    mut reason: String,
+) -> FuzzFailure {
+    let mut runs = 0;
+    let mut shrunk = true;
+
+    while shrunk && runs < MAX_SHRINK_RUNS {
+        shrunk = false;
+
+        'inputs: for i in 0..inputs.len() {
+            for candidate in shrink_input_value(&inputs[i].2) {
+                let mut candidate_inputs = inputs.clone();
+                candidate_inputs[i].2 = candidate;
+                runs += 1;
+
+                // shrunk cases are not included in the coverage report
+                let candidate_coverage = &mut Coverage::new();
+
+                if let Some(candidate_reason) =
+                    run_case(program, program_name, function, input, &candidate_inputs, candidate_coverage)
+                {
+                    inputs = candidate_inputs;
+                    reason = candidate_reason;
+                    shrunk = true;
+
+                    break 'inputs;
+                }
+
+                if runs >= MAX_SHRINK_RUNS {
+                    break 'inputs;
+                }
+            }
+        }
+    }
+
+    FuzzFailure { inputs, reason }
+}
+
+/// Returns a random value of the given type.
+/// Integers and fields are biased towards edge cases like zero and the largest values.
+pub fn fuzz_input_value<F: Field + PrimeField, R: Rng>(
+    type_: &Type,
+    span: &Span,
+    rng: &mut R,
+) -> Result<InputValue, CompilerError> {
+    Ok(match type_ {
+        Type::Boolean => InputValue::Boolean(rng.gen()),
+        Type::Field => {
+            let field = if rng.gen_bool(0.25) {
+                rng.gen_range(0u32, 256u32).to_string()
+            } else {
+                field_to_decimal(&F::rand(rng))
+            };
+
+            InputValue::Field(field)
+        }
+        Type::Group => InputValue::Group(GroupValue::Single(rng.gen::<u64>().to_string(), span.clone())),
+        Type::IntegerType(integer_type) => {
+            let number = match unsigned_max(integer_type) {
+                Some(max) => {
+                    let bits = 128 - max.leading_zeros();
+
+                    match rng.gen_range(0, 8) {
+                        0 => 0u128,
+                        1 => max,
+                        _ => rng.gen::<u128>() >> (128 - bits),
+                    }
+                    .to_string()
+                }
+                None => {
+                    let (min, max) = signed_range(integer_type);
+                    let bits = 128 - max.leading_zeros();
+
+                    match rng.gen_range(0, 8) {
+                        0 => 0i128,
+                        1 => min,
+                        2 => max,
+                        // An arithmetic shift keeps the sign of the random value
+                        _ => rng.gen::<i128>() >> (128 - bits - 1),
+                    }
+                    .to_string()
+                }
+            };
+
+            InputValue::Integer(input_integer_type(integer_type), number)
+        }
+        Type::Array(element_type, dimensions) => {
+            let element_type = element_type.outer_dimension(dimensions);
+            let mut array = vec![];
+
+            for _ in 0..dimensions[0] {
+                array.push(fuzz_input_value::<F, R>(&element_type, span, rng)?);
+            }
+
+            InputValue::Array(array)
+        }
+        Type::Tuple(types) => {
+            let mut tuple = vec![];
+
+            for type_ in types.iter() {
+                tuple.push(fuzz_input_value::<F, R>(type_, span, rng)?);
+            }
+
+            InputValue::Tuple(tuple)
+        }
+        type_ => return Err(CompilerError::FuzzType(type_.to_string())),
+    })
+}
+
+/// Returns simpler values to try in place of a value of a failing case.
+fn shrink_input_value(value: &InputValue) -> Vec<InputValue> {
+    match value {
+        InputValue::Boolean(true) => vec![InputValue::Boolean(false)],
+        InputValue::Field(field) => shrink_number(field)
+            .into_iter()
+            .map(InputValue::Field)
+            .collect(),
+        InputValue::Group(GroupValue::Single(number, span)) => shrink_number(number)
+            .into_iter()
+            .map(|number| InputValue::Group(GroupValue::Single(number, span.clone())))
+            .collect(),
+        InputValue::Integer(integer_type, number) => shrink_number(number)
+            .into_iter()
+            .map(|number| InputValue::Integer(integer_type.clone(), number))
+            .collect(),
+        InputValue::Array(values) => shrink_values(values).into_iter().map(InputValue::Array).collect(),
+        InputValue::Tuple(values) => shrink_values(values).into_iter().map(InputValue::Tuple).collect(),
+        _ => vec![],
+    }
+}
+
+/// Returns candidates for a decimal number that are closer to zero.
+fn shrink_number(number: &str) -> Vec<String> {
+    let mut candidates = match number.parse::<i128>() {
+        Ok(0) => vec![],
+        Ok(number) => vec![0, number / 2, number - number.signum()],
+        // Numbers that do not fit in an i128 are shrunk by their length
+        Err(_) if number.len() > 1 => {
+            return vec!["0".to_owned(), number[..number.len() / 2].to_owned()];
+        }
+        Err(_) => vec![0],
+    };
+
+    candidates.dedup();
+
+    candidates.into_iter().map(|candidate| candidate.to_string()).collect()
+}
+
+fn shrink_values(values: &[InputValue]) -> Vec<Vec<InputValue>> {
+    let mut candidates = vec![];
+
+    for (i, value) in values.iter().enumerate() {
+        for candidate in shrink_input_value(value) {
+            let mut candidate_values = values.to_vec();
+            candidate_values[i] = candidate;
+
+            candidates.push(candidate_values);
+        }
+    }
+
+    candidates
+}
+
+/// Returns the value in the syntax of an input file.
+fn input_value_string(value: &InputValue) -> String {
+    match value {
+        InputValue::Group(group) => format!("{}group", group),
+        InputValue::Integer(_type, number) => number.clone(),
+        InputValue::Array(values) => {
+            let values = values.iter().map(input_value_string).collect::<Vec<_>>();
+
+            format!("[{}]", values.join(", "))
+        }
+        InputValue::Tuple(values) => {
+            let values = values.iter().map(input_value_string).collect::<Vec<_>>();
+
+            format!("({})", values.join(", "))
+        }
+        value => value.to_string(),
+    }
+}
+
+fn input_integer_type(integer_type: &IntegerType) -> InputIntegerType {
+    match integer_type {
+        IntegerType::U8 => InputIntegerType::Unsigned(UnsignedIntegerType::U8Type(U8Type {})),
+        IntegerType::U16 => InputIntegerType::Unsigned(UnsignedIntegerType::U16Type(U16Type {})),
+        IntegerType::U32 => InputIntegerType::Unsigned(UnsignedIntegerType::U32Type(U32Type {})),
+        IntegerType::U64 => InputIntegerType::Unsigned(UnsignedIntegerType::U64Type(U64Type {})),
+        IntegerType::U128 => InputIntegerType::Unsigned(UnsignedIntegerType::U128Type(U128Type {})),
+        IntegerType::I8 => InputIntegerType::Signed(SignedIntegerType::I8Type(I8Type {})),
+        IntegerType::I16 => InputIntegerType::Signed(SignedIntegerType::I16Type(I16Type {})),
+        IntegerType::I32 => InputIntegerType::Signed(SignedIntegerType::I32Type(I32Type {})),
+        IntegerType::I64 => InputIntegerType::Signed(SignedIntegerType::I64Type(I64Type {})),
+        IntegerType::I128 => InputIntegerType::Signed(SignedIntegerType::I128Type(I128Type {})),
+    }
+}
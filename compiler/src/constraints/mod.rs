@@ -18,3 +18,6 @@
 
 pub mod constraints;
 pub use self::constraints::*;
+
+pub mod fuzz;
+pub use self::fuzz::*;
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Records which statements and branches of a program are executed by its tests.
+
+use leo_typed::Span;
+
+use snarkos_models::gadgets::utilities::boolean::Boolean;
+
+use std::{collections::BTreeMap, path::PathBuf};
+
+/// Execution counts for the statements and branches of a program, keyed by file scope and span.
+/// A statement or branch side is executed if its indicator is true for the constant witness.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Coverage {
+    /// (file scope, line) -> number of executions
+    lines: BTreeMap<(String, usize), usize>,
+    /// (file scope, line, start column, block, branch) -> number of executions
+    branches: BTreeMap<(String, usize, usize, usize, usize), usize>,
+}
+
+impl Coverage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the statement at the given span.
+    /// Statements outside of a branch have no indicator and are always executed.
+    pub fn record_statement(&mut self, file_scope: &str, span: &Span, indicator: Option<&Boolean>) {
+        let executed = indicator.map(indicator_value).unwrap_or(true);
+        let count = self.lines.entry((file_scope.to_owned(), span.line)).or_insert(0);
+
+        if executed {
+            *count += 1;
+        }
+    }
+
+    /// Records one side of the branch at the given span.
+    /// A conditional statement is a block with a `true` and a `false` branch, a match statement has a branch per arm.
+    pub fn record_branch(&mut self, file_scope: &str, span: &Span, block: usize, branch: usize, indicator: &Boolean) {
+        let key = (file_scope.to_owned(), span.line, span.start, block, branch);
+        let count = self.branches.entry(key).or_insert(0);
+
+        if indicator_value(indicator) {
+            *count += 1;
+        }
+    }
+
+    /// Adds the execution counts of another run of the program.
+    pub fn merge(&mut self, other: Coverage) {
+        for (key, count) in other.lines {
+            *self.lines.entry(key).or_insert(0) += count;
+        }
+
+        for (key, count) in other.branches {
+            *self.branches.entry(key).or_insert(0) += count;
+        }
+    }
+
+    /// Returns the number of recorded lines and the number of executed lines in the given program.
+    pub fn lines_found_hit(&self, program_name: &str) -> (usize, usize) {
+        let lines = self.program_lines(program_name);
+        let hit = lines.values().filter(|count| **count > 0).count();
+
+        (lines.len(), hit)
+    }
+
+    /// Returns the number of recorded branches and the number of taken branches in the given program.
+    pub fn branches_found_hit(&self, program_name: &str) -> (usize, usize) {
+        let branches = self.program_branches(program_name);
+        let hit = branches.iter().filter(|(_, _, count)| *count > 0).count();
+
+        (branches.len(), hit)
+    }
+
+    /// Returns an lcov tracefile for the statements and branches of the given program.
+    /// Statements of imported packages are not included since their spans refer to other files.
+    pub fn to_lcov(&self, program_name: &str, source_file: &PathBuf) -> String {
+        let mut lcov = format!("TN:{}\nSF:{}\n", program_name, source_file.display());
+
+        let branches = self.program_branches(program_name);
+        for (line, block, count) in branches.iter() {
+            let taken = if *count > 0 { count.to_string() } else { "-".to_owned() };
+
+            lcov.push_str(&format!("BRDA:{},{},{},{}\n", line, block.0, block.1, taken));
+        }
+
+        let (branches_found, branches_hit) = self.branches_found_hit(program_name);
+        lcov.push_str(&format!("BRF:{}\nBRH:{}\n", branches_found, branches_hit));
+
+        for (line, count) in self.program_lines(program_name) {
+            lcov.push_str(&format!("DA:{},{}\n", line, count));
+        }
+
+        let (lines_found, lines_hit) = self.lines_found_hit(program_name);
+        lcov.push_str(&format!("LF:{}\nLH:{}\nend_of_record\n", lines_found, lines_hit));
+
+        lcov
+    }
+
+    /// Returns the execution count of each line in the given program.
+    fn program_lines(&self, program_name: &str) -> BTreeMap<usize, usize> {
+        let mut lines = BTreeMap::new();
+
+        for ((file_scope, line), count) in self.lines.iter() {
+            if is_program_scope(file_scope, program_name) {
+                *lines.entry(*line).or_insert(0) += count;
+            }
+        }
+
+        lines
+    }
+
+    /// Returns the line, the (block, branch) number and the execution count of each branch in the given program.
+    /// Blocks are numbered in order of their position in the program.
+    fn program_branches(&self, program_name: &str) -> Vec<(usize, (usize, usize), usize)> {
+        let mut branches = BTreeMap::new();
+
+        for ((file_scope, line, start, block, branch), count) in self.branches.iter() {
+            if is_program_scope(file_scope, program_name) {
+                *branches.entry((*line, *start, *block, *branch)).or_insert(0) += count;
+            }
+        }
+
+        let mut blocks = BTreeMap::new();
+
+        branches
+            .into_iter()
+            .map(|((line, start, block, branch), count)| {
+                let next_block = blocks.len();
+                let block = *blocks.entry((line, start, block)).or_insert(next_block);
+
+                (line, (block, branch), count)
+            })
+            .collect()
+    }
+}
+
+/// Returns `true` if the file scope belongs to the main program or one of its circuits.
+fn is_program_scope(file_scope: &str, program_name: &str) -> bool {
+    file_scope == program_name || file_scope.starts_with(&format!("{}_", program_name))
+}
+
+fn indicator_value(indicator: &Boolean) -> bool {
+    indicator.get_value().unwrap_or(false)
+}
@@ -17,7 +17,7 @@
 //! Stores all defined names in a compiled Leo program.
 
 use crate::{
-    errors::ImportError,
+    errors::{CompilerError, ImportError},
     program::{new_scope, ConstrainedProgram},
     value::ConstrainedValue,
     GroupType,
@@ -28,7 +28,28 @@ use leo_typed::Program;
 use snarkos_models::curves::{Field, PrimeField};
 
 impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
-    pub fn store_definitions(&mut self, program: Program, imported_programs: &ImportParser) -> Result<(), ImportError> {
+    pub fn store_definitions(
+        &mut self,
+        program: Program,
+        imported_programs: &ImportParser,
+    ) -> Result<(), CompilerError> {
+        self.store_program_definitions(&program, imported_programs)?;
+
+        // monomorphize const generic functions and circuits for every instantiation in the program and its imports
+        self.store_instantiations(&program)?;
+
+        for imported_program in imported_programs.programs() {
+            self.store_instantiations(imported_program)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn store_program_definitions(
+        &mut self,
+        program: &Program,
+        imported_programs: &ImportParser,
+    ) -> Result<(), ImportError> {
         let program_name = program.name.trim_end_matches(".leo");
 
         // evaluate all import statements and store imported definitions
@@ -39,15 +60,27 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             .collect::<Result<Vec<_>, ImportError>>()?;
 
         // evaluate and store all circuit definitions
-        program.circuits.into_iter().for_each(|(identifier, circuit)| {
+        program.circuits.iter().for_each(|(identifier, circuit)| {
             let resolved_circuit_name = new_scope(program_name.to_owned(), identifier.to_string());
-            self.store(resolved_circuit_name, ConstrainedValue::CircuitDefinition(circuit));
+            self.store(
+                resolved_circuit_name,
+                ConstrainedValue::CircuitDefinition(circuit.clone()),
+            );
+        });
+
+        // evaluate and store all enum definitions
+        program.enums.iter().for_each(|(identifier, enum_)| {
+            let resolved_enum_name = new_scope(program_name.to_owned(), identifier.to_string());
+            self.store(resolved_enum_name, ConstrainedValue::EnumDefinition(enum_.clone()));
         });
 
         // evaluate and store all function definitions
-        program.functions.into_iter().for_each(|(function_name, function)| {
+        program.functions.iter().for_each(|(function_name, function)| {
             let resolved_function_name = new_scope(program_name.to_owned(), function_name.to_string());
-            self.store(resolved_function_name, ConstrainedValue::Function(None, function));
+            self.store(
+                resolved_function_name,
+                ConstrainedValue::Function(None, function.clone()),
+            );
         });
 
         Ok(())
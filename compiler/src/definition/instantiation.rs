@@ -0,0 +1,950 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Collects the instantiations of const generic functions and circuits in a compiled Leo program.
+//!
+//! Every function body is searched for calls and circuit expressions whose const parameters can be
+//! inferred from the types of their arguments. Each instantiation is monomorphized when the program
+//! definitions are stored, and mismatched array lengths are reported before any constraints are generated.
+//! Instantiations whose argument types are only known during constraint generation are monomorphized
+//! when they are enforced.
+
+use crate::{
+    errors::{ExpressionError, FunctionError},
+    function::check_arguments_length,
+    program::{new_scope, ConstrainedProgram},
+    value::ConstrainedValue,
+    GroupType,
+};
+use leo_typed::{
+    Assignee,
+    Circuit,
+    CircuitMember,
+    CircuitVariableDefinition,
+    ConditionalNestedOrEndStatement,
+    ConditionalStatement,
+    ConsoleFunction,
+    Declare,
+    Expression,
+    FormattedString,
+    Function,
+    Identifier,
+    InputVariable,
+    MatchPattern,
+    Program,
+    RangeOrExpression,
+    Span,
+    SpreadOrExpression,
+    Statement,
+    Type,
+};
+
+use snarkos_models::curves::{Field, PrimeField};
+use std::collections::HashMap;
+
+/// A function body that is searched for instantiations of const generic functions and circuits.
+struct Instantiation {
+    scope: String,
+    circuit: Option<(Circuit, HashMap<String, usize>)>,
+    function: Function,
+}
+
+/// The known types of variables, values of constants and const parameter values of generic circuit variables.
+#[derive(Clone, Default)]
+struct Environment {
+    types: HashMap<String, Type>,
+    constants: HashMap<String, usize>,
+    circuits: HashMap<String, HashMap<String, usize>>,
+}
+
+impl Environment {
+    fn declare(&mut self, identifier: &Identifier, type_: Option<Type>) {
+        self.constants.remove(&identifier.name);
+        self.circuits.remove(&identifier.name);
+
+        match type_ {
+            Some(type_) => self.types.insert(identifier.name.clone(), type_),
+            None => self.types.remove(&identifier.name),
+        };
+    }
+
+    fn constant(&self, expression: &Expression) -> Option<usize> {
+        match expression {
+            Expression::Integer(_, value, _) | Expression::Implicit(value, _) => value.parse().ok(),
+            Expression::Identifier(identifier) => self.constants.get(&identifier.name).cloned(),
+            _ => None,
+        }
+    }
+}
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    /// Monomorphizes every const generic function and circuit instantiated in the program.
+    pub(crate) fn store_instantiations(&mut self, program: &Program) -> Result<(), FunctionError> {
+        let scope = program.name.trim_end_matches(".leo").to_owned();
+        let mut instantiations = vec![];
+
+        for function in program.functions.values().filter(|function| !function.is_generic()) {
+            instantiations.push(Instantiation {
+                scope: scope.clone(),
+                circuit: None,
+                function: function.clone(),
+            });
+        }
+
+        for test in program.tests.values() {
+            instantiations.push(Instantiation {
+                scope: scope.clone(),
+                circuit: None,
+                function: test.function.clone(),
+            });
+        }
+
+        for circuit in program.circuits.values().filter(|circuit| !circuit.is_generic()) {
+            for member in circuit.members.iter() {
+                if let CircuitMember::CircuitFunction(_static, function) = member {
+                    if !function.is_generic() {
+                        instantiations.push(Instantiation {
+                            scope: scope.clone(),
+                            circuit: Some((circuit.clone(), HashMap::new())),
+                            function: function.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        // Monomorphized copies are searched in turn for the instantiations they contain
+        while let Some(instantiation) = instantiations.pop() {
+            let mut environment = Environment::default();
+
+            for input in instantiation.function.input.iter() {
+                if let InputVariable::FunctionInput(input) = input {
+                    let type_ = resolve_type(&input.type_, &instantiation);
+                    environment.declare(&input.identifier, type_);
+                }
+            }
+
+            let statements = instantiation.function.statements.clone();
+
+            self.instantiate_statements(&instantiation, &mut environment, &statements, &mut instantiations)?;
+        }
+
+        Ok(())
+    }
+
+    fn instantiate_statements(
+        &mut self,
+        instantiation: &Instantiation,
+        environment: &mut Environment,
+        statements: &[Statement],
+        instantiations: &mut Vec<Instantiation>,
+    ) -> Result<(), FunctionError> {
+        for statement in statements {
+            self.instantiate_statement(instantiation, environment, statement, instantiations)?;
+        }
+
+        Ok(())
+    }
+
+    fn instantiate_conditional(
+        &mut self,
+        instantiation: &Instantiation,
+        environment: &Environment,
+        statement: &ConditionalStatement,
+        instantiations: &mut Vec<Instantiation>,
+    ) -> Result<(), FunctionError> {
+        self.instantiate_expression(instantiation, environment, &statement.condition, instantiations)?;
+
+        self.instantiate_statements(
+            instantiation,
+            &mut environment.clone(),
+            &statement.statements,
+            instantiations,
+        )?;
+
+        match &statement.next {
+            Some(ConditionalNestedOrEndStatement::Nested(nested)) => {
+                self.instantiate_conditional(instantiation, environment, nested, instantiations)
+            }
+            Some(ConditionalNestedOrEndStatement::End(statements)) => {
+                self.instantiate_statements(instantiation, &mut environment.clone(), statements, instantiations)
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn instantiate_statement(
+        &mut self,
+        instantiation: &Instantiation,
+        environment: &mut Environment,
+        statement: &Statement,
+        instantiations: &mut Vec<Instantiation>,
+    ) -> Result<(), FunctionError> {
+        match statement {
+            Statement::Return(expression, _span) | Statement::Expression(expression, _span) => {
+                self.instantiate_expression(instantiation, environment, expression, instantiations)?;
+            }
+            Statement::Definition(declare, variables, expressions, _span) => {
+                let mut types = vec![];
+                let mut circuit_constants = HashMap::new();
+
+                for expression in expressions {
+                    let (type_, constants) =
+                        self.instantiate_value(instantiation, environment, expression, instantiations)?;
+
+                    types.push(type_);
+                    circuit_constants = constants;
+                }
+
+                let declared_type = variables
+                    .type_
+                    .as_ref()
+                    .and_then(|type_| resolve_type(type_, instantiation));
+
+                if let [variable] = variables.names.as_slice() {
+                    let type_ = declared_type.or_else(|| types.pop().flatten());
+                    environment.declare(&variable.identifier, type_);
+
+                    if !circuit_constants.is_empty() {
+                        environment
+                            .circuits
+                            .insert(variable.identifier.name.clone(), circuit_constants);
+                    }
+
+                    if let (Declare::Const, [expression]) = (declare, expressions.as_slice()) {
+                        if let Some(value) = environment.constant(expression) {
+                            environment.constants.insert(variable.identifier.name.clone(), value);
+                        }
+                    }
+                } else {
+                    let types = match (declared_type, types.as_slice()) {
+                        (Some(Type::Tuple(types)), _) => types.into_iter().map(Some).collect(),
+                        (None, [Some(Type::Tuple(types))]) => types.iter().cloned().map(Some).collect(),
+                        (None, types) if types.len() == variables.names.len() => types.to_vec(),
+                        _ => vec![],
+                    };
+
+                    for (index, variable) in variables.names.iter().enumerate() {
+                        environment.declare(&variable.identifier, types.get(index).cloned().flatten());
+                    }
+                }
+            }
+            Statement::Assign(assignee, expression, _span) => {
+                self.instantiate_assignee(instantiation, environment, assignee, instantiations)?;
+                self.instantiate_expression(instantiation, environment, expression, instantiations)?;
+            }
+            Statement::Conditional(statement, _span) => {
+                self.instantiate_conditional(instantiation, environment, statement, instantiations)?;
+            }
+            Statement::Iteration(index, start, stop, statements, _span) => {
+                self.instantiate_expression(instantiation, environment, start, instantiations)?;
+                self.instantiate_expression(instantiation, environment, stop, instantiations)?;
+
+                let mut environment = environment.clone();
+                environment.declare(index, None);
+
+                self.instantiate_statements(instantiation, &mut environment, statements, instantiations)?;
+            }
+            Statement::Match(expression, arms, _span) => {
+                self.instantiate_expression(instantiation, environment, expression, instantiations)?;
+
+                for arm in arms {
+                    let mut environment = environment.clone();
+                    bind_pattern(&mut environment, &arm.pattern);
+
+                    self.instantiate_statements(instantiation, &mut environment, &arm.statements, instantiations)?;
+                }
+            }
+            Statement::Console(console) => match &console.function {
+                ConsoleFunction::Assert(expression) => {
+                    self.instantiate_expression(instantiation, environment, expression, instantiations)?;
+                }
+                ConsoleFunction::Debug(string) | ConsoleFunction::Error(string) | ConsoleFunction::Log(string) => {
+                    self.instantiate_formatted_string(instantiation, environment, string, instantiations)?;
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    fn instantiate_assignee(
+        &mut self,
+        instantiation: &Instantiation,
+        environment: &Environment,
+        assignee: &Assignee,
+        instantiations: &mut Vec<Instantiation>,
+    ) -> Result<(), FunctionError> {
+        match assignee {
+            Assignee::Array(array, index) => {
+                self.instantiate_assignee(instantiation, environment, array, instantiations)?;
+                self.instantiate_range_or_expression(instantiation, environment, index, instantiations)
+            }
+            Assignee::Tuple(tuple, _index) => {
+                self.instantiate_assignee(instantiation, environment, tuple, instantiations)
+            }
+            Assignee::CircuitField(circuit, _field) => {
+                self.instantiate_assignee(instantiation, environment, circuit, instantiations)
+            }
+            Assignee::Identifier(_identifier) => Ok(()),
+        }
+    }
+
+    fn instantiate_formatted_string(
+        &mut self,
+        instantiation: &Instantiation,
+        environment: &Environment,
+        string: &FormattedString,
+        instantiations: &mut Vec<Instantiation>,
+    ) -> Result<(), FunctionError> {
+        for parameter in string.parameters.iter() {
+            self.instantiate_expression(instantiation, environment, &parameter.expression, instantiations)?;
+        }
+
+        Ok(())
+    }
+
+    fn instantiate_range_or_expression(
+        &mut self,
+        instantiation: &Instantiation,
+        environment: &Environment,
+        index: &RangeOrExpression,
+        instantiations: &mut Vec<Instantiation>,
+    ) -> Result<(), FunctionError> {
+        match index {
+            RangeOrExpression::Range(from, to) => {
+                for expression in from.iter().chain(to.iter()) {
+                    self.instantiate_expression(instantiation, environment, expression, instantiations)?;
+                }
+            }
+            RangeOrExpression::Expression(index) => {
+                self.instantiate_expression(instantiation, environment, index, instantiations)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn instantiate_expressions(
+        &mut self,
+        instantiation: &Instantiation,
+        environment: &Environment,
+        expressions: &[Expression],
+        instantiations: &mut Vec<Instantiation>,
+    ) -> Result<Vec<Option<Type>>, FunctionError> {
+        expressions
+            .iter()
+            .map(|expression| self.instantiate_expression(instantiation, environment, expression, instantiations))
+            .collect()
+    }
+
+    /// Searches an expression for instantiations and returns its type if it is known before constraint generation.
+    fn instantiate_expression(
+        &mut self,
+        instantiation: &Instantiation,
+        environment: &Environment,
+        expression: &Expression,
+        instantiations: &mut Vec<Instantiation>,
+    ) -> Result<Option<Type>, FunctionError> {
+        Ok(match expression {
+            // Variables, circuits and functions
+            Expression::Identifier(_)
+            | Expression::Circuit(_, _, _)
+            | Expression::CircuitMemberAccess(_, _, _)
+            | Expression::FunctionCall(_, _, _) => {
+                self.instantiate_value(instantiation, environment, expression, instantiations)?
+                    .0
+            }
+
+            // Values
+            Expression::Address(_, _) => Some(Type::Address),
+            Expression::Boolean(_, _) => Some(Type::Boolean),
+            Expression::Field(_, _) => Some(Type::Field),
+            Expression::Group(_) => Some(Type::Group),
+            Expression::Implicit(_, _) => None,
+            Expression::Integer(type_, _, _) => Some(Type::IntegerType(type_.clone())),
+
+            // Number operations
+            Expression::Add(left, right, _)
+            | Expression::Sub(left, right, _)
+            | Expression::Mul(left, right, _)
+            | Expression::Div(left, right, _) => {
+                let left = self.instantiate_expression(instantiation, environment, left, instantiations)?;
+                let right = self.instantiate_expression(instantiation, environment, right, instantiations)?;
+
+                left.or(right)
+            }
+            Expression::Pow(left, right, _) => {
+                let left = self.instantiate_expression(instantiation, environment, left, instantiations)?;
+                self.instantiate_expression(instantiation, environment, right, instantiations)?;
+
+                left
+            }
+            Expression::Negate(expression, _) => {
+                self.instantiate_expression(instantiation, environment, expression, instantiations)?
+            }
+
+            // Boolean operations
+            Expression::Not(expression, _) => {
+                self.instantiate_expression(instantiation, environment, expression, instantiations)?;
+
+                Some(Type::Boolean)
+            }
+            Expression::Or(left, right, _)
+            | Expression::And(left, right, _)
+            | Expression::Eq(left, right, _)
+            | Expression::Ge(left, right, _)
+            | Expression::Gt(left, right, _)
+            | Expression::Le(left, right, _)
+            | Expression::Lt(left, right, _) => {
+                self.instantiate_expression(instantiation, environment, left, instantiations)?;
+                self.instantiate_expression(instantiation, environment, right, instantiations)?;
+
+                Some(Type::Boolean)
+            }
+
+            // Conditionals
+            Expression::IfElse(condition, first, second, _) => {
+                self.instantiate_expression(instantiation, environment, condition, instantiations)?;
+                let first = self.instantiate_expression(instantiation, environment, first, instantiations)?;
+                let second = self.instantiate_expression(instantiation, environment, second, instantiations)?;
+
+                first.or(second)
+            }
+            Expression::Match(expression, arms, _) => {
+                self.instantiate_expression(instantiation, environment, expression, instantiations)?;
+
+                let mut type_ = None;
+
+                for arm in arms {
+                    let mut environment = environment.clone();
+                    bind_pattern(&mut environment, &arm.pattern);
+
+                    let arm_type =
+                        self.instantiate_expression(instantiation, &environment, &arm.expression, instantiations)?;
+                    type_ = type_.or(arm_type);
+                }
+
+                type_
+            }
+
+            // Arrays
+            Expression::Array(elements, _) => {
+                let mut length = 0;
+                let mut element_type = None;
+                let mut known = true;
+
+                for element in elements {
+                    match &**element {
+                        SpreadOrExpression::Spread(expression) => {
+                            match self.instantiate_expression(instantiation, environment, expression, instantiations)? {
+                                Some(Type::Array(type_, dimensions)) => {
+                                    length += dimensions[0];
+                                    element_type = element_type.or_else(|| Some(type_.outer_dimension(&dimensions)));
+                                }
+                                _ => known = false,
+                            }
+                        }
+                        SpreadOrExpression::Expression(expression) => {
+                            let type_ =
+                                self.instantiate_expression(instantiation, environment, expression, instantiations)?;
+
+                            length += 1;
+                            element_type = element_type.or(type_);
+                        }
+                    }
+                }
+
+                match element_type {
+                    Some(element_type) if known && length > 0 => Some(array_type(element_type, length)),
+                    _ => None,
+                }
+            }
+            Expression::GenericArray(element, parameter, _) => {
+                let element_type = self.instantiate_expression(instantiation, environment, element, instantiations)?;
+
+                match (element_type, environment.constants.get(&parameter.name)) {
+                    (Some(element_type), Some(length)) if *length > 0 => Some(array_type(element_type, *length)),
+                    _ => None,
+                }
+            }
+            Expression::ArrayAccess(array, index, _) => {
+                let array_type = self.instantiate_expression(instantiation, environment, array, instantiations)?;
+                self.instantiate_range_or_expression(instantiation, environment, index, instantiations)?;
+
+                match (array_type, &**index) {
+                    (Some(Type::Array(type_, dimensions)), RangeOrExpression::Expression(_)) => {
+                        Some(type_.outer_dimension(&dimensions))
+                    }
+                    (Some(Type::Array(type_, mut dimensions)), RangeOrExpression::Range(from, to)) => {
+                        let from = from.as_ref().map_or(Some(0), |from| environment.constant(from));
+                        let to = to.as_ref().map_or(Some(dimensions[0]), |to| environment.constant(to));
+
+                        match (from, to) {
+                            (Some(from), Some(to)) if from < to && to <= dimensions[0] => {
+                                dimensions[0] = to - from;
+
+                                Some(Type::Array(type_, dimensions))
+                            }
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                }
+            }
+
+            // Tuples
+            Expression::Tuple(expressions, _) => {
+                let types = self.instantiate_expressions(instantiation, environment, expressions, instantiations)?;
+
+                types.into_iter().collect::<Option<Vec<_>>>().map(Type::Tuple)
+            }
+            Expression::TupleAccess(tuple, index, _) => {
+                match self.instantiate_expression(instantiation, environment, tuple, instantiations)? {
+                    Some(Type::Tuple(types)) => types.get(*index).cloned(),
+                    _ => None,
+                }
+            }
+
+            // Circuits
+            Expression::CircuitStaticFunctionAccess(circuit, _member, _) => match &**circuit {
+                Expression::Identifier(identifier) => match self.definition(instantiation, identifier) {
+                    Some((_scope, ConstrainedValue::EnumDefinition(enum_))) => Some(Type::Enum(enum_.enum_name)),
+                    _ => None,
+                },
+                _ => None,
+            },
+
+            // Functions
+            Expression::CoreFunctionCall(_name, arguments, _) => {
+                self.instantiate_expressions(instantiation, environment, arguments, instantiations)?;
+
+                None
+            }
+        })
+    }
+
+    /// Searches a variable, circuit or function call expression for instantiations and returns its type
+    /// together with the const parameter values of a generic circuit value.
+    fn instantiate_value(
+        &mut self,
+        instantiation: &Instantiation,
+        environment: &Environment,
+        expression: &Expression,
+        instantiations: &mut Vec<Instantiation>,
+    ) -> Result<(Option<Type>, HashMap<String, usize>), FunctionError> {
+        match expression {
+            Expression::Identifier(identifier) => Ok(match (identifier.name.as_str(), &instantiation.circuit) {
+                ("self", Some((circuit, constants))) => {
+                    (Some(Type::Circuit(circuit.circuit_name.clone())), constants.clone())
+                }
+                (name, _) => (
+                    environment.types.get(name).cloned(),
+                    environment.circuits.get(name).cloned().unwrap_or_default(),
+                ),
+            }),
+            Expression::Circuit(identifier, members, span) => {
+                self.instantiate_circuit(instantiation, environment, identifier, members, span, instantiations)
+            }
+            Expression::CircuitMemberAccess(circuit, member, _) => {
+                let (circuit_type, constants) =
+                    self.instantiate_value(instantiation, environment, circuit, instantiations)?;
+
+                let type_ = match self.circuit_definition(instantiation, circuit_type) {
+                    Some((_scope, definition)) => {
+                        definition
+                            .members
+                            .iter()
+                            .find_map(|definition_member| match definition_member {
+                                CircuitMember::CircuitVariable(_mutable, identifier, type_)
+                                    if identifier.eq(member) =>
+                                {
+                                    return_type(&type_.resolve_const_parameters(&constants), Some(&definition))
+                                }
+                                _ => None,
+                            })
+                    }
+                    None => None,
+                };
+
+                Ok((type_, HashMap::new()))
+            }
+            Expression::FunctionCall(function, arguments, span) => {
+                let argument_types =
+                    self.instantiate_expressions(instantiation, environment, arguments, instantiations)?;
+
+                self.instantiate_function_call(
+                    instantiation,
+                    environment,
+                    function,
+                    argument_types,
+                    span,
+                    instantiations,
+                )
+            }
+            expression => Ok((
+                self.instantiate_expression(instantiation, environment, expression, instantiations)?,
+                HashMap::new(),
+            )),
+        }
+    }
+
+    /// Infers the const parameters of a circuit expression and monomorphizes the circuit functions.
+    fn instantiate_circuit(
+        &mut self,
+        instantiation: &Instantiation,
+        environment: &Environment,
+        identifier: &Identifier,
+        members: &[CircuitVariableDefinition],
+        span: &Span,
+        instantiations: &mut Vec<Instantiation>,
+    ) -> Result<(Option<Type>, HashMap<String, usize>), FunctionError> {
+        let mut member_types = HashMap::new();
+
+        for member in members {
+            let type_ = self.instantiate_expression(instantiation, environment, &member.expression, instantiations)?;
+            member_types.insert(member.identifier.name.clone(), type_);
+        }
+
+        let (scope, circuit) = match self.definition(instantiation, identifier) {
+            Some((scope, ConstrainedValue::CircuitDefinition(circuit))) => (scope, circuit),
+            _ => return Ok((None, HashMap::new())),
+        };
+        let circuit_type = Some(Type::Circuit(circuit.circuit_name.clone()));
+
+        if !circuit.is_generic() {
+            return Ok((circuit_type, HashMap::new()));
+        }
+
+        // Values of const parameters are inferred from the types of the circuit variables
+        let mut constants = HashMap::new();
+        let mut known = true;
+
+        for member in circuit.members.iter() {
+            if let CircuitMember::CircuitVariable(_mutable, identifier, type_) = member {
+                // Only types that contain const parameters are checked here
+                if !type_.is_generic() {
+                    continue;
+                }
+
+                let type_ = type_.resolve_const_parameters(&constants);
+
+                match member_types.get(&identifier.name).cloned().flatten() {
+                    Some(value_type) => {
+                        if !type_.infer_const_parameters(&value_type, &mut constants) {
+                            return Err(FunctionError::from(ExpressionError::const_member_type(
+                                type_.to_string(),
+                                value_type.to_string(),
+                                span.clone(),
+                            )));
+                        }
+                    }
+                    None => known = false,
+                }
+            }
+        }
+
+        for parameter in circuit.const_parameters.iter() {
+            if !constants.contains_key(&parameter.identifier.name) {
+                if !known {
+                    return Ok((circuit_type, HashMap::new()));
+                }
+
+                return Err(FunctionError::from(ExpressionError::undefined_const_parameter(
+                    parameter.identifier.to_string(),
+                    span.clone(),
+                )));
+            }
+        }
+
+        for member in circuit.members.iter() {
+            if let CircuitMember::CircuitFunction(_static, function) = member {
+                let mut function = function.clone();
+                function.const_parameters = [circuit.const_parameters.clone(), function.const_parameters].concat();
+
+                self.instantiate_function(
+                    scope.clone(),
+                    Some((circuit.clone(), constants.clone())),
+                    function,
+                    &constants,
+                    instantiations,
+                );
+            }
+        }
+
+        Ok((circuit_type, constants))
+    }
+
+    /// Infers the const parameters of a function call and monomorphizes the called function.
+    fn instantiate_function_call(
+        &mut self,
+        instantiation: &Instantiation,
+        environment: &Environment,
+        function: &Expression,
+        argument_types: Vec<Option<Type>>,
+        span: &Span,
+        instantiations: &mut Vec<Instantiation>,
+    ) -> Result<(Option<Type>, HashMap<String, usize>), FunctionError> {
+        let unknown = Ok((None, HashMap::new()));
+
+        let (scope, circuit, function) = match function {
+            Expression::Identifier(identifier) => match self.definition(instantiation, identifier) {
+                Some((scope, ConstrainedValue::Function(None, function))) => (scope, None, function),
+                _ => return unknown,
+            },
+            Expression::CircuitStaticFunctionAccess(circuit, member, _) => {
+                let identifier = match &**circuit {
+                    Expression::Identifier(identifier) => identifier,
+                    _ => return unknown,
+                };
+
+                match self.definition(instantiation, identifier) {
+                    Some((_scope, ConstrainedValue::EnumDefinition(enum_))) => {
+                        return Ok((Some(Type::Enum(enum_.enum_name)), HashMap::new()));
+                    }
+                    Some((scope, ConstrainedValue::CircuitDefinition(circuit))) => {
+                        let mut function = match circuit_function(&circuit, member, true) {
+                            Some(function) => function,
+                            None => return unknown,
+                        };
+
+                        // Const parameters of the circuit are inferred from the static function input variables
+                        function.const_parameters =
+                            [circuit.const_parameters.clone(), function.const_parameters].concat();
+
+                        (scope, Some((circuit, HashMap::new())), function)
+                    }
+                    _ => return unknown,
+                }
+            }
+            Expression::CircuitMemberAccess(circuit_expression, member, _) => {
+                let (circuit_type, constants) =
+                    self.instantiate_value(instantiation, environment, circuit_expression, instantiations)?;
+
+                let (scope, circuit) = match self.circuit_definition(instantiation, circuit_type) {
+                    Some(definition) => definition,
+                    None => return unknown,
+                };
+                let mut function = match circuit_function(&circuit, member, false) {
+                    Some(function) => function,
+                    None => return unknown,
+                };
+
+                // Circuit functions of a generic circuit are monomorphized with the circuit expression
+                if circuit.is_generic() {
+                    if constants.is_empty() {
+                        return unknown;
+                    }
+
+                    function.const_parameters = [circuit.const_parameters.clone(), function.const_parameters].concat();
+                    function = self.instantiate_function(
+                        scope.clone(),
+                        Some((circuit.clone(), constants.clone())),
+                        function,
+                        &constants,
+                        instantiations,
+                    );
+                }
+
+                (scope, Some((circuit, constants)), function)
+            }
+            _ => return unknown,
+        };
+
+        let mut constants = HashMap::new();
+
+        if function.is_generic() {
+            check_arguments_length(function.input.len(), argument_types.len(), span.clone())?;
+
+            // Values of const parameters are inferred from the types of the input variables
+            let mut known = true;
+
+            for (input, argument_type) in function.input.iter().zip(argument_types) {
+                if let InputVariable::FunctionInput(input) = input {
+                    // Only types that contain const parameters are checked here
+                    if !input.type_.is_generic() {
+                        continue;
+                    }
+
+                    let input_type = input.type_.resolve_const_parameters(&constants);
+
+                    match argument_type {
+                        Some(argument_type) => {
+                            if !input_type.infer_const_parameters(&argument_type, &mut constants) {
+                                return Err(FunctionError::const_argument_type(
+                                    input_type.to_string(),
+                                    argument_type.to_string(),
+                                    input.span.clone(),
+                                ));
+                            }
+                        }
+                        None => known = false,
+                    }
+                }
+            }
+
+            for parameter in function.const_parameters.iter() {
+                if !constants.contains_key(&parameter.identifier.name) {
+                    if !known {
+                        return unknown;
+                    }
+
+                    return Err(FunctionError::undefined_const_parameter(
+                        parameter.identifier.to_string(),
+                        parameter.span.clone(),
+                    ));
+                }
+            }
+        }
+
+        // Static functions infer the const parameters of their circuit
+        let circuit = circuit.map(|(circuit, mut circuit_constants)| {
+            if circuit_constants.is_empty() {
+                circuit_constants = constants
+                    .iter()
+                    .filter(|(name, _value)| {
+                        circuit
+                            .const_parameters
+                            .iter()
+                            .any(|parameter| parameter.identifier.name.eq(*name))
+                    })
+                    .map(|(name, value)| (name.clone(), *value))
+                    .collect();
+            }
+
+            (circuit, circuit_constants)
+        });
+
+        let function = if function.is_generic() {
+            self.instantiate_function(scope, circuit.clone(), function, &constants, instantiations)
+        } else {
+            function
+        };
+
+        Ok(match (function.returns, circuit) {
+            (Some(Type::SelfType), Some((circuit, constants))) => {
+                (Some(Type::Circuit(circuit.circuit_name)), constants)
+            }
+            (Some(type_), circuit) => (
+                return_type(&type_, circuit.as_ref().map(|(circuit, _constants)| circuit)),
+                HashMap::new(),
+            ),
+            (None, _) => (None, HashMap::new()),
+        })
+    }
+
+    /// Monomorphizes a function and searches each new copy for further instantiations.
+    fn instantiate_function(
+        &mut self,
+        scope: String,
+        circuit: Option<(Circuit, HashMap<String, usize>)>,
+        function: Function,
+        constants: &HashMap<String, usize>,
+        instantiations: &mut Vec<Instantiation>,
+    ) -> Function {
+        if let Some(monomorphized) = self.get_monomorphization(&function, constants) {
+            return monomorphized;
+        }
+
+        let monomorphized = self.monomorphize(function, constants);
+
+        // Functions with their own const parameters are monomorphized again when they are called
+        if !monomorphized.is_generic() {
+            instantiations.push(Instantiation {
+                scope,
+                circuit,
+                function: monomorphized.clone(),
+            });
+        }
+
+        monomorphized
+    }
+
+    /// Returns a stored definition and the scope it is defined in.
+    fn definition(
+        &self,
+        instantiation: &Instantiation,
+        identifier: &Identifier,
+    ) -> Option<(String, ConstrainedValue<F, G>)> {
+        if identifier.is_self() {
+            return instantiation.circuit.as_ref().map(|(circuit, _constants)| {
+                (
+                    instantiation.scope.clone(),
+                    ConstrainedValue::CircuitDefinition(circuit.clone()),
+                )
+            });
+        }
+
+        match self.get(&new_scope(instantiation.scope.clone(), identifier.name.clone()))? {
+            ConstrainedValue::Import(scope, value) => Some((scope.clone(), *value.clone())),
+            value => Some((instantiation.scope.clone(), value.clone())),
+        }
+    }
+
+    fn circuit_definition(&self, instantiation: &Instantiation, type_: Option<Type>) -> Option<(String, Circuit)> {
+        match type_ {
+            Some(Type::Circuit(identifier)) => match self.definition(instantiation, &identifier)? {
+                (scope, ConstrainedValue::CircuitDefinition(circuit)) => Some((scope, circuit)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Returns a known type with `Self` resolved to the enclosing circuit.
+fn return_type(type_: &Type, circuit: Option<&Circuit>) -> Option<Type> {
+    match (type_, circuit) {
+        (Type::SelfType, Some(circuit)) => Some(Type::Circuit(circuit.circuit_name.clone())),
+        (Type::SelfType, None) => None,
+        (type_, _) if type_.is_generic() => None,
+        (type_, _) => Some(type_.clone()),
+    }
+}
+
+fn resolve_type(type_: &Type, instantiation: &Instantiation) -> Option<Type> {
+    match &instantiation.circuit {
+        Some((circuit, constants)) => return_type(&type_.resolve_const_parameters(constants), Some(circuit)),
+        None => return_type(type_, None),
+    }
+}
+
+/// Returns the type of an array with the given element type and length, with nested arrays flattened.
+fn array_type(element_type: Type, length: usize) -> Type {
+    match element_type {
+        Type::Array(type_, dimensions) => Type::Array(type_, [vec![length], dimensions].concat()),
+        type_ => Type::Array(Box::new(type_), vec![length]),
+    }
+}
+
+fn circuit_function(circuit: &Circuit, identifier: &Identifier, is_static: bool) -> Option<Function> {
+    circuit.members.iter().find_map(|member| match member {
+        CircuitMember::CircuitFunction(_static, function)
+            if *_static == is_static && function.identifier.eq(identifier) =>
+        {
+            Some(function.clone())
+        }
+        _ => None,
+    })
+}
+
+fn bind_pattern(environment: &mut Environment, pattern: &MatchPattern) {
+    if let MatchPattern::Variant(_enum, _variant, bindings, _span) = pattern {
+        for binding in bindings {
+            environment.declare(binding, None);
+        }
+    }
+}
@@ -19,3 +19,9 @@ pub use self::definition::*;
 
 pub mod definitions;
 pub use self::definitions::*;
+
+pub mod instantiation;
+pub use self::instantiation::*;
+
+pub mod monomorphize;
+pub use self::monomorphize::*;
@@ -0,0 +1,457 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Monomorphizes const generic functions in a compiled Leo program.
+
+use crate::{program::ConstrainedProgram, GroupType};
+use leo_typed::{
+    Assignee,
+    CircuitVariableDefinition,
+    ConditionalNestedOrEndStatement,
+    ConditionalStatement,
+    ConsoleFunction,
+    ConsoleFunctionCall,
+    Declare,
+    Expression,
+    FormattedParameter,
+    FormattedString,
+    Function,
+    InputVariable,
+    MatchArm,
+    MatchStatementArm,
+    RangeOrExpression,
+    SpreadOrExpression,
+    Statement,
+    VariableName,
+    Variables,
+};
+
+use snarkos_models::curves::{Field, PrimeField};
+use std::collections::HashMap;
+
+/// A const generic function and its monomorphized copy for one set of const parameter values.
+#[derive(Clone)]
+pub struct Monomorphization {
+    generic: Function,
+    constants: HashMap<String, usize>,
+    function: Function,
+}
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    /// Returns the stored monomorphized copy of a function for the given const parameter values.
+    pub(crate) fn get_monomorphization(
+        &self,
+        function: &Function,
+        constants: &HashMap<String, usize>,
+    ) -> Option<Function> {
+        self.monomorphized
+            .get(&function.get_name())
+            .and_then(|monomorphizations| {
+                monomorphizations
+                    .iter()
+                    .find(|monomorphization| {
+                        monomorphization.constants.eq(constants) && monomorphization.generic.eq(function)
+                    })
+                    .map(|monomorphization| monomorphization.function.clone())
+            })
+    }
+
+    /// Returns the monomorphized copy of a function for the given const parameter values.
+    /// Copies are stored so that a function instantiated repeatedly with the same values is only monomorphized once.
+    pub(crate) fn monomorphize(&mut self, function: Function, constants: &HashMap<String, usize>) -> Function {
+        if let Some(monomorphized) = self.get_monomorphization(&function, constants) {
+            return monomorphized;
+        }
+
+        let monomorphized = monomorphize_function(function.clone(), constants);
+
+        self.monomorphized
+            .entry(function.get_name())
+            .or_default()
+            .push(Monomorphization {
+                generic: function,
+                constants: constants.clone(),
+                function: monomorphized.clone(),
+            });
+
+        monomorphized
+    }
+}
+
+/// Returns a copy of a const generic function with every const parameter replaced by its inferred value.
+/// The values of the const parameters are defined as constants at the start of the function body.
+/// Const parameters without a value are kept so that they can be inferred when the function is called.
+pub fn monomorphize_function(function: Function, constants: &HashMap<String, usize>) -> Function {
+    let mut statements = function
+        .const_parameters
+        .iter()
+        .filter_map(|parameter| {
+            constants.get(&parameter.identifier.name).map(|value| {
+                let variables = Variables {
+                    names: vec![VariableName {
+                        mutable: false,
+                        identifier: parameter.identifier.clone(),
+                        span: parameter.span.clone(),
+                    }],
+                    type_: None,
+                };
+                let value = Expression::Integer(parameter.type_.clone(), value.to_string(), parameter.span.clone());
+
+                Statement::Definition(Declare::Const, variables, vec![value], parameter.span.clone())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    statements.extend(
+        function
+            .statements
+            .into_iter()
+            .map(|statement| monomorphize_statement(statement, constants)),
+    );
+
+    let input = function
+        .input
+        .into_iter()
+        .map(|input| match input {
+            InputVariable::FunctionInput(mut input) => {
+                input.type_ = input.type_.resolve_const_parameters(constants);
+
+                InputVariable::FunctionInput(input)
+            }
+            input => input,
+        })
+        .collect();
+
+    let const_parameters = function
+        .const_parameters
+        .into_iter()
+        .filter(|parameter| !constants.contains_key(&parameter.identifier.name))
+        .collect();
+
+    Function {
+        identifier: function.identifier,
+        const_parameters,
+        input,
+        returns: function.returns.map(|type_| type_.resolve_const_parameters(constants)),
+        statements,
+        span: function.span,
+    }
+}
+
+fn monomorphize_statements(statements: Vec<Statement>, constants: &HashMap<String, usize>) -> Vec<Statement> {
+    statements
+        .into_iter()
+        .map(|statement| monomorphize_statement(statement, constants))
+        .collect()
+}
+
+fn monomorphize_conditional(
+    statement: ConditionalStatement,
+    constants: &HashMap<String, usize>,
+) -> ConditionalStatement {
+    ConditionalStatement {
+        condition: monomorphize_expression(statement.condition, constants),
+        statements: monomorphize_statements(statement.statements, constants),
+        next: statement.next.map(|next| match next {
+            ConditionalNestedOrEndStatement::Nested(nested) => {
+                ConditionalNestedOrEndStatement::Nested(Box::new(monomorphize_conditional(*nested, constants)))
+            }
+            ConditionalNestedOrEndStatement::End(statements) => {
+                ConditionalNestedOrEndStatement::End(monomorphize_statements(statements, constants))
+            }
+        }),
+    }
+}
+
+fn monomorphize_statement(statement: Statement, constants: &HashMap<String, usize>) -> Statement {
+    match statement {
+        Statement::Return(expression, span) => Statement::Return(monomorphize_expression(expression, constants), span),
+        Statement::Definition(declare, mut variables, expressions, span) => {
+            variables.type_ = variables.type_.map(|type_| type_.resolve_const_parameters(constants));
+
+            Statement::Definition(
+                declare,
+                variables,
+                monomorphize_expressions(expressions, constants),
+                span,
+            )
+        }
+        Statement::Assign(assignee, expression, span) => Statement::Assign(
+            monomorphize_assignee(assignee, constants),
+            monomorphize_expression(expression, constants),
+            span,
+        ),
+        Statement::Conditional(statement, span) => {
+            Statement::Conditional(monomorphize_conditional(statement, constants), span)
+        }
+        Statement::Iteration(index, start, stop, statements, span) => Statement::Iteration(
+            index,
+            monomorphize_expression(start, constants),
+            monomorphize_expression(stop, constants),
+            monomorphize_statements(statements, constants),
+            span,
+        ),
+        Statement::Match(expression, arms, span) => {
+            let arms = arms
+                .into_iter()
+                .map(|arm| MatchStatementArm {
+                    pattern: arm.pattern,
+                    statements: monomorphize_statements(arm.statements, constants),
+                })
+                .collect();
+
+            Statement::Match(monomorphize_expression(expression, constants), arms, span)
+        }
+        Statement::Console(console) => {
+            let function = match console.function {
+                ConsoleFunction::Assert(expression) => {
+                    ConsoleFunction::Assert(monomorphize_expression(expression, constants))
+                }
+                ConsoleFunction::Debug(string) => {
+                    ConsoleFunction::Debug(monomorphize_formatted_string(string, constants))
+                }
+                ConsoleFunction::Error(string) => {
+                    ConsoleFunction::Error(monomorphize_formatted_string(string, constants))
+                }
+                ConsoleFunction::Log(string) => ConsoleFunction::Log(monomorphize_formatted_string(string, constants)),
+            };
+
+            Statement::Console(ConsoleFunctionCall {
+                function,
+                span: console.span,
+            })
+        }
+        Statement::Expression(expression, span) => {
+            Statement::Expression(monomorphize_expression(expression, constants), span)
+        }
+    }
+}
+
+fn monomorphize_assignee(assignee: Assignee, constants: &HashMap<String, usize>) -> Assignee {
+    match assignee {
+        Assignee::Array(array, index) => Assignee::Array(
+            Box::new(monomorphize_assignee(*array, constants)),
+            monomorphize_range_or_expression(index, constants),
+        ),
+        Assignee::Tuple(tuple, index) => Assignee::Tuple(Box::new(monomorphize_assignee(*tuple, constants)), index),
+        Assignee::CircuitField(circuit, field) => {
+            Assignee::CircuitField(Box::new(monomorphize_assignee(*circuit, constants)), field)
+        }
+        assignee => assignee,
+    }
+}
+
+fn monomorphize_formatted_string(string: FormattedString, constants: &HashMap<String, usize>) -> FormattedString {
+    FormattedString {
+        parameters: string
+            .parameters
+            .into_iter()
+            .map(|parameter| FormattedParameter {
+                expression: monomorphize_expression(parameter.expression, constants),
+                span: parameter.span,
+            })
+            .collect(),
+        ..string
+    }
+}
+
+fn monomorphize_range_or_expression(index: RangeOrExpression, constants: &HashMap<String, usize>) -> RangeOrExpression {
+    match index {
+        RangeOrExpression::Range(from, to) => RangeOrExpression::Range(
+            from.map(|from| monomorphize_expression(from, constants)),
+            to.map(|to| monomorphize_expression(to, constants)),
+        ),
+        RangeOrExpression::Expression(index) => {
+            RangeOrExpression::Expression(monomorphize_expression(index, constants))
+        }
+    }
+}
+
+fn monomorphize_expressions(expressions: Vec<Expression>, constants: &HashMap<String, usize>) -> Vec<Expression> {
+    expressions
+        .into_iter()
+        .map(|expression| monomorphize_expression(expression, constants))
+        .collect()
+}
+
+fn monomorphize_boxed(mut expression: Box<Expression>, constants: &HashMap<String, usize>) -> Box<Expression> {
+    *expression = monomorphize_expression(*expression, constants);
+    expression
+}
+
+/// Returns the expression with every array initializer over a known const parameter expanded into an array.
+fn monomorphize_expression(expression: Expression, constants: &HashMap<String, usize>) -> Expression {
+    match expression {
+        // Number operations
+        Expression::Add(left, right, span) => Expression::Add(
+            monomorphize_boxed(left, constants),
+            monomorphize_boxed(right, constants),
+            span,
+        ),
+        Expression::Sub(left, right, span) => Expression::Sub(
+            monomorphize_boxed(left, constants),
+            monomorphize_boxed(right, constants),
+            span,
+        ),
+        Expression::Mul(left, right, span) => Expression::Mul(
+            monomorphize_boxed(left, constants),
+            monomorphize_boxed(right, constants),
+            span,
+        ),
+        Expression::Div(left, right, span) => Expression::Div(
+            monomorphize_boxed(left, constants),
+            monomorphize_boxed(right, constants),
+            span,
+        ),
+        Expression::Pow(left, right, span) => Expression::Pow(
+            monomorphize_boxed(left, constants),
+            monomorphize_boxed(right, constants),
+            span,
+        ),
+        Expression::Negate(expression, span) => Expression::Negate(monomorphize_boxed(expression, constants), span),
+
+        // Boolean operations
+        Expression::Not(expression, span) => Expression::Not(monomorphize_boxed(expression, constants), span),
+        Expression::Or(left, right, span) => Expression::Or(
+            monomorphize_boxed(left, constants),
+            monomorphize_boxed(right, constants),
+            span,
+        ),
+        Expression::And(left, right, span) => Expression::And(
+            monomorphize_boxed(left, constants),
+            monomorphize_boxed(right, constants),
+            span,
+        ),
+        Expression::Eq(left, right, span) => Expression::Eq(
+            monomorphize_boxed(left, constants),
+            monomorphize_boxed(right, constants),
+            span,
+        ),
+        Expression::Ge(left, right, span) => Expression::Ge(
+            monomorphize_boxed(left, constants),
+            monomorphize_boxed(right, constants),
+            span,
+        ),
+        Expression::Gt(left, right, span) => Expression::Gt(
+            monomorphize_boxed(left, constants),
+            monomorphize_boxed(right, constants),
+            span,
+        ),
+        Expression::Le(left, right, span) => Expression::Le(
+            monomorphize_boxed(left, constants),
+            monomorphize_boxed(right, constants),
+            span,
+        ),
+        Expression::Lt(left, right, span) => Expression::Lt(
+            monomorphize_boxed(left, constants),
+            monomorphize_boxed(right, constants),
+            span,
+        ),
+
+        // Conditionals
+        Expression::IfElse(first, second, third, span) => Expression::IfElse(
+            monomorphize_boxed(first, constants),
+            monomorphize_boxed(second, constants),
+            monomorphize_boxed(third, constants),
+            span,
+        ),
+        Expression::Match(expression, arms, span) => {
+            let arms = arms
+                .into_iter()
+                .map(|arm| MatchArm {
+                    pattern: arm.pattern,
+                    expression: monomorphize_expression(arm.expression, constants),
+                })
+                .collect();
+
+            Expression::Match(monomorphize_boxed(expression, constants), arms, span)
+        }
+
+        // Arrays
+        Expression::Array(elements, span) => {
+            let elements = elements
+                .into_iter()
+                .map(|element| {
+                    Box::new(match *element {
+                        SpreadOrExpression::Spread(expression) => {
+                            SpreadOrExpression::Spread(monomorphize_expression(expression, constants))
+                        }
+                        SpreadOrExpression::Expression(expression) => {
+                            SpreadOrExpression::Expression(monomorphize_expression(expression, constants))
+                        }
+                    })
+                })
+                .collect();
+
+            Expression::Array(elements, span)
+        }
+        Expression::GenericArray(element, parameter, span) => {
+            let element = monomorphize_expression(*element, constants);
+
+            match constants.get(&parameter.name) {
+                Some(length) => {
+                    Expression::Array(vec![Box::new(SpreadOrExpression::Expression(element)); *length], span)
+                }
+                None => Expression::GenericArray(Box::new(element), parameter, span),
+            }
+        }
+        Expression::ArrayAccess(array, index, span) => Expression::ArrayAccess(
+            monomorphize_boxed(array, constants),
+            Box::new(monomorphize_range_or_expression(*index, constants)),
+            span,
+        ),
+
+        // Tuples
+        Expression::Tuple(expressions, span) => {
+            Expression::Tuple(monomorphize_expressions(expressions, constants), span)
+        }
+        Expression::TupleAccess(tuple, index, span) => {
+            Expression::TupleAccess(monomorphize_boxed(tuple, constants), index, span)
+        }
+
+        // Circuits
+        Expression::Circuit(identifier, members, span) => {
+            let members = members
+                .into_iter()
+                .map(|member| CircuitVariableDefinition {
+                    identifier: member.identifier,
+                    expression: monomorphize_expression(member.expression, constants),
+                })
+                .collect();
+
+            Expression::Circuit(identifier, members, span)
+        }
+        Expression::CircuitMemberAccess(circuit, member, span) => {
+            Expression::CircuitMemberAccess(monomorphize_boxed(circuit, constants), member, span)
+        }
+        Expression::CircuitStaticFunctionAccess(circuit, member, span) => {
+            Expression::CircuitStaticFunctionAccess(monomorphize_boxed(circuit, constants), member, span)
+        }
+
+        // Functions
+        Expression::FunctionCall(function, arguments, span) => Expression::FunctionCall(
+            monomorphize_boxed(function, constants),
+            monomorphize_expressions(arguments, constants),
+            span,
+        ),
+        Expression::CoreFunctionCall(name, arguments, span) => {
+            Expression::CoreFunctionCall(name, monomorphize_expressions(arguments, constants), span)
+        }
+
+        // Variables and values
+        expression => expression,
+    }
+}
@@ -14,13 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::errors::{FunctionError, ImportError, OutputBytesError, OutputFileError};
+use crate::errors::{FunctionError, ImportError, InterpreterError, OutputBytesError, OutputFileError};
 use leo_ast::ParserError;
 use leo_input::InputParserError;
 use leo_state::LocalDataVerificationError;
+use leo_typed::Diagnostic;
 
 use bincode::Error as SerdeError;
-use std::path::PathBuf;
+use std::{io::Error as IOError, path::PathBuf};
 
 #[derive(Debug, Error)]
 pub enum CompilerError {
@@ -30,12 +31,18 @@ pub enum CompilerError {
     #[error("{}", _0)]
     InputParserError(#[from] InputParserError),
 
+    #[error("{}", _0)]
+    InterpreterError(#[from] InterpreterError),
+
     #[error("Cannot find input files with context name `{}`", _0)]
     InvalidTestContext(String),
 
     #[error("{}", _0)]
     FunctionError(#[from] FunctionError),
 
+    #[error("Cannot generate fuzz test inputs of type `{}`", _0)]
+    FuzzType(String),
+
     #[error("Cannot read from the provided file path - {:?}", _0)]
     FileReadError(PathBuf),
 
@@ -62,13 +69,63 @@ pub enum CompilerError {
 
     #[error("{}", _0)]
     SerdeError(#[from] SerdeError),
+
+    #[error("Cannot load the system parameters - {}", _0)]
+    SystemParametersError(IOError),
+}
+
+impl From<&CompilerError> for Diagnostic {
+    fn from(error: &CompilerError) -> Self {
+        match error {
+            CompilerError::ImportError(error) => Diagnostic::from(error),
+            CompilerError::InputParserError(error) => Diagnostic::from(error),
+            CompilerError::InterpreterError(error) => Diagnostic::from(error),
+            CompilerError::FunctionError(error) => Diagnostic::from(error),
+            CompilerError::OutputError(error) => Diagnostic::from(error),
+            CompilerError::OutputStringError(error) => Diagnostic::from(error),
+            CompilerError::ParserError(error) => Diagnostic::from(error),
+            error => Diagnostic::error(error.to_string()).with_code(error.code()),
+        }
+    }
 }
 
 impl CompilerError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            CompilerError::ImportError(error) => error.code(),
+            CompilerError::InputParserError(error) => error.code(),
+            CompilerError::InterpreterError(error) => error.code(),
+            CompilerError::InvalidTestContext(_) => "E0201",
+            CompilerError::FunctionError(error) => error.code(),
+            CompilerError::FuzzType(_) => "E0202",
+            CompilerError::FileReadError(_) => "E0203",
+            CompilerError::LocalDataVerificationError(_) => "E0204",
+            CompilerError::NoMain => "E0205",
+            CompilerError::NoMainFunction => "E0206",
+            CompilerError::NoTestInput => "E0207",
+            CompilerError::OutputError(error) => error.code(),
+            CompilerError::OutputStringError(error) => error.code(),
+            CompilerError::ParserError(error) => error.code(),
+            CompilerError::SerdeError(_) => "E0208",
+            CompilerError::SystemParametersError(_) => "E0209",
+        }
+    }
+
+    /// Returns a diagnostic for the error, or one for each syntax error of a program.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        match self {
+            CompilerError::ImportError(ImportError::ParserError(error)) | CompilerError::ParserError(error) => {
+                Diagnostic::from_parser_error(error)
+            }
+            error => vec![Diagnostic::from(error)],
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             CompilerError::InputParserError(error) => error.set_path(path),
             CompilerError::FunctionError(error) => error.set_path(path),
+            CompilerError::InterpreterError(error) => error.set_path(path),
             CompilerError::OutputStringError(error) => error.set_path(path),
             _ => {}
         }
@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::errors::ExpressionError;
-use leo_typed::{Error as FormattedError, Span};
+use leo_typed::{Diagnostic, Error as FormattedError, Span};
 
 use std::path::PathBuf;
 
@@ -28,7 +28,23 @@ pub enum ConsoleError {
     Expression(#[from] ExpressionError),
 }
 
+impl From<&ConsoleError> for Diagnostic {
+    fn from(error: &ConsoleError) -> Self {
+        match error {
+            ConsoleError::Error(error) => Diagnostic::from(error),
+            ConsoleError::Expression(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl ConsoleError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ConsoleError::Error(error) => error.code,
+            ConsoleError::Expression(error) => error.code(),
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             ConsoleError::Expression(error) => error.set_path(path),
@@ -36,8 +52,8 @@ impl ConsoleError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        ConsoleError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        ConsoleError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn length(containers: usize, parameters: usize, span: Span) -> Self {
@@ -46,24 +62,24 @@ impl ConsoleError {
             containers, parameters
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0210", message, span)
     }
 
     pub fn assertion_depends_on_input(span: Span) -> Self {
         let message = format!("console.assert() failed to evaluate. This error is caused by empty input file values");
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0211", message, span)
     }
 
     pub fn assertion_failed(expression: String, span: Span) -> Self {
         let message = format!("Assertion `{}` failed", expression);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0212", message, span)
     }
 
     pub fn assertion_must_be_boolean(expression: String, span: Span) -> Self {
         let message = format!("Assertion expression `{}` must evaluate to a boolean value", expression);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0213", message, span)
     }
 }
@@ -16,7 +16,7 @@
 
 use crate::errors::{AddressError, BooleanError, FieldError, FunctionError, GroupError, IntegerError, ValueError};
 use leo_core::LeoCoreError;
-use leo_typed::{Error as FormattedError, Identifier, Span};
+use leo_typed::{Diagnostic, Error as FormattedError, Identifier, Span};
 
 use snarkos_errors::gadgets::SynthesisError;
 use std::path::PathBuf;
@@ -51,7 +51,37 @@ pub enum ExpressionError {
     ValueError(#[from] ValueError),
 }
 
+impl From<&ExpressionError> for Diagnostic {
+    fn from(error: &ExpressionError) -> Self {
+        match error {
+            ExpressionError::AddressError(error) => Diagnostic::from(error),
+            ExpressionError::BooleanError(error) => Diagnostic::from(error),
+            ExpressionError::Error(error) => Diagnostic::from(error),
+            ExpressionError::FieldError(error) => Diagnostic::from(error),
+            ExpressionError::FunctionError(error) => Diagnostic::from(error.as_ref()),
+            ExpressionError::GroupError(error) => Diagnostic::from(error),
+            ExpressionError::IntegerError(error) => Diagnostic::from(error),
+            ExpressionError::LeoCoreError(error) => Diagnostic::from(error),
+            ExpressionError::ValueError(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl ExpressionError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ExpressionError::AddressError(error) => error.code(),
+            ExpressionError::BooleanError(error) => error.code(),
+            ExpressionError::Error(error) => error.code,
+            ExpressionError::FieldError(error) => error.code(),
+            ExpressionError::FunctionError(error) => error.code(),
+            ExpressionError::GroupError(error) => error.code(),
+            ExpressionError::IntegerError(error) => error.code(),
+            ExpressionError::LeoCoreError(error) => error.code(),
+            ExpressionError::ValueError(error) => error.code(),
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             ExpressionError::AddressError(error) => error.set_path(path),
@@ -66,8 +96,8 @@ impl ExpressionError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        ExpressionError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        ExpressionError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -76,85 +106,146 @@ impl ExpressionError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0301", message, span)
     }
 
     pub fn cannot_evaluate(operation: String, span: Span) -> Self {
         let message = format!("Mismatched types found for operation `{}`", operation);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0302", message, span)
     }
 
     pub fn conditional_boolean(actual: String, span: Span) -> Self {
         let message = format!("if, else conditional must resolve to a boolean, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0303", message, span)
+    }
+
+    pub fn enum_variant_type(variant: String, expected: String, actual: String, span: Span) -> Self {
+        let message = format!(
+            "enum variant `{}` expected a value of type `{}`, found `{}`",
+            variant, expected, actual
+        );
+
+        Self::new_from_span("E0304", message, span)
+    }
+
+    pub fn const_member_type(expected: String, actual: String, span: Span) -> Self {
+        let message = format!("Expected circuit member of type `{}`, found `{}`", expected, actual);
+
+        Self::new_from_span("E0305", message, span)
+    }
+
+    pub fn undefined_const_parameter(parameter: String, span: Span) -> Self {
+        let message = format!("cannot infer const parameter `{}` from the given circuit members", parameter);
+
+        Self::new_from_span("E0306", message, span)
     }
 
     pub fn expected_circuit_member(expected: String, span: Span) -> Self {
         let message = format!("expected circuit member `{}`, not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0307", message, span)
     }
 
     pub fn incompatible_types(operation: String, span: Span) -> Self {
         let message = format!("no implementation for `{}`", operation);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0308", message, span)
     }
 
     pub fn index_out_of_bounds(index: usize, span: Span) -> Self {
         let message = format!("cannot access index {} of tuple out of bounds", index);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0309", message, span)
     }
 
     pub fn invalid_index(actual: String, span: Span) -> Self {
         let message = format!("index must resolve to an integer, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0310", message, span)
     }
 
     pub fn invalid_length(expected: usize, actual: usize, span: Span) -> Self {
         let message = format!("expected array length {}, found one with length {}", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0311", message, span)
     }
 
     pub fn invalid_spread(actual: String, span: Span) -> Self {
         let message = format!("spread should contain an array, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0312", message, span)
+    }
+
+    pub fn invalid_match(actual: String, span: Span) -> Self {
+        let message = format!("match expression must resolve to an enum value, found `{}`", actual);
+
+        Self::new_from_span("E0313", message, span)
+    }
+
+    pub fn invalid_match_bindings(variant: String, expected: usize, actual: usize, span: Span) -> Self {
+        let message = format!(
+            "pattern `{}` must bind {} values, found {} bindings",
+            variant, expected, actual
+        );
+
+        Self::new_from_span("E0314", message, span)
+    }
+
+    pub fn invalid_variant_arguments(variant: String, expected: usize, actual: usize, span: Span) -> Self {
+        let message = format!(
+            "enum variant `{}` expected {} values, found {} values",
+            variant, expected, actual
+        );
+
+        Self::new_from_span("E0315", message, span)
     }
 
     pub fn invalid_member_access(member: String, span: Span) -> Self {
         let message = format!("non-static member `{}` must be accessed using `.` syntax", member);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0316", message, span)
     }
 
     pub fn invalid_static_access(member: String, span: Span) -> Self {
         let message = format!("static member `{}` must be accessed using `::` syntax", member);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0317", message, span)
     }
 
     pub fn function_no_return(function: String, span: Span) -> Self {
         let message = format!("inline function call to `{}` did not return", function);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0318", message, span)
+    }
+
+    pub fn mismatched_enum(expected: String, actual: String, span: Span) -> Self {
+        let message = format!("expected a pattern of enum `{}`, found enum `{}`", expected, actual);
+
+        Self::new_from_span("E0319", message, span)
+    }
+
+    pub fn non_exhaustive_match(enum_name: String, missing: Vec<String>, span: Span) -> Self {
+        let message = format!(
+            "non-exhaustive match on enum `{}`, variants `{}` are not covered",
+            enum_name,
+            missing.join("`, `")
+        );
+
+        Self::new_from_span("E0320", message, span)
     }
 
     pub fn self_keyword(span: Span) -> Self {
         let message = format!("cannot call keyword `Self` outside of a circuit function");
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0321", message, span)
     }
 
     pub fn undefined_array(actual: String, span: Span) -> Self {
         let message = format!("array `{}` must be declared before it is used in an expression", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0322", message, span)
     }
 
     pub fn undefined_circuit(actual: String, span: Span) -> Self {
@@ -163,13 +254,19 @@ impl ExpressionError {
             actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0323", message, span)
+    }
+
+    pub fn undefined_enum(actual: String, span: Span) -> Self {
+        let message = format!("enum `{}` must be declared before it is used in an expression", actual);
+
+        Self::new_from_span("E0324", message, span)
     }
 
     pub fn undefined_identifier(identifier: Identifier) -> Self {
         let message = format!("cannot find value `{}` in this scope", identifier.name);
 
-        Self::new_from_span(message, identifier.span)
+        Self::new_from_span("E0325", message, identifier.span)
     }
 
     pub fn undefined_function(function: String, span: Span) -> Self {
@@ -178,30 +275,45 @@ impl ExpressionError {
             function
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0326", message, span)
     }
 
     pub fn undefined_member_access(circuit: String, member: String, span: Span) -> Self {
         let message = format!("Circuit `{}` has no member `{}`", circuit, member);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0327", message, span)
     }
 
     pub fn undefined_static_access(circuit: String, member: String, span: Span) -> Self {
         let message = format!("Circuit `{}` has no static member `{}`", circuit, member);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0328", message, span)
+    }
+
+    pub fn undefined_variant(enum_name: String, variant: String, span: Span) -> Self {
+        let message = format!("Enum `{}` has no variant `{}`", enum_name, variant);
+
+        Self::new_from_span("E0329", message, span)
     }
 
     pub fn unexpected_array(expected: String, span: Span) -> Self {
         let message = format!("expected type `{}`, found array with elements", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0330", message, span)
     }
 
     pub fn unexpected_tuple(expected: String, actual: String, span: Span) -> Self {
         let message = format!("expected type `{}`, found tuple with values `{}`", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0331", message, span)
+    }
+
+    pub fn unresolved_array_length(parameter: String, span: Span) -> Self {
+        let message = format!(
+            "the array length `{}` is not a const parameter of the enclosing function or circuit",
+            parameter
+        );
+
+        Self::new_from_span("E0332", message, span)
     }
 }
@@ -25,8 +25,9 @@ use crate::errors::{
     StatementError,
     ValueError,
 };
-use leo_typed::{Error as FormattedError, Span};
+use leo_typed::{Diagnostic, Error as FormattedError, Span};
 
+use snarkos_errors::gadgets::SynthesisError;
 use std::path::PathBuf;
 
 #[derive(Debug, Error)]
@@ -62,7 +63,39 @@ pub enum FunctionError {
     ValueError(#[from] ValueError),
 }
 
+impl From<&FunctionError> for Diagnostic {
+    fn from(error: &FunctionError) -> Self {
+        match error {
+            FunctionError::AddressError(error) => Diagnostic::from(error),
+            FunctionError::BooleanError(error) => Diagnostic::from(error),
+            FunctionError::ExpressionError(error) => Diagnostic::from(error),
+            FunctionError::Error(error) => Diagnostic::from(error),
+            FunctionError::FieldError(error) => Diagnostic::from(error),
+            FunctionError::GroupError(error) => Diagnostic::from(error),
+            FunctionError::IntegerError(error) => Diagnostic::from(error),
+            FunctionError::OutputStringError(error) => Diagnostic::from(error),
+            FunctionError::StatementError(error) => Diagnostic::from(error),
+            FunctionError::ValueError(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl FunctionError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            FunctionError::AddressError(error) => error.code(),
+            FunctionError::BooleanError(error) => error.code(),
+            FunctionError::ExpressionError(error) => error.code(),
+            FunctionError::Error(error) => error.code,
+            FunctionError::FieldError(error) => error.code(),
+            FunctionError::GroupError(error) => error.code(),
+            FunctionError::IntegerError(error) => error.code(),
+            FunctionError::OutputStringError(error) => error.code(),
+            FunctionError::StatementError(error) => error.code(),
+            FunctionError::ValueError(error) => error.code(),
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             FunctionError::AddressError(error) => error.set_path(path),
@@ -78,43 +111,82 @@ impl FunctionError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        FunctionError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        FunctionError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn arguments_length(expected: usize, actual: usize, span: Span) -> Self {
         let message = format!("function expected {} input variables, found {}", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0220", message, span)
+    }
+
+    pub fn const_argument_type(expected: String, actual: String, span: Span) -> Self {
+        let message = format!("Expected function input of type `{}`, found `{}`", expected, actual);
+
+        Self::new_from_span("E0221", message, span)
+    }
+
+    pub fn generic_main(span: Span) -> Self {
+        let message = format!("main function cannot have const parameters");
+
+        Self::new_from_span("E0222", message, span)
     }
 
     pub fn invalid_array(actual: String, span: Span) -> Self {
         let message = format!("Expected function input array, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0223", message, span)
+    }
+
+    pub fn invalid_circuit(expected: String, actual: String, span: Span) -> Self {
+        let message = format!("Expected function input circuit `{}`, found `{}`", expected, actual);
+
+        Self::new_from_span("E0229", message, span)
     }
 
     pub fn invalid_tuple(actual: String, span: Span) -> Self {
         let message = format!("Expected function input tuple, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0224", message, span)
     }
 
     pub fn return_arguments_length(expected: usize, actual: usize, span: Span) -> Self {
         let message = format!("function expected {} returns, found {} returns", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0225", message, span)
     }
 
     pub fn return_argument_type(expected: String, actual: String, span: Span) -> Self {
         let message = format!("Expected function return type `{}`, found `{}`", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0226", message, span)
+    }
+
+    pub fn undefined_const_parameter(parameter: String, span: Span) -> Self {
+        let message = format!("cannot infer const parameter `{}` from the given input variables", parameter);
+
+        Self::new_from_span("E0227", message, span)
     }
 
     pub fn input_not_found(expected: String, span: Span) -> Self {
         let message = format!("main function input {} not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0228", message, span)
+    }
+
+    pub fn invalid_local_data(member: String, expected: String, span: Span) -> Self {
+        let message = format!("expected program state `{}` to be a {}", member, expected);
+
+        Self::new_from_span("E0244", message, span)
+    }
+
+    pub fn local_data_synthesis(error: SynthesisError, span: Span) -> Self {
+        let message = format!(
+            "the local data commitment could not be enforced due to synthesis error `{:?}`",
+            error,
+        );
+
+        Self::new_from_span("E0245", message, span)
     }
 }
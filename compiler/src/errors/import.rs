@@ -15,9 +15,10 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::ParserError;
-use leo_typed::{Error as FormattedError, Identifier, ImportSymbol, Span};
+use leo_typed::{Diagnostic, Error as FormattedError, Identifier, ImportSymbol, Span};
 
 use leo_core::LeoCoreError;
+use leo_package::errors::LockFileError;
 use std::{io, path::PathBuf};
 
 #[derive(Debug, Error)]
@@ -32,25 +33,43 @@ pub enum ImportError {
     ParserError(#[from] ParserError),
 }
 
+impl From<&ImportError> for Diagnostic {
+    fn from(error: &ImportError) -> Self {
+        match error {
+            ImportError::Error(error) => Diagnostic::from(error),
+            ImportError::LeoCoreError(error) => Diagnostic::from(error),
+            ImportError::ParserError(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl ImportError {
-    fn new_from_span(message: String, span: Span) -> Self {
-        ImportError::Error(FormattedError::new_from_span(message, span))
+    pub fn code(&self) -> &'static str {
+        match self {
+            ImportError::Error(error) => error.code,
+            ImportError::LeoCoreError(error) => error.code(),
+            ImportError::ParserError(error) => error.code(),
+        }
     }
 
-    fn new_from_span_with_path(message: String, span: Span, path: PathBuf) -> Self {
-        ImportError::Error(FormattedError::new_from_span_with_path(message, span, path))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        ImportError::Error(FormattedError::new_from_span(code, message, span))
+    }
+
+    fn new_from_span_with_path(code: &'static str, message: String, span: Span, path: PathBuf) -> Self {
+        ImportError::Error(FormattedError::new_from_span_with_path(code, message, span, path))
     }
 
     pub fn conflicting_imports(identifier: Identifier) -> Self {
         let message = format!("conflicting imports found for `{}`", identifier.name);
 
-        Self::new_from_span(message, identifier.span)
+        Self::new_from_span("E0230", message, identifier.span)
     }
 
     pub fn convert_os_string(span: Span) -> Self {
         let message = format!("failed to convert file string name, maybe an illegal character?");
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0231", message, span)
     }
 
     pub fn current_directory_error(error: io::Error) -> Self {
@@ -62,19 +81,53 @@ impl ImportError {
         };
         let message = format!("compilation failed trying to find current directory - {:?}", error);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0232", message, span)
     }
 
     pub fn directory_error(error: io::Error, span: Span, path: PathBuf) -> Self {
         let message = format!("compilation failed due to directory error - {:?}", error);
 
-        Self::new_from_span_with_path(message, span, path)
+        Self::new_from_span_with_path("E0233", message, span, path)
+    }
+
+    pub fn undeclared_dependency(identifier: Identifier, importing_package: Option<String>) -> Self {
+        let importing_package = match importing_package {
+            Some(name) => format!("`{}`", name),
+            None => "the current package".to_string(),
+        };
+        let message = format!(
+            "cannot find imported package `{}` in source files or in the dependencies of {}",
+            identifier.name, importing_package
+        );
+
+        Self::new_from_span("E0238", message, identifier.span)
+    }
+
+    pub fn dependency_not_installed(identifier: Identifier) -> Self {
+        let message = format!(
+            "dependency `{}` is not installed in the imports directory, run `leo fetch` to install it",
+            identifier.name
+        );
+
+        Self::new_from_span("E0239", message, identifier.span)
+    }
+
+    pub fn lock_file_error(error: LockFileError) -> Self {
+        let span = Span {
+            text: "".to_string(),
+            line: 0,
+            start: 0,
+            end: 0,
+        };
+        let message = format!("compilation failed trying to read the dependency graph - {}", error);
+
+        Self::new_from_span("E0241", message, span)
     }
 
     pub fn star(path: PathBuf, span: Span) -> Self {
         let message = format!("cannot import `*` from path `{:?}`", path);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0234", message, span)
     }
 
     pub fn expected_lib_file(entry: String, span: Span) -> Self {
@@ -83,7 +136,7 @@ impl ImportError {
             entry, span.text
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0235", message, span)
     }
 
     pub fn unknown_package(identifier: Identifier) -> Self {
@@ -92,12 +145,12 @@ impl ImportError {
             identifier.name
         );
 
-        Self::new_from_span(message, identifier.span)
+        Self::new_from_span("E0236", message, identifier.span)
     }
 
     pub fn unknown_symbol(symbol: ImportSymbol, file: String) -> Self {
         let message = format!("cannot find imported symbol `{}` in imported file `{}`", symbol, file);
-        let error = FormattedError::new_from_span(message, symbol.span);
+        let error = FormattedError::new_from_span("E0237", message, symbol.span);
 
         ImportError::Error(error)
     }
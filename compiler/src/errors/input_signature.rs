@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_typed::{Diagnostic, DiagnosticRange, Error as FormattedError, RelatedSpan, Span, Type, INDENT};
+
+use std::{fmt, path::PathBuf};
+
+/// An input file parameter that does not match the parameters of the `main` function.
+/// The related span points to the matching location in the other file, if there is one.
+#[derive(Debug)]
+pub struct InputSignatureError {
+    error: FormattedError,
+    related: Option<RelatedSpan>,
+}
+
+impl From<&InputSignatureError> for Diagnostic {
+    fn from(error: &InputSignatureError) -> Self {
+        let mut diagnostic = Diagnostic::from(&error.error);
+
+        if let Some(related) = &error.related {
+            diagnostic.add_related(related.clone());
+        }
+
+        diagnostic
+    }
+}
+
+impl InputSignatureError {
+    pub fn code(&self) -> &'static str {
+        self.error.code
+    }
+
+    fn new(code: &'static str, message: String, span: Span, path: &PathBuf) -> Self {
+        Self {
+            error: FormattedError::new_from_span_with_path(code, message, span, path.clone()),
+            related: None,
+        }
+    }
+
+    fn with_related(mut self, message: String, span: &Span, path: &PathBuf) -> Self {
+        self.related = Some(RelatedSpan {
+            file: Some(path.to_string_lossy().to_string()),
+            range: DiagnosticRange::from(span),
+            message,
+        });
+        self
+    }
+
+    pub fn missing_input(
+        name: &str,
+        type_: &Type,
+        span: Span,
+        main_file_path: &PathBuf,
+        section: Option<(Span, &PathBuf)>,
+    ) -> Self {
+        let message = format!(
+            "main function input `{}: {}` is missing from the input file",
+            name,
+            type_.input_type()
+        );
+        let error = Self::new("E0228", message, span, main_file_path);
+
+        match section {
+            Some((span, input_path)) => {
+                error.with_related("add it to this `[main]` section".to_owned(), &span, input_path)
+            }
+            None => error,
+        }
+    }
+
+    pub fn extra_input(
+        name: &str,
+        span: Span,
+        input_path: &PathBuf,
+        main_span: &Span,
+        main_file_path: &PathBuf,
+    ) -> Self {
+        let message = format!("input `{}` is not a parameter of the main function", name);
+
+        Self::new("E0242", message, span, input_path).with_related(
+            "main function declared here".to_owned(),
+            main_span,
+            main_file_path,
+        )
+    }
+
+    pub fn mistyped_input(
+        name: &str,
+        expected: &Type,
+        found: &Type,
+        span: Span,
+        input_path: &PathBuf,
+        parameter_span: &Span,
+        main_file_path: &PathBuf,
+    ) -> Self {
+        let message = format!(
+            "input `{}` has type `{}` but the main function expects `{}`",
+            name,
+            found.input_type(),
+            expected.input_type()
+        );
+
+        Self::new("E0243", message, span, input_path).with_related(
+            format!("`{}: {}` declared here", name, expected.input_type()),
+            parameter_span,
+            main_file_path,
+        )
+    }
+}
+
+impl fmt::Display for InputSignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+
+        // The related location is printed as a note below the error
+        if let Some(related) = &self.related {
+            write!(
+                f,
+                "\n{} = note: {} at {}:{}:{}",
+                INDENT,
+                related.message,
+                related.file.as_deref().unwrap_or_default(),
+                related.range.line_start,
+                related.range.column_start
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputSignatureError {}
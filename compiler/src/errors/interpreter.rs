@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::{
+    AddressError,
+    BooleanError,
+    ConsoleError,
+    ExpressionError,
+    FieldError,
+    FunctionError,
+    GroupError,
+    ImportError,
+    IntegerError,
+    OutputBytesError,
+    StatementError,
+    ValueError,
+};
+use leo_core::LeoCoreError;
+use leo_typed::{Diagnostic, Error as FormattedError, Span, Type};
+
+use std::path::PathBuf;
+
+#[derive(Debug, Error)]
+pub enum InterpreterError {
+    #[error("{}", _0)]
+    AddressError(#[from] AddressError),
+
+    #[error("{}", _0)]
+    BooleanError(#[from] BooleanError),
+
+    #[error("{}", _0)]
+    ConsoleError(#[from] ConsoleError),
+
+    #[error("{}", _0)]
+    Error(#[from] FormattedError),
+
+    #[error("{}", _0)]
+    ExpressionError(#[from] ExpressionError),
+
+    #[error("{}", _0)]
+    FieldError(#[from] FieldError),
+
+    #[error("{}", _0)]
+    FunctionError(#[from] FunctionError),
+
+    #[error("{}", _0)]
+    GroupError(#[from] GroupError),
+
+    #[error("{}", _0)]
+    ImportError(#[from] ImportError),
+
+    #[error("{}", _0)]
+    IntegerError(#[from] IntegerError),
+
+    #[error("{}", _0)]
+    LeoCoreError(#[from] LeoCoreError),
+
+    #[error("{}", _0)]
+    OutputBytesError(#[from] OutputBytesError),
+
+    #[error("{}", _0)]
+    StatementError(#[from] StatementError),
+
+    #[error("{}", _0)]
+    ValueError(#[from] ValueError),
+}
+
+impl From<&InterpreterError> for Diagnostic {
+    fn from(error: &InterpreterError) -> Self {
+        match error {
+            InterpreterError::AddressError(error) => Diagnostic::from(error),
+            InterpreterError::BooleanError(error) => Diagnostic::from(error),
+            InterpreterError::ConsoleError(error) => Diagnostic::from(error),
+            InterpreterError::Error(error) => Diagnostic::from(error),
+            InterpreterError::ExpressionError(error) => Diagnostic::from(error),
+            InterpreterError::FieldError(error) => Diagnostic::from(error),
+            InterpreterError::FunctionError(error) => Diagnostic::from(error),
+            InterpreterError::GroupError(error) => Diagnostic::from(error),
+            InterpreterError::ImportError(error) => Diagnostic::from(error),
+            InterpreterError::IntegerError(error) => Diagnostic::from(error),
+            InterpreterError::LeoCoreError(error) => Diagnostic::from(error),
+            InterpreterError::OutputBytesError(error) => Diagnostic::from(error),
+            InterpreterError::StatementError(error) => Diagnostic::from(error),
+            InterpreterError::ValueError(error) => Diagnostic::from(error),
+        }
+    }
+}
+
+impl InterpreterError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            InterpreterError::AddressError(error) => error.code(),
+            InterpreterError::BooleanError(error) => error.code(),
+            InterpreterError::ConsoleError(error) => error.code(),
+            InterpreterError::Error(error) => error.code,
+            InterpreterError::ExpressionError(error) => error.code(),
+            InterpreterError::FieldError(error) => error.code(),
+            InterpreterError::FunctionError(error) => error.code(),
+            InterpreterError::GroupError(error) => error.code(),
+            InterpreterError::ImportError(error) => error.code(),
+            InterpreterError::IntegerError(error) => error.code(),
+            InterpreterError::LeoCoreError(error) => error.code(),
+            InterpreterError::OutputBytesError(error) => error.code(),
+            InterpreterError::StatementError(error) => error.code(),
+            InterpreterError::ValueError(error) => error.code(),
+        }
+    }
+
+    pub fn set_path(&mut self, path: PathBuf) {
+        match self {
+            InterpreterError::AddressError(error) => error.set_path(path),
+            InterpreterError::BooleanError(error) => error.set_path(path),
+            InterpreterError::ConsoleError(error) => error.set_path(path),
+            InterpreterError::Error(error) => error.set_path(path),
+            InterpreterError::ExpressionError(error) => error.set_path(path),
+            InterpreterError::FieldError(error) => error.set_path(path),
+            InterpreterError::FunctionError(error) => error.set_path(path),
+            InterpreterError::GroupError(error) => error.set_path(path),
+            InterpreterError::ImportError(_error) => {}
+            InterpreterError::IntegerError(error) => error.set_path(path),
+            InterpreterError::LeoCoreError(error) => error.set_path(path),
+            InterpreterError::OutputBytesError(error) => error.set_path(path),
+            InterpreterError::StatementError(error) => error.set_path(path),
+            InterpreterError::ValueError(error) => error.set_path(path),
+        }
+    }
+
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        InterpreterError::Error(FormattedError::new_from_span(code, message, span))
+    }
+
+    pub fn assign_type(name: String, expected: &Type, actual: &Type, span: Span) -> Self {
+        let message = format!(
+            "cannot assign a value of type `{}` to `{}` of type `{}`",
+            actual, name, expected
+        );
+
+        Self::new_from_span("E0501", message, span)
+    }
+
+    pub fn definition_type(name: String, expected: &Type, actual: &Type, span: Span) -> Self {
+        let message = format!("expected `{}` to have type `{}`, found type `{}`", name, expected, actual);
+
+        Self::new_from_span("E0502", message, span)
+    }
+
+    pub fn division_by_zero(operation: String, span: Span) -> Self {
+        let message = format!("division by zero in `{}`", operation);
+
+        Self::new_from_span("E0503", message, span)
+    }
+
+    pub fn immutable_self(function: String, span: Span) -> Self {
+        let message = format!(
+            "cannot call `{}` on an immutable circuit value because it mutates `self`",
+            function
+        );
+
+        Self::new_from_span("E0504", message, span)
+    }
+
+    pub fn integer_overflow(operation: String, type_: String, span: Span) -> Self {
+        let message = format!("the result of `{}` does not fit into type `{}`", operation, type_);
+
+        Self::new_from_span("E0505", message, span)
+    }
+
+    pub fn invalid_exponent(exponent: String, span: Span) -> Self {
+        let message = format!("cannot raise an integer to the negative power `{}`", exponent);
+
+        Self::new_from_span("E0506", message, span)
+    }
+
+    pub fn missing_input(name: String, span: Span) -> Self {
+        let message = format!("the value of input `{}` is missing from the input file", name);
+
+        Self::new_from_span("E0507", message, span)
+    }
+
+    pub fn slice_out_of_bounds(from: usize, to: usize, length: usize, span: Span) -> Self {
+        let message = format!(
+            "slice `[{}..{}]` is out of bounds for an array of length {}",
+            from, to, length
+        );
+
+        Self::new_from_span("E0508", message, span)
+    }
+}
@@ -26,6 +26,12 @@ pub use self::function::*;
 pub mod import;
 pub use self::import::*;
 
+pub mod input_signature;
+pub use self::input_signature::*;
+
+pub mod interpreter;
+pub use self::interpreter::*;
+
 pub mod console;
 pub use self::console::*;
 
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_typed::{Error as FormattedError, Span};
+use leo_typed::{Diagnostic, Error as FormattedError, Span};
 
 use std::path::PathBuf;
 
@@ -24,20 +24,34 @@ pub enum OutputBytesError {
     Error(#[from] FormattedError),
 }
 
+impl From<&OutputBytesError> for Diagnostic {
+    fn from(error: &OutputBytesError) -> Self {
+        match error {
+            OutputBytesError::Error(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl OutputBytesError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            OutputBytesError::Error(error) => error.code,
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             OutputBytesError::Error(error) => error.set_path(path),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        OutputBytesError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        OutputBytesError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn not_enough_registers(span: Span) -> Self {
         let message = format!("number of input registers must be greater than or equal to output registers");
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0240", message, span)
     }
 }
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use leo_typed::Diagnostic;
+
 use std::{io, path::PathBuf};
 
 #[derive(Debug, Error)]
@@ -34,6 +36,24 @@ pub enum OutputFileError {
     Writing(io::Error),
 }
 
+impl From<&OutputFileError> for Diagnostic {
+    fn from(error: &OutputFileError) -> Self {
+        Diagnostic::error(error.to_string()).with_code(error.code())
+    }
+}
+
+impl OutputFileError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            OutputFileError::Crate(_, _) => "E0250",
+            OutputFileError::Creating(_) => "E0251",
+            OutputFileError::FileReadError(_) => "E0252",
+            OutputFileError::FileRemovalError(_) => "E0253",
+            OutputFileError::Writing(_) => "E0254",
+        }
+    }
+}
+
 impl From<std::io::Error> for OutputFileError {
     fn from(error: std::io::Error) -> Self {
         OutputFileError::Crate("std::io", format!("{}", error))
@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::errors::{AddressError, BooleanError, ConsoleError, ExpressionError, IntegerError, ValueError};
-use leo_typed::{Error as FormattedError, Span, Type};
+use leo_typed::{Diagnostic, Error as FormattedError, Span, Type};
 
 use std::path::PathBuf;
 
@@ -43,7 +43,33 @@ pub enum StatementError {
     ValueError(#[from] ValueError),
 }
 
+impl From<&StatementError> for Diagnostic {
+    fn from(error: &StatementError) -> Self {
+        match error {
+            StatementError::AddressError(error) => Diagnostic::from(error),
+            StatementError::BooleanError(error) => Diagnostic::from(error),
+            StatementError::Error(error) => Diagnostic::from(error),
+            StatementError::ExpressionError(error) => Diagnostic::from(error),
+            StatementError::IntegerError(error) => Diagnostic::from(error),
+            StatementError::MacroError(error) => Diagnostic::from(error),
+            StatementError::ValueError(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl StatementError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            StatementError::AddressError(error) => error.code(),
+            StatementError::BooleanError(error) => error.code(),
+            StatementError::Error(error) => error.code,
+            StatementError::ExpressionError(error) => error.code(),
+            StatementError::IntegerError(error) => error.code(),
+            StatementError::MacroError(error) => error.code(),
+            StatementError::ValueError(error) => error.code(),
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             StatementError::AddressError(error) => error.set_path(path),
@@ -56,50 +82,50 @@ impl StatementError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        StatementError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        StatementError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn arguments_type(expected: &Type, actual: &Type, span: Span) -> Self {
         let message = format!("expected return argument type `{}`, found type `{}`", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0350", message, span)
     }
 
     pub fn array_assign_index(span: Span) -> Self {
         let message = format!("Cannot assign single index to array of values");
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0351", message, span)
     }
 
     pub fn array_assign_range(span: Span) -> Self {
         let message = format!("Cannot assign range of array values to single value");
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0352", message, span)
     }
 
     pub fn conditional_boolean(actual: String, span: Span) -> Self {
         let message = format!("If, else conditional must resolve to a boolean, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0353", message, span)
     }
 
     pub fn immutable_assign(name: String, span: Span) -> Self {
         let message = format!("Cannot assign to immutable variable `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0354", message, span)
     }
 
     pub fn immutable_circuit_function(name: String, span: Span) -> Self {
         let message = format!("Cannot mutate circuit function, `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0355", message, span)
     }
 
     pub fn immutable_circuit_variable(name: String, span: Span) -> Self {
         let message = format!("Circuit member variable `{}` is immutable", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0356", message, span)
     }
 
     pub fn indicator_calculation(name: String, span: Span) -> Self {
@@ -108,7 +134,7 @@ impl StatementError {
             name
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0357", message, span)
     }
 
     pub fn invalid_number_of_definitions(expected: usize, actual: usize, span: Span) -> Self {
@@ -117,7 +143,7 @@ impl StatementError {
             expected, actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0358", message, span)
     }
 
     pub fn invalid_number_of_returns(expected: usize, actual: usize, span: Span) -> Self {
@@ -126,13 +152,13 @@ impl StatementError {
             expected, actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0359", message, span)
     }
 
     pub fn multiple_definition(value: String, span: Span) -> Self {
         let message = format!("cannot assign multiple variables to a single value: {}", value,);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0360", message, span)
     }
 
     pub fn select_fail(first: String, second: String, span: Span) -> Self {
@@ -141,42 +167,42 @@ impl StatementError {
             first, second
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0361", message, span)
     }
 
     pub fn tuple_assign_index(span: Span) -> Self {
         let message = format!("Cannot assign single index to tuple of values");
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0362", message, span)
     }
 
     pub fn tuple_type(type_: String, span: Span) -> Self {
         let message = format!("Expected tuple type, found type `{}`", type_);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0363", message, span)
     }
 
     pub fn unassigned(name: String, span: Span) -> Self {
         let message = format!("Expected assignment of return values for expression `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0364", message, span)
     }
 
     pub fn undefined_variable(name: String, span: Span) -> Self {
         let message = format!("Attempted to assign to unknown variable `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0365", message, span)
     }
 
     pub fn undefined_circuit(name: String, span: Span) -> Self {
         let message = format!("Attempted to assign to unknown circuit `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0366", message, span)
     }
 
     pub fn undefined_circuit_variable(name: String, span: Span) -> Self {
         let message = format!("Attempted to assign to unknown circuit member variable `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0367", message, span)
     }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_typed::{Error as FormattedError, Span};
+use leo_typed::{Diagnostic, Error as FormattedError, Span};
 
 use snarkos_errors::{gadgets::SynthesisError, objects::account::AccountError};
 use std::path::PathBuf;
@@ -25,21 +25,35 @@ pub enum AddressError {
     Error(#[from] FormattedError),
 }
 
+impl From<&AddressError> for Diagnostic {
+    fn from(error: &AddressError) -> Self {
+        match error {
+            AddressError::Error(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl AddressError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            AddressError::Error(error) => error.code,
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             AddressError::Error(error) => error.set_path(path),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        AddressError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        AddressError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn account_error(error: AccountError, span: Span) -> Self {
         let message = format!("account creation failed due to `{}`", error);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0401", message, span)
     }
 
     pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -48,24 +62,24 @@ impl AddressError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0402", message, span)
     }
 
     pub fn cannot_evaluate(operation: String, span: Span) -> Self {
         let message = format!("no implementation found for `{}`", operation);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0403", message, span)
     }
 
     pub fn invalid_address(actual: String, span: Span) -> Self {
         let message = format!("expected address input type, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0404", message, span)
     }
 
     pub fn missing_address(span: Span) -> Self {
         let message = format!("expected address input not found");
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0405", message, span)
     }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_typed::{Error as FormattedError, Span};
+use leo_typed::{Diagnostic, Error as FormattedError, Span};
 
 use snarkos_errors::gadgets::SynthesisError;
 use std::path::PathBuf;
@@ -25,15 +25,29 @@ pub enum BooleanError {
     Error(#[from] FormattedError),
 }
 
+impl From<&BooleanError> for Diagnostic {
+    fn from(error: &BooleanError) -> Self {
+        match error {
+            BooleanError::Error(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl BooleanError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            BooleanError::Error(error) => error.code,
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             BooleanError::Error(error) => error.set_path(path),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        BooleanError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        BooleanError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -42,24 +56,24 @@ impl BooleanError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0410", message, span)
     }
 
     pub fn cannot_evaluate(operation: String, span: Span) -> Self {
         let message = format!("no implementation found for `{}`", operation);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0411", message, span)
     }
 
     pub fn invalid_boolean(actual: String, span: Span) -> Self {
         let message = format!("expected boolean input type, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0412", message, span)
     }
 
     pub fn missing_boolean(expected: String, span: Span) -> Self {
         let message = format!("expected boolean input `{}` not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0413", message, span)
     }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_typed::{Error as FormattedError, Span};
+use leo_typed::{Diagnostic, Error as FormattedError, Span};
 
 use snarkos_errors::gadgets::SynthesisError;
 use std::path::PathBuf;
@@ -25,21 +25,35 @@ pub enum FieldError {
     Error(#[from] FormattedError),
 }
 
+impl From<&FieldError> for Diagnostic {
+    fn from(error: &FieldError) -> Self {
+        match error {
+            FieldError::Error(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl FieldError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            FieldError::Error(error) => error.code,
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             FieldError::Error(error) => error.set_path(path),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        FieldError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        FieldError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn negate_operation(error: SynthesisError, span: Span) -> Self {
         let message = format!("field negation failed due to synthesis error `{:?}`", error,);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0420", message, span)
     }
 
     pub fn binary_operation(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -48,30 +62,30 @@ impl FieldError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0421", message, span)
     }
 
     pub fn invalid_field(actual: String, span: Span) -> Self {
         let message = format!("expected field element input type, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0422", message, span)
     }
 
     pub fn missing_field(expected: String, span: Span) -> Self {
         let message = format!("expected field input `{}` not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0423", message, span)
     }
 
     pub fn no_inverse(field: String, span: Span) -> Self {
         let message = format!("no multiplicative inverse found for field `{}`", field);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0424", message, span)
     }
 
     pub fn synthesis_error(error: SynthesisError, span: Span) -> Self {
         let message = format!("compilation failed due to field synthesis error `{:?}`", error);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0425", message, span)
     }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_typed::{Error as FormattedError, Span};
+use leo_typed::{Diagnostic, Error as FormattedError, Span};
 
 use snarkos_errors::gadgets::SynthesisError;
 use std::path::PathBuf;
@@ -25,21 +25,35 @@ pub enum GroupError {
     Error(#[from] FormattedError),
 }
 
+impl From<&GroupError> for Diagnostic {
+    fn from(error: &GroupError) -> Self {
+        match error {
+            GroupError::Error(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl GroupError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            GroupError::Error(error) => error.code,
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             GroupError::Error(error) => error.set_path(path),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        GroupError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        GroupError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn negate_operation(error: SynthesisError, span: Span) -> Self {
         let message = format!("group negation failed due to the synthesis error `{:?}`", error,);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0430", message, span)
     }
 
     pub fn binary_operation(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -48,60 +62,60 @@ impl GroupError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0431", message, span)
     }
 
     pub fn invalid_group(actual: String, span: Span) -> Self {
         let message = format!("expected group affine point input type, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0432", message, span)
     }
 
     pub fn missing_group(expected: String, span: Span) -> Self {
         let message = format!("expected group input `{}` not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0433", message, span)
     }
 
     pub fn synthesis_error(error: SynthesisError, span: Span) -> Self {
         let message = format!("compilation failed due to group synthesis error `{:?}`", error);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0434", message, span)
     }
 
     pub fn x_invalid(x: String, span: Span) -> Self {
         let message = format!("invalid x coordinate `{}`", x);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0435", message, span)
     }
 
     pub fn y_invalid(y: String, span: Span) -> Self {
         let message = format!("invalid y coordinate `{}`", y);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0436", message, span)
     }
 
     pub fn not_on_curve(element: String, span: Span) -> Self {
         let message = format!("group element `{}` is not on the supported curve", element);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0437", message, span)
     }
 
     pub fn x_recover(span: Span) -> Self {
         let message = format!("could not recover group element from x coordinate");
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0438", message, span)
     }
 
     pub fn y_recover(span: Span) -> Self {
         let message = format!("could not recover group element from y coordinate");
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0439", message, span)
     }
 
     pub fn n_group(number: String, span: Span) -> Self {
         let message = format!("cannot multiply group generator by \"{}\"", number);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0440", message, span)
     }
 }
@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_gadgets::errors::SignedIntegerError;
-use leo_typed::{error::Error as FormattedError, Span};
+use leo_typed::{error::Error as FormattedError, Diagnostic, Span};
 
 use snarkos_errors::gadgets::SynthesisError;
 use std::path::PathBuf;
@@ -26,15 +26,29 @@ pub enum IntegerError {
     Error(#[from] FormattedError),
 }
 
+impl From<&IntegerError> for Diagnostic {
+    fn from(error: &IntegerError) -> Self {
+        match error {
+            IntegerError::Error(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl IntegerError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            IntegerError::Error(error) => error.code,
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             IntegerError::Error(error) => error.set_path(path),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        IntegerError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        IntegerError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -43,19 +57,19 @@ impl IntegerError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0450", message, span)
     }
 
     pub fn signed(error: SignedIntegerError, span: Span) -> Self {
         let message = format!("integer operation failed due to the signed integer error `{:?}`", error,);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0451", message, span)
     }
 
     pub fn synthesis(error: SynthesisError, span: Span) -> Self {
         let message = format!("integer operation failed due to the synthesis error `{}`", error,);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0452", message, span)
     }
 
     pub fn signed_error(operation: String, error: SignedIntegerError, span: Span) -> Self {
@@ -64,13 +78,13 @@ impl IntegerError {
             operation, error
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0453", message, span)
     }
 
     pub fn negate_operation(span: Span) -> Self {
         let message = format!("integer negation can only be enforced on signed integers");
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0454", message, span)
     }
 
     pub fn binary_operation(operation: String, span: Span) -> Self {
@@ -79,7 +93,7 @@ impl IntegerError {
             operation
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0455", message, span)
     }
 
     pub fn invalid_index(span: Span) -> Self {
@@ -87,18 +101,18 @@ impl IntegerError {
             "index must be a constant value unsigned integer. allocated indices produce a circuit of unknown size"
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0456", message, span)
     }
 
     pub fn invalid_integer(actual: String, span: Span) -> Self {
         let message = format!("failed to parse `{}` as expected integer type", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0457", message, span)
     }
 
     pub fn missing_integer(expected: String, span: Span) -> Self {
         let message = format!("expected integer input `{}` not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0458", message, span)
     }
 }
@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::errors::{AddressError, BooleanError, FieldError, GroupError, IntegerError};
-use leo_typed::{Error as FormattedError, Span};
+use leo_typed::{Diagnostic, Error as FormattedError, Span};
 
 use std::path::PathBuf;
 
@@ -40,7 +40,31 @@ pub enum ValueError {
     IntegerError(#[from] IntegerError),
 }
 
+impl From<&ValueError> for Diagnostic {
+    fn from(error: &ValueError) -> Self {
+        match error {
+            ValueError::AddressError(error) => Diagnostic::from(error),
+            ValueError::BooleanError(error) => Diagnostic::from(error),
+            ValueError::Error(error) => Diagnostic::from(error),
+            ValueError::FieldError(error) => Diagnostic::from(error),
+            ValueError::GroupError(error) => Diagnostic::from(error),
+            ValueError::IntegerError(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl ValueError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValueError::AddressError(error) => error.code(),
+            ValueError::BooleanError(error) => error.code(),
+            ValueError::Error(error) => error.code,
+            ValueError::FieldError(error) => error.code(),
+            ValueError::GroupError(error) => error.code(),
+            ValueError::IntegerError(error) => error.code(),
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             ValueError::AddressError(error) => error.set_path(path),
@@ -52,19 +76,19 @@ impl ValueError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        ValueError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        ValueError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn implicit(value: String, span: Span) -> Self {
         let message = format!("explicit type needed for `{}`", value);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0470", message, span)
     }
 
     pub fn implicit_group(span: Span) -> Self {
         let message = format!("group coordinates should be in (x, y)group format");
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0471", message, span)
     }
 }
@@ -28,6 +28,7 @@ use snarkos_models::{
     curves::{Field, PrimeField},
     gadgets::r1cs::ConstraintSystem,
 };
+use std::collections::HashMap;
 
 impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
     pub fn enforce_circuit<CS: ConstraintSystem<F>>(
@@ -55,6 +56,9 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         let circuit_identifier = circuit.circuit_name.clone();
         let mut resolved_members = vec![];
 
+        // Values of const parameters are inferred from the lengths of the circuit variables
+        let mut constants = HashMap::new();
+
         for member in circuit.members.clone().into_iter() {
             match member {
                 CircuitMember::CircuitVariable(is_mutable, identifier, type_) => {
@@ -64,15 +68,32 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                         .find(|variable| variable.identifier.eq(&identifier));
                     match matched_variable {
                         Some(variable) => {
+                            // Resolve any const parameters that are already known
+                            let type_ = type_.resolve_const_parameters(&constants);
+                            let expected_type = if type_.is_generic() { None } else { Some(type_.clone()) };
+
                             // Resolve and enforce circuit variable
                             let mut variable_value = self.enforce_expression(
                                 cs,
                                 file_scope.clone(),
                                 function_scope.clone(),
-                                Some(type_.clone()),
+                                expected_type,
                                 variable.expression,
                             )?;
 
+                            // Infer const parameters from the type of the circuit variable
+                            if type_.is_generic() {
+                                let value_type = variable_value.to_type(span.clone())?;
+
+                                if !type_.infer_const_parameters(&value_type, &mut constants) {
+                                    return Err(ExpressionError::const_member_type(
+                                        type_.to_string(),
+                                        value_type.to_string(),
+                                        span,
+                                    ));
+                                }
+                            }
+
                             // Add mutability to circuit variable
                             if is_mutable {
                                 variable_value = ConstrainedValue::Mutable(Box::new(variable_value))
@@ -97,6 +118,34 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             };
         }
 
+        // Monomorphize circuit functions with the inferred const parameters
+        if circuit.is_generic() {
+            for parameter in circuit.const_parameters.iter() {
+                if !constants.contains_key(&parameter.identifier.name) {
+                    return Err(ExpressionError::undefined_const_parameter(
+                        parameter.identifier.to_string(),
+                        span,
+                    ));
+                }
+            }
+
+            for member in resolved_members.iter_mut() {
+                let function = match &mut member.1 {
+                    ConstrainedValue::Static(value) => match &mut **value {
+                        ConstrainedValue::Function(_, function) => function,
+                        _ => continue,
+                    },
+                    ConstrainedValue::Function(_, function) => function,
+                    _ => continue,
+                };
+
+                function.const_parameters =
+                    [circuit.const_parameters.clone(), function.const_parameters.clone()].concat();
+
+                *function = self.monomorphize(function.clone(), &constants);
+            }
+        }
+
         Ok(ConstrainedValue::CircuitExpression(
             circuit_identifier.clone(),
             resolved_members,
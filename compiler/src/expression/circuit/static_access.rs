@@ -35,8 +35,8 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         circuit_member: Identifier,
         span: Span,
     ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
-        // Get defined circuit
-        let circuit = match *circuit_identifier.clone() {
+        // Get defined circuit or enum
+        let definition = match *circuit_identifier.clone() {
             Expression::Identifier(identifier) => {
                 // Use the "Self" keyword to access a static circuit function
                 if identifier.is_self() {
@@ -56,8 +56,16 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 expected_type,
                 expression,
             )?,
+        };
+
+        // Enum variants without values are accessed like static members
+        if definition.is_enum_definition() {
+            let enum_ = definition.extract_enum(span.clone())?;
+
+            return self.enforce_enum_variant(cs, file_scope, function_scope, enum_, circuit_member, vec![], span);
         }
-        .extract_circuit(span.clone())?;
+
+        let circuit = definition.extract_circuit(span.clone())?;
 
         // Find static circuit function
         let matched_function = circuit.members.into_iter().find(|member| match member {
@@ -66,7 +74,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         });
 
         // Return errors if no static function exists
-        let function = match matched_function {
+        let mut function = match matched_function {
             Some(CircuitMember::CircuitFunction(_static, function)) => {
                 if _static {
                     function
@@ -86,6 +94,9 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             }
         };
 
+        // Const parameters of the circuit are inferred from the static function input variables
+        function.const_parameters = [circuit.const_parameters, function.const_parameters].concat();
+
         Ok(ConstrainedValue::Function(Some(circuit.circuit_name), function))
     }
 }
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces an enum variant expression in a compiled Leo program.
+
+use crate::{
+    errors::ExpressionError,
+    program::{new_scope, ConstrainedProgram},
+    value::{ConstrainedEnumVariant, ConstrainedValue},
+    GroupType,
+    Integer,
+};
+use leo_typed::{Enum, Expression, Identifier, Span};
+
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{r1cs::ConstraintSystem, utilities::uint::UInt32},
+};
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    /// Returns the enum definition for the given identifier if one is in scope
+    pub(crate) fn get_enum_definition(
+        &self,
+        file_scope: &String,
+        function_scope: &String,
+        identifier: &Identifier,
+    ) -> Option<Enum> {
+        let variable_name = new_scope(function_scope.clone(), identifier.to_string());
+        let identifier_name = new_scope(file_scope.clone(), identifier.to_string());
+
+        let value = self
+            .get(&variable_name)
+            .or(self.get(&identifier_name))
+            .or(self.get(&identifier.name))?;
+
+        if value.is_enum_definition() {
+            value.clone().extract_enum(identifier.span.clone()).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Enforce an enum variant `Enum::Variant` or `Enum::Variant(values)`.
+    /// The variant tag is its position in the enum definition.
+    pub fn enforce_enum_variant<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        file_scope: String,
+        function_scope: String,
+        enum_: Enum,
+        variant: Identifier,
+        arguments: Vec<Expression>,
+        span: Span,
+    ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+        let index = enum_.variant_index(&variant).ok_or(ExpressionError::undefined_variant(
+            enum_.enum_name.to_string(),
+            variant.to_string(),
+            span.clone(),
+        ))?;
+        let variant_name = format!("{}::{}", enum_.enum_name, variant);
+        let types = enum_.variants[index].types.clone();
+
+        if types.len() != arguments.len() {
+            return Err(ExpressionError::invalid_variant_arguments(
+                variant_name,
+                types.len(),
+                arguments.len(),
+                span,
+            ));
+        }
+
+        let mut payload = vec![];
+
+        for (type_, argument) in types.into_iter().zip(arguments.into_iter()) {
+            let value = self.enforce_operand(
+                cs,
+                file_scope.clone(),
+                function_scope.clone(),
+                Some(type_.clone()),
+                argument,
+                span.clone(),
+            )?;
+            let value_type = value.to_type(span.clone())?;
+
+            if !type_.matches(&value_type) {
+                return Err(ExpressionError::enum_variant_type(
+                    variant_name,
+                    type_.to_string(),
+                    value_type.to_string(),
+                    span,
+                ));
+            }
+
+            payload.push(value);
+        }
+
+        // Only the constructed variant holds a payload
+        let mut payload = Some(payload);
+        let mut variants = vec![];
+
+        for (i, defined) in enum_.variants.iter().enumerate() {
+            let variant_payload = if i == index { payload.take() } else { None };
+
+            variants.push(ConstrainedEnumVariant(defined.identifier.clone(), variant_payload));
+        }
+
+        Ok(ConstrainedValue::EnumExpression(
+            enum_.enum_name,
+            Integer::U32(UInt32::constant(index as u32)),
+            variants,
+        ))
+    }
+}
@@ -242,10 +242,23 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 span,
             ),
 
+            Expression::Match(expression, arms, span) => self.enforce_match_expression(
+                cs,
+                file_scope,
+                function_scope,
+                expected_type,
+                *expression,
+                arms,
+                span,
+            ),
+
             // Arrays
             Expression::Array(array, span) => {
                 self.enforce_array(cs, file_scope, function_scope, expected_type, array, span)
             }
+            Expression::GenericArray(_element, parameter, span) => {
+                Err(ExpressionError::unresolved_array_length(parameter.to_string(), span))
+            }
             Expression::ArrayAccess(array, index, span) => {
                 self.enforce_array_access(cs, file_scope, function_scope, expected_type, array, *index, span)
             }
@@ -35,6 +35,23 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         arguments: Vec<Expression>,
         span: Span,
     ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+        // Enum variants with values are constructed like static function calls
+        if let Expression::CircuitStaticFunctionAccess(ref enum_expression, ref variant, ref _span) = *function {
+            if let Expression::Identifier(ref enum_identifier) = **enum_expression {
+                if let Some(enum_) = self.get_enum_definition(&file_scope, &function_scope, enum_identifier) {
+                    return self.enforce_enum_variant(
+                        cs,
+                        file_scope,
+                        function_scope,
+                        enum_,
+                        variant.clone(),
+                        arguments,
+                        span,
+                    );
+                }
+            }
+        }
+
         let (declared_circuit_reference, function_value) = match *function.clone() {
             Expression::CircuitMemberAccess(circuit_identifier, circuit_member, span) => {
                 // Call a circuit function that can mutate self.
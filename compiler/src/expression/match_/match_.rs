@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces a match expression in a compiled Leo program.
+
+use crate::{errors::ExpressionError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
+use leo_typed::{Expression, MatchArm, Span, Type};
+
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{r1cs::ConstraintSystem, utilities::select::CondSelectGadget},
+};
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    /// Enforce a match expression.
+    /// Every reachable arm is evaluated, and the results are conditionally selected
+    /// starting from the last arm so that the first matching arm takes precedence.
+    pub fn enforce_match_expression<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        file_scope: String,
+        function_scope: String,
+        expected_type: Option<Type>,
+        expression: Expression,
+        arms: Vec<MatchArm>,
+        span: Span,
+    ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+        let patterns = arms.iter().map(|arm| arm.pattern.clone()).collect::<Vec<_>>();
+        let (enum_name, tag, variants) = self.enforce_match_value(
+            cs,
+            file_scope.clone(),
+            function_scope.clone(),
+            expression,
+            &patterns,
+            span.clone(),
+        )?;
+
+        let mut branches = vec![];
+
+        for arm in arms.into_iter() {
+            let (indicator, shadowed) = match self.enforce_match_pattern(
+                cs,
+                file_scope.clone(),
+                function_scope.clone(),
+                &enum_name,
+                &tag,
+                &variants,
+                &arm.pattern,
+            )? {
+                Some(result) => result,
+                None => continue,
+            };
+
+            let value = self.enforce_operand(
+                cs,
+                file_scope.clone(),
+                function_scope.clone(),
+                expected_type.clone(),
+                arm.expression,
+                span.clone(),
+            )?;
+
+            self.restore_match_bindings(shadowed);
+
+            branches.push((arm.pattern, indicator, value));
+        }
+
+        let (_pattern, _indicator, mut result) = branches.pop().ok_or(ExpressionError::invalid_match(
+            enum_name.to_string(),
+            span.clone(),
+        ))?;
+
+        for (pattern, indicator, value) in branches.into_iter().rev() {
+            let unique_namespace = cs.ns(|| format!("select match arm {} {}:{}", pattern, span.line, span.start));
+
+            result = ConstrainedValue::conditionally_select(unique_namespace, &indicator, &value, &result)
+                .map_err(|e| ExpressionError::cannot_enforce(format!("match select"), e, span.clone()))?;
+        }
+
+        Ok(result)
+    }
+}
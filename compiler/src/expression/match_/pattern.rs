@@ -0,0 +1,175 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces match patterns in a compiled Leo program.
+
+use crate::{
+    errors::ExpressionError,
+    program::{new_scope, ConstrainedProgram},
+    value::{ConstrainedEnumVariant, ConstrainedValue},
+    GroupType,
+    Integer,
+};
+use leo_typed::{Expression, Identifier, MatchPattern, Span};
+
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::{boolean::Boolean, eq::EvaluateEqGadget, uint::UInt32},
+    },
+};
+
+/// The values shadowed by the bindings of a match pattern.
+/// A binding that did not shadow a value is paired with `None`.
+pub(crate) type ShadowedBindings<F, G> = Vec<(String, Option<ConstrainedValue<F, G>>)>;
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    /// Enforce the matched value of a match expression or statement.
+    /// Returns the enum name, variant tag and variants of the value after checking that the patterns are exhaustive.
+    pub(crate) fn enforce_match_value<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        file_scope: String,
+        function_scope: String,
+        expression: Expression,
+        patterns: &Vec<MatchPattern>,
+        span: Span,
+    ) -> Result<(Identifier, Integer, Vec<ConstrainedEnumVariant<F, G>>), ExpressionError> {
+        let mut value = self.enforce_expression(cs, file_scope, function_scope, None, expression)?;
+
+        value.get_inner_mut();
+
+        let (enum_name, tag, variants) = match value {
+            ConstrainedValue::EnumExpression(enum_name, tag, variants) => (enum_name, tag, variants),
+            value => return Err(ExpressionError::invalid_match(value.to_string(), span)),
+        };
+
+        // Every variant must be covered by a pattern unless there is a wildcard
+        let has_wildcard = patterns.iter().any(|pattern| match pattern {
+            MatchPattern::Wildcard(_) => true,
+            _ => false,
+        });
+
+        if !has_wildcard {
+            let missing = variants
+                .iter()
+                .filter(|variant| {
+                    !patterns.iter().any(|pattern| match pattern {
+                        MatchPattern::Variant(_, name, _, _) => name.eq(&variant.0),
+                        _ => false,
+                    })
+                })
+                .map(|variant| variant.0.to_string())
+                .collect::<Vec<_>>();
+
+            if !missing.is_empty() {
+                return Err(ExpressionError::non_exhaustive_match(enum_name.to_string(), missing, span));
+            }
+        }
+
+        Ok((enum_name, tag, variants))
+    }
+
+    /// Enforce a single match pattern against the variant tag of a matched value.
+    /// Stores the pattern bindings in the function scope and returns the indicator bit of the pattern
+    /// along with the shadowed values, which must be restored with `restore_match_bindings` after the arm.
+    /// Returns `None` if the matched value can never hold the variant of the pattern.
+    pub(crate) fn enforce_match_pattern<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        file_scope: String,
+        function_scope: String,
+        enum_name: &Identifier,
+        tag: &Integer,
+        variants: &Vec<ConstrainedEnumVariant<F, G>>,
+        pattern: &MatchPattern,
+    ) -> Result<Option<(Boolean, ShadowedBindings<F, G>)>, ExpressionError> {
+        let (pattern_enum_name, variant, bindings, span) = match pattern {
+            MatchPattern::Wildcard(_span) => return Ok(Some((Boolean::constant(true), vec![]))),
+            MatchPattern::Variant(pattern_enum_name, variant, bindings, span) => {
+                (pattern_enum_name, variant, bindings, span)
+            }
+        };
+
+        let enum_ = self
+            .get_enum_definition(&file_scope, &function_scope, pattern_enum_name)
+            .ok_or(ExpressionError::undefined_enum(
+                pattern_enum_name.to_string(),
+                span.clone(),
+            ))?;
+
+        if enum_.enum_name.ne(enum_name) {
+            return Err(ExpressionError::mismatched_enum(
+                enum_name.to_string(),
+                enum_.enum_name.to_string(),
+                span.clone(),
+            ));
+        }
+
+        let index = enum_.variant_index(variant).ok_or(ExpressionError::undefined_variant(
+            enum_name.to_string(),
+            variant.to_string(),
+            span.clone(),
+        ))?;
+
+        let expected_bindings = enum_.variants[index].types.len();
+
+        if bindings.len() != expected_bindings {
+            return Err(ExpressionError::invalid_match_bindings(
+                pattern.to_string(),
+                expected_bindings,
+                bindings.len(),
+                span.clone(),
+            ));
+        }
+
+        let payload = match &variants[index].1 {
+            Some(payload) => payload.clone(),
+            None => return Ok(None),
+        };
+
+        // Store the bound values in the current function scope, remembering any values they shadow
+        let mut shadowed = vec![];
+
+        for (binding, value) in bindings.iter().zip(payload.into_iter()) {
+            let binding_name = new_scope(function_scope.clone(), binding.to_string());
+            let previous = self.get(&binding_name).cloned();
+
+            self.store(binding_name.clone(), value);
+            shadowed.push((binding_name, previous));
+        }
+
+        let unique_namespace = cs.ns(|| format!("evaluate match {} {}:{}", pattern, span.line, span.start));
+        let indicator = tag
+            .evaluate_equal(unique_namespace, &Integer::U32(UInt32::constant(index as u32)))
+            .map_err(|e| ExpressionError::cannot_enforce(format!("=="), e, span.clone()))?;
+
+        Ok(Some((indicator, shadowed)))
+    }
+
+    /// Remove the bindings of a match arm from the function scope and restore the values they shadowed.
+    pub(crate) fn restore_match_bindings(&mut self, shadowed: ShadowedBindings<F, G>) {
+        for (binding_name, previous) in shadowed.into_iter().rev() {
+            match previous {
+                Some(value) => self.store(binding_name, value),
+                None => {
+                    self.identifiers.remove(&binding_name);
+                }
+            }
+        }
+    }
+}
@@ -31,6 +31,9 @@ pub use self::circuit::*;
 pub mod conditional;
 pub use self::conditional::*;
 
+pub mod enum_;
+pub use self::enum_::*;
+
 pub mod expression;
 pub use self::expression::*;
 
@@ -43,6 +46,9 @@ pub use self::identifier::*;
 pub mod logical;
 pub use self::logical::*;
 
+pub mod match_;
+pub use self::match_::*;
+
 pub mod relational;
 pub use self::relational::*;
 
@@ -16,14 +16,14 @@
 
 //! Enforces a relational `==` operator in a resolved Leo program.
 
-use crate::{enforce_and, errors::ExpressionError, value::ConstrainedValue, GroupType};
+use crate::{enforce_and, enforce_or, errors::ExpressionError, value::ConstrainedValue, GroupType, Integer};
 use leo_typed::Span;
 
 use snarkos_models::{
     curves::{Field, PrimeField},
     gadgets::{
         r1cs::ConstraintSystem,
-        utilities::{boolean::Boolean, eq::EvaluateEqGadget},
+        utilities::{boolean::Boolean, eq::EvaluateEqGadget, uint::UInt32},
     },
 };
 
@@ -84,6 +84,61 @@ pub fn evaluate_eq<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<
             }
             return Ok(current);
         }
+        (
+            ConstrainedValue::EnumExpression(enum_name_1, tag_1, variants_1),
+            ConstrainedValue::EnumExpression(enum_name_2, tag_2, variants_2),
+        ) => {
+            if enum_name_1 != enum_name_2 {
+                return Err(ExpressionError::mismatched_enum(
+                    enum_name_1.to_string(),
+                    enum_name_2.to_string(),
+                    span,
+                ));
+            }
+
+            let mut unique_namespace = cs.ns(|| namespace_string);
+            let tag_equal = tag_1
+                .evaluate_equal(unique_namespace.ns(|| format!("enum tag")), &tag_2)
+                .map_err(|_| ExpressionError::cannot_evaluate(format!("=="), span.clone()))?;
+            let mut current = ConstrainedValue::Boolean(tag_equal);
+
+            // Values of a variant are only compared if both sides can hold that variant
+            for (i, (variant_1, variant_2)) in variants_1.into_iter().zip(variants_2.into_iter()).enumerate() {
+                let (values_1, values_2) = match (variant_1.1, variant_2.1) {
+                    (Some(values_1), Some(values_2)) if !values_1.is_empty() => (values_1, values_2),
+                    _ => continue,
+                };
+
+                let values_equal = evaluate_eq(
+                    &mut unique_namespace.ns(|| format!("enum variant {} values", i)),
+                    ConstrainedValue::Tuple(values_1),
+                    ConstrainedValue::Tuple(values_2),
+                    span.clone(),
+                )?;
+
+                // !(tag == i) || values_equal
+                let is_variant = tag_1
+                    .evaluate_equal(
+                        unique_namespace.ns(|| format!("enum variant {} tag", i)),
+                        &Integer::U32(UInt32::constant(i as u32)),
+                    )
+                    .map_err(|_| ExpressionError::cannot_evaluate(format!("=="), span.clone()))?;
+                let variant_equal = enforce_or(
+                    &mut unique_namespace.ns(|| format!("enum variant {} result", i)),
+                    ConstrainedValue::Boolean(is_variant.not()),
+                    values_equal,
+                    span.clone(),
+                )?;
+
+                current = enforce_and(
+                    &mut unique_namespace.ns(|| format!("enum result {}", i)),
+                    current,
+                    variant_equal,
+                    span.clone(),
+                )?;
+            }
+            return Ok(current);
+        }
         (ConstrainedValue::Unresolved(string), val_2) => {
             let mut unique_namespace = cs.ns(|| namespace_string);
             let val_1 = ConstrainedValue::from_other(string, &val_2, span.clone())?;
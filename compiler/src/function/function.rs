@@ -29,6 +29,7 @@ use snarkos_models::{
     curves::{Field, PrimeField},
     gadgets::r1cs::ConstraintSystem,
 };
+use std::collections::HashMap;
 
 pub fn check_arguments_length(expected: usize, actual: usize, span: Span) -> Result<(), FunctionError> {
     // Make sure we are given the correct number of arguments
@@ -54,6 +55,9 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         // Make sure we are given the correct number of input variables
         check_arguments_length(function.input.len(), input.len(), function.span.clone())?;
 
+        // Values of const parameters are inferred from the lengths of the input variables
+        let mut constants = HashMap::new();
+
         // Store input values as new variables in resolved program
         for (input_model, input_expression) in function.input.clone().iter().zip(input.into_iter()) {
             let (name, value) = match input_model {
@@ -70,16 +74,37 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     (identifier.name.clone(), input_value)
                 }
                 InputVariable::FunctionInput(input_model) => {
+                    // Resolve any const parameters that are already known
+                    let input_type = input_model.type_.resolve_const_parameters(&constants);
+                    let expected_type = if input_type.is_generic() {
+                        None
+                    } else {
+                        Some(input_type.clone())
+                    };
+
                     // First evaluate input expression
                     let mut input_value = self.enforce_function_input(
                         cs,
                         scope.clone(),
                         caller_scope.clone(),
                         function_name.clone(),
-                        Some(input_model.type_.clone()),
+                        expected_type,
                         input_expression,
                     )?;
 
+                    // Infer const parameters from the type of the input value
+                    if input_type.is_generic() {
+                        let value_type = input_value.to_type(input_model.span.clone())?;
+
+                        if !input_type.infer_const_parameters(&value_type, &mut constants) {
+                            return Err(FunctionError::const_argument_type(
+                                input_type.to_string(),
+                                value_type.to_string(),
+                                input_model.span.clone(),
+                            ));
+                        }
+                    }
+
                     if input_model.mutable {
                         input_value = ConstrainedValue::Mutable(Box::new(input_value))
                     }
@@ -93,6 +118,22 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             self.store(input_program_identifier, value);
         }
 
+        // Monomorphize the function with the inferred const parameters
+        let function = if function.is_generic() {
+            for parameter in function.const_parameters.iter() {
+                if !constants.contains_key(&parameter.identifier.name) {
+                    return Err(FunctionError::undefined_const_parameter(
+                        parameter.identifier.to_string(),
+                        parameter.span.clone(),
+                    ));
+                }
+            }
+
+            self.monomorphize(function, &constants)
+        } else {
+            function
+        };
+
         // Evaluate every statement in the function and save all potential results
         let mut results = vec![];
 
@@ -34,6 +34,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
     pub fn allocate_array<CS: ConstraintSystem<F>>(
         &mut self,
         cs: &mut CS,
+        scope: String,
         name: String,
         array_type: Type,
         array_dimensions: Vec<usize>,
@@ -52,6 +53,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
 
                     array_value.push(self.allocate_main_function_input(
                         cs,
+                        scope.clone(),
                         value_type,
                         value_name,
                         Some(value),
@@ -67,6 +69,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
 
                     array_value.push(self.allocate_main_function_input(
                         cs,
+                        scope.clone(),
                         value_type,
                         value_name,
                         None,
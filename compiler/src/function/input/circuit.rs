@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Allocates a circuit as a main function input parameter in a compiled Leo program.
+
+use crate::{
+    errors::{ExpressionError, FunctionError},
+    program::{new_scope, ConstrainedProgram},
+    value::{ConstrainedCircuitMember, ConstrainedValue},
+    GroupType,
+};
+
+use leo_typed::{CircuitMember, Identifier, InputValue, Span};
+
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::r1cs::ConstraintSystem,
+};
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    pub fn allocate_circuit<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        scope: String,
+        name: String,
+        identifier: Identifier,
+        input_value: Option<InputValue>,
+        span: Span,
+    ) -> Result<ConstrainedValue<F, G>, FunctionError> {
+        // Circuit definitions are located at the program scope
+        let program_identifier = new_scope(scope.clone(), identifier.name.clone());
+        let circuit = match self.get(&program_identifier) {
+            Some(value) => value.clone().extract_circuit(span.clone())?,
+            None => return Err(ExpressionError::undefined_circuit(identifier.to_string(), span).into()),
+        };
+
+        // The member types of a generic circuit depend on const parameters that are never given
+        if let Some(parameter) = circuit.const_parameters.first() {
+            return Err(FunctionError::undefined_const_parameter(
+                parameter.identifier.to_string(),
+                span,
+            ));
+        }
+
+        let mut input_members = match input_value {
+            Some(InputValue::Circuit(circuit_name, members)) if circuit_name == identifier.name => Some(members),
+            None => None,
+            Some(value) => {
+                return Err(FunctionError::invalid_circuit(
+                    identifier.to_string(),
+                    value.to_string(),
+                    span,
+                ));
+            }
+        };
+
+        let mut members = vec![];
+
+        for member in circuit.members.into_iter() {
+            match member {
+                CircuitMember::CircuitVariable(is_mutable, member_identifier, type_) => {
+                    let value_name = new_scope(name.clone(), member_identifier.name.clone());
+
+                    // Take the value of the circuit variable from the circuit literal
+                    let member_input = match input_members.as_mut() {
+                        Some(input_members) => {
+                            let index = input_members
+                                .iter()
+                                .position(|(member_name, _)| member_name == &member_identifier.name)
+                                .ok_or_else(|| {
+                                    ExpressionError::expected_circuit_member(
+                                        member_identifier.to_string(),
+                                        span.clone(),
+                                    )
+                                })?;
+
+                            Some(input_members.remove(index).1)
+                        }
+                        None => None,
+                    };
+
+                    let mut value = self.allocate_main_function_input(
+                        cs,
+                        scope.clone(),
+                        type_,
+                        value_name,
+                        member_input,
+                        span.clone(),
+                    )?;
+
+                    // Add mutability to circuit variable
+                    if is_mutable {
+                        value = ConstrainedValue::Mutable(Box::new(value))
+                    }
+
+                    members.push(ConstrainedCircuitMember(member_identifier, value))
+                }
+                CircuitMember::CircuitFunction(is_static, function) => {
+                    let function_identifier = function.identifier.clone();
+                    let mut function_value = ConstrainedValue::Function(Some(circuit.circuit_name.clone()), function);
+
+                    if is_static {
+                        function_value = ConstrainedValue::Static(Box::new(function_value));
+                    }
+
+                    members.push(ConstrainedCircuitMember(function_identifier, function_value));
+                }
+            }
+        }
+
+        // Return an error for members of the circuit literal that the circuit does not have
+        if let Some((member_name, _)) = input_members.and_then(|input_members| input_members.into_iter().next()) {
+            return Err(ExpressionError::undefined_member_access(identifier.to_string(), member_name, span).into());
+        }
+
+        Ok(ConstrainedValue::CircuitExpression(circuit.circuit_name, members))
+    }
+}
@@ -31,6 +31,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
     pub fn allocate_input_keyword<CS: ConstraintSystem<F>>(
         &mut self,
         cs: &mut CS,
+        scope: String,
         identifier: Identifier,
         input: &Input,
     ) -> Result<ConstrainedValue<F, G>, FunctionError> {
@@ -73,7 +74,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
 
         for (name, values) in sections {
             let member_name = name.clone();
-            let member_value = self.allocate_input_section(cs, name, values)?;
+            let member_value = self.allocate_input_section(cs, scope.clone(), name, values)?;
 
             let member = ConstrainedCircuitMember(member_name, member_value);
 
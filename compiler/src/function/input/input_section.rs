@@ -27,6 +27,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
     pub fn allocate_input_section<CS: ConstraintSystem<F>>(
         &mut self,
         cs: &mut CS,
+        scope: String,
         identifier: Identifier,
         section: HashMap<Parameter, Option<InputValue>>,
     ) -> Result<ConstrainedValue<F, G>, FunctionError> {
@@ -38,6 +39,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             let member_name = parameter.variable.clone();
             let member_value = self.allocate_main_function_input(
                 cs,
+                scope.clone(),
                 parameter.type_,
                 parameter.variable.name,
                 option,
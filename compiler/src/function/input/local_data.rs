@@ -0,0 +1,465 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces the record commitment and local data Merkle path of the program state in the circuit.
+
+use crate::{
+    address::Address,
+    errors::FunctionError,
+    program::{new_scope, ConstrainedProgram},
+    value::ConstrainedValue,
+    GroupType,
+    Integer,
+    RECORD_VARIABLE_NAME,
+    STATE_LEAF_VARIABLE_NAME,
+    STATE_VARIABLE_NAME,
+};
+use leo_typed::{Function, Identifier, Input, InputVariable, Span};
+
+use snarkos_curves::edwards_bls12::Fq;
+use snarkos_dpc::base_dpc::{
+    instantiated::{
+        Components,
+        LocalDataCRH,
+        LocalDataCRHGadget,
+        LocalDataCommitment,
+        LocalDataCommitmentGadget,
+        RecordCommitment,
+        RecordCommitmentGadget,
+    },
+    parameters::SystemParameters,
+};
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    algorithms::{CommitmentScheme, CRH},
+    curves::{Field, PrimeField},
+    dpc::DPCComponents,
+    gadgets::{
+        algorithms::{CRHGadget, CommitmentGadget},
+        r1cs::ConstraintSystem,
+        utilities::{
+            alloc::AllocGadget,
+            boolean::Boolean,
+            eq::{ConditionalEqGadget, EqGadget},
+            select::CondSelectGadget,
+            uint::{UInt, UInt32, UInt64, UInt8},
+            ToBytesGadget,
+        },
+    },
+};
+use snarkos_utilities::FromBytes;
+
+const INPUT_KEYWORD: &str = "input";
+
+// The byte sizes of the local data members as serialized by the snarkOS DPC types
+const ROOT_SIZE: usize = 32;
+const SERIAL_NUMBER_SIZE: usize = 64;
+const COMMITMENT_SIZE: usize = 32;
+const PAYLOAD_SIZE: usize = 32;
+const PROGRAM_ID_SIZE: usize = 48;
+const SERIAL_NUMBER_NONCE_SIZE: usize = 32;
+const COMMITMENT_RANDOMNESS_SIZE: usize = 32;
+const PATH_SIZE: usize = 4 * ROOT_SIZE;
+const MEMO_SIZE: usize = 32;
+const LEAF_RANDOMNESS_SIZE: usize = 32;
+
+/// The allocated `[state]`, `[record]` and `[state_leaf]` values of the program input.
+#[derive(Clone, Debug)]
+pub struct LocalDataInput {
+    pub leaf_index: UInt32,
+    pub root: Vec<UInt8>,
+
+    pub serial_number: Vec<UInt8>,
+    pub commitment: Vec<UInt8>,
+    pub owner: Vec<UInt8>,
+    pub is_dummy: Boolean,
+    pub value: UInt64,
+    pub payload: Vec<UInt8>,
+    pub birth_program_id: Vec<UInt8>,
+    pub death_program_id: Vec<UInt8>,
+    pub serial_number_nonce: Vec<UInt8>,
+    pub commitment_randomness: Vec<UInt8>,
+
+    pub path: Vec<UInt8>,
+    pub memo: Vec<UInt8>,
+    pub network_id: UInt8,
+    pub leaf_randomness: Vec<UInt8>,
+}
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    /// Enforces that the record of the program state is committed to in the local data root.
+    /// The local data root is allocated as a public input of the circuit.
+    ///
+    /// The check uses the `input` values of the main function, so the record values that
+    /// the program reads are the ones committed to.
+    pub fn enforce_local_data<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        scope: String,
+        function: &Function,
+        input: &Input,
+        system_parameters: &SystemParameters<Components>,
+    ) -> Result<(), FunctionError> {
+        let function_name = new_scope(scope.clone(), function.get_name());
+        let span = function.span.clone();
+
+        let input_keyword = function.input.iter().find_map(|input_model| match input_model {
+            InputVariable::InputKeyword(identifier) => Some(identifier),
+            InputVariable::FunctionInput(_) => None,
+        });
+
+        // Use the allocated `input` of the main function, or allocate it if main does not declare it
+        let stored_input =
+            input_keyword.and_then(|identifier| self.get(&new_scope(function_name, identifier.name.clone())));
+        let value = match stored_input {
+            Some(value) => value.clone(),
+            None => {
+                let identifier = Identifier {
+                    name: INPUT_KEYWORD.to_string(),
+                    span: span.clone(),
+                };
+
+                self.allocate_input_keyword(&mut cs.ns(|| "local data input"), scope, identifier, input)?
+            }
+        };
+
+        let local_data = LocalDataInput::from_input_keyword(&value, &span)?;
+
+        G::enforce_local_data(cs.ns(|| "enforce local data"), &local_data, system_parameters)
+            .map_err(|error| FunctionError::local_data_synthesis(error, span))
+    }
+}
+
+impl LocalDataInput {
+    /// Collects the allocated state values from the value of the `input` keyword.
+    pub fn from_input_keyword<F: Field + PrimeField, G: GroupType<F>>(
+        input: &ConstrainedValue<F, G>,
+        span: &Span,
+    ) -> Result<Self, FunctionError> {
+        let state = section(input, STATE_VARIABLE_NAME, span)?;
+        let record = section(input, RECORD_VARIABLE_NAME, span)?;
+        let state_leaf = section(input, STATE_LEAF_VARIABLE_NAME, span)?;
+
+        let invalid = |member: &str, expected: &str| {
+            FunctionError::invalid_local_data(member.to_string(), expected.to_string(), span.clone())
+        };
+
+        Ok(Self {
+            leaf_index: match member(state, STATE_VARIABLE_NAME, "leaf_index", span)? {
+                ConstrainedValue::Integer(Integer::U32(integer)) => integer.clone(),
+                _ => return Err(invalid("state.leaf_index", "u32")),
+            },
+            root: bytes(state, STATE_VARIABLE_NAME, "root", ROOT_SIZE, span)?,
+
+            serial_number: bytes(record, RECORD_VARIABLE_NAME, "serial_number", SERIAL_NUMBER_SIZE, span)?,
+            commitment: bytes(record, RECORD_VARIABLE_NAME, "commitment", COMMITMENT_SIZE, span)?,
+            owner: match member(record, RECORD_VARIABLE_NAME, "owner", span)? {
+                ConstrainedValue::Address(Address { bytes, .. }) => bytes.clone(),
+                _ => return Err(invalid("record.owner", "address")),
+            },
+            is_dummy: match member(record, RECORD_VARIABLE_NAME, "is_dummy", span)? {
+                ConstrainedValue::Boolean(boolean) => *boolean,
+                _ => return Err(invalid("record.is_dummy", "bool")),
+            },
+            value: match member(record, RECORD_VARIABLE_NAME, "value", span)? {
+                ConstrainedValue::Integer(Integer::U64(integer)) => integer.clone(),
+                _ => return Err(invalid("record.value", "u64")),
+            },
+            payload: bytes(record, RECORD_VARIABLE_NAME, "payload", PAYLOAD_SIZE, span)?,
+            birth_program_id: bytes(record, RECORD_VARIABLE_NAME, "birth_program_id", PROGRAM_ID_SIZE, span)?,
+            death_program_id: bytes(record, RECORD_VARIABLE_NAME, "death_program_id", PROGRAM_ID_SIZE, span)?,
+            serial_number_nonce: bytes(
+                record,
+                RECORD_VARIABLE_NAME,
+                "serial_number_nonce",
+                SERIAL_NUMBER_NONCE_SIZE,
+                span,
+            )?,
+            commitment_randomness: bytes(
+                record,
+                RECORD_VARIABLE_NAME,
+                "commitment_randomness",
+                COMMITMENT_RANDOMNESS_SIZE,
+                span,
+            )?,
+
+            path: bytes(state_leaf, STATE_LEAF_VARIABLE_NAME, "path", PATH_SIZE, span)?,
+            memo: bytes(state_leaf, STATE_LEAF_VARIABLE_NAME, "memo", MEMO_SIZE, span)?,
+            network_id: match member(state_leaf, STATE_LEAF_VARIABLE_NAME, "network_id", span)? {
+                ConstrainedValue::Integer(Integer::U8(integer)) => integer.clone(),
+                _ => return Err(invalid("state_leaf.network_id", "u8")),
+            },
+            leaf_randomness: bytes(
+                state_leaf,
+                STATE_LEAF_VARIABLE_NAME,
+                "leaf_randomness",
+                LEAF_RANDOMNESS_SIZE,
+                span,
+            )?,
+        })
+    }
+
+    /// Enforces the record commitment, the local data commitment, and the local data Merkle path
+    /// with the DPC gadgets over the Edwards BLS12 curve.
+    ///
+    /// Mirrors `leo_state::verify_local_data_commitment`.
+    pub fn enforce<CS: ConstraintSystem<Fq>>(
+        &self,
+        mut cs: CS,
+        system_parameters: &SystemParameters<Components>,
+    ) -> Result<(), SynthesisError> {
+        let record_commitment_parameters =
+            <RecordCommitmentGadget as CommitmentGadget<RecordCommitment, Fq>>::ParametersGadget::alloc(
+                cs.ns(|| "record commitment parameters"),
+                || Ok(system_parameters.record_commitment.parameters()),
+            )?;
+        let local_data_commitment_parameters =
+            <LocalDataCommitmentGadget as CommitmentGadget<LocalDataCommitment, Fq>>::ParametersGadget::alloc(
+                cs.ns(|| "local data commitment parameters"),
+                || Ok(system_parameters.local_data_commitment.parameters()),
+            )?;
+        let local_data_crh_parameters = <LocalDataCRHGadget as CRHGadget<LocalDataCRH, Fq>>::ParametersGadget::alloc(
+            cs.ns(|| "local data crh parameters"),
+            || Ok(system_parameters.local_data_crh.parameters()),
+        )?;
+
+        // Check the record commitment
+        {
+            let mut cs = cs.ns(|| "record commitment");
+
+            let mut commitment_input = vec![];
+            commitment_input.extend_from_slice(&self.owner);
+            commitment_input.extend_from_slice(&self.is_dummy.to_bytes(cs.ns(|| "is_dummy to bytes"))?);
+            commitment_input.extend(self.value.to_bits_le().chunks(8).map(UInt8::from_bits_le));
+            commitment_input.extend_from_slice(&self.payload);
+            commitment_input.extend_from_slice(&self.birth_program_id);
+            commitment_input.extend_from_slice(&self.death_program_id);
+            commitment_input.extend_from_slice(&self.serial_number_nonce);
+
+            let randomness =
+                <RecordCommitmentGadget as CommitmentGadget<RecordCommitment, Fq>>::RandomnessGadget::alloc(
+                    cs.ns(|| "commitment randomness"),
+                    || {
+                        randomness_value::<<RecordCommitment as CommitmentScheme>::Randomness>(
+                            &self.commitment_randomness,
+                        )
+                    },
+                )?;
+            randomness.0.enforce_equal(
+                cs.ns(|| "commitment randomness is the record randomness"),
+                &self.commitment_randomness,
+            )?;
+
+            let candidate_commitment = RecordCommitmentGadget::check_commitment_gadget(
+                cs.ns(|| "compute commitment"),
+                &record_commitment_parameters,
+                &commitment_input,
+                &randomness,
+            )?;
+            let candidate_commitment_bytes = candidate_commitment.to_bytes(cs.ns(|| "commitment to bytes"))?;
+
+            candidate_commitment_bytes
+                .enforce_equal(cs.ns(|| "commitment is the record commitment"), &self.commitment)?;
+        }
+
+        // The local data tree has four leaves, so only the two lowest bits of the leaf index may be set
+        let leaf_index_bits = self.leaf_index.to_bits_le();
+        for (i, bit) in leaf_index_bits.iter().enumerate().skip(2) {
+            bit.enforce_equal(
+                cs.ns(|| format!("leaf index bit {} is unset", i)),
+                &Boolean::constant(false),
+            )?;
+        }
+        let is_right_leaf = leaf_index_bits[0];
+        let is_right_subtree = leaf_index_bits[1];
+        debug_assert_eq!(Components::NUM_INPUT_RECORDS, 2);
+
+        // Check the local data commitment of the record
+        let leaf = {
+            let mut cs = cs.ns(|| "local data commitment");
+
+            let randomness =
+                <LocalDataCommitmentGadget as CommitmentGadget<LocalDataCommitment, Fq>>::RandomnessGadget::alloc(
+                    cs.ns(|| "leaf randomness"),
+                    || randomness_value::<<LocalDataCommitment as CommitmentScheme>::Randomness>(&self.leaf_randomness),
+                )?;
+            randomness.0.enforce_equal(
+                cs.ns(|| "leaf randomness is the state leaf randomness"),
+                &self.leaf_randomness,
+            )?;
+
+            // Input records are in the left subtree and commit to their serial number
+            let mut input_record_input = vec![];
+            input_record_input.extend_from_slice(&self.serial_number);
+            input_record_input.extend_from_slice(&self.commitment);
+            input_record_input.extend_from_slice(&self.memo);
+            input_record_input.push(self.network_id.clone());
+
+            let mut output_record_input = vec![];
+            output_record_input.extend_from_slice(&self.commitment);
+            output_record_input.extend_from_slice(&self.memo);
+            output_record_input.push(self.network_id.clone());
+
+            let input_record_leaf = LocalDataCommitmentGadget::check_commitment_gadget(
+                cs.ns(|| "input record leaf"),
+                &local_data_commitment_parameters,
+                &input_record_input,
+                &randomness,
+            )?;
+            let output_record_leaf = LocalDataCommitmentGadget::check_commitment_gadget(
+                cs.ns(|| "output record leaf"),
+                &local_data_commitment_parameters,
+                &output_record_input,
+                &randomness,
+            )?;
+
+            let leaf = CondSelectGadget::conditionally_select(
+                cs.ns(|| "select leaf"),
+                &is_right_subtree,
+                &output_record_leaf,
+                &input_record_leaf,
+            )?;
+
+            leaf.to_bytes(cs.ns(|| "leaf to bytes"))?
+        };
+
+        // Check the local data Merkle path
+        {
+            let mut cs = cs.ns(|| "local data merkle path");
+
+            let (leaves, inner_hashes) = self.path.split_at(self.path.len() / 2);
+            let (left_leaf, right_leaf) = leaves.split_at(leaves.len() / 2);
+            let (left_inner_hash, right_inner_hash) = inner_hashes.split_at(inner_hashes.len() / 2);
+
+            left_leaf.conditional_enforce_equal(cs.ns(|| "leaf is the left leaf"), &leaf, &is_right_leaf.not())?;
+            right_leaf.conditional_enforce_equal(cs.ns(|| "leaf is the right leaf"), &leaf, &is_right_leaf)?;
+
+            let inner_hash = LocalDataCRHGadget::check_evaluation_gadget(
+                cs.ns(|| "inner hash"),
+                &local_data_crh_parameters,
+                leaves,
+            )?;
+            let inner_hash = inner_hash.to_bytes(cs.ns(|| "inner hash to bytes"))?;
+
+            left_inner_hash.conditional_enforce_equal(
+                cs.ns(|| "inner hash is the left inner hash"),
+                &inner_hash,
+                &is_right_subtree.not(),
+            )?;
+            right_inner_hash.conditional_enforce_equal(
+                cs.ns(|| "inner hash is the right inner hash"),
+                &inner_hash,
+                &is_right_subtree,
+            )?;
+
+            let candidate_root = LocalDataCRHGadget::check_evaluation_gadget(
+                cs.ns(|| "root hash"),
+                &local_data_crh_parameters,
+                inner_hashes,
+            )?;
+            let candidate_root_bytes = candidate_root.to_bytes(cs.ns(|| "root to bytes"))?;
+            candidate_root_bytes.enforce_equal(cs.ns(|| "root is the state root"), &self.root)?;
+
+            // Expose the root as a public input
+            let declared_root = <LocalDataCRHGadget as CRHGadget<LocalDataCRH, Fq>>::OutputGadget::alloc_input(
+                cs.ns(|| "declared root"),
+                || <LocalDataCRH as CRH>::Output::read(&bytes_value(&self.root)?[..]).map_err(SynthesisError::from),
+            )?;
+            candidate_root.enforce_equal(cs.ns(|| "root is the declared root"), &declared_root)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns a section of the value of the `input` keyword.
+fn section<'a, F: Field + PrimeField, G: GroupType<F>>(
+    input: &'a ConstrainedValue<F, G>,
+    name: &str,
+    span: &Span,
+) -> Result<&'a ConstrainedValue<F, G>, FunctionError> {
+    member(input, INPUT_KEYWORD, name, span)
+}
+
+/// Returns a member of a circuit expression.
+fn member<'a, F: Field + PrimeField, G: GroupType<F>>(
+    value: &'a ConstrainedValue<F, G>,
+    section: &str,
+    name: &str,
+    span: &Span,
+) -> Result<&'a ConstrainedValue<F, G>, FunctionError> {
+    let members = match value {
+        ConstrainedValue::CircuitExpression(_, members) => members,
+        _ => {
+            return Err(FunctionError::invalid_local_data(
+                section.to_string(),
+                "section".to_string(),
+                span.clone(),
+            ));
+        }
+    };
+
+    members
+        .iter()
+        .find(|member| member.0.name == name)
+        .map(|member| &member.1)
+        .ok_or_else(|| {
+            FunctionError::invalid_local_data(format!("{}.{}", section, name), "definition".to_string(), span.clone())
+        })
+}
+
+/// Returns the bytes of a `[u8; size]` member of a circuit expression.
+/// The gadgets compare byte slices element-wise, so members of any other length are rejected.
+fn bytes<F: Field + PrimeField, G: GroupType<F>>(
+    value: &ConstrainedValue<F, G>,
+    section: &str,
+    name: &str,
+    size: usize,
+    span: &Span,
+) -> Result<Vec<UInt8>, FunctionError> {
+    let error =
+        || FunctionError::invalid_local_data(format!("{}.{}", section, name), format!("[u8; {}]", size), span.clone());
+
+    match member(value, section, name, span)? {
+        ConstrainedValue::Array(array) if array.len() == size => array
+            .iter()
+            .map(|element| match element {
+                ConstrainedValue::Integer(Integer::U8(byte)) => Ok(byte.clone()),
+                _ => Err(error()),
+            })
+            .collect(),
+        _ => Err(error()),
+    }
+}
+
+/// Returns the values of allocated bytes.
+fn bytes_value(bytes: &[UInt8]) -> Result<Vec<u8>, SynthesisError> {
+    bytes
+        .iter()
+        .map(|byte| byte.value.ok_or(SynthesisError::AssignmentMissing))
+        .collect()
+}
+
+/// Returns the commitment randomness of allocated bytes.
+///
+/// The randomness gadget reads its value eagerly, so a default randomness is used while the
+/// circuit is synthesized without values. The randomness bytes are enforced to be the allocated bytes.
+fn randomness_value<R: FromBytes + Default>(bytes: &[UInt8]) -> Result<R, SynthesisError> {
+    match bytes_value(bytes) {
+        Ok(bytes) => Ok(R::read(&bytes[..])?),
+        Err(_) => Ok(R::default()),
+    }
+}
@@ -41,6 +41,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
     pub fn allocate_main_function_input<CS: ConstraintSystem<F>>(
         &mut self,
         cs: &mut CS,
+        scope: String,
         type_: Type,
         name: String,
         input_option: Option<InputValue>,
@@ -58,8 +59,11 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 input_option,
                 span,
             )?)),
-            Type::Array(type_, dimensions) => self.allocate_array(cs, name, *type_, dimensions, input_option, span),
-            Type::Tuple(types) => self.allocate_tuple(cs, name, types, input_option, span),
+            Type::Array(type_, dimensions) => {
+                self.allocate_array(cs, scope, name, *type_, dimensions, input_option, span)
+            }
+            Type::Tuple(types) => self.allocate_tuple(cs, scope, name, types, input_option, span),
+            Type::Circuit(identifier) => self.allocate_circuit(cs, scope, name, identifier, input_option, span),
             _ => unimplemented!("main function input not implemented for type"),
         }
     }
@@ -19,9 +19,15 @@
 pub mod array;
 pub use self::array::*;
 
+pub mod circuit;
+pub use self::circuit::*;
+
 pub mod function_input;
 pub use self::function_input::*;
 
+pub mod local_data;
+pub use self::local_data::*;
+
 pub mod main_function_input;
 pub use self::main_function_input::*;
 
@@ -34,6 +34,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
     pub fn allocate_tuple<CS: ConstraintSystem<F>>(
         &mut self,
         cs: &mut CS,
+        scope: String,
         name: String,
         types: Vec<Type>,
         input_value: Option<InputValue>,
@@ -49,6 +50,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
 
                     tuple_values.push(self.allocate_main_function_input(
                         cs,
+                        scope.clone(),
                         type_,
                         value_name,
                         Some(value),
@@ -61,7 +63,14 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 for (i, type_) in types.into_iter().enumerate() {
                     let value_name = new_scope(name.clone(), i.to_string());
 
-                    tuple_values.push(self.allocate_main_function_input(cs, type_, value_name, None, span.clone())?);
+                    tuple_values.push(self.allocate_main_function_input(
+                        cs,
+                        scope.clone(),
+                        type_,
+                        value_name,
+                        None,
+                        span.clone(),
+                    )?);
                 }
             }
             _ => return Err(FunctionError::invalid_tuple(input_value.unwrap().to_string(), span)),
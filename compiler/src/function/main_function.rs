@@ -38,6 +38,11 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         function: Function,
         input: Input,
     ) -> Result<OutputBytes, FunctionError> {
+        // Main function input types must be known before the input variables are allocated
+        if function.is_generic() {
+            return Err(FunctionError::generic_main(function.span.clone()));
+        }
+
         let function_name = new_scope(scope.clone(), function.get_name());
         let registers = input.get_registers();
 
@@ -46,7 +51,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         for input_model in function.input.clone().into_iter() {
             let (identifier, value) = match input_model {
                 InputVariable::InputKeyword(identifier) => {
-                    let value = self.allocate_input_keyword(cs, identifier.clone(), &input)?;
+                    let value = self.allocate_input_keyword(cs, scope.clone(), identifier.clone(), &input)?;
 
                     (identifier, value)
                 }
@@ -57,6 +62,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                         .ok_or(FunctionError::input_not_found(name.clone(), function.span.clone()))?;
                     let input_value = self.allocate_main_function_input(
                         cs,
+                        scope.clone(),
                         input_model.type_,
                         name.clone(),
                         input_option,
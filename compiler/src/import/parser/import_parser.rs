@@ -14,10 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::errors::ImportError;
+use crate::{errors::ImportError, optimize_program};
+use leo_package::resolver::DependencyGraph;
 use leo_typed::{Package, Program};
 
-use std::{collections::HashMap, env::current_dir};
+use std::{
+    collections::{BTreeMap, HashMap},
+    env::current_dir,
+    path::PathBuf,
+};
 
 /// Parses all relevant import files for a program.
 /// Stores compiled program structs.
@@ -25,6 +30,8 @@ use std::{collections::HashMap, env::current_dir};
 pub struct ImportParser {
     imports: HashMap<String, Program>,
     core_packages: Vec<Package>,
+    sources: BTreeMap<PathBuf, String>,
+    graph: Option<DependencyGraph>,
 }
 
 impl ImportParser {
@@ -32,6 +39,8 @@ impl ImportParser {
         Self {
             imports: HashMap::new(),
             core_packages: vec![],
+            sources: BTreeMap::new(),
+            graph: None,
         }
     }
 
@@ -44,20 +53,53 @@ impl ImportParser {
         let _res = self.core_packages.push(package.clone());
     }
 
+    pub(crate) fn insert_source(&mut self, file_path: PathBuf, source: String) {
+        let _res = self.sources.insert(file_path, source);
+    }
+
     pub fn get_import(&self, file_name: &String) -> Option<&Program> {
         self.imports.get(file_name)
     }
 
+    /// Returns every imported program.
+    pub fn programs(&self) -> impl Iterator<Item = &Program> {
+        self.imports.values()
+    }
+
+    /// Folds constant expressions and removes dead branches in every imported program.
+    pub(crate) fn optimize(&mut self) {
+        for program in self.imports.values_mut() {
+            *program = optimize_program(program.clone());
+        }
+    }
+
     pub fn core_packages(&self) -> &Vec<Package> {
         &self.core_packages
     }
 
-    pub fn parse(program: &Program) -> Result<Self, ImportError> {
-        let mut imports = Self::new();
+    /// Returns the source of every imported file, ordered by file path.
+    pub fn sources(&self) -> &BTreeMap<PathBuf, String> {
+        &self.sources
+    }
+
+    /// Returns the resolved dependency graph of the package, if it has a lock file.
+    pub(crate) fn graph(&self) -> Option<&DependencyGraph> {
+        self.graph.as_ref()
+    }
 
+    pub fn parse(program: &Program) -> Result<Self, ImportError> {
         // Find all imports relative to current directory
         let path = current_dir().map_err(|error| ImportError::current_directory_error(error))?;
 
+        Self::parse_at(program, path)
+    }
+
+    /// Parses the imports of a program in the package at the given path.
+    /// If the package has a lock file, dependencies are resolved against its dependency graph.
+    pub fn parse_at(program: &Program, path: PathBuf) -> Result<Self, ImportError> {
+        let mut imports = Self::new();
+        imports.graph = DependencyGraph::load(&path).map_err(ImportError::lock_file_error)?;
+
         // Parse each imported file
         program
             .imports
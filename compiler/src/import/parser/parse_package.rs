@@ -84,9 +84,45 @@ impl ImportParser {
                 .eq(&package_name.name)
         });
 
+        // Packages installed by `leo fetch` are resolved against the dependency graph
+        let resolved_import = self.graph().map(|graph| {
+            (
+                graph.resolve(&error_path, &package_name.name),
+                graph.package_at(&error_path),
+            )
+        });
+
         if core_package {
             // Enforce core library package access
             self.parse_core_package(&package)
+        } else if let Some((resolved_import, importing_package)) = resolved_import {
+            let matched_import_entry = match resolved_import {
+                Some(import_directory) => {
+                    let parent_directory = import_directory.parent().unwrap_or(&import_directory);
+                    let entries = fs::read_dir(parent_directory)
+                        .map_err(|_| ImportError::dependency_not_installed(package_name.clone()))?
+                        .into_iter()
+                        .collect::<Result<Vec<_>, std::io::Error>>()
+                        .map_err(|error| {
+                            ImportError::directory_error(error, package_name.span.clone(), error_path.clone())
+                        })?;
+
+                    let matched_import_entry = entries.into_iter().find(|entry| entry.path() == import_directory);
+
+                    match matched_import_entry {
+                        Some(import_entry) => Some(import_entry),
+                        None => return Err(ImportError::dependency_not_installed(package_name)),
+                    }
+                }
+                None => None,
+            };
+
+            match (matched_source_entry, matched_import_entry) {
+                (Some(_), Some(_)) => Err(ImportError::conflicting_imports(package_name)),
+                (Some(source_entry), None) => self.parse_package_access(&source_entry, &package.access),
+                (None, Some(import_entry)) => self.parse_package_access(&import_entry, &package.access),
+                (None, None) => Err(ImportError::undeclared_dependency(package_name, importing_package)),
+            }
         } else if imports_directory.exists() {
             let entries = fs::read_dir(imports_directory)
                 .map_err(|error| ImportError::directory_error(error, package_name.span.clone(), error_path.clone()))?
@@ -23,38 +23,41 @@ use std::{ffi::OsString, fs::DirEntry, path::PathBuf};
 static LIBRARY_FILE: &str = "src/lib.leo";
 static FILE_EXTENSION: &str = "leo";
 
-fn parse_import_file(entry: &DirEntry, span: &Span) -> Result<Program, ImportError> {
-    // make sure the given entry is file
-    let file_type = entry
-        .file_type()
-        .map_err(|error| ImportError::directory_error(error, span.clone(), entry.path()))?;
-    let file_name = entry
-        .file_name()
-        .to_os_string()
-        .into_string()
-        .map_err(|_| ImportError::convert_os_string(span.clone()))?;
-
-    let mut file_path = entry.path().to_path_buf();
-    if file_type.is_dir() {
-        file_path.push(LIBRARY_FILE);
-
-        if !file_path.exists() {
-            return Err(ImportError::expected_lib_file(
-                format!("{:?}", file_path.as_path()),
-                span.clone(),
-            ));
+impl ImportParser {
+    fn parse_import_file(&mut self, entry: &DirEntry, span: &Span) -> Result<Program, ImportError> {
+        // make sure the given entry is file
+        let file_type = entry
+            .file_type()
+            .map_err(|error| ImportError::directory_error(error, span.clone(), entry.path()))?;
+        let file_name = entry
+            .file_name()
+            .to_os_string()
+            .into_string()
+            .map_err(|_| ImportError::convert_os_string(span.clone()))?;
+
+        let mut file_path = entry.path().to_path_buf();
+        if file_type.is_dir() {
+            file_path.push(LIBRARY_FILE);
+
+            if !file_path.exists() {
+                return Err(ImportError::expected_lib_file(
+                    format!("{:?}", file_path.as_path()),
+                    span.clone(),
+                ));
+            }
         }
-    }
 
-    // Builds the abstract syntax tree.
-    let program_string = &LeoAst::load_file(&file_path)?;
-    let ast = &LeoAst::new(&file_path, &program_string)?;
+        // Builds the abstract syntax tree.
+        let program_string = LeoAst::load_file(&file_path)?;
+        let ast = &LeoAst::new(&file_path, &program_string)?;
 
-    // Generates the Leo program from file.
-    Ok(Program::from(&file_name, ast.as_repr()))
-}
+        // Store the source so that it is included in the build checksum.
+        self.insert_source(file_path.clone(), program_string.clone());
+
+        // Generates the Leo program from file.
+        Ok(Program::from(&file_name, ast.as_repr()))
+    }
 
-impl ImportParser {
     pub fn parse_import_star(&mut self, entry: &DirEntry, span: &Span) -> Result<(), ImportError> {
         let path = entry.path();
         let is_dir = path.is_dir();
@@ -70,7 +73,7 @@ impl ImportParser {
         // import * can only be invoked on a package with a library file or a leo file
         if is_package || is_leo_file {
             // Generate aleo program from file
-            let program = parse_import_file(entry, &span)?;
+            let program = self.parse_import_file(entry, &span)?;
 
             // Store program's imports in imports hashmap
             program
@@ -99,7 +102,7 @@ impl ImportParser {
 
     pub fn parse_import_symbol(&mut self, entry: &DirEntry, symbol: &ImportSymbol) -> Result<(), ImportError> {
         // Generate aleo program from file
-        let program = parse_import_file(entry, &symbol.span)?;
+        let program = self.parse_import_file(entry, &symbol.span)?;
 
         // Store program's imports in imports hashmap
         program
@@ -48,7 +48,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 .ok_or(ImportError::unknown_package(import.package.name.clone()))?;
 
             // Parse imported program
-            self.store_definitions(program.clone(), imported_programs)?;
+            self.store_program_definitions(program, imported_programs)?;
 
             // Store the imported symbol
             self.store_symbol(scope.clone(), package, &symbol, program)?;
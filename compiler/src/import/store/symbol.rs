@@ -40,6 +40,17 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 self.store(name, value);
             });
 
+            // evaluate and store all enum definitions
+            program.enums.iter().for_each(|(identifier, enum_)| {
+                let name = new_scope(scope.clone(), identifier.to_string());
+                let value = ConstrainedValue::Import(
+                    program_name.clone(),
+                    Box::new(ConstrainedValue::EnumDefinition(enum_.clone())),
+                );
+
+                self.store(name, value);
+            });
+
             // evaluate and store all function definitions
             program.functions.iter().for_each(|(identifier, function)| {
                 let name = new_scope(scope.clone(), identifier.to_string());
@@ -63,18 +74,28 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     Box::new(ConstrainedValue::CircuitDefinition(circuit.clone())),
                 ),
                 None => {
+                    // see if the imported symbol is an enum
+                    let matched_enum = program
+                        .enums
+                        .iter()
+                        .find(|(enum_name, _enum)| symbol.symbol == **enum_name);
+
                     // see if the imported symbol is a function
                     let matched_function = program
                         .functions
                         .iter()
                         .find(|(function_name, _function)| symbol.symbol == **function_name);
 
-                    match matched_function {
-                        Some((_function_name, function)) => ConstrainedValue::Import(
+                    match (matched_enum, matched_function) {
+                        (Some((_enum_name, enum_)), _) => ConstrainedValue::Import(
+                            program_name.clone(),
+                            Box::new(ConstrainedValue::EnumDefinition(enum_.clone())),
+                        ),
+                        (None, Some((_function_name, function))) => ConstrainedValue::Import(
                             program_name.clone(),
                             Box::new(ConstrainedValue::Function(None, function.clone())),
                         ),
-                        None => return Err(ImportError::unknown_symbol(symbol.to_owned(), program_name)),
+                        (None, None) => return Err(ImportError::unknown_symbol(symbol.to_owned(), program_name)),
                     }
                 }
             };
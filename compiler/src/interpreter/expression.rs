@@ -0,0 +1,981 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Evaluates an expression in an interpreted Leo program.
+
+use crate::{
+    definition::monomorphize_function,
+    errors::{BooleanError, ExpressionError, FieldError, InterpreterError},
+    field_to_decimal,
+    interpreter::{CircuitValue, Definition, EnumValue, Frame, Interpreter, IntegerValue, ShadowedVariables, Value},
+};
+use leo_core::{CoreCircuitError, LeoCoreError, CORE_UNSTABLE_BLAKE2S_NAME};
+use leo_typed::{
+    CircuitMember,
+    CircuitVariableDefinition,
+    Enum,
+    Expression,
+    Identifier,
+    IntegerType,
+    MatchArm,
+    MatchPattern,
+    RangeOrExpression,
+    Span,
+    SpreadOrExpression,
+    Type,
+};
+
+use snarkos_algorithms::prf::blake2s::Blake2s;
+use snarkos_models::{algorithms::PRF, curves::Field};
+use std::{cmp::Ordering, collections::HashMap};
+
+impl Interpreter {
+    pub(crate) fn evaluate_expression(
+        &self,
+        frame: &mut Frame,
+        expected_type: Option<&Type>,
+        expression: &Expression,
+    ) -> Result<Value, InterpreterError> {
+        match expression {
+            // Variables
+            Expression::Identifier(identifier) => self.evaluate_identifier(frame, expected_type, identifier),
+
+            // Values
+            Expression::Address(address, span) => Ok(Value::address(address.clone(), span.clone())?),
+            Expression::Boolean(boolean, span) => Ok(Value::boolean(boolean.clone(), span.clone())?),
+            Expression::Field(field, span) => Ok(Value::field(field.clone(), span.clone())?),
+            Expression::Group(group) => Ok(Value::group(group.clone())?),
+            Expression::Implicit(value, span) => match expected_type {
+                Some(type_) => Ok(Value::from_type(value.clone(), type_, span.clone())?),
+                None => Ok(Value::Implicit(value.clone())),
+            },
+            Expression::Integer(type_, integer, span) => {
+                Ok(Value::Integer(IntegerValue::new(type_, integer.clone(), span.clone())?))
+            }
+
+            // Number operations
+            Expression::Negate(expression, span) => {
+                let value = self.evaluate_expression(frame, expected_type, expression)?;
+
+                match value {
+                    Value::Integer(integer) => Ok(Value::Integer(integer.negate(span.clone())?)),
+                    Value::Field(field) => Ok(Value::Field(-field)),
+                    Value::Group(group) => Ok(Value::Group(-group)),
+                    value => Err(ExpressionError::incompatible_types(format!("-{}", value), span.clone()).into()),
+                }
+            }
+            Expression::Add(left, right, span) => {
+                let (left, right) = self.evaluate_binary_expression(frame, expected_type, left, right, span)?;
+
+                match (left, right) {
+                    (Value::Integer(left), Value::Integer(right)) => {
+                        Ok(Value::Integer(left.add(&right, span.clone())?))
+                    }
+                    (Value::Field(left), Value::Field(right)) => Ok(Value::Field(left + &right)),
+                    (Value::Group(left), Value::Group(right)) => Ok(Value::Group(left + &right)),
+                    (left, right) => Err(incompatible_types(left, "+", right, span)),
+                }
+            }
+            Expression::Sub(left, right, span) => {
+                let (left, right) = self.evaluate_binary_expression(frame, expected_type, left, right, span)?;
+
+                match (left, right) {
+                    (Value::Integer(left), Value::Integer(right)) => {
+                        Ok(Value::Integer(left.sub(&right, span.clone())?))
+                    }
+                    (Value::Field(left), Value::Field(right)) => Ok(Value::Field(left - &right)),
+                    (Value::Group(left), Value::Group(right)) => Ok(Value::Group(left - &right)),
+                    (left, right) => Err(incompatible_types(left, "-", right, span)),
+                }
+            }
+            Expression::Mul(left, right, span) => {
+                let (left, right) = self.evaluate_binary_expression(frame, expected_type, left, right, span)?;
+
+                match (left, right) {
+                    (Value::Integer(left), Value::Integer(right)) => {
+                        Ok(Value::Integer(left.mul(&right, span.clone())?))
+                    }
+                    (Value::Field(left), Value::Field(right)) => Ok(Value::Field(left * &right)),
+                    (left, right) => Err(incompatible_types(left, "*", right, span)),
+                }
+            }
+            Expression::Div(left, right, span) => {
+                let (left, right) = self.evaluate_binary_expression(frame, expected_type, left, right, span)?;
+
+                match (left, right) {
+                    (Value::Integer(left), Value::Integer(right)) => {
+                        Ok(Value::Integer(left.div(&right, span.clone())?))
+                    }
+                    (Value::Field(left), Value::Field(right)) => {
+                        let inverse = right
+                            .inverse()
+                            .ok_or_else(|| FieldError::no_inverse(field_to_decimal(&right), span.clone()))?;
+
+                        Ok(Value::Field(left * &inverse))
+                    }
+                    (left, right) => Err(incompatible_types(left, "/", right, span)),
+                }
+            }
+            Expression::Pow(left, right, span) => {
+                let (left, right) = self.evaluate_binary_expression(frame, expected_type, left, right, span)?;
+
+                match (left, right) {
+                    (Value::Integer(left), Value::Integer(right)) => {
+                        Ok(Value::Integer(left.pow(&right, span.clone())?))
+                    }
+                    (left, right) => Err(incompatible_types(left, "**", right, span)),
+                }
+            }
+
+            // Boolean operations
+            Expression::Not(expression, span) => match self.evaluate_expression(frame, expected_type, expression)? {
+                Value::Boolean(boolean) => Ok(Value::Boolean(!boolean)),
+                value => Err(BooleanError::cannot_evaluate(format!("!{}", value), span.clone()).into()),
+            },
+            Expression::Or(left, right, span) => {
+                // Both operands are evaluated like in the compiled circuit
+                match self.evaluate_binary_expression(frame, expected_type, left, right, span)? {
+                    (Value::Boolean(left), Value::Boolean(right)) => Ok(Value::Boolean(left || right)),
+                    (left, right) => {
+                        Err(BooleanError::cannot_evaluate(format!("{} || {}", left, right), span.clone()).into())
+                    }
+                }
+            }
+            Expression::And(left, right, span) => {
+                match self.evaluate_binary_expression(frame, expected_type, left, right, span)? {
+                    (Value::Boolean(left), Value::Boolean(right)) => Ok(Value::Boolean(left && right)),
+                    (left, right) => {
+                        Err(BooleanError::cannot_evaluate(format!("{} && {}", left, right), span.clone()).into())
+                    }
+                }
+            }
+            Expression::Eq(left, right, span) => {
+                let (left, right) = self.evaluate_binary_expression(frame, None, left, right, span)?;
+
+                Ok(Value::Boolean(values_equal(left, right, span)?))
+            }
+            Expression::Ge(left, right, span) => {
+                let ordering = self.evaluate_comparison(frame, left, right, ">=", span)?;
+
+                Ok(Value::Boolean(ordering != Ordering::Less))
+            }
+            Expression::Gt(left, right, span) => {
+                let ordering = self.evaluate_comparison(frame, left, right, ">", span)?;
+
+                Ok(Value::Boolean(ordering == Ordering::Greater))
+            }
+            Expression::Le(left, right, span) => {
+                let ordering = self.evaluate_comparison(frame, left, right, "<=", span)?;
+
+                Ok(Value::Boolean(ordering != Ordering::Greater))
+            }
+            Expression::Lt(left, right, span) => {
+                let ordering = self.evaluate_comparison(frame, left, right, "<", span)?;
+
+                Ok(Value::Boolean(ordering == Ordering::Less))
+            }
+
+            // Conditionals
+            Expression::IfElse(condition, first, second, span) => {
+                // Only the selected branch is evaluated
+                let branch = match self.evaluate_operand(frame, Some(&Type::Boolean), condition, span.clone())? {
+                    Value::Boolean(true) => first,
+                    Value::Boolean(false) => second,
+                    value => return Err(ExpressionError::conditional_boolean(value.to_string(), span.clone()).into()),
+                };
+
+                self.evaluate_operand(frame, expected_type, branch, span.clone())
+            }
+            Expression::Match(expression, arms, span) => {
+                let patterns = arms.iter().map(|arm| arm.pattern.clone()).collect::<Vec<_>>();
+                let value = self.evaluate_match_value(frame, expression, &patterns, span)?;
+                let (arm, shadowed) = arms
+                    .iter()
+                    .find_map(|arm: &MatchArm| {
+                        self.pattern_matches(frame, &value, &arm.pattern)
+                            .map(|shadowed| (arm.expression.clone(), shadowed))
+                    })
+                    .ok_or_else(|| ExpressionError::invalid_match(value.enum_.enum_name.to_string(), span.clone()))?;
+
+                let result = self.evaluate_operand(frame, expected_type, &arm, span.clone());
+
+                frame.restore(shadowed);
+
+                result
+            }
+
+            // Arrays
+            Expression::Array(array, span) => self.evaluate_array(frame, expected_type, array, span),
+            Expression::GenericArray(_element, parameter, span) => {
+                Err(ExpressionError::unresolved_array_length(parameter.to_string(), span.clone()).into())
+            }
+            Expression::ArrayAccess(array, index, span) => {
+                let array = match self.evaluate_operand(frame, None, array, span.clone())? {
+                    Value::Array(array) => array,
+                    value => return Err(ExpressionError::undefined_array(value.to_string(), span.clone()).into()),
+                };
+
+                let value = match &**index {
+                    RangeOrExpression::Range(from, to) => {
+                        let from = match from {
+                            Some(from) => self.evaluate_index(frame, from, span)?,
+                            None => 0usize,
+                        };
+                        let to = match to {
+                            Some(to) => self.evaluate_index(frame, to, span)?,
+                            None => array.len(),
+                        };
+
+                        if from > to || to > array.len() {
+                            return Err(InterpreterError::slice_out_of_bounds(
+                                from,
+                                to,
+                                array.len(),
+                                span.clone(),
+                            ));
+                        }
+
+                        Value::Array(array[from..to].to_vec())
+                    }
+                    RangeOrExpression::Expression(index) => {
+                        let index = self.evaluate_index(frame, index, span)?;
+
+                        array
+                            .get(index)
+                            .cloned()
+                            .ok_or_else(|| ExpressionError::index_out_of_bounds(index, span.clone()))?
+                    }
+                };
+
+                resolve(value, expected_type, span)
+            }
+
+            // Tuples
+            Expression::Tuple(tuple, span) => {
+                let types = match expected_type {
+                    Some(Type::Tuple(types)) => Some(types),
+                    Some(type_) => {
+                        return Err(ExpressionError::unexpected_tuple(
+                            type_.to_string(),
+                            format!("{:?}", tuple),
+                            span.clone(),
+                        )
+                        .into());
+                    }
+                    None => None,
+                };
+
+                let mut values = vec![];
+
+                for (i, expression) in tuple.iter().enumerate() {
+                    let type_ = types.and_then(|types| types.get(i));
+
+                    values.push(self.evaluate_expression(frame, type_, expression)?);
+                }
+
+                Ok(Value::Tuple(values))
+            }
+            Expression::TupleAccess(tuple, index, span) => {
+                let tuple = match self.evaluate_operand(frame, None, tuple, span.clone())? {
+                    Value::Tuple(tuple) => tuple,
+                    value => return Err(ExpressionError::undefined_array(value.to_string(), span.clone()).into()),
+                };
+
+                let value = tuple
+                    .get(*index)
+                    .cloned()
+                    .ok_or_else(|| ExpressionError::index_out_of_bounds(*index, span.clone()))?;
+
+                resolve(value, expected_type, span)
+            }
+
+            // Circuits
+            Expression::Circuit(identifier, members, span) => self.evaluate_circuit(frame, identifier, members, span),
+            Expression::CircuitMemberAccess(circuit, member, span) => {
+                let circuit = match self.evaluate_expression(frame, None, circuit)? {
+                    Value::Circuit(circuit) => circuit,
+                    value => return Err(ExpressionError::undefined_circuit(value.to_string(), span.clone()).into()),
+                };
+
+                match circuit.get(member) {
+                    Some(value) => resolve(value.clone(), expected_type, span),
+                    None => Err(ExpressionError::undefined_member_access(
+                        circuit.circuit.circuit_name.to_string(),
+                        member.to_string(),
+                        span.clone(),
+                    )
+                    .into()),
+                }
+            }
+            Expression::CircuitStaticFunctionAccess(circuit, member, span) => {
+                // Enum variants without values are accessed like static members
+                if let Some(enum_) = self.get_enum(frame, circuit) {
+                    return self.evaluate_enum_variant(frame, enum_, member, &[], span);
+                }
+
+                Err(
+                    ExpressionError::undefined_static_access(circuit.to_string(), member.to_string(), span.clone())
+                        .into(),
+                )
+            }
+
+            // Functions
+            Expression::FunctionCall(function, arguments, span) => {
+                let value = self.evaluate_function_call(frame, function, arguments, span)?;
+
+                resolve(value, expected_type, span)
+            }
+            Expression::CoreFunctionCall(function, arguments, span) => {
+                let value = self.evaluate_core_function_call(frame, function, arguments, span)?;
+
+                if let Some(expected) = expected_type {
+                    self.check_return_type(
+                        &frame.program,
+                        Some(expected),
+                        value.to_type(span.clone())?,
+                        span.clone(),
+                    )?;
+                }
+
+                Ok(value)
+            }
+        }
+    }
+
+    /// Evaluates an expression and resolves an implicit result to the expected type.
+    pub(crate) fn evaluate_operand(
+        &self,
+        frame: &mut Frame,
+        expected_type: Option<&Type>,
+        expression: &Expression,
+        span: Span,
+    ) -> Result<Value, InterpreterError> {
+        let value = self.evaluate_expression(frame, expected_type, expression)?;
+
+        resolve(value, expected_type, &span)
+    }
+
+    fn evaluate_binary_expression(
+        &self,
+        frame: &mut Frame,
+        expected_type: Option<&Type>,
+        left: &Expression,
+        right: &Expression,
+        span: &Span,
+    ) -> Result<(Value, Value), InterpreterError> {
+        let mut left = self.evaluate_operand(frame, expected_type, left, span.clone())?;
+        let mut right = self.evaluate_operand(frame, expected_type, right, span.clone())?;
+
+        left.resolve_types(&mut right, expected_type, span.clone())?;
+
+        Ok((left, right))
+    }
+
+    fn evaluate_comparison(
+        &self,
+        frame: &mut Frame,
+        left: &Expression,
+        right: &Expression,
+        operation: &str,
+        span: &Span,
+    ) -> Result<Ordering, InterpreterError> {
+        match self.evaluate_binary_expression(frame, None, left, right, span)? {
+            (Value::Integer(left), Value::Integer(right)) => left.compare(&right, operation, span.clone()),
+            (left, right) => Err(incompatible_types(left, operation, right, span)),
+        }
+    }
+
+    fn evaluate_identifier(
+        &self,
+        frame: &mut Frame,
+        expected_type: Option<&Type>,
+        identifier: &Identifier,
+    ) -> Result<Value, InterpreterError> {
+        match frame.variables.get(&identifier.name) {
+            Some(variable) => resolve(variable.value.clone(), expected_type, &identifier.span),
+            // If we expect an address type, try to return an address
+            None if expected_type == Some(&Type::Address) => {
+                Ok(Value::address(identifier.name.clone(), identifier.span.clone())?)
+            }
+            None => Err(ExpressionError::undefined_identifier(identifier.clone()).into()),
+        }
+    }
+
+    /// Evaluates an array index or slice bound as a `u32`.
+    pub(crate) fn evaluate_index(
+        &self,
+        frame: &mut Frame,
+        index: &Expression,
+        span: &Span,
+    ) -> Result<usize, InterpreterError> {
+        let expected_type = Type::IntegerType(IntegerType::U32);
+
+        match self.evaluate_operand(frame, Some(&expected_type), index, span.clone())? {
+            Value::Integer(integer) => Ok(integer
+                .to_usize()
+                .ok_or_else(|| ExpressionError::invalid_index(integer.to_string(), span.clone()))?),
+            value => Err(ExpressionError::invalid_index(value.to_string(), span.clone()).into()),
+        }
+    }
+
+    fn evaluate_array(
+        &self,
+        frame: &mut Frame,
+        expected_type: Option<&Type>,
+        array: &[Box<SpreadOrExpression>],
+        span: &Span,
+    ) -> Result<Value, InterpreterError> {
+        // Check explicit array type dimension if given
+        let (expected_length, element_type) = match expected_type {
+            Some(Type::Array(type_, dimensions)) => match dimensions.first() {
+                Some(length) => (Some(*length), Some(type_.outer_dimension(dimensions))),
+                None => return Err(ExpressionError::unexpected_array(type_.to_string(), span.clone()).into()),
+            },
+            Some(type_) => return Err(ExpressionError::unexpected_array(type_.to_string(), span.clone()).into()),
+            None => (None, None),
+        };
+
+        let mut values = vec![];
+
+        for element in array.iter() {
+            match &**element {
+                SpreadOrExpression::Spread(Expression::Identifier(identifier)) => {
+                    match frame.variables.get(&identifier.name) {
+                        Some(variable) => match &variable.value {
+                            Value::Array(array) => values.extend(array.iter().cloned()),
+                            value => {
+                                return Err(ExpressionError::invalid_spread(value.to_string(), span.clone()).into());
+                            }
+                        },
+                        None => {
+                            return Err(ExpressionError::undefined_array(identifier.to_string(), span.clone()).into());
+                        }
+                    }
+                }
+                SpreadOrExpression::Spread(expression) => {
+                    return Err(ExpressionError::invalid_spread(expression.to_string(), span.clone()).into());
+                }
+                SpreadOrExpression::Expression(expression) => {
+                    values.push(self.evaluate_expression(frame, element_type.as_ref(), expression)?);
+                }
+            }
+        }
+
+        if let Some(expected_length) = expected_length {
+            if expected_length != values.len() {
+                return Err(ExpressionError::invalid_length(expected_length, values.len(), span.clone()).into());
+            }
+        }
+
+        Ok(Value::Array(values))
+    }
+
+    fn evaluate_circuit(
+        &self,
+        frame: &mut Frame,
+        identifier: &Identifier,
+        members: &[CircuitVariableDefinition],
+        span: &Span,
+    ) -> Result<Value, InterpreterError> {
+        let (program, mut circuit) = self
+            .get_circuit(frame, identifier)
+            .ok_or_else(|| ExpressionError::undefined_circuit(identifier.to_string(), span.clone()))?;
+
+        // Values of const parameters are inferred from the lengths of the circuit variables
+        let mut constants = HashMap::new();
+        let mut values = vec![];
+
+        for member in circuit.members.iter() {
+            if let CircuitMember::CircuitVariable(_mutable, identifier, type_) = member {
+                let variable = members
+                    .iter()
+                    .find(|variable| variable.identifier.eq(identifier))
+                    .ok_or_else(|| ExpressionError::expected_circuit_member(identifier.to_string(), span.clone()))?;
+
+                // Resolve any const parameters that are already known
+                let type_ = type_.resolve_const_parameters(&constants);
+                let expected_type = if type_.is_generic() { None } else { Some(&type_) };
+
+                let value = self.evaluate_operand(frame, expected_type, &variable.expression, span.clone())?;
+                let value_type = value.to_type(span.clone())?;
+
+                if type_.is_generic() {
+                    if !type_.infer_const_parameters(&value_type, &mut constants) {
+                        return Err(ExpressionError::const_member_type(
+                            type_.to_string(),
+                            value_type.to_string(),
+                            span.clone(),
+                        )
+                        .into());
+                    }
+                } else if !self.types_match(&program, &type_, &value_type) {
+                    return Err(InterpreterError::definition_type(
+                        identifier.to_string(),
+                        &type_,
+                        &value_type,
+                        span.clone(),
+                    ));
+                }
+
+                values.push((identifier.clone(), value));
+            }
+        }
+
+        // Monomorphize circuit functions with the inferred const parameters
+        if circuit.is_generic() {
+            for parameter in circuit.const_parameters.iter() {
+                if !constants.contains_key(&parameter.identifier.name) {
+                    return Err(ExpressionError::undefined_const_parameter(
+                        parameter.identifier.to_string(),
+                        span.clone(),
+                    )
+                    .into());
+                }
+            }
+
+            let const_parameters = circuit.const_parameters.clone();
+
+            for member in circuit.members.iter_mut() {
+                if let CircuitMember::CircuitFunction(_static, function) = member {
+                    function.const_parameters = [const_parameters.clone(), function.const_parameters.clone()].concat();
+                    *function = monomorphize_function(function.clone(), &constants);
+                }
+            }
+        }
+
+        Ok(Value::Circuit(CircuitValue {
+            program,
+            circuit,
+            members: values,
+        }))
+    }
+
+    /// Returns the enum definition named by an identifier expression.
+    fn get_enum(&self, frame: &Frame, expression: &Expression) -> Option<Enum> {
+        match expression {
+            Expression::Identifier(identifier) => match self.get_definition(&frame.program, &identifier.name) {
+                Some(Definition::Enum(enum_)) => Some(enum_.clone()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Evaluates an enum variant `Enum::Variant` or `Enum::Variant(values)`.
+    fn evaluate_enum_variant(
+        &self,
+        frame: &mut Frame,
+        enum_: Enum,
+        variant: &Identifier,
+        arguments: &[Expression],
+        span: &Span,
+    ) -> Result<Value, InterpreterError> {
+        let index = enum_.variant_index(variant).ok_or_else(|| {
+            ExpressionError::undefined_variant(enum_.enum_name.to_string(), variant.to_string(), span.clone())
+        })?;
+        let variant_name = format!("{}::{}", enum_.enum_name, variant);
+        let types = enum_.variants[index].types.clone();
+
+        if types.len() != arguments.len() {
+            return Err(ExpressionError::invalid_variant_arguments(
+                variant_name,
+                types.len(),
+                arguments.len(),
+                span.clone(),
+            )
+            .into());
+        }
+
+        let mut payload = vec![];
+
+        for (type_, argument) in types.iter().zip(arguments.iter()) {
+            let value = self.evaluate_operand(frame, Some(type_), argument, span.clone())?;
+            let value_type = value.to_type(span.clone())?;
+
+            if !self.types_match(&frame.program, type_, &value_type) {
+                return Err(ExpressionError::enum_variant_type(
+                    variant_name,
+                    type_.to_string(),
+                    value_type.to_string(),
+                    span.clone(),
+                )
+                .into());
+            }
+
+            payload.push(value);
+        }
+
+        Ok(Value::Enum(EnumValue { enum_, index, payload }))
+    }
+
+    /// Evaluates the matched value of a match expression or statement.
+    /// Checks that every pattern is valid for the value and that the patterns are exhaustive.
+    pub(crate) fn evaluate_match_value(
+        &self,
+        frame: &mut Frame,
+        expression: &Expression,
+        patterns: &[MatchPattern],
+        span: &Span,
+    ) -> Result<EnumValue, InterpreterError> {
+        let value = match self.evaluate_expression(frame, None, expression)? {
+            Value::Enum(value) => value,
+            value => return Err(ExpressionError::invalid_match(value.to_string(), span.clone()).into()),
+        };
+        let enum_name = &value.enum_.enum_name;
+
+        for pattern in patterns.iter() {
+            if let MatchPattern::Variant(pattern_enum_name, variant, bindings, span) = pattern {
+                let enum_ = self
+                    .get_enum(frame, &Expression::Identifier(pattern_enum_name.clone()))
+                    .ok_or_else(|| ExpressionError::undefined_enum(pattern_enum_name.to_string(), span.clone()))?;
+
+                if enum_.enum_name.ne(enum_name) {
+                    return Err(ExpressionError::mismatched_enum(
+                        enum_name.to_string(),
+                        enum_.enum_name.to_string(),
+                        span.clone(),
+                    )
+                    .into());
+                }
+
+                let index = enum_.variant_index(variant).ok_or_else(|| {
+                    ExpressionError::undefined_variant(enum_name.to_string(), variant.to_string(), span.clone())
+                })?;
+                let expected_bindings = enum_.variants[index].types.len();
+
+                if bindings.len() != expected_bindings {
+                    return Err(ExpressionError::invalid_match_bindings(
+                        pattern.to_string(),
+                        expected_bindings,
+                        bindings.len(),
+                        span.clone(),
+                    )
+                    .into());
+                }
+            }
+        }
+
+        // Every variant must be covered by a pattern unless there is a wildcard
+        let has_wildcard = patterns.iter().any(|pattern| match pattern {
+            MatchPattern::Wildcard(_) => true,
+            _ => false,
+        });
+
+        if !has_wildcard {
+            let missing = value
+                .enum_
+                .variants
+                .iter()
+                .filter(|variant| {
+                    !patterns.iter().any(|pattern| match pattern {
+                        MatchPattern::Variant(_, name, _, _) => name.eq(&variant.identifier),
+                        _ => false,
+                    })
+                })
+                .map(|variant| variant.identifier.to_string())
+                .collect::<Vec<_>>();
+
+            if !missing.is_empty() {
+                return Err(ExpressionError::non_exhaustive_match(enum_name.to_string(), missing, span.clone()).into());
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Returns the shadowed variables if the pattern matches the variant of the value.
+    /// Stores the pattern bindings in the function scope if it does, the caller restores the
+    /// shadowed variables with `Frame::restore` after evaluating the arm.
+    pub(crate) fn pattern_matches(
+        &self,
+        frame: &mut Frame,
+        value: &EnumValue,
+        pattern: &MatchPattern,
+    ) -> Option<ShadowedVariables> {
+        match pattern {
+            MatchPattern::Wildcard(_span) => Some(vec![]),
+            MatchPattern::Variant(_enum_name, variant, bindings, _span) => {
+                if value.enum_.variant_index(variant) != Some(value.index) {
+                    return None;
+                }
+
+                let shadowed = bindings
+                    .iter()
+                    .zip(value.payload.iter())
+                    .map(|(binding, value)| {
+                        let previous = frame.variables.get(&binding.name).cloned();
+
+                        frame.store(binding.name.clone(), false, value.clone());
+
+                        (binding.name.clone(), previous)
+                    })
+                    .collect();
+
+                Some(shadowed)
+            }
+        }
+    }
+
+    fn evaluate_function_call(
+        &self,
+        frame: &mut Frame,
+        function: &Expression,
+        arguments: &[Expression],
+        span: &Span,
+    ) -> Result<Value, InterpreterError> {
+        match function {
+            Expression::CircuitStaticFunctionAccess(circuit, member, _span) => {
+                // Enum variants with values are constructed like static function calls
+                if let Some(enum_) = self.get_enum(frame, circuit) {
+                    return self.evaluate_enum_variant(frame, enum_, member, arguments, span);
+                }
+
+                let (program, circuit) = match &**circuit {
+                    Expression::Identifier(identifier) if identifier.is_self() => frame
+                        .circuit
+                        .clone()
+                        .ok_or_else(|| ExpressionError::self_keyword(identifier.span.clone()))?,
+                    Expression::Identifier(identifier) => self
+                        .get_circuit(frame, identifier)
+                        .ok_or_else(|| ExpressionError::undefined_circuit(identifier.to_string(), span.clone()))?,
+                    expression => {
+                        return Err(ExpressionError::undefined_circuit(expression.to_string(), span.clone()).into());
+                    }
+                };
+
+                let mut function = match find_function(&circuit, member) {
+                    Some((true, function)) => function,
+                    Some((false, function)) => {
+                        return Err(ExpressionError::invalid_member_access(
+                            function.identifier.to_string(),
+                            span.clone(),
+                        )
+                        .into());
+                    }
+                    None => {
+                        return Err(ExpressionError::undefined_member_access(
+                            circuit.circuit_name.to_string(),
+                            member.to_string(),
+                            span.clone(),
+                        )
+                        .into());
+                    }
+                };
+
+                // Const parameters of the circuit are inferred from the static function input variables
+                function.const_parameters = [circuit.const_parameters.clone(), function.const_parameters].concat();
+
+                let (arguments, constants) = self.evaluate_arguments(frame, &function, arguments)?;
+                let (value, _self) = self.call_function(
+                    program.clone(),
+                    Some((program, circuit)),
+                    None,
+                    function,
+                    arguments,
+                    constants,
+                )?;
+
+                Ok(value)
+            }
+            Expression::CircuitMemberAccess(receiver, member, _span) => {
+                let circuit = match self.evaluate_expression(frame, None, receiver)? {
+                    Value::Circuit(circuit) => circuit,
+                    value => return Err(ExpressionError::undefined_circuit(value.to_string(), span.clone()).into()),
+                };
+
+                let function = match find_function(&circuit.circuit, member) {
+                    Some((false, function)) => function,
+                    Some((true, function)) => {
+                        return Err(ExpressionError::invalid_static_access(
+                            function.identifier.to_string(),
+                            span.clone(),
+                        )
+                        .into());
+                    }
+                    None => {
+                        return Err(ExpressionError::undefined_member_access(
+                            circuit.circuit.circuit_name.to_string(),
+                            member.to_string(),
+                            span.clone(),
+                        )
+                        .into());
+                    }
+                };
+                let function_name = function.get_name();
+
+                let (arguments, constants) = self.evaluate_arguments(frame, &function, arguments)?;
+                let program = circuit.program.clone();
+                let definition = Some((program.clone(), circuit.circuit.clone()));
+                let (value, self_value) = self.call_function(
+                    program,
+                    definition,
+                    Some(circuit.clone()),
+                    function,
+                    arguments,
+                    constants,
+                )?;
+
+                // Write a mutated `self` back to the variable the function was called on
+                if let Some(self_value) = self_value {
+                    if self_value != circuit {
+                        let variable = match &**receiver {
+                            Expression::Identifier(identifier) => frame.variables.get_mut(&identifier.name),
+                            _ => None,
+                        };
+
+                        match variable {
+                            Some(variable) if variable.mutable => variable.value = Value::Circuit(self_value),
+                            _ => return Err(InterpreterError::immutable_self(function_name, span.clone())),
+                        }
+                    }
+                }
+
+                Ok(value)
+            }
+            Expression::Identifier(identifier) => match self.get_definition(&frame.program, &identifier.name) {
+                Some(Definition::Function(program, function)) => {
+                    let function = function.clone();
+                    let (arguments, constants) = self.evaluate_arguments(frame, &function, arguments)?;
+                    let (value, _self) =
+                        self.call_function(program.clone(), None, None, function, arguments, constants)?;
+
+                    Ok(value)
+                }
+                _ => Err(ExpressionError::undefined_function(identifier.to_string(), span.clone()).into()),
+            },
+            expression => Err(ExpressionError::undefined_function(expression.to_string(), span.clone()).into()),
+        }
+    }
+
+    /// Calls a core circuit function natively.
+    fn evaluate_core_function_call(
+        &self,
+        frame: &mut Frame,
+        function: &str,
+        arguments: &[Expression],
+        span: &Span,
+    ) -> Result<Value, InterpreterError> {
+        let mut values = vec![];
+
+        for argument in arguments.iter() {
+            values.push(self.evaluate_expression(frame, None, argument)?);
+        }
+
+        match function {
+            CORE_UNSTABLE_BLAKE2S_NAME => {
+                if values.len() != 2 {
+                    return Err(
+                        LeoCoreError::from(CoreCircuitError::arguments_length(2, values.len(), span.clone())).into(),
+                    );
+                }
+
+                let seed = array_bytes(&values[0], span)?;
+                let message = array_bytes(&values[1], span)?;
+
+                let digest = Blake2s::evaluate(&seed, &message)
+                    .map_err(|_| ExpressionError::cannot_evaluate(format!("{}::hash", function), span.clone()))?;
+                let bytes = digest
+                    .iter()
+                    .map(|byte| Value::Integer(IntegerValue::Unsigned(IntegerType::U8, *byte as u128)))
+                    .collect();
+
+                Ok(Value::Array(bytes))
+            }
+            _ => Err(LeoCoreError::undefined_core_circuit(function.to_string(), span.clone()).into()),
+        }
+    }
+}
+
+/// Returns the value of an implicit number resolved to the expected type.
+fn resolve(mut value: Value, expected_type: Option<&Type>, span: &Span) -> Result<Value, InterpreterError> {
+    value.resolve_type(expected_type, span.clone())?;
+
+    Ok(value)
+}
+
+fn incompatible_types(left: Value, operation: &str, right: Value, span: &Span) -> InterpreterError {
+    ExpressionError::incompatible_types(format!("{} {} {}", left, operation, right), span.clone()).into()
+}
+
+/// Returns true if both values are equal.
+fn values_equal(left: Value, right: Value, span: &Span) -> Result<bool, InterpreterError> {
+    Ok(match (left, right) {
+        (Value::Address(left), Value::Address(right)) => left == right,
+        (Value::Boolean(left), Value::Boolean(right)) => left == right,
+        (Value::Field(left), Value::Field(right)) => left == right,
+        (Value::Group(left), Value::Group(right)) => left == right,
+        (Value::Integer(left), Value::Integer(right)) => left.compare(&right, "==", span.clone())? == Ordering::Equal,
+        (Value::Array(left), Value::Array(right)) | (Value::Tuple(left), Value::Tuple(right)) => {
+            let mut result = true;
+
+            for (left, right) in left.into_iter().zip(right.into_iter()) {
+                result = values_equal(left, right, span)? && result;
+            }
+
+            result
+        }
+        (Value::Enum(left), Value::Enum(right)) => {
+            if left.enum_.enum_name != right.enum_.enum_name {
+                return Err(ExpressionError::mismatched_enum(
+                    left.enum_.enum_name.to_string(),
+                    right.enum_.enum_name.to_string(),
+                    span.clone(),
+                )
+                .into());
+            }
+
+            left.index == right.index && values_equal(Value::Tuple(left.payload), Value::Tuple(right.payload), span)?
+        }
+        (left, right) => return Err(incompatible_types(left, "==", right, span)),
+    })
+}
+
+/// Returns the function member of a circuit and whether it is static.
+fn find_function(circuit: &leo_typed::Circuit, name: &Identifier) -> Option<(bool, leo_typed::Function)> {
+    circuit.members.iter().find_map(|member| match member {
+        CircuitMember::CircuitFunction(static_, function) if function.identifier.eq(name) => {
+            Some((*static_, function.clone()))
+        }
+        _ => None,
+    })
+}
+
+/// Returns the bytes of a `[u8; 32]` core function argument.
+fn array_bytes(value: &Value, span: &Span) -> Result<[u8; 32], InterpreterError> {
+    let array = match value {
+        Value::Array(array) => array,
+        value => {
+            return Err(ExpressionError::incompatible_types(format!("blake2s({})", value), span.clone()).into());
+        }
+    };
+
+    if array.len() != 32 {
+        return Err(LeoCoreError::from(CoreCircuitError::array_length(32, array.len(), span.clone())).into());
+    }
+
+    let mut bytes = [0u8; 32];
+
+    for (byte, value) in bytes.iter_mut().zip(array.iter()) {
+        *byte = match value {
+            Value::Integer(IntegerValue::Unsigned(IntegerType::U8, integer)) => *integer as u8,
+            value => {
+                return Err(ExpressionError::incompatible_types(format!("blake2s({})", value), span.clone()).into());
+            }
+        };
+    }
+
+    Ok(bytes)
+}
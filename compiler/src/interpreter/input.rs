@@ -0,0 +1,254 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Reads the values of main function inputs in an interpreted Leo program.
+
+use crate::{
+    errors::{
+        AddressError,
+        BooleanError,
+        ExpressionError,
+        FieldError,
+        FunctionError,
+        GroupError,
+        IntegerError,
+        InterpreterError,
+    },
+    interpreter::{CircuitValue, Definition, Interpreter, IntegerValue, Value},
+    RECORD_VARIABLE_NAME,
+    REGISTERS_VARIABLE_NAME,
+    STATE_LEAF_VARIABLE_NAME,
+    STATE_VARIABLE_NAME,
+};
+use leo_typed::{Circuit, CircuitMember, Identifier, Input, InputValue, Parameter, Span, Type};
+
+use std::collections::HashMap;
+
+impl Interpreter {
+    /// Returns the `input` keyword as a circuit value with a member for every input section.
+    pub(crate) fn input_keyword(
+        &self,
+        program: &str,
+        identifier: Identifier,
+        input: &Input,
+    ) -> Result<Value, InterpreterError> {
+        let sections = vec![
+            (REGISTERS_VARIABLE_NAME, input.get_registers().values()),
+            (RECORD_VARIABLE_NAME, input.get_record().values()),
+            (STATE_VARIABLE_NAME, input.get_state().values()),
+            (STATE_LEAF_VARIABLE_NAME, input.get_state_leaf().values()),
+        ];
+
+        let mut definitions = vec![];
+        let mut members = vec![];
+
+        for (name, values) in sections {
+            let name = Identifier {
+                name: name.to_string(),
+                span: identifier.span.clone(),
+            };
+            let value = self.input_section(program, name.clone(), values)?;
+
+            definitions.push(CircuitMember::CircuitVariable(
+                false,
+                name.clone(),
+                Type::Circuit(name.clone()),
+            ));
+            members.push((name, value));
+        }
+
+        Ok(Value::Circuit(CircuitValue {
+            program: String::new(),
+            circuit: Circuit {
+                circuit_name: identifier,
+                const_parameters: vec![],
+                members: definitions,
+            },
+            members,
+        }))
+    }
+
+    /// Returns an input section as a circuit value with a member for every defined parameter.
+    /// Parameters without a value are left out of the section.
+    fn input_section(
+        &self,
+        program: &str,
+        identifier: Identifier,
+        section: HashMap<Parameter, Option<InputValue>>,
+    ) -> Result<Value, InterpreterError> {
+        let mut definitions = vec![];
+        let mut members = vec![];
+
+        for (parameter, option) in section.into_iter() {
+            if let Some(input_value) = option {
+                let value = self.input_value(
+                    program,
+                    &parameter.type_,
+                    parameter.variable.name.clone(),
+                    Some(input_value),
+                    parameter.span.clone(),
+                )?;
+
+                definitions.push(CircuitMember::CircuitVariable(
+                    false,
+                    parameter.variable.clone(),
+                    parameter.type_,
+                ));
+                members.push((parameter.variable, value));
+            }
+        }
+
+        Ok(Value::Circuit(CircuitValue {
+            program: String::new(),
+            circuit: Circuit {
+                circuit_name: identifier,
+                const_parameters: vec![],
+                members: definitions,
+            },
+            members,
+        }))
+    }
+
+    /// Returns the value of a main function input of the given type.
+    pub(crate) fn input_value(
+        &self,
+        program: &str,
+        type_: &Type,
+        name: String,
+        option: Option<InputValue>,
+        span: Span,
+    ) -> Result<Value, InterpreterError> {
+        let input_value = option.ok_or_else(|| InterpreterError::missing_input(name.clone(), span.clone()))?;
+
+        Ok(match (type_, input_value) {
+            (Type::Address, InputValue::Address(address)) => Value::address(address, span)?,
+            (Type::Boolean, InputValue::Boolean(boolean)) => Value::Boolean(boolean),
+            (Type::Field, InputValue::Field(field)) => Value::field(field, span)?,
+            (Type::Group, InputValue::Group(group)) => Value::group(group)?,
+            (Type::IntegerType(integer_type), InputValue::Integer(_type, integer)) => {
+                Value::Integer(IntegerValue::new(integer_type, integer, span)?)
+            }
+            (Type::Array(element_type, dimensions), InputValue::Array(values)) => {
+                // Like the compiler, take the array length from the input value
+                let element_type = element_type.outer_dimension(dimensions);
+                let mut array = vec![];
+
+                for (i, value) in values.into_iter().enumerate() {
+                    array.push(self.input_value(
+                        program,
+                        &element_type,
+                        format!("{}_{}", name, i),
+                        Some(value),
+                        span.clone(),
+                    )?);
+                }
+
+                Value::Array(array)
+            }
+            (Type::Tuple(types), InputValue::Tuple(values)) => {
+                if types.len() != values.len() {
+                    return Err(FunctionError::invalid_tuple(InputValue::Tuple(values).to_string(), span).into());
+                }
+
+                let mut tuple = vec![];
+
+                for (i, (type_, value)) in types.iter().zip(values.into_iter()).enumerate() {
+                    tuple.push(self.input_value(
+                        program,
+                        type_,
+                        format!("{}_{}", name, i),
+                        Some(value),
+                        span.clone(),
+                    )?);
+                }
+
+                Value::Tuple(tuple)
+            }
+            (Type::Circuit(identifier), InputValue::Circuit(circuit_name, members))
+                if identifier.name == circuit_name =>
+            {
+                self.input_circuit(program, identifier, name, members, span)?
+            }
+            (Type::Address, value) => return Err(AddressError::invalid_address(value.to_string(), span).into()),
+            (Type::Boolean, value) => return Err(BooleanError::invalid_boolean(value.to_string(), span).into()),
+            (Type::Field, value) => return Err(FieldError::invalid_field(value.to_string(), span).into()),
+            (Type::Group, value) => return Err(GroupError::invalid_group(value.to_string(), span).into()),
+            (Type::IntegerType(_type), value) => {
+                return Err(IntegerError::invalid_integer(value.to_string(), span).into());
+            }
+            (Type::Tuple(_types), value) => return Err(FunctionError::invalid_tuple(value.to_string(), span).into()),
+            (Type::Circuit(identifier), value) => {
+                return Err(FunctionError::invalid_circuit(identifier.to_string(), value.to_string(), span).into());
+            }
+            (_type, value) => return Err(FunctionError::invalid_array(value.to_string(), span).into()),
+        })
+    }
+
+    /// Returns a circuit literal of the input as a value of the circuit with the given name.
+    fn input_circuit(
+        &self,
+        program: &str,
+        identifier: &Identifier,
+        name: String,
+        mut members: Vec<(String, InputValue)>,
+        span: Span,
+    ) -> Result<Value, InterpreterError> {
+        let (circuit_program, circuit) = match self.get_definition(program, &identifier.name) {
+            Some(Definition::Circuit(program, circuit)) => (program.clone(), circuit.clone()),
+            _ => return Err(ExpressionError::undefined_circuit(identifier.to_string(), span).into()),
+        };
+
+        // The member types of a generic circuit depend on const parameters that are never given
+        if let Some(parameter) = circuit.const_parameters.first() {
+            return Err(FunctionError::undefined_const_parameter(parameter.identifier.to_string(), span).into());
+        }
+
+        let mut values = vec![];
+
+        for member in circuit.members.iter() {
+            if let CircuitMember::CircuitVariable(_mutable, member_identifier, type_) = member {
+                let index = members
+                    .iter()
+                    .position(|(member_name, _)| member_name == &member_identifier.name)
+                    .ok_or_else(|| {
+                        ExpressionError::expected_circuit_member(member_identifier.to_string(), span.clone())
+                    })?;
+                let (_member_name, input_value) = members.remove(index);
+
+                let value = self.input_value(
+                    program,
+                    type_,
+                    format!("{}_{}", name, member_identifier.name),
+                    Some(input_value),
+                    span.clone(),
+                )?;
+
+                values.push((member_identifier.clone(), value));
+            }
+        }
+
+        // Return an error for members of the circuit literal that the circuit does not have
+        if let Some((member_name, _)) = members.into_iter().next() {
+            return Err(ExpressionError::undefined_member_access(identifier.to_string(), member_name, span).into());
+        }
+
+        Ok(Value::Circuit(CircuitValue {
+            program: circuit_program,
+            circuit,
+            members: values,
+        }))
+    }
+}
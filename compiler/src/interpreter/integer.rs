@@ -0,0 +1,196 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A fixed-width integer value in an interpreted Leo program.
+
+use crate::{
+    errors::{IntegerError, InterpreterError},
+    optimizer::{signed_range, unsigned_max},
+};
+use leo_typed::{IntegerType, Span};
+
+use std::{cmp::Ordering, convert::TryFrom, fmt};
+
+/// An integer value of a fixed width.
+/// Every operation checks that its result fits into the width of the integer type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntegerValue {
+    Unsigned(IntegerType, u128),
+    Signed(IntegerType, i128),
+}
+
+impl IntegerValue {
+    pub fn new(type_: &IntegerType, string: String, span: Span) -> Result<Self, IntegerError> {
+        match unsigned_max(type_) {
+            Some(max) => match string.parse::<u128>() {
+                Ok(value) if value <= max => Ok(IntegerValue::Unsigned(type_.clone(), value)),
+                _ => Err(IntegerError::invalid_integer(string, span)),
+            },
+            None => {
+                let (min, max) = signed_range(type_);
+
+                match string.parse::<i128>() {
+                    Ok(value) if value >= min && value <= max => Ok(IntegerValue::Signed(type_.clone(), value)),
+                    _ => Err(IntegerError::invalid_integer(string, span)),
+                }
+            }
+        }
+    }
+
+    pub fn get_type(&self) -> IntegerType {
+        match self {
+            IntegerValue::Unsigned(type_, _) | IntegerValue::Signed(type_, _) => type_.clone(),
+        }
+    }
+
+    pub fn to_usize(&self) -> Option<usize> {
+        match self {
+            IntegerValue::Unsigned(_, value) => usize::try_from(*value).ok(),
+            IntegerValue::Signed(_, value) => usize::try_from(*value).ok(),
+        }
+    }
+
+    pub fn add(&self, other: &Self, span: Span) -> Result<Self, InterpreterError> {
+        self.binary(other, "+", span, u128::checked_add, i128::checked_add)
+    }
+
+    pub fn sub(&self, other: &Self, span: Span) -> Result<Self, InterpreterError> {
+        self.binary(other, "-", span, u128::checked_sub, i128::checked_sub)
+    }
+
+    pub fn mul(&self, other: &Self, span: Span) -> Result<Self, InterpreterError> {
+        self.binary(other, "*", span, u128::checked_mul, i128::checked_mul)
+    }
+
+    pub fn div(&self, other: &Self, span: Span) -> Result<Self, InterpreterError> {
+        if other.is_zero() {
+            return Err(InterpreterError::division_by_zero(
+                format!("{} / {}", self, other),
+                span,
+            ));
+        }
+
+        self.binary(other, "/", span, u128::checked_div, i128::checked_div)
+    }
+
+    pub fn pow(&self, other: &Self, span: Span) -> Result<Self, InterpreterError> {
+        if let IntegerValue::Signed(_, exponent) = other {
+            if *exponent < 0 {
+                return Err(InterpreterError::invalid_exponent(exponent.to_string(), span));
+            }
+        }
+
+        self.binary(
+            other,
+            "**",
+            span,
+            |base, exponent| base.checked_pow(u32::try_from(exponent).ok()?),
+            |base, exponent| base.checked_pow(u32::try_from(exponent).ok()?),
+        )
+    }
+
+    pub fn negate(&self, span: Span) -> Result<Self, InterpreterError> {
+        match self {
+            IntegerValue::Unsigned(_, _) => Err(IntegerError::negate_operation(span).into()),
+            IntegerValue::Signed(type_, value) => {
+                let result = value.checked_neg();
+
+                Self::signed_result(type_, result, format!("-{}", value), span)
+            }
+        }
+    }
+
+    /// Returns the ordering of two integers of the same type.
+    pub fn compare(&self, other: &Self, operation: &str, span: Span) -> Result<Ordering, InterpreterError> {
+        match (self, other) {
+            (IntegerValue::Unsigned(left_type, left), IntegerValue::Unsigned(right_type, right))
+                if left_type == right_type =>
+            {
+                Ok(left.cmp(right))
+            }
+            (IntegerValue::Signed(left_type, left), IntegerValue::Signed(right_type, right))
+                if left_type == right_type =>
+            {
+                Ok(left.cmp(right))
+            }
+            _ => Err(IntegerError::binary_operation(format!("{} {} {}", self, operation, other), span).into()),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        match self {
+            IntegerValue::Unsigned(_, value) => *value == 0,
+            IntegerValue::Signed(_, value) => *value == 0,
+        }
+    }
+
+    fn binary(
+        &self,
+        other: &Self,
+        operation: &str,
+        span: Span,
+        unsigned: fn(u128, u128) -> Option<u128>,
+        signed: fn(i128, i128) -> Option<i128>,
+    ) -> Result<Self, InterpreterError> {
+        let operation = format!("{} {} {}", self, operation, other);
+
+        match (self, other) {
+            (IntegerValue::Unsigned(left_type, left), IntegerValue::Unsigned(right_type, right))
+                if left_type == right_type =>
+            {
+                let max = unsigned_max(left_type).unwrap_or(u128::MAX);
+
+                match unsigned(*left, *right) {
+                    Some(result) if result <= max => Ok(IntegerValue::Unsigned(left_type.clone(), result)),
+                    _ => Err(InterpreterError::integer_overflow(
+                        operation,
+                        left_type.to_string(),
+                        span,
+                    )),
+                }
+            }
+            (IntegerValue::Signed(left_type, left), IntegerValue::Signed(right_type, right))
+                if left_type == right_type =>
+            {
+                Self::signed_result(left_type, signed(*left, *right), operation, span)
+            }
+            _ => Err(IntegerError::binary_operation(operation, span).into()),
+        }
+    }
+
+    fn signed_result(
+        type_: &IntegerType,
+        result: Option<i128>,
+        operation: String,
+        span: Span,
+    ) -> Result<Self, InterpreterError> {
+        let (min, max) = signed_range(type_);
+
+        match result {
+            Some(result) if result >= min && result <= max => Ok(IntegerValue::Signed(type_.clone(), result)),
+            _ => Err(InterpreterError::integer_overflow(operation, type_.to_string(), span)),
+        }
+    }
+}
+
+impl fmt::Display for IntegerValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IntegerValue::Unsigned(_, value) => write!(f, "{}", value),
+            IntegerValue::Signed(_, value) => write!(f, "{}", value),
+        }
+    }
+}
@@ -0,0 +1,424 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Interprets a typed Leo program with plain Rust values.
+
+use crate::{
+    definition::monomorphize_function,
+    errors::{FunctionError, ImportError, InterpreterError, StatementError},
+    function::check_arguments_length,
+    imported_symbols::ImportedSymbols,
+    interpreter::{CircuitValue, Value},
+    ImportParser,
+    OutputBytes,
+};
+use leo_core::{CorePackageList, LeoCoreError};
+use leo_typed::{Circuit, Enum, Expression, Function, Identifier, Input, InputVariable, Program, Span, Type};
+
+use std::collections::HashMap;
+
+/// A circuit, enum or function definition together with the program it was defined in.
+#[derive(Clone, Debug)]
+pub enum Definition {
+    Circuit(String, Circuit),
+    Enum(Enum),
+    Function(String, Function),
+}
+
+/// A variable in the scope of a function call.
+#[derive(Clone, Debug)]
+pub struct Variable {
+    pub value: Value,
+    pub mutable: bool,
+}
+
+/// The variables shadowed by the bindings of a match pattern.
+/// A binding that did not shadow a variable is paired with `None`.
+pub type ShadowedVariables = Vec<(String, Option<Variable>)>;
+
+/// The state of a single function call.
+/// Like in the compiler, loops and branches share the scope of the function.
+#[derive(Clone, Debug)]
+pub struct Frame {
+    pub program: String,
+    pub circuit: Option<(String, Circuit)>,
+    pub variables: HashMap<String, Variable>,
+}
+
+impl Frame {
+    pub fn new(program: String, circuit: Option<(String, Circuit)>) -> Self {
+        Self {
+            program,
+            circuit,
+            variables: HashMap::new(),
+        }
+    }
+
+    pub fn store(&mut self, name: String, mutable: bool, value: Value) {
+        self.variables.insert(name, Variable { value, mutable });
+    }
+
+    /// Removes the bindings of a match arm and restores the variables they shadowed.
+    pub fn restore(&mut self, shadowed: ShadowedVariables) {
+        for (name, previous) in shadowed.into_iter().rev() {
+            match previous {
+                Some(variable) => {
+                    self.variables.insert(name, variable);
+                }
+                None => {
+                    self.variables.remove(&name);
+                }
+            }
+        }
+    }
+}
+
+/// A reference interpreter for typed Leo programs.
+/// Evaluates a program natively without generating constraints, so that its output can be
+/// compared against the output of the compiled circuit.
+#[derive(Clone, Debug)]
+pub struct Interpreter {
+    definitions: HashMap<String, HashMap<String, Definition>>,
+}
+
+impl Interpreter {
+    pub fn new(program: &Program, imported_programs: &ImportParser) -> Result<Self, InterpreterError> {
+        let mut interpreter = Self {
+            definitions: HashMap::new(),
+        };
+
+        interpreter.store_definitions(program.get_name(), program, imported_programs)?;
+
+        Ok(interpreter)
+    }
+
+    fn store_definitions(
+        &mut self,
+        program_name: String,
+        program: &Program,
+        imported_programs: &ImportParser,
+    ) -> Result<(), InterpreterError> {
+        let mut definitions = HashMap::new();
+
+        for import in program.imports.iter() {
+            // Core packages define circuits that call native functions
+            if imported_programs
+                .core_packages()
+                .iter()
+                .any(|package| import.package.eq(package))
+            {
+                let list =
+                    CorePackageList::from_package_access(import.package.access.clone()).map_err(LeoCoreError::from)?;
+
+                for (symbol, circuit) in list.to_symbols().map_err(LeoCoreError::from)?.symbols() {
+                    definitions.insert(symbol, Definition::Circuit(program_name.clone(), circuit));
+                }
+
+                continue;
+            }
+
+            for (package, symbol) in ImportedSymbols::from(import).symbols {
+                let imported_program = imported_programs
+                    .get_import(&package)
+                    .ok_or_else(|| ImportError::unknown_package(import.package.name.clone()))?;
+
+                if !self.definitions.contains_key(&package) {
+                    self.store_definitions(package.clone(), imported_program, imported_programs)?;
+                }
+
+                let imported_definitions = program_definitions(&package, imported_program);
+
+                if symbol.is_star() {
+                    definitions.extend(imported_definitions);
+                } else {
+                    let definition = imported_definitions
+                        .get(&symbol.symbol.name)
+                        .cloned()
+                        .ok_or_else(|| ImportError::unknown_symbol(symbol.clone(), package.clone()))?;
+                    let name = symbol.alias.as_ref().unwrap_or(&symbol.symbol).name.clone();
+
+                    definitions.insert(name, definition);
+                }
+            }
+        }
+
+        definitions.extend(program_definitions(&program_name, program));
+
+        self.definitions.insert(program_name, definitions);
+
+        Ok(())
+    }
+
+    /// Returns the definition with the given name that is visible in the given program.
+    pub fn get_definition(&self, program: &str, name: &str) -> Option<&Definition> {
+        self.definitions.get(program)?.get(name)
+    }
+
+    /// Runs the main function of a program on the given input.
+    pub fn run_main(&self, program: &Program, input: &Input) -> Result<Option<OutputBytes>, InterpreterError> {
+        let program_name = program.get_name();
+        let function = match self.get_definition(&program_name, "main") {
+            Some(Definition::Function(_program, function)) => function.clone(),
+            _ => return Ok(None),
+        };
+
+        self.run_function(program_name, function, input).map(Some)
+    }
+
+    /// Runs a function that takes its arguments from the program input, like `main` or a test function.
+    pub fn run_function(
+        &self,
+        program: String,
+        function: Function,
+        input: &Input,
+    ) -> Result<OutputBytes, InterpreterError> {
+        // Main function input types must be known before the input values are read
+        if function.is_generic() {
+            return Err(FunctionError::generic_main(function.span.clone()).into());
+        }
+
+        let mut arguments = vec![];
+
+        for input_model in function.input.iter() {
+            let value = match input_model {
+                InputVariable::InputKeyword(identifier) => self.input_keyword(&program, identifier.clone(), input)?,
+                InputVariable::FunctionInput(input_model) => {
+                    let name = input_model.identifier.name.clone();
+                    let option = input
+                        .get(&name)
+                        .ok_or_else(|| FunctionError::input_not_found(name.clone(), function.span.clone()))?;
+
+                    self.input_value(&program, &input_model.type_, name, option, input_model.span.clone())?
+                }
+            };
+
+            arguments.push(value);
+        }
+
+        let span = function.span.clone();
+        let (value, _self) = self.call_function(program, None, None, function, arguments, HashMap::new())?;
+
+        Ok(OutputBytes::new_from_value(input.get_registers(), value, span)?)
+    }
+
+    /// Evaluates the arguments of a function call in the scope of the caller.
+    /// Returns the argument values and the values of the const parameters inferred from them.
+    pub(crate) fn evaluate_arguments(
+        &self,
+        frame: &mut Frame,
+        function: &Function,
+        arguments: &[Expression],
+    ) -> Result<(Vec<Value>, HashMap<String, usize>), InterpreterError> {
+        check_arguments_length(function.input.len(), arguments.len(), function.span.clone())?;
+
+        let mut values = vec![];
+        let mut constants = HashMap::new();
+
+        for (input_model, argument) in function.input.iter().zip(arguments.iter()) {
+            let value = match input_model {
+                InputVariable::InputKeyword(_identifier) => self.evaluate_expression(frame, None, argument)?,
+                InputVariable::FunctionInput(input_model) => {
+                    // Resolve any const parameters that are already known
+                    let input_type = input_model.type_.resolve_const_parameters(&constants);
+                    let expected_type = if input_type.is_generic() {
+                        None
+                    } else {
+                        Some(&input_type)
+                    };
+
+                    let value = self.evaluate_operand(frame, expected_type, argument, input_model.span.clone())?;
+                    let value_type = value.to_type(input_model.span.clone())?;
+
+                    if input_type.is_generic() {
+                        if !input_type.infer_const_parameters(&value_type, &mut constants) {
+                            return Err(FunctionError::const_argument_type(
+                                input_type.to_string(),
+                                value_type.to_string(),
+                                input_model.span.clone(),
+                            )
+                            .into());
+                        }
+                    } else if !self.types_match(&frame.program, &input_type, &value_type) {
+                        return Err(InterpreterError::definition_type(
+                            input_model.identifier.to_string(),
+                            &input_type,
+                            &value_type,
+                            input_model.span.clone(),
+                        ));
+                    }
+
+                    value
+                }
+            };
+
+            values.push(value);
+        }
+
+        Ok((values, constants))
+    }
+
+    /// Calls a function with evaluated arguments.
+    /// Returns the result of the function and the value of `self` after a circuit function call.
+    pub(crate) fn call_function(
+        &self,
+        program: String,
+        circuit: Option<(String, Circuit)>,
+        self_value: Option<CircuitValue>,
+        function: Function,
+        arguments: Vec<Value>,
+        constants: HashMap<String, usize>,
+    ) -> Result<(Value, Option<CircuitValue>), InterpreterError> {
+        // Monomorphize the function with the inferred const parameters
+        let function = if function.is_generic() {
+            for parameter in function.const_parameters.iter() {
+                if !constants.contains_key(&parameter.identifier.name) {
+                    return Err(FunctionError::undefined_const_parameter(
+                        parameter.identifier.to_string(),
+                        parameter.span.clone(),
+                    )
+                    .into());
+                }
+            }
+
+            monomorphize_function(function, &constants)
+        } else {
+            function
+        };
+
+        let mut frame = Frame::new(program, circuit);
+
+        if let Some(self_value) = self_value {
+            frame.store(SELF_KEYWORD.to_string(), true, Value::Circuit(self_value));
+        }
+
+        for (input_model, value) in function.input.iter().zip(arguments.into_iter()) {
+            match input_model {
+                InputVariable::InputKeyword(identifier) => frame.store(identifier.name.clone(), false, value),
+                InputVariable::FunctionInput(input_model) => {
+                    frame.store(input_model.identifier.name.clone(), input_model.mutable, value)
+                }
+            }
+        }
+
+        // The first executed return statement returns from the function
+        let result = self
+            .execute_statements(&mut frame, &function.statements, function.returns.as_ref())?
+            .unwrap_or_else(|| Value::Tuple(vec![]));
+
+        if let Value::Tuple(ref returns) = result {
+            let return_types = match function.returns {
+                Some(Type::Tuple(ref types)) => types.len(),
+                Some(_) => 1usize,
+                None => 0usize,
+            };
+
+            if return_types != returns.len() {
+                return Err(FunctionError::return_arguments_length(return_types, returns.len(), function.span).into());
+            }
+        }
+
+        let self_value = match frame.variables.remove(SELF_KEYWORD) {
+            Some(Variable {
+                value: Value::Circuit(circuit),
+                ..
+            }) => Some(circuit),
+            _ => None,
+        };
+
+        Ok((result, self_value))
+    }
+
+    /// Returns true if a value of the actual type can be stored as the expected type.
+    pub(crate) fn types_match(&self, program: &str, expected: &Type, actual: &Type) -> bool {
+        if expected.matches(actual) {
+            return true;
+        }
+
+        match (expected, actual) {
+            (Type::SelfType, Type::Circuit(_)) => true,
+            (Type::Circuit(expected), Type::Circuit(actual)) => match self.get_definition(program, &expected.name) {
+                // Imported circuits may be renamed with an alias
+                Some(Definition::Circuit(_program, circuit)) => circuit.circuit_name.eq(actual),
+                _ => false,
+            },
+            (Type::Circuit(expected), Type::Enum(actual)) => match self.get_definition(program, &expected.name) {
+                // Imported enums may be renamed with an alias
+                Some(Definition::Enum(enum_)) => enum_.enum_name.eq(actual),
+                _ => false,
+            },
+            (Type::Tuple(expected), Type::Tuple(actual)) => {
+                expected.len() == actual.len()
+                    && expected
+                        .iter()
+                        .zip(actual.iter())
+                        .all(|(expected, actual)| self.types_match(program, expected, actual))
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks the type of a returned value against the return type of the function.
+    pub(crate) fn check_return_type(
+        &self,
+        program: &str,
+        expected: Option<&Type>,
+        actual: Type,
+        span: Span,
+    ) -> Result<(), InterpreterError> {
+        match expected {
+            Some(expected) if !self.types_match(program, expected, &actual) => {
+                Err(StatementError::arguments_type(expected, &actual, span).into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the identifier of the circuit defined by `Self` or by a circuit name.
+    pub(crate) fn get_circuit(&self, frame: &Frame, identifier: &Identifier) -> Option<(String, Circuit)> {
+        if identifier.is_self() {
+            return frame.circuit.clone();
+        }
+
+        match self.get_definition(&frame.program, &identifier.name) {
+            Some(Definition::Circuit(program, circuit)) => Some((program.clone(), circuit.clone())),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) const SELF_KEYWORD: &str = "self";
+
+/// Returns the circuits, enums and functions defined in a program.
+fn program_definitions(program_name: &str, program: &Program) -> HashMap<String, Definition> {
+    let circuits = program.circuits.iter().map(|(identifier, circuit)| {
+        (
+            identifier.name.clone(),
+            Definition::Circuit(program_name.to_owned(), circuit.clone()),
+        )
+    });
+    let enums = program
+        .enums
+        .iter()
+        .map(|(identifier, enum_)| (identifier.name.clone(), Definition::Enum(enum_.clone())));
+    let functions = program.functions.iter().map(|(identifier, function)| {
+        (
+            identifier.name.clone(),
+            Definition::Function(program_name.to_owned(), function.clone()),
+        )
+    });
+
+    circuits.chain(enums).chain(functions).collect()
+}
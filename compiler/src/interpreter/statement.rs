@@ -0,0 +1,455 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Executes statements in an interpreted Leo program.
+
+use crate::{
+    errors::{ConsoleError, InterpreterError, StatementError},
+    interpreter::{Frame, Interpreter, IntegerValue, Value},
+};
+use leo_typed::{
+    Assignee,
+    ConditionalNestedOrEndStatement,
+    ConditionalStatement,
+    ConsoleFunction,
+    Declare,
+    Expression,
+    FormattedString,
+    Identifier,
+    IntegerType,
+    RangeOrExpression,
+    Span,
+    Statement,
+    Type,
+    Variables,
+};
+
+/// A resolved step in the path of an assignee.
+enum Access {
+    Index(usize),
+    Range(usize, Option<usize>),
+    Tuple(usize),
+    Member(Identifier),
+}
+
+impl Interpreter {
+    /// Executes statements in order.
+    /// Returns the value of the first executed return statement.
+    pub(crate) fn execute_statements(
+        &self,
+        frame: &mut Frame,
+        statements: &[Statement],
+        return_type: Option<&Type>,
+    ) -> Result<Option<Value>, InterpreterError> {
+        for statement in statements.iter() {
+            if let Some(value) = self.execute_statement(frame, statement, return_type)? {
+                return Ok(Some(value));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn execute_statement(
+        &self,
+        frame: &mut Frame,
+        statement: &Statement,
+        return_type: Option<&Type>,
+    ) -> Result<Option<Value>, InterpreterError> {
+        match statement {
+            Statement::Return(expression, span) => {
+                let value = self.evaluate_operand(frame, return_type, expression, span.clone())?;
+
+                self.check_return_type(&frame.program, return_type, value.to_type(span.clone())?, span.clone())?;
+
+                return Ok(Some(value));
+            }
+            Statement::Definition(declare, variables, expressions, span) => {
+                self.execute_definition(frame, declare, variables, expressions, span)?;
+            }
+            Statement::Assign(assignee, expression, span) => {
+                self.execute_assign(frame, assignee, expression, span)?;
+            }
+            Statement::Conditional(statement, span) => {
+                return self.execute_conditional(frame, statement, return_type, span);
+            }
+            Statement::Iteration(index, start, stop, statements, span) => {
+                let from = self.evaluate_index(frame, start, span)?;
+                let to = self.evaluate_index(frame, stop, span)?;
+
+                for i in from..to {
+                    // Store index in current function scope.
+                    // For loop scope is not implemented.
+                    let index_value = Value::Integer(IntegerValue::Unsigned(IntegerType::U32, i as u128));
+
+                    frame.store(index.name.clone(), false, index_value);
+
+                    if let Some(value) = self.execute_statements(frame, statements, return_type)? {
+                        return Ok(Some(value));
+                    }
+                }
+            }
+            Statement::Match(expression, arms, span) => {
+                let patterns = arms.iter().map(|arm| arm.pattern.clone()).collect::<Vec<_>>();
+                let value = self.evaluate_match_value(frame, expression, &patterns, span)?;
+
+                for arm in arms.iter() {
+                    if let Some(shadowed) = self.pattern_matches(frame, &value, &arm.pattern) {
+                        let result = self.execute_statements(frame, &arm.statements, return_type);
+
+                        frame.restore(shadowed);
+
+                        return result;
+                    }
+                }
+            }
+            Statement::Console(console) => match &console.function {
+                ConsoleFunction::Assert(expression) => {
+                    let expression_string = expression.to_string();
+
+                    match self.evaluate_operand(frame, Some(&Type::Boolean), expression, console.span.clone())? {
+                        Value::Boolean(true) => {}
+                        Value::Boolean(false) => {
+                            return Err(ConsoleError::assertion_failed(expression_string, console.span.clone()).into());
+                        }
+                        _ => {
+                            return Err(ConsoleError::assertion_must_be_boolean(
+                                expression_string,
+                                console.span.clone(),
+                            )
+                            .into());
+                        }
+                    }
+                }
+                ConsoleFunction::Debug(string) => tracing::debug!("{}", self.format(frame, string)?),
+                ConsoleFunction::Error(string) => tracing::error!("{}", self.format(frame, string)?),
+                ConsoleFunction::Log(string) => tracing::info!("{}", self.format(frame, string)?),
+            },
+            Statement::Expression(expression, span) => {
+                let value = self.evaluate_expression(frame, None, expression)?;
+
+                // Only expressions without a result may be used as statements
+                match value {
+                    Value::Tuple(ref values) if values.is_empty() => {}
+                    _ => return Err(StatementError::unassigned(expression.to_string(), span.clone()).into()),
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn execute_definition(
+        &self,
+        frame: &mut Frame,
+        declare: &Declare,
+        variables: &Variables,
+        expressions: &[Expression],
+        span: &Span,
+    ) -> Result<(), InterpreterError> {
+        let type_ = variables.type_.as_ref();
+
+        let values = if variables.names.len() == 1 && expressions.len() == 1 {
+            // Define a single variable with a single value
+            vec![self.evaluate_operand(frame, type_, &expressions[0], span.clone())?]
+        } else if variables.names.len() == 1 {
+            // Define a tuple (single variable with multiple values)
+            vec![Value::Tuple(self.evaluate_expressions(
+                frame,
+                type_,
+                expressions,
+                span,
+            )?)]
+        } else if expressions.len() == 1 {
+            // Define multiple variables for an expression that returns multiple results (multiple definition)
+            match self.evaluate_operand(frame, type_, &expressions[0], span.clone())? {
+                Value::Tuple(values) => values,
+                value => return Err(StatementError::multiple_definition(value.to_string(), span.clone()).into()),
+            }
+        } else {
+            // Define multiple variables for multiple expressions
+            self.evaluate_expressions(frame, type_, expressions, span)?
+        };
+
+        if values.len() != variables.names.len() {
+            return Err(StatementError::invalid_number_of_definitions(
+                values.len(),
+                variables.names.len(),
+                span.clone(),
+            )
+            .into());
+        }
+
+        let types = match (type_, variables.names.len()) {
+            (Some(type_), 1) => vec![Some(type_)],
+            (Some(Type::Tuple(types)), _) => types.iter().map(Some).collect(),
+            _ => vec![None; values.len()],
+        };
+
+        for ((variable, value), type_) in variables.names.iter().zip(values.into_iter()).zip(types.into_iter()) {
+            if *declare == Declare::Const && variable.mutable {
+                return Err(StatementError::immutable_assign(variable.to_string(), span.clone()).into());
+            }
+
+            // The types of all definitions must be known
+            let value_type = value.to_type(span.clone())?;
+
+            if let Some(type_) = type_ {
+                if !self.types_match(&frame.program, type_, &value_type) {
+                    return Err(InterpreterError::definition_type(
+                        variable.identifier.to_string(),
+                        type_,
+                        &value_type,
+                        span.clone(),
+                    ));
+                }
+            }
+
+            frame.store(variable.identifier.name.clone(), variable.mutable, value);
+        }
+
+        Ok(())
+    }
+
+    fn evaluate_expressions(
+        &self,
+        frame: &mut Frame,
+        type_: Option<&Type>,
+        expressions: &[Expression],
+        span: &Span,
+    ) -> Result<Vec<Value>, InterpreterError> {
+        let types = match type_ {
+            Some(Type::Tuple(types)) => Some(types),
+            Some(type_) => return Err(StatementError::tuple_type(type_.to_string(), span.clone()).into()),
+            None => None,
+        };
+
+        let mut values = vec![];
+
+        for (i, expression) in expressions.iter().enumerate() {
+            let expected_type = types.and_then(|types| types.get(i));
+
+            values.push(self.evaluate_operand(frame, expected_type, expression, span.clone())?);
+        }
+
+        Ok(values)
+    }
+
+    fn execute_assign(
+        &self,
+        frame: &mut Frame,
+        assignee: &Assignee,
+        expression: &Expression,
+        span: &Span,
+    ) -> Result<(), InterpreterError> {
+        // Resolve the variable and the path to the assigned value before evaluating the new value
+        let mut path = vec![];
+        let identifier = self.resolve_assignee(frame, assignee, &mut path, span)?;
+
+        let variable = frame
+            .variables
+            .get(&identifier.name)
+            .ok_or_else(|| StatementError::undefined_variable(identifier.to_string(), span.clone()))?;
+
+        if !variable.mutable {
+            return Err(StatementError::immutable_assign(identifier.to_string(), span.clone()).into());
+        }
+
+        // A slice may only be the last access of an assignee
+        let range = match path.last() {
+            Some(Access::Range(from, to)) => {
+                let range = (*from, *to);
+                path.pop();
+                Some(range)
+            }
+            _ => None,
+        };
+
+        let mut old_value = variable.value.clone();
+        let old_value = access_value(&mut old_value, &path, span)?;
+        let old_type = match (range, old_value) {
+            (Some((from, to)), Value::Array(array)) => {
+                let to = to.unwrap_or_else(|| array.len());
+
+                if from > to || to > array.len() {
+                    return Err(InterpreterError::slice_out_of_bounds(
+                        from,
+                        to,
+                        array.len(),
+                        span.clone(),
+                    ));
+                }
+
+                Value::Array(array[from..to].to_vec()).to_type(span.clone())?
+            }
+            (Some(_), _) => return Err(StatementError::array_assign_range(span.clone()).into()),
+            (None, value) => value.to_type(span.clone())?,
+        };
+
+        let new_value = self.evaluate_operand(frame, Some(&old_type), expression, span.clone())?;
+        let new_type = new_value.to_type(span.clone())?;
+
+        if !self.types_match(&frame.program, &old_type, &new_type) {
+            return Err(InterpreterError::assign_type(
+                assignee.to_string(),
+                &old_type,
+                &new_type,
+                span.clone(),
+            ));
+        }
+
+        let variable = frame.variables.get_mut(&identifier.name).unwrap();
+        let old_value = access_value(&mut variable.value, &path, span)?;
+
+        match (range, old_value, new_value) {
+            (Some((from, to)), Value::Array(array), Value::Array(new_array)) => {
+                let to = to.unwrap_or_else(|| array.len());
+
+                array.splice(from..to, new_array);
+            }
+            (_, old_value, new_value) => *old_value = new_value,
+        }
+
+        Ok(())
+    }
+
+    /// Returns the variable of an assignee and pushes the accesses on the path to the assigned value.
+    fn resolve_assignee(
+        &self,
+        frame: &mut Frame,
+        assignee: &Assignee,
+        path: &mut Vec<Access>,
+        span: &Span,
+    ) -> Result<Identifier, InterpreterError> {
+        let (inner, access) = match assignee {
+            Assignee::Identifier(identifier) => return Ok(identifier.clone()),
+            Assignee::Array(array, RangeOrExpression::Expression(index)) => {
+                (array, Access::Index(self.evaluate_index(frame, index, span)?))
+            }
+            Assignee::Array(array, RangeOrExpression::Range(from, to)) => {
+                let from = match from {
+                    Some(from) => self.evaluate_index(frame, from, span)?,
+                    None => 0usize,
+                };
+                let to = match to {
+                    Some(to) => Some(self.evaluate_index(frame, to, span)?),
+                    None => None,
+                };
+
+                (array, Access::Range(from, to))
+            }
+            Assignee::Tuple(tuple, index) => (tuple, Access::Tuple(*index)),
+            Assignee::CircuitField(circuit, member) => (circuit, Access::Member(member.clone())),
+        };
+
+        let identifier = self.resolve_assignee(frame, inner, path, span)?;
+
+        path.push(access);
+
+        Ok(identifier)
+    }
+
+    fn execute_conditional(
+        &self,
+        frame: &mut Frame,
+        statement: &ConditionalStatement,
+        return_type: Option<&Type>,
+        span: &Span,
+    ) -> Result<Option<Value>, InterpreterError> {
+        let condition = match self.evaluate_operand(frame, Some(&Type::Boolean), &statement.condition, span.clone())? {
+            Value::Boolean(condition) => condition,
+            value => return Err(StatementError::conditional_boolean(value.to_string(), span.clone()).into()),
+        };
+
+        // Only the taken branch is executed
+        if condition {
+            self.execute_statements(frame, &statement.statements, return_type)
+        } else {
+            match &statement.next {
+                Some(ConditionalNestedOrEndStatement::Nested(nested)) => {
+                    self.execute_conditional(frame, nested, return_type, span)
+                }
+                Some(ConditionalNestedOrEndStatement::End(statements)) => {
+                    self.execute_statements(frame, statements, return_type)
+                }
+                None => Ok(None),
+            }
+        }
+    }
+
+    /// Formats a console string with the values of its parameters.
+    fn format(&self, frame: &mut Frame, formatted: &FormattedString) -> Result<String, InterpreterError> {
+        // Check that containers and parameters match
+        if formatted.containers.len() != formatted.parameters.len() {
+            return Err(ConsoleError::length(
+                formatted.containers.len(),
+                formatted.parameters.len(),
+                formatted.span.clone(),
+            )
+            .into());
+        }
+
+        // Trim the double quotes `"` around the string
+        let string = formatted.string.trim_start_matches('"');
+        let mut result = string.split('"').next().unwrap_or_default().to_string();
+
+        // Insert the parameter for each container `{}`
+        for parameter in formatted.parameters.iter() {
+            let value = self.evaluate_expression(frame, None, &parameter.expression)?;
+
+            result = result.replacen("{}", &value.to_string(), 1);
+        }
+
+        Ok(result)
+    }
+}
+
+/// Returns the value at the end of an assignee path.
+fn access_value<'a>(value: &'a mut Value, path: &[Access], span: &Span) -> Result<&'a mut Value, InterpreterError> {
+    let (access, rest) = match path.split_first() {
+        Some(split) => split,
+        None => return Ok(value),
+    };
+
+    let inner = match (access, value) {
+        (Access::Index(index), Value::Array(array)) => array
+            .get_mut(*index)
+            .ok_or_else(|| StatementError::array_assign_index(span.clone()))?,
+        (Access::Tuple(index), Value::Tuple(tuple)) => tuple
+            .get_mut(*index)
+            .ok_or_else(|| StatementError::tuple_assign_index(span.clone()))?,
+        (Access::Member(member), Value::Circuit(circuit)) => {
+            // Only mutable circuit variables may be assigned
+            if circuit.get(member).is_none() {
+                return Err(StatementError::undefined_circuit_variable(member.to_string(), span.clone()).into());
+            }
+
+            if !circuit.is_mutable(member) {
+                return Err(StatementError::immutable_circuit_variable(member.to_string(), span.clone()).into());
+            }
+
+            circuit.get_mut(member).unwrap()
+        }
+        (Access::Index(_), _) => return Err(StatementError::array_assign_index(span.clone()).into()),
+        (Access::Range(..), _) => return Err(StatementError::array_assign_range(span.clone()).into()),
+        (Access::Tuple(_), _) => return Err(StatementError::tuple_assign_index(span.clone()).into()),
+        (Access::Member(member), _) => {
+            return Err(StatementError::undefined_circuit(member.to_string(), span.clone()).into());
+        }
+    };
+
+    access_value(inner, rest, span)
+}
@@ -0,0 +1,237 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The plain value of a name in an interpreted Leo program.
+
+use crate::{
+    errors::{AddressError, BooleanError, FieldError, ValueError},
+    field_to_decimal,
+    group::targets::edwards_bls12::{edwards_affine_to_string, EdwardsGroupType},
+    interpreter::IntegerValue,
+};
+use leo_typed::{Circuit, CircuitMember, Enum, GroupValue, Identifier, Span, Type};
+
+use snarkos_curves::edwards_bls12::{EdwardsAffine, Fq};
+use snarkos_dpc::base_dpc::instantiated::Components;
+use snarkos_objects::account::AccountAddress;
+use std::{fmt, str::FromStr};
+
+/// A circuit value together with the monomorphized definition it was constructed from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircuitValue {
+    pub program: String,
+    pub circuit: Circuit,
+    pub members: Vec<(Identifier, Value)>,
+}
+
+impl CircuitValue {
+    pub fn get(&self, member: &Identifier) -> Option<&Value> {
+        self.members
+            .iter()
+            .find(|(identifier, _value)| identifier.eq(member))
+            .map(|(_identifier, value)| value)
+    }
+
+    pub fn get_mut(&mut self, member: &Identifier) -> Option<&mut Value> {
+        self.members
+            .iter_mut()
+            .find(|(identifier, _value)| identifier.eq(member))
+            .map(|(_identifier, value)| value)
+    }
+
+    /// Returns true if the circuit definition declares the member variable as mutable.
+    pub fn is_mutable(&self, member: &Identifier) -> bool {
+        self.circuit.members.iter().any(|definition| match definition {
+            CircuitMember::CircuitVariable(mutable, identifier, _type) => *mutable && identifier.eq(member),
+            CircuitMember::CircuitFunction(_static, _function) => false,
+        })
+    }
+}
+
+/// An enum value holding the payload of the variant at `index`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnumValue {
+    pub enum_: Enum,
+    pub index: usize,
+    pub payload: Vec<Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    // Data types
+    Address(String),
+    Boolean(bool),
+    Field(Fq),
+    Group(EdwardsAffine),
+    Integer(IntegerValue),
+
+    // A number without an explicit type that is resolved when it is used
+    Implicit(String),
+
+    // Data type wrappers
+    Array(Vec<Value>),
+    Tuple(Vec<Value>),
+    Circuit(CircuitValue),
+    Enum(EnumValue),
+}
+
+impl Value {
+    pub fn address(address: String, span: Span) -> Result<Self, AddressError> {
+        let address = AccountAddress::<Components>::from_str(&address)
+            .map_err(|error| AddressError::account_error(error, span))?;
+
+        Ok(Value::Address(address.to_string()))
+    }
+
+    pub fn boolean(boolean: String, span: Span) -> Result<Self, BooleanError> {
+        let boolean = boolean
+            .parse::<bool>()
+            .map_err(|_| BooleanError::invalid_boolean(boolean, span))?;
+
+        Ok(Value::Boolean(boolean))
+    }
+
+    pub fn field(field: String, span: Span) -> Result<Self, FieldError> {
+        let field = Fq::from_str(&field).map_err(|_| FieldError::invalid_field(field, span))?;
+
+        Ok(Value::Field(field))
+    }
+
+    pub fn group(group: GroupValue) -> Result<Self, ValueError> {
+        Ok(Value::Group(EdwardsGroupType::edwards_affine_from_value(group)?))
+    }
+
+    /// Returns the value of a number string of the given type.
+    /// Numbers of types that cannot hold a number are left implicit.
+    pub fn from_type(value: String, type_: &Type, span: Span) -> Result<Self, ValueError> {
+        Ok(match type_ {
+            Type::Address => Value::address(value, span)?,
+            Type::Boolean => Value::boolean(value, span)?,
+            Type::Field => Value::field(value, span)?,
+            Type::Group => Value::group(GroupValue::Single(value, span))?,
+            Type::IntegerType(integer_type) => Value::Integer(IntegerValue::new(integer_type, value, span)?),
+            Type::Array(type_, _dimensions) => Value::from_type(value, type_, span)?,
+            _ => Value::Implicit(value),
+        })
+    }
+
+    pub fn to_type(&self, span: Span) -> Result<Type, ValueError> {
+        Ok(match self {
+            Value::Address(_address) => Type::Address,
+            Value::Boolean(_boolean) => Type::Boolean,
+            Value::Field(_field) => Type::Field,
+            Value::Group(_group) => Type::Group,
+            Value::Integer(integer) => Type::IntegerType(integer.get_type()),
+            Value::Implicit(value) => return Err(ValueError::implicit(value.clone(), span)),
+            Value::Array(array) => {
+                let first = array
+                    .first()
+                    .ok_or_else(|| ValueError::implicit(self.to_string(), span.clone()))?;
+                let array_type = first.to_type(span)?;
+                let mut dimensions = vec![array.len()];
+
+                // Nested array type
+                if let Type::Array(inner_type, inner_dimensions) = array_type {
+                    dimensions.extend(inner_dimensions);
+
+                    return Ok(Type::Array(inner_type, dimensions));
+                }
+
+                Type::Array(Box::new(array_type), dimensions)
+            }
+            Value::Tuple(tuple) => Type::Tuple(
+                tuple
+                    .iter()
+                    .map(|value| value.to_type(span.clone()))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            Value::Circuit(circuit) => Type::Circuit(circuit.circuit.circuit_name.clone()),
+            Value::Enum(enum_) => Type::Enum(enum_.enum_.enum_name.clone()),
+        })
+    }
+
+    /// Resolves an implicit number to the given type.
+    pub fn resolve_type(&mut self, type_: Option<&Type>, span: Span) -> Result<(), ValueError> {
+        if let (Value::Implicit(value), Some(type_)) = (&self, type_) {
+            *self = Value::from_type(value.clone(), type_, span)?;
+        }
+
+        Ok(())
+    }
+
+    /// Expects both `self` and `other` to resolve to the same type.
+    pub fn resolve_types(&mut self, other: &mut Self, type_: Option<&Type>, span: Span) -> Result<(), ValueError> {
+        if type_.is_some() {
+            self.resolve_type(type_, span.clone())?;
+
+            return other.resolve_type(type_, span);
+        }
+
+        match (&self, &other) {
+            (Value::Implicit(_), Value::Implicit(_)) => Ok(()),
+            (Value::Implicit(_), _) => self.resolve_type(Some(&other.to_type(span.clone())?), span),
+            (_, Value::Implicit(_)) => other.resolve_type(Some(&self.to_type(span.clone())?), span),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Address(address) => write!(f, "{}", address),
+            Value::Boolean(boolean) => write!(f, "{}", boolean),
+            Value::Field(field) => write!(f, "{}", field_to_decimal(field)),
+            Value::Group(group) => write!(f, "{}", edwards_affine_to_string(group)),
+            Value::Integer(integer) => write!(f, "{}", integer),
+            Value::Implicit(value) => write!(f, "{}", value),
+            Value::Array(array) => {
+                let values = array.iter().map(|value| value.to_string()).collect::<Vec<_>>();
+
+                write!(f, "[{}]", values.join(", "))
+            }
+            Value::Tuple(tuple) => {
+                let values = tuple.iter().map(|value| value.to_string()).collect::<Vec<_>>();
+
+                write!(f, "({})", values.join(", "))
+            }
+            Value::Circuit(circuit) => {
+                // Circuit functions are displayed in definition order like the compiler does
+                let members = circuit
+                    .circuit
+                    .members
+                    .iter()
+                    .filter_map(|member| match member {
+                        CircuitMember::CircuitVariable(_mutable, identifier, _type) => circuit
+                            .get(identifier)
+                            .map(|value| format!("{}: {}", identifier, value)),
+                        CircuitMember::CircuitFunction(_static, function) => Some(format!(
+                            "{}: function {{ {}() }}",
+                            function.identifier, function.identifier
+                        )),
+                    })
+                    .collect::<Vec<_>>();
+
+                write!(f, "{} {{{}}}", circuit.circuit.circuit_name, members.join(", "))
+            }
+            Value::Enum(enum_) => write!(
+                f,
+                "{}::{}",
+                enum_.enum_.enum_name, enum_.enum_.variants[enum_.index].identifier
+            ),
+        }
+    }
+}
@@ -19,6 +19,9 @@
 #[macro_use]
 extern crate thiserror;
 
+pub mod build_manifest;
+pub use self::build_manifest::*;
+
 pub mod compiler;
 
 pub mod console;
@@ -27,6 +30,9 @@ pub use self::console::*;
 pub mod constraints;
 pub use self::constraints::*;
 
+pub mod coverage;
+pub use self::coverage::*;
+
 pub mod definition;
 
 pub mod errors;
@@ -40,6 +46,14 @@ pub use self::function::*;
 pub mod import;
 pub use self::import::*;
 
+pub mod interpreter;
+
+pub mod main_signature;
+pub use self::main_signature::*;
+
+pub mod optimizer;
+pub use self::optimizer::{optimize_program, Optimizer};
+
 pub mod output;
 pub use self::output::*;
 
@@ -0,0 +1,275 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Generates and checks program input files from the signature of the `main` function.
+
+use crate::{errors::InputSignatureError, ImportParser};
+use leo_input::{files::TableOrSection, sections::Header, InputParserError, LeoInputParser};
+use leo_package::inputs::StateFile;
+use leo_typed::{Circuit, CircuitMember, Function, FunctionInput, InputVariable, Parameter, Program, Span, Type};
+
+use std::path::PathBuf;
+
+/// Placeholder for `address` inputs in a generated input file.
+pub const PLACEHOLDER_ADDRESS: &str = "aleo1daxej63vwrmn2zhl4dymygagh89k5d2vaw6rjauueme7le6k2q8sjn0ng9";
+
+/// The parameters and return type of the `main` function of a program.
+#[derive(Clone)]
+pub struct MainSignature {
+    function: Function,
+    circuits: Vec<Circuit>,
+}
+
+impl MainSignature {
+    /// Returns the signature of the `main` function of the program, if it has one.
+    /// Circuit types of the parameters are looked up in the program and its imports.
+    pub fn new(program: &Program, imported_programs: &ImportParser) -> Option<Self> {
+        let function = program
+            .functions
+            .values()
+            .find(|function| function.identifier.name == "main")?
+            .clone();
+
+        let circuits = program
+            .circuits
+            .values()
+            .chain(
+                imported_programs
+                    .programs()
+                    .flat_map(|program| program.circuits.values()),
+            )
+            .cloned()
+            .collect();
+
+        Some(Self { function, circuits })
+    }
+
+    /// Returns the `main` function.
+    pub fn function(&self) -> &Function {
+        &self.function
+    }
+
+    /// Returns the parameters of `main` that are read from the `[main]` section of an input file.
+    pub fn parameters(&self) -> Vec<&FunctionInput> {
+        self.function
+            .input
+            .iter()
+            .filter_map(|input| match input {
+                InputVariable::FunctionInput(parameter) => Some(parameter),
+                InputVariable::InputKeyword(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns true if `main` takes the `input` keyword, which reads the registers and the program state.
+    pub fn takes_input(&self) -> bool {
+        self.function
+            .input
+            .iter()
+            .any(|input| matches!(input, InputVariable::InputKeyword(_)))
+    }
+
+    /// Returns a program state file with a placeholder for every `[state]`, `[record]` and `[state_leaf]` value,
+    /// or `None` if `main` does not take `input`.
+    pub fn state_template(&self, package_name: &str) -> Option<String> {
+        match self.takes_input() {
+            true => Some(StateFile::new(package_name).template()),
+            false => None,
+        }
+    }
+
+    /// Returns a program input file with a placeholder value for every parameter of `main`
+    /// and a `[registers]` section with a register for every value that `main` returns.
+    pub fn input_template(&self, package_name: &str) -> String {
+        let mut template = format!("// The program input for {}/src/main.leo\n[main]\n", package_name);
+
+        for parameter in self.parameters() {
+            template.push_str(&format!(
+                "{}: {} = {};\n",
+                parameter.identifier.name,
+                parameter.type_.input_type(),
+                self.placeholder(&parameter.type_)
+            ));
+        }
+
+        template.push_str("\n[registers]\n");
+
+        let return_types = match &self.function.returns {
+            Some(Type::Tuple(types)) => types.clone(),
+            Some(type_) => vec![type_.clone()],
+            None => vec![],
+        };
+
+        for (index, type_) in return_types.iter().enumerate() {
+            template.push_str(&format!(
+                "r{}: {} = {};\n",
+                index,
+                type_.input_type(),
+                self.placeholder(type_)
+            ));
+        }
+
+        template
+    }
+
+    /// Returns a value of the given type in the input file format.
+    fn placeholder(&self, type_: &Type) -> String {
+        match type_ {
+            Type::Address => PLACEHOLDER_ADDRESS.to_owned(),
+            Type::Boolean => "false".to_owned(),
+            Type::Field => "0field".to_owned(),
+            Type::Group => "0group".to_owned(),
+            Type::IntegerType(integer_type) => format!("0{}", integer_type),
+            Type::Array(element_type, dimensions) => {
+                let element = self.placeholder(element_type);
+
+                match dimensions.as_slice() {
+                    [dimension] => format!("[{}; {}]", element, dimension),
+                    dimensions => {
+                        let dimensions = dimensions.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+
+                        format!("[{}; ({})]", element, dimensions)
+                    }
+                }
+            }
+            Type::Tuple(types) => {
+                let values = types
+                    .iter()
+                    .map(|type_| self.placeholder(type_))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("({})", values)
+            }
+            Type::Circuit(identifier) => {
+                let members = self
+                    .circuits
+                    .iter()
+                    .find(|circuit| circuit.circuit_name.name == identifier.name)
+                    .map(|circuit| {
+                        circuit
+                            .members
+                            .iter()
+                            .filter_map(|member| match member {
+                                CircuitMember::CircuitVariable(_, name, type_) => {
+                                    Some(format!("{}: {}", name.name, self.placeholder(type_)))
+                                }
+                                CircuitMember::CircuitFunction(_, _) => None,
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+
+                format!("{} {{ {} }}", identifier.name, members.join(", "))
+            }
+            // Generic `main` functions, `Self` parameters and enum inputs are rejected by the compiler
+            Type::GenericArray(_, _) | Type::Enum(_) | Type::SelfType => "_".to_owned(),
+        }
+    }
+
+    /// Compares the `[main]` section of a program input file with the parameters of `main`.
+    /// Returns an error for every missing, extra, or mistyped input.
+    pub fn check_input_file(
+        &self,
+        input_string: &str,
+        input_path: &PathBuf,
+        main_file_path: &PathBuf,
+    ) -> Result<Vec<InputSignatureError>, InputParserError> {
+        let file = LeoInputParser::parse_file(input_string).map_err(|mut error| {
+            error.set_path(input_path.clone());
+
+            error
+        })?;
+
+        let mut section = None;
+        let mut definitions = vec![];
+
+        for entry in file.entries.into_iter() {
+            if let TableOrSection::Section(main_section) = entry {
+                if let Header::Main(main) = main_section.header {
+                    section = Some(Span::from(main.span));
+                    definitions.extend(
+                        main_section
+                            .definitions
+                            .into_iter()
+                            .map(|definition| Parameter::from(definition.parameter)),
+                    );
+                }
+            }
+        }
+
+        let parameters = self.parameters();
+        let mut errors = vec![];
+
+        for parameter in parameters.iter() {
+            let name = &parameter.identifier.name;
+
+            match definitions.iter().find(|definition| &definition.variable.name == name) {
+                Some(definition) if !types_match(&parameter.type_, &definition.type_) => {
+                    errors.push(InputSignatureError::mistyped_input(
+                        name,
+                        &parameter.type_,
+                        &definition.type_,
+                        definition.span.clone(),
+                        input_path,
+                        &parameter.span,
+                        main_file_path,
+                    ))
+                }
+                Some(_) => {}
+                None => errors.push(InputSignatureError::missing_input(
+                    name,
+                    &parameter.type_,
+                    parameter.span.clone(),
+                    main_file_path,
+                    section.clone().map(|span| (span, input_path)),
+                )),
+            }
+        }
+
+        for definition in definitions.iter() {
+            let name = &definition.variable.name;
+
+            if !parameters.iter().any(|parameter| &parameter.identifier.name == name) {
+                errors.push(InputSignatureError::extra_input(
+                    name,
+                    definition.span.clone(),
+                    input_path,
+                    &self.function.identifier.span,
+                    main_file_path,
+                ));
+            }
+        }
+
+        Ok(errors)
+    }
+}
+
+/// Returns true if an input of the `found` type can be passed to a parameter of the `expected` type.
+/// Multidimensional array types match their nested form.
+fn types_match(expected: &Type, found: &Type) -> bool {
+    match (expected, found) {
+        (Type::Tuple(expected_types), Type::Tuple(found_types)) => {
+            expected_types.len() == found_types.len()
+                && expected_types
+                    .iter()
+                    .zip(found_types.iter())
+                    .all(|(expected, found)| types_match(expected, found))
+        }
+        (Type::Array(_, _), Type::Array(_, _)) => expected.match_array_types(found),
+        (expected, found) => expected == found,
+    }
+}
@@ -0,0 +1,376 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Folds constant expressions in a compiled Leo program.
+
+use crate::optimizer::{
+    compare,
+    evaluate_arithmetic,
+    evaluate_negate,
+    integer_operands,
+    is_constant,
+    is_value,
+    Arithmetic,
+    Optimizer,
+};
+use leo_typed::{
+    CircuitVariableDefinition,
+    Expression,
+    MatchArm,
+    MatchPattern,
+    RangeOrExpression,
+    Span,
+    SpreadOrExpression,
+};
+
+use std::cmp::Ordering;
+
+impl<'a> Optimizer<'a> {
+    /// Returns the expression with every operation over constant values replaced by its result.
+    pub fn fold_expression(&self, expression: Expression) -> Expression {
+        match expression {
+            // Variables
+            Expression::Identifier(identifier) => match self.constants.get(&identifier.name) {
+                Some(value) if is_value(value) => with_span(value.clone(), identifier.span),
+                _ => Expression::Identifier(identifier),
+            },
+
+            // Number operations
+            Expression::Add(left, right, span) => self.fold_arithmetic(Arithmetic::Add, *left, *right, span),
+            Expression::Sub(left, right, span) => self.fold_arithmetic(Arithmetic::Sub, *left, *right, span),
+            Expression::Mul(left, right, span) => self.fold_arithmetic(Arithmetic::Mul, *left, *right, span),
+            Expression::Div(left, right, span) => self.fold_arithmetic(Arithmetic::Div, *left, *right, span),
+            Expression::Pow(left, right, span) => self.fold_arithmetic(Arithmetic::Pow, *left, *right, span),
+            Expression::Negate(expression, span) => match self.fold_expression(*expression) {
+                Expression::Integer(type_, value, value_span) => match evaluate_negate(&type_, &value) {
+                    Some(result) => Expression::Integer(type_, result, span),
+                    None => Expression::Negate(Box::new(Expression::Integer(type_, value, value_span)), span),
+                },
+                expression => Expression::Negate(Box::new(expression), span),
+            },
+
+            // Boolean operations
+            Expression::Not(expression, span) => {
+                let expression = self.fold_expression(*expression);
+
+                match constant_boolean(&expression) {
+                    Some(value) => Expression::Boolean((!value).to_string(), span),
+                    None => Expression::Not(Box::new(expression), span),
+                }
+            }
+            Expression::Or(left, right, span) => {
+                let (left, right) = (self.fold_expression(*left), self.fold_expression(*right));
+
+                match (constant_boolean(&left), constant_boolean(&right)) {
+                    (Some(left), Some(right)) => Expression::Boolean((left || right).to_string(), span),
+                    _ => Expression::Or(Box::new(left), Box::new(right), span),
+                }
+            }
+            Expression::And(left, right, span) => {
+                let (left, right) = (self.fold_expression(*left), self.fold_expression(*right));
+
+                match (constant_boolean(&left), constant_boolean(&right)) {
+                    (Some(left), Some(right)) => Expression::Boolean((left && right).to_string(), span),
+                    _ => Expression::And(Box::new(left), Box::new(right), span),
+                }
+            }
+            Expression::Eq(left, right, span) => {
+                let (left, right) = (self.fold_expression(*left), self.fold_expression(*right));
+
+                let result = match (&left, &right) {
+                    (Expression::Boolean(_, _), Expression::Boolean(_, _)) => {
+                        match (constant_boolean(&left), constant_boolean(&right)) {
+                            (Some(left), Some(right)) => Some(left == right),
+                            _ => None,
+                        }
+                    }
+                    (Expression::Address(left, _), Expression::Address(right, _)) => Some(left == right),
+                    _ => integer_operands(&left, &right)
+                        .and_then(|(type_, left, right)| compare(&type_, &left, &right))
+                        .map(|ordering| ordering == Ordering::Equal),
+                };
+
+                match result {
+                    Some(result) => Expression::Boolean(result.to_string(), span),
+                    None => Expression::Eq(Box::new(left), Box::new(right), span),
+                }
+            }
+            Expression::Ge(left, right, span) => {
+                self.fold_comparison(*left, *right, span, |ordering| ordering != Ordering::Less, Expression::Ge)
+            }
+            Expression::Gt(left, right, span) => {
+                self.fold_comparison(*left, *right, span, |ordering| ordering == Ordering::Greater, Expression::Gt)
+            }
+            Expression::Le(left, right, span) => {
+                self.fold_comparison(*left, *right, span, |ordering| ordering != Ordering::Greater, Expression::Le)
+            }
+            Expression::Lt(left, right, span) => {
+                self.fold_comparison(*left, *right, span, |ordering| ordering == Ordering::Less, Expression::Lt)
+            }
+
+            // Conditionals
+            Expression::IfElse(first, second, third, span) => {
+                let first = self.fold_expression(*first);
+
+                match constant_boolean(&first) {
+                    Some(true) => self.fold_expression(*second),
+                    Some(false) => self.fold_expression(*third),
+                    None => Expression::IfElse(
+                        Box::new(first),
+                        Box::new(self.fold_expression(*second)),
+                        Box::new(self.fold_expression(*third)),
+                        span,
+                    ),
+                }
+            }
+            Expression::Match(expression, arms, span) => {
+                let expression = self.fold_expression(*expression);
+                let arms = arms
+                    .into_iter()
+                    .map(|arm| {
+                        // Payload bindings shadow constant variables
+                        let mut optimizer = self.clone();
+                        if let MatchPattern::Variant(_, _, bindings, _) = &arm.pattern {
+                            for binding in bindings {
+                                optimizer.constants.remove(&binding.name);
+                            }
+                        }
+
+                        MatchArm {
+                            expression: optimizer.fold_expression(arm.expression),
+                            pattern: arm.pattern,
+                        }
+                    })
+                    .collect();
+
+                Expression::Match(Box::new(expression), arms, span)
+            }
+
+            // Arrays
+            Expression::Array(elements, span) => {
+                let elements = elements
+                    .into_iter()
+                    .map(|element| {
+                        Box::new(match *element {
+                            SpreadOrExpression::Spread(expression) => {
+                                SpreadOrExpression::Spread(self.fold_expression(expression))
+                            }
+                            SpreadOrExpression::Expression(expression) => {
+                                SpreadOrExpression::Expression(self.fold_expression(expression))
+                            }
+                        })
+                    })
+                    .collect();
+
+                Expression::Array(elements, span)
+            }
+            Expression::GenericArray(element, parameter, span) => {
+                Expression::GenericArray(Box::new(self.fold_expression(*element)), parameter, span)
+            }
+            Expression::ArrayAccess(array, index, span) => {
+                let array = self.fold_expression(*array);
+                let index = match *index {
+                    RangeOrExpression::Range(from, to) => RangeOrExpression::Range(
+                        from.map(|from| self.fold_expression(from)),
+                        to.map(|to| self.fold_expression(to)),
+                    ),
+                    RangeOrExpression::Expression(index) => RangeOrExpression::Expression(self.fold_expression(index)),
+                };
+
+                let element = match (self.constant(&array), &index) {
+                    (Some(Expression::Array(elements, _)), RangeOrExpression::Expression(index)) => {
+                        index_value(index).and_then(|index| match elements.get(index).map(|element| element.as_ref()) {
+                            Some(SpreadOrExpression::Expression(element)) => Some(element.clone()),
+                            _ => None,
+                        })
+                    }
+                    _ => None,
+                };
+
+                match element {
+                    Some(element) => with_span(element, span),
+                    None => Expression::ArrayAccess(Box::new(array), Box::new(index), span),
+                }
+            }
+
+            // Tuples
+            Expression::Tuple(expressions, span) => Expression::Tuple(
+                expressions
+                    .into_iter()
+                    .map(|expression| self.fold_expression(expression))
+                    .collect(),
+                span,
+            ),
+            Expression::TupleAccess(tuple, index, span) => {
+                let tuple = self.fold_expression(*tuple);
+
+                match self.constant(&tuple) {
+                    Some(Expression::Tuple(mut expressions, _)) if index < expressions.len() => {
+                        with_span(expressions.swap_remove(index), span)
+                    }
+                    _ => Expression::TupleAccess(Box::new(tuple), index, span),
+                }
+            }
+
+            // Circuits
+            Expression::Circuit(identifier, members, span) => {
+                let members = members
+                    .into_iter()
+                    .map(|member| CircuitVariableDefinition {
+                        identifier: member.identifier,
+                        expression: self.fold_expression(member.expression),
+                    })
+                    .collect();
+
+                Expression::Circuit(identifier, members, span)
+            }
+            Expression::CircuitMemberAccess(circuit, member, span) => {
+                let circuit = self.fold_expression(*circuit);
+
+                let value = match self.constant(&circuit) {
+                    Some(Expression::Circuit(_, members, _)) => members
+                        .into_iter()
+                        .find(|variable| variable.identifier.name == member.name)
+                        .map(|variable| variable.expression),
+                    _ => None,
+                };
+
+                match value {
+                    Some(value) => with_span(value, span),
+                    None => Expression::CircuitMemberAccess(Box::new(circuit), member, span),
+                }
+            }
+
+            // Functions
+            Expression::FunctionCall(function, arguments, span) => {
+                let arguments = arguments
+                    .into_iter()
+                    .map(|argument| self.fold_expression(argument))
+                    .collect::<Vec<_>>();
+
+                let value = match function.as_ref() {
+                    Expression::Identifier(identifier) if !self.constants.contains_key(&identifier.name) => {
+                        self.evaluate_function(&identifier.name, &arguments)
+                    }
+                    _ => None,
+                };
+
+                match value {
+                    Some(value) => with_span(value, span),
+                    None => Expression::FunctionCall(function, arguments, span),
+                }
+            }
+            Expression::CoreFunctionCall(name, arguments, span) => Expression::CoreFunctionCall(
+                name,
+                arguments
+                    .into_iter()
+                    .map(|argument| self.fold_expression(argument))
+                    .collect(),
+                span,
+            ),
+
+            // Values and static accesses
+            expression => expression,
+        }
+    }
+
+    fn fold_arithmetic(&self, operation: Arithmetic, left: Expression, right: Expression, span: Span) -> Expression {
+        let rebuild: fn(Box<Expression>, Box<Expression>, Span) -> Expression = match operation {
+            Arithmetic::Add => Expression::Add,
+            Arithmetic::Sub => Expression::Sub,
+            Arithmetic::Mul => Expression::Mul,
+            Arithmetic::Div => Expression::Div,
+            Arithmetic::Pow => Expression::Pow,
+        };
+        let (left, right) = (self.fold_expression(left), self.fold_expression(right));
+
+        let result = integer_operands(&left, &right).and_then(|(type_, left, right)| {
+            evaluate_arithmetic(operation, &type_, &left, &right).map(|result| (type_, result))
+        });
+
+        match result {
+            Some((type_, result)) => Expression::Integer(type_, result, span),
+            None => rebuild(Box::new(left), Box::new(right), span),
+        }
+    }
+
+    fn fold_comparison(
+        &self,
+        left: Expression,
+        right: Expression,
+        span: Span,
+        predicate: fn(Ordering) -> bool,
+        rebuild: fn(Box<Expression>, Box<Expression>, Span) -> Expression,
+    ) -> Expression {
+        let (left, right) = (self.fold_expression(left), self.fold_expression(right));
+
+        let ordering = integer_operands(&left, &right).and_then(|(type_, left, right)| compare(&type_, &left, &right));
+
+        match ordering {
+            Some(ordering) => Expression::Boolean(predicate(ordering).to_string(), span),
+            None => rebuild(Box::new(left), Box::new(right), span),
+        }
+    }
+
+    /// Returns the constant array, tuple or circuit that an expression evaluates to.
+    fn constant(&self, expression: &Expression) -> Option<Expression> {
+        match expression {
+            Expression::Identifier(identifier) => self.constants.get(&identifier.name).cloned(),
+            expression if is_constant(expression) => Some(expression.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the value of a boolean string.
+pub(crate) fn boolean_value(value: &str) -> Option<bool> {
+    match value {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// Returns the value of a constant boolean expression.
+pub(crate) fn constant_boolean(expression: &Expression) -> Option<bool> {
+    match expression {
+        Expression::Boolean(value, _) => boolean_value(value),
+        _ => None,
+    }
+}
+
+/// Returns the value of a constant array index.
+pub(crate) fn index_value(expression: &Expression) -> Option<usize> {
+    match expression {
+        Expression::Integer(_, value, _) | Expression::Implicit(value, _) => value.parse::<usize>().ok(),
+        _ => None,
+    }
+}
+
+/// Returns the literal value with the span of the expression it replaces.
+fn with_span(value: Expression, span: Span) -> Expression {
+    match value {
+        Expression::Address(value, _) => Expression::Address(value, span),
+        Expression::Boolean(value, _) => Expression::Boolean(value, span),
+        Expression::Field(value, _) => Expression::Field(value, span),
+        Expression::Implicit(value, _) => Expression::Implicit(value, span),
+        Expression::Integer(type_, value, _) => Expression::Integer(type_, value, span),
+        mut value => {
+            value.set_span(&span);
+            value
+        }
+    }
+}
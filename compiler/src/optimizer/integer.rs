@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Evaluates integer operations on constant values in a compiled Leo program.
+
+use leo_typed::{Expression, IntegerType};
+
+use std::{cmp::Ordering, convert::TryFrom};
+
+/// An arithmetic operation on two integers.
+pub(crate) enum Arithmetic {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+/// Returns the integer type and the values of two integer operands.
+/// An implicit value is treated as an integer of the same type as the other operand.
+pub(crate) fn integer_operands(left: &Expression, right: &Expression) -> Option<(IntegerType, String, String)> {
+    match (left, right) {
+        (Expression::Integer(left_type, left, _), Expression::Integer(right_type, right, _)) => {
+            if left_type == right_type {
+                Some((left_type.clone(), left.clone(), right.clone()))
+            } else {
+                None
+            }
+        }
+        (Expression::Integer(type_, left, _), Expression::Implicit(right, _))
+        | (Expression::Implicit(left, _), Expression::Integer(type_, right, _)) => {
+            Some((type_.clone(), left.clone(), right.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the result of an arithmetic operation or `None` if the result does not fit into the integer type.
+/// Signed division and exponentiation are left to the constraint system.
+pub(crate) fn evaluate_arithmetic(
+    operation: Arithmetic,
+    type_: &IntegerType,
+    left: &str,
+    right: &str,
+) -> Option<String> {
+    if let Some(max) = unsigned_max(type_) {
+        let (left, right) = (left.parse::<u128>().ok()?, right.parse::<u128>().ok()?);
+        let result = match operation {
+            Arithmetic::Add => left.checked_add(right),
+            Arithmetic::Sub => left.checked_sub(right),
+            Arithmetic::Mul => left.checked_mul(right),
+            Arithmetic::Div => left.checked_div(right),
+            Arithmetic::Pow => left.checked_pow(u32::try_from(right).ok()?),
+        }?;
+
+        return if result <= max { Some(result.to_string()) } else { None };
+    }
+
+    let (min, max) = signed_range(type_);
+    let (left, right) = (left.parse::<i128>().ok()?, right.parse::<i128>().ok()?);
+    let result = match operation {
+        Arithmetic::Add => left.checked_add(right),
+        Arithmetic::Sub => left.checked_sub(right),
+        Arithmetic::Mul => left.checked_mul(right),
+        Arithmetic::Div | Arithmetic::Pow => None,
+    }?;
+
+    if result >= min && result <= max {
+        Some(result.to_string())
+    } else {
+        None
+    }
+}
+
+/// Returns the negated value of a signed integer or `None` if it does not fit into the integer type.
+pub(crate) fn evaluate_negate(type_: &IntegerType, value: &str) -> Option<String> {
+    if unsigned_max(type_).is_some() {
+        return None;
+    }
+
+    let (min, max) = signed_range(type_);
+    let result = value.parse::<i128>().ok()?.checked_neg()?;
+
+    if result >= min && result <= max {
+        Some(result.to_string())
+    } else {
+        None
+    }
+}
+
+/// Returns the ordering of two integers of the given type.
+pub(crate) fn compare(type_: &IntegerType, left: &str, right: &str) -> Option<Ordering> {
+    if unsigned_max(type_).is_some() {
+        Some(left.parse::<u128>().ok()?.cmp(&right.parse::<u128>().ok()?))
+    } else {
+        Some(left.parse::<i128>().ok()?.cmp(&right.parse::<i128>().ok()?))
+    }
+}
+
+pub(crate) fn unsigned_max(type_: &IntegerType) -> Option<u128> {
+    match type_ {
+        IntegerType::U8 => Some(u8::MAX as u128),
+        IntegerType::U16 => Some(u16::MAX as u128),
+        IntegerType::U32 => Some(u32::MAX as u128),
+        IntegerType::U64 => Some(u64::MAX as u128),
+        IntegerType::U128 => Some(u128::MAX),
+        _ => None,
+    }
+}
+
+pub(crate) fn signed_range(type_: &IntegerType) -> (i128, i128) {
+    match type_ {
+        IntegerType::I8 => (i8::MIN as i128, i8::MAX as i128),
+        IntegerType::I16 => (i16::MIN as i128, i16::MAX as i128),
+        IntegerType::I32 => (i32::MIN as i128, i32::MAX as i128),
+        IntegerType::I64 => (i64::MIN as i128, i64::MAX as i128),
+        _ => (i128::MIN, i128::MAX),
+    }
+}
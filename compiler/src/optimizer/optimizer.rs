@@ -0,0 +1,238 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Folds constant expressions and removes dead branches in a typed Leo program.
+
+use leo_typed::{
+    Circuit,
+    CircuitMember,
+    Expression,
+    Function,
+    InputVariable,
+    Program,
+    SpreadOrExpression,
+    Statement,
+    Type,
+};
+
+use std::collections::HashMap;
+
+/// The maximum number of nested function calls that are evaluated at compile time.
+const MAX_CALL_DEPTH: usize = 16;
+
+/// Evaluates expressions over constant values before constraints are generated.
+///
+/// Constant values of immutable variables are substituted for their identifiers, and calls to functions
+/// which only return an expression are evaluated when all arguments are constant.
+/// Branches of conditional statements and expressions with a constant condition are replaced by the
+/// branch that is taken, so that dead branches are never namespaced, evaluated or merged.
+#[derive(Clone)]
+pub struct Optimizer<'a> {
+    pub(crate) functions: &'a HashMap<String, Function>,
+    pub(crate) constants: HashMap<String, Expression>,
+    pub(crate) depth: usize,
+}
+
+/// Returns the program with constant expressions folded and dead branches removed in every function.
+pub fn optimize_program(program: Program) -> Program {
+    // Functions that consist of a single return statement can be evaluated at compile time
+    let functions = program
+        .functions
+        .values()
+        .filter(|function| is_evaluable(function))
+        .map(|function| (function.identifier.name.clone(), function.clone()))
+        .collect::<HashMap<_, _>>();
+
+    let optimizer = Optimizer {
+        functions: &functions,
+        constants: HashMap::new(),
+        depth: 0,
+    };
+
+    let circuits = program
+        .circuits
+        .into_iter()
+        .map(|(identifier, circuit)| {
+            let members = circuit
+                .members
+                .into_iter()
+                .map(|member| match member {
+                    CircuitMember::CircuitFunction(static_, function) => {
+                        CircuitMember::CircuitFunction(static_, optimizer.optimize_function(function))
+                    }
+                    member => member,
+                })
+                .collect();
+
+            (identifier, Circuit { members, ..circuit })
+        })
+        .collect();
+
+    let program_functions = program
+        .functions
+        .into_iter()
+        .map(|(identifier, function)| (identifier, optimizer.optimize_function(function)))
+        .collect();
+
+    let tests = program
+        .tests
+        .into_iter()
+        .map(|(identifier, mut test)| {
+            test.function = optimizer.optimize_function(test.function);
+
+            (identifier, test)
+        })
+        .collect();
+
+    Program {
+        name: program.name,
+        expected_input: program.expected_input,
+        imports: program.imports,
+        circuits,
+        enums: program.enums,
+        functions: program_functions,
+        tests,
+    }
+}
+
+impl<'a> Optimizer<'a> {
+    /// Returns the function with constant expressions folded and dead branches removed.
+    pub fn optimize_function(&self, function: Function) -> Function {
+        // Function inputs are not known at compile time
+        let mut optimizer = Optimizer {
+            functions: self.functions,
+            constants: HashMap::new(),
+            depth: self.depth,
+        };
+
+        let statements = optimizer.fold_statements(function.statements);
+
+        Function { statements, ..function }
+    }
+
+    /// Evaluates a call to a function which only returns an expression.
+    /// Returns `None` if an argument or the returned value is not constant.
+    pub(crate) fn evaluate_function(&self, name: &str, arguments: &[Expression]) -> Option<Expression> {
+        let function = self.functions.get(name)?;
+        if self.depth >= MAX_CALL_DEPTH || function.input.len() != arguments.len() {
+            return None;
+        }
+
+        let mut constants = HashMap::new();
+        for (input, argument) in function.input.iter().zip(arguments.iter()) {
+            match input {
+                InputVariable::FunctionInput(input) => {
+                    let value = constant_value(Some(&input.type_), argument)?;
+                    constants.insert(input.identifier.name.clone(), value);
+                }
+                InputVariable::InputKeyword(_) => return None,
+            }
+        }
+
+        let optimizer = Optimizer {
+            functions: self.functions,
+            constants,
+            depth: self.depth + 1,
+        };
+
+        match function.statements.first() {
+            Some(Statement::Return(expression, _)) => {
+                let value = optimizer.fold_expression(expression.clone());
+
+                constant_value(function.returns.as_ref(), &value)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns `true` if the function body is a single return statement over immutable inputs.
+fn is_evaluable(function: &Function) -> bool {
+    let immutable_inputs = function.input.iter().all(|input| match input {
+        InputVariable::FunctionInput(input) => !input.mutable,
+        InputVariable::InputKeyword(_) => false,
+    });
+
+    function.const_parameters.is_empty()
+        && immutable_inputs
+        && function.returns.is_some()
+        && matches!(function.statements.as_slice(), [Statement::Return(_, _)])
+}
+
+/// Returns `true` if the expression is a literal value.
+pub(crate) fn is_value(expression: &Expression) -> bool {
+    matches!(
+        expression,
+        Expression::Address(_, _)
+            | Expression::Boolean(_, _)
+            | Expression::Field(_, _)
+            | Expression::Group(_)
+            | Expression::Integer(_, _, _)
+    )
+}
+
+/// Returns `true` if the expression is a literal value or an array, tuple or circuit of literal values.
+pub(crate) fn is_constant(expression: &Expression) -> bool {
+    match expression {
+        Expression::Array(elements, _) => elements.iter().all(|element| match element.as_ref() {
+            SpreadOrExpression::Expression(expression) => is_constant(expression),
+            SpreadOrExpression::Spread(_) => false,
+        }),
+        Expression::Tuple(expressions, _) => expressions.iter().all(is_constant),
+        Expression::Circuit(_, members, _) => members.iter().all(|member| is_constant(&member.expression)),
+        expression => is_value(expression),
+    }
+}
+
+/// Returns the constant value of an expression with the given type.
+/// Implicit values are given the expected type. Returns `None` if the value is not constant or the type differs.
+pub(crate) fn constant_value(type_: Option<&Type>, expression: &Expression) -> Option<Expression> {
+    let type_ = match type_ {
+        Some(type_) => type_,
+        None => return if is_constant(expression) { Some(expression.clone()) } else { None },
+    };
+
+    match (type_, expression) {
+        (Type::IntegerType(integer_type), Expression::Implicit(value, span)) => {
+            Some(Expression::Integer(integer_type.clone(), value.clone(), span.clone()))
+        }
+        (Type::IntegerType(integer_type), Expression::Integer(value_type, _, _)) if integer_type == value_type => {
+            Some(expression.clone())
+        }
+        (Type::Field, Expression::Implicit(value, span)) => Some(Expression::Field(value.clone(), span.clone())),
+        (Type::Field, Expression::Field(_, _))
+        | (Type::Boolean, Expression::Boolean(_, _))
+        | (Type::Address, Expression::Address(_, _))
+        | (Type::Group, Expression::Group(_)) => Some(expression.clone()),
+        (Type::Tuple(types), Expression::Tuple(expressions, span)) if types.len() == expressions.len() => {
+            let expressions = types
+                .iter()
+                .zip(expressions.iter())
+                .map(|(type_, expression)| constant_value(Some(type_), expression))
+                .collect::<Option<Vec<_>>>()?;
+
+            Some(Expression::Tuple(expressions, span.clone()))
+        }
+        (Type::Circuit(identifier), Expression::Circuit(name, _, _)) if identifier.name == name.name => {
+            if is_constant(expression) {
+                Some(expression.clone())
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,269 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Removes dead branches from statements in a compiled Leo program.
+
+use crate::optimizer::{constant_boolean, constant_value, index_value, Optimizer};
+use leo_typed::{
+    Assignee,
+    ConditionalNestedOrEndStatement,
+    ConditionalStatement,
+    ConsoleFunction,
+    ConsoleFunctionCall,
+    FormattedParameter,
+    FormattedString,
+    MatchPattern,
+    MatchStatementArm,
+    Statement,
+};
+
+/// The result of folding a conditional statement.
+enum Branch {
+    /// The condition is constant and only these statements are executed.
+    Taken(Vec<Statement>),
+    /// The condition is only known when the program is executed.
+    Conditional(ConditionalStatement),
+}
+
+impl<'a> Optimizer<'a> {
+    /// Returns the statements with constant expressions folded and dead branches removed.
+    pub fn fold_statements(&mut self, statements: Vec<Statement>) -> Vec<Statement> {
+        statements
+            .into_iter()
+            .flat_map(|statement| self.fold_statement(statement))
+            .collect()
+    }
+
+    fn fold_statement(&mut self, statement: Statement) -> Vec<Statement> {
+        match statement {
+            Statement::Return(expression, span) => vec![Statement::Return(self.fold_expression(expression), span)],
+            Statement::Definition(declare, variables, expressions, span) => {
+                let expressions = expressions
+                    .into_iter()
+                    .map(|expression| self.fold_expression(expression))
+                    .collect::<Vec<_>>();
+
+                // A new definition shadows any previous constant with the same name
+                for name in variables.names.iter() {
+                    self.constants.remove(&name.identifier.name);
+                }
+
+                if let ([name], [expression]) = (variables.names.as_slice(), expressions.as_slice()) {
+                    if !name.mutable {
+                        if let Some(value) = constant_value(variables.type_.as_ref(), expression) {
+                            self.constants.insert(name.identifier.name.clone(), value);
+                        }
+                    }
+                }
+
+                vec![Statement::Definition(declare, variables, expressions, span)]
+            }
+            Statement::Assign(assignee, expression, span) => {
+                self.constants.remove(&assignee_name(&assignee));
+
+                vec![Statement::Assign(assignee, self.fold_expression(expression), span)]
+            }
+            Statement::Conditional(statement, span) => match self.fold_conditional(statement) {
+                Branch::Taken(statements) => self.fold_statements(statements),
+                Branch::Conditional(statement) => vec![Statement::Conditional(statement, span)],
+            },
+            Statement::Iteration(index, start, stop, statements, span) => {
+                let start = self.fold_expression(start);
+                let stop = self.fold_expression(stop);
+
+                // A loop over an empty range is never executed
+                if let (Some(from), Some(to)) = (index_value(&start), index_value(&stop)) {
+                    if from >= to {
+                        return vec![];
+                    }
+                }
+
+                // Variables defined in the loop body differ between iterations
+                let mut names = defined_names(&statements);
+                names.push(index.name.clone());
+
+                let mut block = self.clone();
+                for name in names.iter() {
+                    block.constants.remove(name);
+                }
+                let statements = block.fold_statements(statements);
+
+                self.remove_constants(&names);
+
+                vec![Statement::Iteration(index, start, stop, statements, span)]
+            }
+            Statement::Match(expression, arms, span) => {
+                let expression = self.fold_expression(expression);
+                let arms = arms
+                    .into_iter()
+                    .map(|arm| {
+                        let mut block = self.clone();
+                        if let MatchPattern::Variant(_, _, bindings, _) = &arm.pattern {
+                            for binding in bindings {
+                                block.constants.remove(&binding.name);
+                            }
+                        }
+
+                        let names = defined_names(&arm.statements);
+                        let statements = block.fold_statements(arm.statements);
+                        self.remove_constants(&names);
+
+                        MatchStatementArm {
+                            pattern: arm.pattern,
+                            statements,
+                        }
+                    })
+                    .collect();
+
+                vec![Statement::Match(expression, arms, span)]
+            }
+            Statement::Console(console) => {
+                let function = match console.function {
+                    ConsoleFunction::Assert(expression) => ConsoleFunction::Assert(self.fold_expression(expression)),
+                    ConsoleFunction::Debug(string) => ConsoleFunction::Debug(self.fold_formatted_string(string)),
+                    ConsoleFunction::Error(string) => ConsoleFunction::Error(self.fold_formatted_string(string)),
+                    ConsoleFunction::Log(string) => ConsoleFunction::Log(self.fold_formatted_string(string)),
+                };
+
+                vec![Statement::Console(ConsoleFunctionCall {
+                    function,
+                    span: console.span,
+                })]
+            }
+            Statement::Expression(expression, span) => {
+                vec![Statement::Expression(self.fold_expression(expression), span)]
+            }
+        }
+    }
+
+    /// Folds a conditional statement and returns the statements of the branch that is taken,
+    /// or the remaining conditional statement if the condition is not constant.
+    fn fold_conditional(&mut self, statement: ConditionalStatement) -> Branch {
+        let condition = self.fold_expression(statement.condition);
+
+        match constant_boolean(&condition) {
+            Some(true) => Branch::Taken(statement.statements),
+            Some(false) => match statement.next {
+                Some(ConditionalNestedOrEndStatement::Nested(nested)) => self.fold_conditional(*nested),
+                Some(ConditionalNestedOrEndStatement::End(statements)) => Branch::Taken(statements),
+                None => Branch::Taken(vec![]),
+            },
+            None => {
+                let statements = self.fold_block(statement.statements);
+
+                let next = match statement.next {
+                    Some(ConditionalNestedOrEndStatement::Nested(nested)) => match self.fold_conditional(*nested) {
+                        Branch::Taken(statements) => self.fold_else_block(statements),
+                        Branch::Conditional(nested) => Some(ConditionalNestedOrEndStatement::Nested(Box::new(nested))),
+                    },
+                    Some(ConditionalNestedOrEndStatement::End(statements)) => self.fold_else_block(statements),
+                    None => None,
+                };
+
+                Branch::Conditional(ConditionalStatement {
+                    condition,
+                    statements,
+                    next,
+                })
+            }
+        }
+    }
+
+    fn fold_else_block(&mut self, statements: Vec<Statement>) -> Option<ConditionalNestedOrEndStatement> {
+        let statements = self.fold_block(statements);
+
+        if statements.is_empty() {
+            None
+        } else {
+            Some(ConditionalNestedOrEndStatement::End(statements))
+        }
+    }
+
+    /// Folds the statements of a branch that may not be executed.
+    /// Variables defined in the branch are no longer constant after it.
+    fn fold_block(&mut self, statements: Vec<Statement>) -> Vec<Statement> {
+        let names = defined_names(&statements);
+        let statements = self.clone().fold_statements(statements);
+
+        self.remove_constants(&names);
+
+        statements
+    }
+
+    fn fold_formatted_string(&self, string: FormattedString) -> FormattedString {
+        let parameters = string
+            .parameters
+            .into_iter()
+            .map(|parameter| FormattedParameter {
+                expression: self.fold_expression(parameter.expression),
+                span: parameter.span,
+            })
+            .collect();
+
+        FormattedString { parameters, ..string }
+    }
+
+    fn remove_constants(&mut self, names: &[String]) {
+        for name in names {
+            self.constants.remove(name);
+        }
+    }
+}
+
+/// Returns the name of the variable that is assigned to.
+fn assignee_name(assignee: &Assignee) -> String {
+    match assignee {
+        Assignee::Identifier(identifier) => identifier.name.clone(),
+        Assignee::Array(assignee, _) | Assignee::Tuple(assignee, _) | Assignee::CircuitField(assignee, _) => {
+            assignee_name(assignee)
+        }
+    }
+}
+
+/// Returns the names of all variables defined in the statements, including nested blocks.
+fn defined_names(statements: &[Statement]) -> Vec<String> {
+    let mut names = vec![];
+
+    for statement in statements {
+        match statement {
+            Statement::Definition(_, variables, _, _) => {
+                names.extend(variables.names.iter().map(|name| name.identifier.name.clone()));
+            }
+            Statement::Conditional(statement, _) => names.extend(conditional_defined_names(statement)),
+            Statement::Iteration(_, _, _, statements, _) => names.extend(defined_names(statements)),
+            Statement::Match(_, arms, _) => {
+                for arm in arms {
+                    names.extend(defined_names(&arm.statements));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    names
+}
+
+fn conditional_defined_names(statement: &ConditionalStatement) -> Vec<String> {
+    let mut names = defined_names(&statement.statements);
+
+    match &statement.next {
+        Some(ConditionalNestedOrEndStatement::Nested(nested)) => names.extend(conditional_defined_names(nested)),
+        Some(ConditionalNestedOrEndStatement::End(statements)) => names.extend(defined_names(statements)),
+        None => {}
+    }
+
+    names
+}
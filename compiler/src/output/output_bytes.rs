@@ -14,8 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::OutputBytesError, ConstrainedValue, GroupType, REGISTERS_VARIABLE_NAME};
-use leo_typed::{Parameter, Registers, Span};
+use crate::{errors::OutputBytesError, interpreter::Value, ConstrainedValue, GroupType, REGISTERS_VARIABLE_NAME};
+use leo_input::{InputParserError, LeoInputParser};
+use leo_typed::{Input, Parameter, Registers, Span};
 
 use snarkos_models::curves::{Field, PrimeField};
 
@@ -30,6 +31,17 @@ impl OutputBytes {
         &self.0
     }
 
+    /// Returns the output registers in the JSON input file format.
+    pub fn to_json(&self) -> Result<String, InputParserError> {
+        let output = String::from_utf8_lossy(&self.0);
+
+        // The output is written in the input file format, so it is read back as program input
+        let mut input = Input::new();
+        input.parse_input(LeoInputParser::parse_file(&output)?)?;
+
+        input.to_json().to_json_string()
+    }
+
     pub fn new_from_constrained_value<F: Field + PrimeField, G: GroupType<F>>(
         registers: &Registers,
         value: ConstrainedValue<F, G>,
@@ -39,6 +51,21 @@ impl OutputBytes {
             ConstrainedValue::Tuple(values) => values,
             value => vec![value],
         };
+
+        Self::new_from_strings(registers, return_values.iter().map(|value| value.to_string()).collect(), span)
+    }
+
+    /// Serializes a value returned by the native interpreter in the same format as a compiled program output.
+    pub fn new_from_value(registers: &Registers, value: Value, span: Span) -> Result<Self, OutputBytesError> {
+        let return_values = match value {
+            Value::Tuple(values) => values,
+            value => vec![value],
+        };
+
+        Self::new_from_strings(registers, return_values.iter().map(|value| value.to_string()).collect(), span)
+    }
+
+    fn new_from_strings(registers: &Registers, return_values: Vec<String>, span: Span) -> Result<Self, OutputBytesError> {
         let register_hashmap = registers.values();
 
         // Create vector of parameter values in alphabetical order
@@ -64,7 +91,6 @@ impl OutputBytes {
         for (parameter, value) in register_values.into_iter().zip(return_values.into_iter()) {
             let name = parameter.variable.name;
             let type_ = parameter.type_;
-            let value = value.to_string();
 
             let format = format!("{}: {} = {};\n", name, type_, value,);
 
@@ -17,6 +17,7 @@
 //! The `program.out` file.
 
 use crate::errors::OutputFileError;
+use leo_package::outputs::OutputsDirectory;
 
 use std::{
     fs::{self, File},
@@ -75,7 +76,7 @@ impl OutputFile {
     fn setup_file_path(&self, path: &PathBuf) -> PathBuf {
         let mut path = path.to_owned();
         if path.is_dir() {
-            if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+            if !OutputsDirectory::is_outputs_directory(&path) {
                 path.push(PathBuf::from(OUTPUTS_DIRECTORY_NAME));
             }
             path.push(PathBuf::from(format!("{}{}", self.package_name, OUTPUT_FILE_EXTENSION)));
@@ -16,7 +16,7 @@
 
 //! An in memory store to keep track of defined names when constraining a Leo program.
 
-use crate::{value::ConstrainedValue, GroupType};
+use crate::{definition::Monomorphization, value::ConstrainedValue, Coverage, GroupType};
 
 use snarkos_models::curves::{Field, PrimeField};
 
@@ -25,6 +25,8 @@ use std::collections::HashMap;
 #[derive(Clone)]
 pub struct ConstrainedProgram<F: Field + PrimeField, G: GroupType<F>> {
     pub identifiers: HashMap<String, ConstrainedValue<F, G>>,
+    pub coverage: Coverage,
+    pub monomorphized: HashMap<String, Vec<Monomorphization>>,
 }
 
 pub fn new_scope(outer: String, inner: String) -> String {
@@ -39,6 +41,8 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
     pub fn new() -> Self {
         Self {
             identifiers: HashMap::new(),
+            coverage: Coverage::new(),
+            monomorphized: HashMap::new(),
         }
     }
 
@@ -45,6 +45,31 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         statement: ConditionalStatement,
         return_type: Option<Type>,
         span: Span,
+    ) -> Result<Vec<(Option<Boolean>, ConstrainedValue<F, G>)>, StatementError> {
+        self.enforce_conditional_block(
+            cs,
+            file_scope,
+            function_scope,
+            indicator,
+            statement,
+            return_type,
+            span,
+            0,
+        )
+    }
+
+    /// Enforces the `if` or `else if` block of a conditional statement.
+    /// Nested `else if` blocks share the span of the conditional statement and are numbered by their `block`.
+    fn enforce_conditional_block<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        file_scope: String,
+        function_scope: String,
+        indicator: Option<Boolean>,
+        statement: ConditionalStatement,
+        return_type: Option<Type>,
+        span: Span,
+        block: usize,
     ) -> Result<Vec<(Option<Boolean>, ConstrainedValue<F, G>)>, StatementError> {
         let statement_string = statement.to_string();
 
@@ -77,6 +102,9 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         )
         .map_err(|_| StatementError::indicator_calculation(branch_1_name, span.clone()))?;
 
+        self.coverage
+            .record_branch(&file_scope, &span, block, 0, &branch_1_indicator);
+
         let mut results = vec![];
 
         // Evaluate branch 1
@@ -105,10 +133,13 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         )
         .map_err(|_| StatementError::indicator_calculation(branch_2_name, span.clone()))?;
 
+        self.coverage
+            .record_branch(&file_scope, &span, block, 1, &branch_2_indicator);
+
         // Evaluate branch 2
         let mut branch_2_result = match statement.next {
             Some(next) => match next {
-                ConditionalNestedOrEndStatement::Nested(nested) => self.enforce_conditional_statement(
+                ConditionalNestedOrEndStatement::Nested(nested) => self.enforce_conditional_block(
                     cs,
                     file_scope,
                     function_scope,
@@ -116,6 +147,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     *nested,
                     return_type,
                     span,
+                    block + 1,
                 )?,
                 ConditionalNestedOrEndStatement::End(statements) => self.evaluate_branch(
                     cs,
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces a match statement in a compiled Leo program.
+
+use crate::{errors::StatementError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
+use leo_typed::{Expression, MatchStatementArm, Span, Type};
+
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{r1cs::ConstraintSystem, utilities::boolean::Boolean},
+};
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    /// Enforces a match statement with one or more arms.
+    /// Like a conditional statement, every reachable arm is evaluated with its own `indicator` bit.
+    /// An arm is selected if its pattern matches and no previous pattern matched.
+    pub fn enforce_match_statement<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        file_scope: String,
+        function_scope: String,
+        indicator: Option<Boolean>,
+        expression: Expression,
+        arms: Vec<MatchStatementArm>,
+        return_type: Option<Type>,
+        span: Span,
+    ) -> Result<Vec<(Option<Boolean>, ConstrainedValue<F, G>)>, StatementError> {
+        // Inherit the indicator from a previous conditional statement or assume that we are the outer parent
+        let outer_indicator = indicator.unwrap_or(Boolean::Constant(true));
+
+        let patterns = arms.iter().map(|arm| arm.pattern.clone()).collect::<Vec<_>>();
+        let (enum_name, tag, variants) = self.enforce_match_value(
+            cs,
+            file_scope.clone(),
+            function_scope.clone(),
+            expression,
+            &patterns,
+            span.clone(),
+        )?;
+
+        let mut matched = Boolean::constant(false);
+        let mut results = vec![];
+
+        for (branch, arm) in arms.into_iter().enumerate() {
+            let (pattern_indicator, shadowed) = match self.enforce_match_pattern(
+                cs,
+                file_scope.clone(),
+                function_scope.clone(),
+                &enum_name,
+                &tag,
+                &variants,
+                &arm.pattern,
+            )? {
+                Some(result) => result,
+                None => continue,
+            };
+
+            // If outer_indicator && pattern_indicator && !matched, then select this arm
+            let pattern_string = arm.pattern.to_string();
+            let branch_name = format!("match arm indicator {}", pattern_string);
+            let arm_indicator = Boolean::and(
+                &mut cs.ns(|| format!("match arm {} {}:{}", pattern_string, span.line, span.start)),
+                &pattern_indicator,
+                &matched.not(),
+            )
+            .map_err(|_| StatementError::indicator_calculation(branch_name.clone(), span.clone()))?;
+            let branch_indicator = Boolean::and(
+                &mut cs.ns(|| format!("match branch {} {}:{}", pattern_string, span.line, span.start)),
+                &outer_indicator,
+                &arm_indicator,
+            )
+            .map_err(|_| StatementError::indicator_calculation(branch_name.clone(), span.clone()))?;
+
+            self.coverage
+                .record_branch(&file_scope, &span, 0, branch, &branch_indicator);
+
+            matched = Boolean::or(
+                &mut cs.ns(|| format!("match matched {} {}:{}", pattern_string, span.line, span.start)),
+                &matched,
+                &pattern_indicator,
+            )
+            .map_err(|_| StatementError::indicator_calculation(branch_name, span.clone()))?;
+
+            let mut branch_result = self.evaluate_branch(
+                cs,
+                file_scope.clone(),
+                function_scope.clone(),
+                Some(branch_indicator),
+                arm.statements,
+                return_type.clone(),
+            )?;
+
+            self.restore_match_bindings(shadowed);
+
+            results.append(&mut branch_result);
+        }
+
+        // We return the results of all arms and leave it up to the caller to select the appropriate return
+        Ok(results)
+    }
+}
@@ -27,7 +27,7 @@ use snarkos_models::{
 fn check_return_type(expected: Option<Type>, actual: Type, span: Span) -> Result<(), StatementError> {
     match expected {
         Some(expected) => {
-            if expected.ne(&actual) {
+            if !expected.matches(&actual) {
                 if expected.is_self() && actual.is_circuit() {
                     return Ok(());
                 } else if expected.match_array_types(&actual) {
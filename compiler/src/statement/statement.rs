@@ -42,6 +42,9 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
     ) -> Result<Vec<(Option<Boolean>, ConstrainedValue<F, G>)>, StatementError> {
         let mut results = vec![];
 
+        self.coverage
+            .record_statement(&file_scope, statement.span(), indicator.as_ref());
+
         match statement {
             Statement::Return(expression, span) => {
                 let return_value = (
@@ -103,6 +106,20 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
 
                 results.append(&mut result);
             }
+            Statement::Match(expression, arms, span) => {
+                let mut result = self.enforce_match_statement(
+                    cs,
+                    file_scope,
+                    function_scope,
+                    indicator,
+                    expression,
+                    arms,
+                    return_type,
+                    span,
+                )?;
+
+                results.append(&mut result);
+            }
             Statement::Console(console) => {
                 self.evaluate_console_function_call(cs, file_scope, function_scope, indicator, console)?;
             }
@@ -325,6 +325,39 @@ impl<F: Field + PrimeField> ToBytesGadget<F> for FieldType<F> {
 
 impl<F: Field + PrimeField> std::fmt::Display for FieldType<F> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self.get_value().ok_or(std::fmt::Error))
+        match self.get_value() {
+            Some(value) => write!(f, "{}", field_to_decimal(&value)),
+            None => write!(f, "[allocated]"),
+        }
+    }
+}
+
+/// Returns the canonical decimal representation of a field element.
+pub fn field_to_decimal<F: PrimeField>(field: &F) -> String {
+    const RADIX: u128 = 10_000_000_000_000_000_000;
+
+    let mut limbs = field.into_repr().as_ref().to_vec();
+    let mut chunks = vec![];
+
+    // Divide the little-endian limbs by 10^19 until the quotient is zero
+    while limbs.iter().any(|limb| *limb != 0) {
+        let mut remainder = 0u128;
+
+        for limb in limbs.iter_mut().rev() {
+            let value = (remainder << 64) | *limb as u128;
+
+            *limb = (value / RADIX) as u64;
+            remainder = value % RADIX;
+        }
+
+        chunks.push(remainder as u64);
+    }
+
+    match chunks.split_last() {
+        Some((first, rest)) => rest
+            .iter()
+            .rev()
+            .fold(first.to_string(), |string, chunk| format!("{}{:019}", string, chunk)),
+        None => "0".to_string(),
     }
 }
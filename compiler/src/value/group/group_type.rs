@@ -16,9 +16,12 @@
 
 //! A data type that represents members in the group formed by the set of affine points on a curve.
 
-use crate::errors::GroupError;
+use crate::{errors::GroupError, LocalDataInput};
 use leo_typed::{GroupValue, Span};
 
+use snarkos_dpc::base_dpc::{instantiated::Components, parameters::SystemParameters};
+use snarkos_errors::gadgets::SynthesisError;
+
 use snarkos_models::{
     curves::{Field, One},
     gadgets::{
@@ -57,4 +60,11 @@ pub trait GroupType<F: Field>:
     fn add<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self, span: Span) -> Result<Self, GroupError>;
 
     fn sub<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self, span: Span) -> Result<Self, GroupError>;
+
+    /// Enforces the record commitment and local data Merkle path of the program state.
+    fn enforce_local_data<CS: ConstraintSystem<F>>(
+        cs: CS,
+        local_data: &LocalDataInput,
+        system_parameters: &SystemParameters<Components>,
+    ) -> Result<(), SynthesisError>;
 }
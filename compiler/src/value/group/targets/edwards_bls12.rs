@@ -14,13 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::GroupError, GroupType};
+use crate::{errors::GroupError, field_to_decimal, GroupType, LocalDataInput};
 use leo_typed::{GroupCoordinate, GroupTuple, GroupValue, Span};
 
 use snarkos_curves::{
     edwards_bls12::{EdwardsAffine, EdwardsParameters, Fq},
     templates::twisted_edwards_extended::GroupAffine,
 };
+use snarkos_dpc::base_dpc::{instantiated::Components, parameters::SystemParameters};
 use snarkos_errors::gadgets::SynthesisError;
 use snarkos_gadgets::curves::edwards_bls12::EdwardsBlsGadget;
 use snarkos_models::{
@@ -131,6 +132,14 @@ impl GroupType<Fq> for EdwardsGroupType {
             }
         }
     }
+
+    fn enforce_local_data<CS: ConstraintSystem<Fq>>(
+        cs: CS,
+        local_data: &LocalDataInput,
+        system_parameters: &SystemParameters<Components>,
+    ) -> Result<(), SynthesisError> {
+        local_data.enforce(cs, system_parameters)
+    }
 }
 
 impl EdwardsGroupType {
@@ -493,6 +502,11 @@ fn edwards_affine_one() -> GroupAffine<EdwardsParameters> {
     EdwardsAffine::new(x, y)
 }
 
+/// Returns the `(x, y)group` representation of an affine point that is accepted in input files.
+pub fn edwards_affine_to_string(affine: &EdwardsAffine) -> String {
+    format!("({}, {})group", field_to_decimal(&affine.x), field_to_decimal(&affine.y))
+}
+
 impl One for EdwardsGroupType {
     fn one() -> Self {
         let one = edwards_affine_one();
@@ -508,8 +522,13 @@ impl One for EdwardsGroupType {
 impl std::fmt::Display for EdwardsGroupType {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            EdwardsGroupType::Constant(constant) => write!(f, "{:?}", constant),
-            EdwardsGroupType::Allocated(allocated) => write!(f, "{:?}", allocated),
+            EdwardsGroupType::Constant(constant) => write!(f, "{}", edwards_affine_to_string(constant)),
+            EdwardsGroupType::Allocated(allocated) => {
+                match <EdwardsBlsGadget as GroupGadget<GroupAffine<EdwardsParameters>, Fq>>::get_value(allocated) {
+                    Some(value) => write!(f, "{}", edwards_affine_to_string(&value)),
+                    None => write!(f, "[allocated]"),
+                }
+            }
         }
     }
 }
@@ -27,7 +27,7 @@ use crate::{
     Integer,
 };
 use leo_core::Value;
-use leo_typed::{Circuit, Function, GroupValue, Identifier, Span, Type};
+use leo_typed::{Circuit, Enum, Function, GroupValue, Identifier, Span, Type};
 
 use snarkos_errors::gadgets::SynthesisError;
 use snarkos_models::{
@@ -42,6 +42,14 @@ use std::fmt;
 #[derive(Clone, PartialEq, Eq)]
 pub struct ConstrainedCircuitMember<F: Field + PrimeField, G: GroupType<F>>(pub Identifier, pub ConstrainedValue<F, G>);
 
+/// A single variant slot of an enum value.
+/// The payload is `None` if the enum value can never hold this variant.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ConstrainedEnumVariant<F: Field + PrimeField, G: GroupType<F>>(
+    pub Identifier,
+    pub Option<Vec<ConstrainedValue<F, G>>>,
+);
+
 #[derive(Clone, PartialEq, Eq)]
 pub enum ConstrainedValue<F: Field + PrimeField, G: GroupType<F>> {
     // Data types
@@ -61,6 +69,10 @@ pub enum ConstrainedValue<F: Field + PrimeField, G: GroupType<F>> {
     CircuitDefinition(Circuit),
     CircuitExpression(Identifier, Vec<ConstrainedCircuitMember<F, G>>),
 
+    // Enums
+    EnumDefinition(Enum),
+    EnumExpression(Identifier, Integer, Vec<ConstrainedEnumVariant<F, G>>), // (enum name, variant tag, variants)
+
     // Functions
     Function(Option<Identifier>, Function), // (optional circuit identifier, function definition)
 
@@ -132,6 +144,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
                 Type::Tuple(types)
             }
             ConstrainedValue::CircuitExpression(id, _members) => Type::Circuit(id.clone()),
+            ConstrainedValue::EnumExpression(id, _tag, _variants) => Type::Enum(id.clone()),
             ConstrainedValue::Mutable(value) => return value.to_type(span),
             value => return Err(ValueError::implicit(value.to_string(), span)),
         })
@@ -225,6 +238,22 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
         }
     }
 
+    pub(crate) fn extract_enum(self, span: Span) -> Result<Enum, ExpressionError> {
+        match self {
+            ConstrainedValue::EnumDefinition(enum_) => Ok(enum_),
+            ConstrainedValue::Import(_import_scope, enum_) => enum_.extract_enum(span),
+            value => Err(ExpressionError::undefined_enum(value.to_string(), span)),
+        }
+    }
+
+    pub(crate) fn is_enum_definition(&self) -> bool {
+        match self {
+            ConstrainedValue::EnumDefinition(_) => true,
+            ConstrainedValue::Import(_import_scope, value) => value.is_enum_definition(),
+            _ => false,
+        }
+    }
+
     pub(crate) fn get_inner_mut(&mut self) {
         if let ConstrainedValue::Mutable(inner) = self {
             *self = *inner.clone()
@@ -295,6 +324,29 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
                     })
                     .collect::<Result<(), ValueError>>()?;
             }
+            ConstrainedValue::EnumExpression(_id, tag, variants) => {
+                let option = tag.get_value();
+                let name = option.clone().unwrap_or(format!("[allocated]"));
+
+                *tag = Integer::allocate_type(
+                    &mut cs.ns(|| format!("allocate enum tag {}:{}", span.line, span.start)),
+                    tag.get_type(),
+                    name,
+                    option,
+                    span.clone(),
+                )?;
+
+                for (i, variant) in variants.iter_mut().enumerate() {
+                    if let Some(payload) = &mut variant.1 {
+                        for (j, value) in payload.iter_mut().enumerate() {
+                            let unique_name =
+                                format!("allocate enum variant {} value {} {}:{}", i, j, span.line, span.start);
+
+                            value.allocate_value(cs.ns(|| unique_name), span.clone())?;
+                        }
+                    }
+                }
+            }
             ConstrainedValue::Mutable(value) => {
                 value.allocate_value(cs, span)?;
             }
@@ -304,6 +356,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
 
             // Empty wrappers that are unreachable
             ConstrainedValue::CircuitDefinition(_) => {}
+            ConstrainedValue::EnumDefinition(_) => {}
             ConstrainedValue::Function(_, _) => {}
             ConstrainedValue::Import(_, _) => {}
 
@@ -330,8 +383,8 @@ impl<F: Field + PrimeField, G: GroupType<F>> fmt::Display for ConstrainedValue<F
                     .map(|v| v.to_string())
                     .unwrap_or(format!("[allocated]"))
             ),
-            ConstrainedValue::Field(ref value) => write!(f, "{:?}", value),
-            ConstrainedValue::Group(ref value) => write!(f, "{:?}", value),
+            ConstrainedValue::Field(ref value) => write!(f, "{}", value),
+            ConstrainedValue::Group(ref value) => write!(f, "{}", value),
             ConstrainedValue::Integer(ref value) => write!(f, "{}", value),
 
             // Data type wrappers
@@ -361,6 +414,17 @@ impl<F: Field + PrimeField, G: GroupType<F>> fmt::Display for ConstrainedValue<F
                 write!(f, "}}")
             }
             ConstrainedValue::CircuitDefinition(ref circuit) => write!(f, "circuit {{ {} }}", circuit.circuit_name),
+            ConstrainedValue::EnumExpression(ref identifier, ref tag, ref variants) => {
+                let variant = tag
+                    .get_value()
+                    .and_then(|tag| tag.parse::<usize>().ok())
+                    .and_then(|index| variants.get(index))
+                    .map(|variant| variant.0.to_string())
+                    .unwrap_or(format!("[allocated]"));
+
+                write!(f, "{}::{}", identifier, variant)
+            }
+            ConstrainedValue::EnumDefinition(ref enum_) => write!(f, "enum {{ {} }}", enum_.enum_name),
             ConstrainedValue::Function(ref _circuit_option, ref function) => {
                 write!(f, "function {{ {}() }}", function.identifier)
             }
@@ -495,6 +559,24 @@ impl<F: Field + PrimeField, G: GroupType<F>> CondSelectGadget<F> for Constrained
 
                 ConstrainedValue::CircuitExpression(identifier.clone(), members)
             }
+            (
+                ConstrainedValue::EnumExpression(identifier, tag_1, variants_1),
+                ConstrainedValue::EnumExpression(_identifier, tag_2, variants_2),
+            ) => {
+                let tag = Integer::conditionally_select(cs.ns(|| "enum tag"), cond, tag_1, tag_2)?;
+                let mut variants = vec![];
+
+                for (i, (first, second)) in variants_1.iter().zip(variants_2).enumerate() {
+                    variants.push(ConstrainedEnumVariant::conditionally_select(
+                        cs.ns(|| format!("enum variant[{}]", i)),
+                        cond,
+                        first,
+                        second,
+                    )?);
+                }
+
+                ConstrainedValue::EnumExpression(identifier.clone(), tag, variants)
+            }
             (ConstrainedValue::Static(first), ConstrainedValue::Static(second)) => {
                 let value = Self::conditionally_select(cs, cond, first, second)?;
 
@@ -529,6 +611,41 @@ impl<F: Field + PrimeField, G: GroupType<F>> CondSelectGadget<F> for Constrained
     }
 }
 
+impl<F: Field + PrimeField, G: GroupType<F>> CondSelectGadget<F> for ConstrainedEnumVariant<F, G> {
+    fn conditionally_select<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        cond: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        // A missing payload means that side can never hold this variant, so the other payload is kept as is
+        let payload = match (&first.1, &second.1) {
+            (Some(payload_1), Some(payload_2)) => {
+                let mut payload = vec![];
+
+                for (i, (first, second)) in payload_1.into_iter().zip(payload_2.into_iter()).enumerate() {
+                    payload.push(ConstrainedValue::conditionally_select(
+                        cs.ns(|| format!("variant value[{}]", i)),
+                        cond,
+                        first,
+                        second,
+                    )?);
+                }
+
+                Some(payload)
+            }
+            (Some(payload), None) | (None, Some(payload)) => Some(payload.clone()),
+            (None, None) => None,
+        };
+
+        Ok(ConstrainedEnumVariant(first.0.clone(), payload))
+    }
+
+    fn cost() -> usize {
+        unimplemented!()
+    }
+}
+
 impl<F: Field + PrimeField, G: GroupType<F>> From<Value> for ConstrainedValue<F, G> {
     fn from(v: Value) -> Self {
         match v {
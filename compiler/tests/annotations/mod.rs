@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::EdwardsTestCompiler;
+use leo_package::inputs::InputPairs;
+
+use std::{env, fs, path::PathBuf};
+
+#[test]
+fn test_annotations() {
+    let bytes = include_bytes!("annotations.leo");
+    let output_directory = env::temp_dir().join("leo-annotations").join("outputs");
+    let _ = fs::remove_dir_all(&output_directory);
+    fs::create_dir_all(&output_directory).unwrap();
+
+    let path = PathBuf::from("/test/src/main.leo");
+    let mut program = EdwardsTestCompiler::new("test".to_string(), path, output_directory);
+    program
+        .parse_program_from_string(&String::from_utf8_lossy(bytes))
+        .unwrap();
+
+    // `overflow`, `inline_inputs` and `failed_assertion` pass, `skipped` is not run, and
+    // `no_overflow`, `undefined_variable` and `malformed_inputs` fail without stopping the other tests
+    let (passed, failed) = program.compile_test_constraints(InputPairs::new()).unwrap();
+
+    assert_eq!((passed, failed), (3, 3));
+}
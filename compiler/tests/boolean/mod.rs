@@ -84,6 +84,19 @@ fn test_registers() {
     output_false(program);
 }
 
+#[test]
+fn test_registers_json() {
+    let program_bytes = include_bytes!("output_register.leo");
+    let input_bytes = include_bytes!("input/registers_true.in");
+
+    let program = parse_program_with_input(program_bytes, input_bytes).unwrap();
+
+    let expected = include_str!("output/registers_true.out.json");
+    let actual = get_output(program).to_json().unwrap();
+
+    assert_eq!(expected, actual);
+}
+
 // Boolean not !
 
 #[test]
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{import::set_local_dir, EdwardsTestCompiler};
+use leo_compiler::COMPILER_VERSION;
+
+use std::path::PathBuf;
+
+static TEST_MANIFEST_DIRECTORY: &str = "tests/build_manifest";
+static TEST_OUTPUT_DIRECTORY: &str = "/output/";
+
+fn source_path(file_name: &str) -> PathBuf {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(TEST_MANIFEST_DIRECTORY);
+    path.push(file_name);
+
+    path
+}
+
+fn compile_with_input(main_file: &str, input_bytes: &[u8]) -> EdwardsTestCompiler {
+    let input_string = String::from_utf8_lossy(input_bytes);
+
+    EdwardsTestCompiler::parse_program_with_input(
+        "test".to_string(),
+        source_path(main_file),
+        PathBuf::from(TEST_OUTPUT_DIRECTORY),
+        &input_string,
+        PathBuf::new(),
+        "",
+        PathBuf::new(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_manifest_contents() {
+    let program = compile_with_input("main.leo", include_bytes!("input/main.in"));
+    let manifest = program.build_manifest().unwrap();
+
+    assert_eq!(manifest.compiler_version, COMPILER_VERSION);
+    assert!(manifest.main_file.path.ends_with("main.leo"));
+    assert!(manifest.imports.is_empty());
+    assert!(manifest.core_packages.is_empty());
+    assert_eq!(manifest.inputs, vec![
+        "main.a: u32".to_string(),
+        "main.b: [u8; 3]".to_string(),
+        "registers.r0: u32".to_string(),
+    ]);
+
+    assert_eq!(program.checksum().unwrap(), manifest.checksum());
+}
+
+#[test]
+fn test_checksum_ignores_input_values() {
+    let program = compile_with_input("main.leo", include_bytes!("input/main.in"));
+    let other_values = compile_with_input("main.leo", include_bytes!("input/main_values.in"));
+
+    assert_eq!(program.checksum().unwrap(), other_values.checksum().unwrap());
+}
+
+#[test]
+fn test_checksum_input_shape() {
+    let program = compile_with_input("main.leo", include_bytes!("input/main.in"));
+    let other_shape = compile_with_input("main.leo", include_bytes!("input/main_shape.in"));
+
+    assert_ne!(program.checksum().unwrap(), other_shape.checksum().unwrap());
+}
+
+// Import tests rely on knowledge of local directories. They should be run locally only.
+
+#[test]
+#[ignore]
+fn test_manifest_imports() {
+    set_local_dir();
+
+    let program = EdwardsTestCompiler::parse_program_without_input(
+        "test".to_string(),
+        PathBuf::from("many_import.leo"),
+        PathBuf::from(TEST_OUTPUT_DIRECTORY),
+    )
+    .unwrap();
+    let manifest = program.build_manifest().unwrap();
+
+    assert!(!manifest.imports.is_empty());
+    assert!(manifest.imports.iter().all(|import| import.path.is_relative()));
+}
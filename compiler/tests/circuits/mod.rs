@@ -242,6 +242,41 @@ fn test_self_member_undefined() {
     let _err = expect_compiler_error(program);
 }
 
+// Const generics
+
+#[test]
+fn test_const_generic() {
+    let bytes = include_bytes!("const_generic.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_const_generic_initializer() {
+    let bytes = include_bytes!("const_generic_initializer.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_const_generic_static() {
+    let bytes = include_bytes!("const_generic_static.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_const_generic_mismatch_fail() {
+    let bytes = include_bytes!("const_generic_mismatch_fail.leo");
+    let program = parse_program(bytes).unwrap();
+    let error = expect_compiler_error(program);
+
+    assert_eq!(error.code(), "E0305");
+}
+
 // All
 
 #[test]
@@ -0,0 +1,42 @@
+use crate::EdwardsTestCompiler;
+use leo_compiler::CoverageFile;
+use leo_package::inputs::InputPairs;
+
+use std::{env, fs, path::PathBuf};
+
+#[test]
+fn test_coverage_lcov() {
+    let bytes = include_bytes!("coverage.leo");
+    let output_directory = env::temp_dir().join("leo-coverage").join("outputs");
+    let _ = fs::remove_dir_all(&output_directory);
+    fs::create_dir_all(&output_directory).unwrap();
+
+    let path = PathBuf::from("/test/src/main.leo");
+    let mut program = EdwardsTestCompiler::new("test".to_string(), path, output_directory.clone());
+    program
+        .parse_program_from_string(&String::from_utf8_lossy(bytes))
+        .unwrap();
+
+    let (passed, failed) = program.compile_test_constraints(InputPairs::new()).unwrap();
+
+    assert_eq!((passed, failed), (1, 0));
+    assert!(CoverageFile::new("test").exists_at(&output_directory));
+
+    // only the `else` branch of `abs` is taken
+    let lcov = fs::read_to_string(output_directory.join("test.lcov")).unwrap();
+    let expected = "TN:test\n\
+        SF:/test/src/main.leo\n\
+        BRDA:2,0,0,-\n\
+        BRDA:2,0,1,1\n\
+        BRF:2\n\
+        BRH:1\n\
+        DA:2,1\n\
+        DA:3,0\n\
+        DA:5,1\n\
+        DA:11,1\n\
+        LF:4\n\
+        LH:3\n\
+        end_of_record\n";
+
+    assert_eq!(lcov, expected);
+}
@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_satisfied, expect_compiler_error, generate_main_input, parse_program};
+use leo_typed::InputValue;
+
+#[test]
+fn test_unit() {
+    let bytes = include_bytes!("unit.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_payload() {
+    let bytes = include_bytes!("payload.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_types() {
+    let bytes = include_bytes!("types.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_annotated() {
+    let bytes = include_bytes!("annotated.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_variant_undefined() {
+    let bytes = include_bytes!("variant_undefined.leo");
+    let program = parse_program(bytes).unwrap();
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_variant_arguments_fail() {
+    let bytes = include_bytes!("variant_arguments_fail.leo");
+    let program = parse_program(bytes).unwrap();
+
+    expect_compiler_error(program);
+}
+
+// Match
+
+#[test]
+fn test_match_expression() {
+    let bytes = include_bytes!("match_expression.leo");
+
+    for b in vec![true, false] {
+        let mut program = parse_program(bytes).unwrap();
+        let main_input = generate_main_input(vec![("b", Some(InputValue::Boolean(b)))]);
+
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_match_statement() {
+    let bytes = include_bytes!("match_statement.leo");
+
+    for b in vec![true, false] {
+        let mut program = parse_program(bytes).unwrap();
+        let main_input = generate_main_input(vec![("b", Some(InputValue::Boolean(b)))]);
+
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_match_expression_shadowing() {
+    let bytes = include_bytes!("match_expression_shadowing.leo");
+
+    for b in vec![true, false] {
+        let mut program = parse_program(bytes).unwrap();
+        let main_input = generate_main_input(vec![("b", Some(InputValue::Boolean(b)))]);
+
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_match_statement_shadowing() {
+    let bytes = include_bytes!("match_statement_shadowing.leo");
+
+    for b in vec![true, false] {
+        let mut program = parse_program(bytes).unwrap();
+        let main_input = generate_main_input(vec![("b", Some(InputValue::Boolean(b)))]);
+
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_match_binding_out_of_scope() {
+    let bytes = include_bytes!("match_binding_out_of_scope.leo");
+    let program = parse_program(bytes).unwrap();
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_match_wildcard() {
+    let bytes = include_bytes!("match_wildcard.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_match_non_exhaustive() {
+    let bytes = include_bytes!("match_non_exhaustive.leo");
+    let program = parse_program(bytes).unwrap();
+
+    expect_compiler_error(program);
+}
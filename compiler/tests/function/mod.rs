@@ -175,3 +175,63 @@ fn test_return_array_tuple_pass() {
 
     assert_satisfied(program);
 }
+
+// Const generics
+
+#[test]
+fn test_const_generic() {
+    let bytes = include_bytes!("const_generic.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_const_generic_return() {
+    let bytes = include_bytes!("const_generic_return.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_const_generic_initializer() {
+    let bytes = include_bytes!("const_generic_initializer.leo");
+    let program = parse_program(bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_const_generic_initializer_fail() {
+    let bytes = include_bytes!("const_generic_initializer_fail.leo");
+    let program = parse_program(bytes).unwrap();
+    let error = expect_compiler_error(program);
+
+    assert_eq!(error.code(), "E0332");
+}
+
+#[test]
+fn test_const_generic_mismatch_fail() {
+    let bytes = include_bytes!("const_generic_mismatch_fail.leo");
+    let program = parse_program(bytes).unwrap();
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_const_generic_mismatch_unused_fail() {
+    let bytes = include_bytes!("const_generic_mismatch_unused_fail.leo");
+    let program = parse_program(bytes).unwrap();
+    let error = expect_compiler_error(program);
+
+    assert_eq!(error.code(), "E0221");
+}
+
+#[test]
+fn test_const_generic_main_fail() {
+    let bytes = include_bytes!("const_generic_main_fail.leo");
+    let program = parse_program(bytes).unwrap();
+
+    expect_compiler_error(program);
+}
@@ -0,0 +1,44 @@
+use crate::EdwardsTestCompiler;
+use leo_package::inputs::InputPairs;
+
+use std::{env, fs, path::PathBuf};
+
+fn fuzz_program(bytes: &[u8], output_directory: &PathBuf) -> EdwardsTestCompiler {
+    let _ = fs::remove_dir_all(output_directory);
+    fs::create_dir_all(output_directory).unwrap();
+
+    let path = PathBuf::from("/test/src/main.leo");
+    let mut program = EdwardsTestCompiler::new("test".to_string(), path, output_directory.clone());
+    program
+        .parse_program_from_string(&String::from_utf8_lossy(bytes))
+        .unwrap();
+
+    program
+}
+
+#[test]
+fn test_fuzz_pass() {
+    let bytes = include_bytes!("pass.leo");
+    let output_directory = env::temp_dir().join("leo-fuzz-pass").join("outputs");
+    let program = fuzz_program(bytes, &output_directory);
+
+    let (passed, failed) = program.compile_test_constraints(InputPairs::new()).unwrap();
+
+    assert_eq!((passed, failed), (1, 0));
+}
+
+#[test]
+fn test_fuzz_fail_shrinks_input() {
+    let bytes = include_bytes!("fail.leo");
+    let output_directory = env::temp_dir().join("leo-fuzz-fail").join("outputs");
+    let program = fuzz_program(bytes, &output_directory);
+
+    let (passed, failed) = program.compile_test_constraints(InputPairs::new()).unwrap();
+
+    assert_eq!((passed, failed), (0, 1));
+
+    // the failing case is shrunk to the smallest failing value
+    let input = fs::read_to_string(output_directory.join("test_small.in")).unwrap();
+
+    assert!(input.contains("[main]\na: u8 = 200;\nb: bool = false;\n"));
+}
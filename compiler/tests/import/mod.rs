@@ -15,8 +15,14 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{assert_satisfied, parse_program};
+use leo_ast::LeoAst;
+use leo_compiler::{errors::ImportError, ImportParser};
+use leo_typed::Program;
 
-use std::env::{current_dir, set_current_dir};
+use std::{
+    env::{current_dir, set_current_dir},
+    path::PathBuf,
+};
 
 static TEST_SOURCE_DIRECTORY: &str = "tests/import";
 
@@ -152,3 +158,47 @@ fn test_many_import_star() {
 
     assert_satisfied(program);
 }
+
+// dependency graph tests
+
+/// Parses the imports of a program in the `graph` package, which has a lock file.
+fn parse_graph_imports(bytes: &[u8]) -> Result<ImportParser, ImportError> {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(TEST_SOURCE_DIRECTORY);
+    path.push("graph");
+
+    let main_file_path = path.join("src/main.leo");
+    let program_string = String::from_utf8_lossy(bytes);
+    let ast = LeoAst::new(&main_file_path, &program_string).unwrap();
+    let program = Program::from("graph", ast.as_repr());
+
+    ImportParser::parse_at(&program, path)
+}
+
+#[test]
+fn test_graph_dependency() {
+    let bytes = include_bytes!("graph_dependency.leo");
+    let imports = parse_graph_imports(bytes).unwrap();
+
+    // `math` is imported by `geometry` from the flat imports directory.
+    assert!(imports.get_import(&"geometry".to_string()).is_some());
+    assert!(imports.get_import(&"math".to_string()).is_some());
+}
+
+#[test]
+fn test_graph_transitive_fail() {
+    // `math` is a dependency of `geometry`, but not of the package itself.
+    let bytes = include_bytes!("graph_transitive_fail.leo");
+    let error = parse_graph_imports(bytes).err().unwrap();
+
+    assert_eq!(error.code(), "E0238");
+}
+
+#[test]
+fn test_graph_undeclared_fail() {
+    // `extra` is in the imports directory, but not in the dependency graph.
+    let bytes = include_bytes!("graph_undeclared_fail.leo");
+    let error = parse_graph_imports(bytes).err().unwrap();
+
+    assert_eq!(error.code(), "E0238");
+}
@@ -17,3 +17,4 @@
 mod program_input;
 mod program_input_and_program_state;
 mod program_state;
+mod signature;
@@ -17,6 +17,8 @@
 use crate::{assert_satisfied, expect_compiler_error, parse_program_with_input, EdwardsTestCompiler};
 use leo_compiler::errors::CompilerError;
 
+use std::path::PathBuf;
+
 fn expect_fail(program: EdwardsTestCompiler) {
     match expect_compiler_error(program) {
         CompilerError::FunctionError(_) => {}
@@ -63,3 +65,63 @@ fn test_input_multiple() {
 
     assert_satisfied(program);
 }
+
+#[test]
+fn test_with_input() {
+    let program_bytes = include_bytes!("main.leo");
+    let input_bytes = include_bytes!("input/main_fail_type.in");
+
+    let program = parse_program_with_input(program_bytes, input_bytes).unwrap();
+
+    // The new input replaces the input of the program instead of adding to it
+    let input_string = String::from_utf8_lossy(include_bytes!("input/main.in"));
+    let program_with_input = program
+        .with_input("main", &input_string, PathBuf::new(), "", PathBuf::new())
+        .unwrap();
+
+    assert_satisfied(program_with_input);
+    expect_fail(program);
+}
+
+#[test]
+fn test_input_circuit() {
+    let program_bytes = include_bytes!("main_circuit.leo");
+    let input_bytes = include_bytes!("input/main_circuit.in");
+
+    let program = parse_program_with_input(program_bytes, input_bytes).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_input_circuit_fail_member() {
+    let program_bytes = include_bytes!("main_circuit.leo");
+    let input_bytes = include_bytes!("input/main_circuit_fail_member.in");
+
+    let program = parse_program_with_input(program_bytes, input_bytes).unwrap();
+
+    expect_fail(program);
+}
+
+#[test]
+fn test_input_circuit_fail_type() {
+    let program_bytes = include_bytes!("main_circuit.leo");
+    let input_bytes = include_bytes!("input/main_circuit_fail_type.in");
+
+    let program = parse_program_with_input(program_bytes, input_bytes).unwrap();
+
+    expect_fail(program);
+}
+
+#[test]
+fn test_input_circuit_fail_implicit() {
+    let program_bytes = include_bytes!("main_circuit.leo");
+    let input_bytes = include_bytes!("input/main_circuit_fail_implicit.in");
+
+    let error = parse_program_with_input(program_bytes, input_bytes).err().unwrap();
+
+    match error {
+        CompilerError::InputParserError(_) => {}
+        err => panic!("expected input parser error, got {:?}", err),
+    }
+}
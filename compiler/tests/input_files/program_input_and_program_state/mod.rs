@@ -15,6 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{assert_satisfied, parse_input_and_state, parse_program_with_input_and_state};
+use leo_input::files::JsonFile;
 
 #[test]
 fn test_basic() {
@@ -42,3 +43,35 @@ fn test_access() {
 
     assert_satisfied(program);
 }
+
+#[test]
+fn test_access_json() {
+    let program_bytes = include_bytes!("access.leo");
+    let json = JsonFile::parse(include_str!("input/token_withdraw.json")).unwrap();
+
+    let input_string = json.input_file_string().unwrap();
+    let state_string = json.state_file_string().unwrap();
+
+    let program =
+        parse_program_with_input_and_state(program_bytes, input_string.as_bytes(), state_string.as_bytes()).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_access_toml() {
+    let program_bytes = include_bytes!("access.leo");
+    let toml = JsonFile::parse_toml(include_str!("input/token_withdraw.toml")).unwrap();
+
+    // A TOML input file gives the same input and state files as the equivalent JSON input file
+    let json = JsonFile::parse(include_str!("input/token_withdraw.json")).unwrap();
+    assert_eq!(toml, json);
+
+    let input_string = toml.input_file_string().unwrap();
+    let state_string = toml.state_file_string().unwrap();
+
+    let program =
+        parse_program_with_input_and_state(program_bytes, input_string.as_bytes(), state_string.as_bytes()).unwrap();
+
+    assert_satisfied(program);
+}
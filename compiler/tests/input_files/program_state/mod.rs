@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, parse_program_with_state, parse_state};
+use crate::{assert_satisfied, expect_compiler_error, expect_synthesis_error, parse_program_with_state, parse_state};
 
 #[test]
 fn test_basic() {
@@ -50,6 +50,74 @@ fn test_access_all() {
     assert_satisfied(program);
 }
 
+#[test]
+fn test_local_data_input_record() {
+    let program_bytes = include_bytes!("local_data.leo");
+    let state_bytes = include_bytes!("input/local_data_input_record.state");
+
+    let mut program = parse_program_with_state(program_bytes, state_bytes).unwrap();
+    program.enforce_local_data();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_local_data_output_record() {
+    let program_bytes = include_bytes!("local_data.leo");
+    let state_bytes = include_bytes!("input/local_data_output_record.state");
+
+    let mut program = parse_program_with_state(program_bytes, state_bytes).unwrap();
+    program.enforce_local_data();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_local_data_without_input() {
+    let program_bytes = include_bytes!("local_data_without_input.leo");
+    let state_bytes = include_bytes!("input/local_data_output_record.state");
+
+    let mut program = parse_program_with_state(program_bytes, state_bytes).unwrap();
+    program.enforce_local_data();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_local_data_invalid() {
+    let program_bytes = include_bytes!("local_data.leo");
+    let state_bytes = include_bytes!("input/local_data_invalid.state");
+
+    let mut program = parse_program_with_state(program_bytes, state_bytes).unwrap();
+    program.enforce_local_data();
+
+    expect_synthesis_error(program);
+}
+
+#[test]
+fn test_local_data_invalid_path_length() {
+    let program_bytes = include_bytes!("local_data.leo");
+    let state_bytes = include_bytes!("input/local_data_invalid_path.state");
+
+    let mut program = parse_program_with_state(program_bytes, state_bytes).unwrap();
+    program.enforce_local_data();
+
+    let error = expect_compiler_error(program);
+    assert!(error.to_string().contains("state_leaf.path` to be a [u8; 128]"));
+}
+
+#[test]
+fn test_local_data_invalid_commitment_length() {
+    let program_bytes = include_bytes!("local_data.leo");
+    let state_bytes = include_bytes!("input/local_data_invalid_commitment.state");
+
+    let mut program = parse_program_with_state(program_bytes, state_bytes).unwrap();
+    program.enforce_local_data();
+
+    let error = expect_compiler_error(program);
+    assert!(error.to_string().contains("record.commitment` to be a [u8; 32]"));
+}
+
 #[test]
 fn test_visibility_fail() {
     let state_bytes = include_bytes!("input/visibility_fail.state");
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{get_output, parse_program, parse_program_with_input, parse_program_with_input_and_state};
+use leo_package::inputs::StateFile;
+
+use std::path::PathBuf;
+
+#[test]
+fn test_input_template() {
+    let program_bytes = include_bytes!("main.leo");
+    let expected = include_str!("input/template.in");
+
+    let program = parse_program(program_bytes).unwrap();
+    let template = program.main_signature().unwrap().input_template("test");
+
+    assert_eq!(template, expected);
+
+    // The template is a valid input file for the program
+    let program = parse_program_with_input(program_bytes, template.as_bytes()).unwrap();
+    let output = get_output(program);
+
+    let expected_output = b"[registers]\nr0: bool = true;\nr1: field = 0;\n";
+
+    assert_eq!(output.bytes().as_slice(), expected_output);
+}
+
+#[test]
+fn test_state_template() {
+    let program_bytes = include_bytes!("main.leo");
+    let input_bytes = include_bytes!("input/template.in");
+
+    let program = parse_program(program_bytes).unwrap();
+    let template = program.main_signature().unwrap().state_template("test").unwrap();
+
+    assert_eq!(template, StateFile::new("test").template());
+
+    // The template is a valid state file for the program
+    let program = parse_program_with_input_and_state(program_bytes, input_bytes, template.as_bytes()).unwrap();
+    get_output(program);
+
+    // There is no state template if `main` does not take `input`
+    let program = parse_program(b"function main(a: u32) {}").unwrap();
+
+    assert!(program.main_signature().unwrap().state_template("test").is_none());
+}
+
+#[test]
+fn test_check_input_file() {
+    let program_bytes = include_bytes!("main.leo");
+    let input_string = include_str!("input/main.in");
+
+    let program = parse_program(program_bytes).unwrap();
+    let errors = program.check_input_file(input_string, &PathBuf::new()).unwrap();
+
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn test_check_input_file_mismatch() {
+    let program_bytes = include_bytes!("main.leo");
+    let input_string = include_str!("input/main_mismatch.in");
+
+    let program = parse_program(program_bytes).unwrap();
+    let errors = program
+        .check_input_file(input_string, &PathBuf::from("inputs/main_mismatch.in"))
+        .unwrap();
+    let codes = errors.iter().map(|error| error.code()).collect::<Vec<_>>();
+
+    // `a` is mistyped, `b` is missing, and `c` is extra
+    assert_eq!(codes, vec!["E0243", "E0228", "E0242"]);
+
+    // Every error points to both the input file and the main file
+    let mistyped = errors[0].to_string();
+    let missing = errors[1].to_string();
+
+    assert!(mistyped.contains("inputs/main_mismatch.in"));
+    assert!(mistyped.contains("note: `a: u32` declared here at /test/src/main.leo:6:22"));
+    assert!(missing.contains("/test/src/main.leo"));
+    assert!(missing.contains("note: add it to this `[main]` section at inputs/main_mismatch.in:1:2"));
+}
@@ -15,19 +15,25 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod address;
+pub mod annotations;
 pub mod array;
 pub mod boolean;
+pub mod build_manifest;
 pub mod circuits;
 pub mod console;
+pub mod coverage;
 pub mod core;
 pub mod definition;
+pub mod enums;
 // pub mod field;
 pub mod function;
+pub mod fuzz;
 // pub mod group;
 pub mod import;
 pub mod input_files;
 pub mod integers;
 pub mod mutability;
+pub mod optimizer;
 pub mod statements;
 pub mod syntax;
 pub mod tuples;
@@ -155,6 +161,9 @@ pub fn parse_program_with_input_and_state(
 }
 
 pub(crate) fn get_output(program: EdwardsTestCompiler) -> OutputBytes {
+    // run the program natively as a differential oracle for the circuit
+    let interpreted_output = program.interpret().unwrap();
+
     // synthesize the circuit on the test constraint system
     let mut cs = TestConstraintSystem::<Fq>::new();
     let output = program.generate_constraints_helper(&mut cs).unwrap();
@@ -162,6 +171,9 @@ pub(crate) fn get_output(program: EdwardsTestCompiler) -> OutputBytes {
     // assert the constraint system is satisfied
     assert!(cs.is_satisfied());
 
+    // assert the interpreter agrees with the circuit
+    assert_eq!(interpreted_output, output);
+
     output
 }
 
@@ -178,12 +190,12 @@ pub(crate) fn expect_compiler_error(program: EdwardsTestCompiler) -> CompilerErr
     program.generate_constraints_helper(&mut cs).unwrap_err()
 }
 
-// pub(crate) fn expect_synthesis_error(program: EdwardsTestCompiler) {
-//     let mut cs = TestConstraintSystem::<Fq>::new();
-//     let _output = program.generate_constraints_helper(&mut cs).unwrap();
-//
-//     assert!(!cs.is_satisfied());
-// }
+pub(crate) fn expect_synthesis_error(program: EdwardsTestCompiler) {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let _output = program.generate_constraints_helper(&mut cs).unwrap();
+
+    assert!(!cs.is_satisfied());
+}
 
 pub(crate) fn generate_main_input(input: Vec<(&str, Option<InputValue>)>) -> MainInput {
     let mut main_input = MainInput::new();
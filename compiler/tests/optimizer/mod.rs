@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{generate_main_input, generate_test_input_u32, parse_program, EdwardsTestCompiler};
+
+use snarkos_curves::edwards_bls12::Fq;
+use snarkos_models::gadgets::r1cs::TestConstraintSystem;
+
+fn num_constraints(program: EdwardsTestCompiler) -> usize {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let _output = program.generate_constraints_helper(&mut cs).unwrap();
+
+    // assert the constraint system is satisfied
+    assert!(cs.is_satisfied());
+
+    cs.num_constraints()
+}
+
+/// Asserts that the optimized program is satisfied with fewer constraints than the original program.
+fn assert_optimized(bytes: &[u8], inputs: Vec<&str>) {
+    let main_input = generate_main_input(
+        inputs
+            .into_iter()
+            .map(|name| (name, generate_test_input_u32(3)))
+            .collect(),
+    );
+
+    let mut program = parse_program(bytes).unwrap();
+    program.set_main_input(main_input);
+
+    let original_constraints = num_constraints(program.clone());
+
+    program.optimize();
+
+    let optimized_constraints = num_constraints(program);
+
+    assert!(optimized_constraints < original_constraints);
+}
+
+#[test]
+fn test_dead_branch() {
+    let bytes = include_bytes!("dead_branch.leo");
+
+    assert_optimized(bytes, vec!["a", "b"]);
+}
+
+#[test]
+fn test_constant_folding() {
+    let bytes = include_bytes!("constant_folding.leo");
+
+    assert_optimized(bytes, vec!["a"]);
+}
+
+#[test]
+fn test_ternary() {
+    let bytes = include_bytes!("ternary.leo");
+
+    assert_optimized(bytes, vec!["a", "b"]);
+}
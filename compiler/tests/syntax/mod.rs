@@ -33,6 +33,17 @@ fn test_semicolon() {
     }
 }
 
+#[test]
+fn test_multiple_errors() {
+    let bytes = include_bytes!("multiple_errors.leo");
+    let error = parse_program(bytes).err().unwrap();
+
+    match error {
+        CompilerError::ParserError(ParserError::SyntaxErrors(errors)) => assert_eq!(errors.len(), 2),
+        _ => panic!("test_multiple_errors should report every syntax error"),
+    }
+}
+
 #[test]
 fn test_undefined() {
     let bytes = include_bytes!("undefined.leo");
@@ -52,7 +63,7 @@ fn test_undefined() {
                     "   2 |      return a",
                     "     |             ^",
                     "     |",
-                    "     = cannot find value `a` in this scope",
+                    "     = [E0325] cannot find value `a` in this scope",
                 ]
                 .join("\n")
             );
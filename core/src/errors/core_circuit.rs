@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::Value;
-use leo_typed::{Error as FormattedError, Span};
+use leo_typed::{Diagnostic, Error as FormattedError, Span};
 
 use snarkos_errors::gadgets::SynthesisError;
 
@@ -27,21 +27,35 @@ pub enum CoreCircuitError {
     Error(#[from] FormattedError),
 }
 
+impl From<&CoreCircuitError> for Diagnostic {
+    fn from(error: &CoreCircuitError) -> Self {
+        match error {
+            CoreCircuitError::Error(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl CoreCircuitError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            CoreCircuitError::Error(error) => error.code,
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             CoreCircuitError::Error(error) => error.set_path(path),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        CoreCircuitError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        CoreCircuitError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn arguments_length(expected: usize, actual: usize, span: Span) -> Self {
         let message = format!("Core circuit expected {} arguments, found {}", expected, actual);
 
-        CoreCircuitError::new_from_span(message, span)
+        CoreCircuitError::new_from_span("E0560", message, span)
     }
 
     pub fn array_length(expected: usize, actual: usize, span: Span) -> Self {
@@ -50,7 +64,7 @@ impl CoreCircuitError {
             expected, actual
         );
 
-        CoreCircuitError::new_from_span(message, span)
+        CoreCircuitError::new_from_span("E0561", message, span)
     }
 
     pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -59,13 +73,13 @@ impl CoreCircuitError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0562", message, span)
     }
 
     pub fn invalid_array(actual: Value, span: Span) -> Self {
         let message = format!("Core circuit expected an array argument, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0563", message, span)
     }
 
     pub fn invalid_array_bytes(actual: Value, span: Span) -> Self {
@@ -74,6 +88,6 @@ impl CoreCircuitError {
             actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0564", message, span)
     }
 }
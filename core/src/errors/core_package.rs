@@ -13,7 +13,7 @@
 
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
-use leo_typed::{Error as FormattedError, Span};
+use leo_typed::{Diagnostic, Error as FormattedError, Span};
 
 use std::path::PathBuf;
 
@@ -23,32 +23,46 @@ pub enum CorePackageError {
     Error(#[from] FormattedError),
 }
 
+impl From<&CorePackageError> for Diagnostic {
+    fn from(error: &CorePackageError) -> Self {
+        match error {
+            CorePackageError::Error(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl CorePackageError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            CorePackageError::Error(error) => error.code,
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             CorePackageError::Error(error) => error.set_path(path),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        CorePackageError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        CorePackageError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn core_package_star(span: Span) -> Self {
         let message = format!("Cannot import star from leo-core");
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0570", message, span)
     }
 
     pub fn undefined_core_circuit(name: String, span: Span) -> Self {
         let message = format!("Core circuit `{}` not found in leo-core", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0571", message, span)
     }
 
     pub fn undefined_unstable_core_circuit(name: String, span: Span) -> Self {
         let message = format!("Unstable core circuit `{}` not found in leo-core", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0572", message, span)
     }
 }
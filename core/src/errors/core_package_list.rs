@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_typed::{Error as FormattedError, ImportSymbol, Span};
+use leo_typed::{Diagnostic, Error as FormattedError, ImportSymbol, Span};
 
 use crate::CorePackageError;
 use std::path::PathBuf;
@@ -28,7 +28,23 @@ pub enum CorePackageListError {
     Error(#[from] FormattedError),
 }
 
+impl From<&CorePackageListError> for Diagnostic {
+    fn from(error: &CorePackageListError) -> Self {
+        match error {
+            CorePackageListError::CorePackageError(error) => Diagnostic::from(error),
+            CorePackageListError::Error(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl CorePackageListError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            CorePackageListError::CorePackageError(error) => error.code(),
+            CorePackageListError::Error(error) => error.code,
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             CorePackageListError::CorePackageError(error) => error.set_path(path),
@@ -36,20 +52,20 @@ impl CorePackageListError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        CorePackageListError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        CorePackageListError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn invalid_core_package(symbol: ImportSymbol) -> Self {
         let message = format!("No package `{}` in leo-core", symbol);
         let span = symbol.span;
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0580", message, span)
     }
 
     pub fn core_package_star(span: Span) -> Self {
         let message = format!("Cannot import star from leo-core");
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0581", message, span)
     }
 }
@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{CoreCircuitError, CorePackageListError};
-use leo_typed::{Error as FormattedError, Span};
+use leo_typed::{Diagnostic, Error as FormattedError, Span};
 
 use std::path::PathBuf;
 
@@ -31,7 +31,25 @@ pub enum LeoCoreError {
     Error(#[from] FormattedError),
 }
 
+impl From<&LeoCoreError> for Diagnostic {
+    fn from(error: &LeoCoreError) -> Self {
+        match error {
+            LeoCoreError::CoreCircuitError(error) => Diagnostic::from(error),
+            LeoCoreError::CorePackageListError(error) => Diagnostic::from(error),
+            LeoCoreError::Error(error) => Diagnostic::from(error),
+        }
+    }
+}
+
 impl LeoCoreError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            LeoCoreError::CoreCircuitError(error) => error.code(),
+            LeoCoreError::CorePackageListError(error) => error.code(),
+            LeoCoreError::Error(error) => error.code,
+        }
+    }
+
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
             LeoCoreError::CoreCircuitError(error) => error.set_path(path),
@@ -40,13 +58,13 @@ impl LeoCoreError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        LeoCoreError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        LeoCoreError::Error(FormattedError::new_from_span(code, message, span))
     }
 
     pub fn undefined_core_circuit(circuit_name: String, span: Span) -> Self {
         let message = format!("Core circuit `{}` not found in `leo-core`", circuit_name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0550", message, span)
     }
 }
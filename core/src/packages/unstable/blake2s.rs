@@ -59,6 +59,7 @@ impl CoreCircuit for Blake2sCircuit {
     fn ast(circuit_name: Identifier, span: Span) -> Circuit {
         Circuit {
             circuit_name,
+            const_parameters: vec![],
             members: vec![CircuitMember::CircuitFunction(
                 true, // static function
                 Function {
@@ -66,6 +67,7 @@ impl CoreCircuit for Blake2sCircuit {
                         name: "hash".to_owned(),
                         span: span.clone(),
                     },
+                    const_parameters: vec![],
                     input: vec![
                         InputVariable::FunctionInput(FunctionInput {
                             identifier: Identifier {
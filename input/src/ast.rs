@@ -25,6 +25,14 @@ pub fn parse(input: &str) -> Result<Pairs<Rule>, Error<Rule>> {
     LanguageParser::parse(Rule::file, input)
 }
 
+pub fn parse_json_type(input: &str) -> Result<Pairs<Rule>, Error<Rule>> {
+    LanguageParser::parse(Rule::json_type, input)
+}
+
+pub fn parse_json_identifier(input: &str) -> Result<Pairs<Rule>, Error<Rule>> {
+    LanguageParser::parse(Rule::json_identifier, input)
+}
+
 pub fn span_into_string(span: Span) -> String {
     span.as_str().to_string()
 }
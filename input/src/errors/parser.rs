@@ -16,6 +16,7 @@
 
 use crate::{
     ast::Rule,
+    common::Identifier,
     errors::SyntaxError as InputSyntaxError,
     expressions::{ArrayInlineExpression, Expression},
     sections::Header,
@@ -28,16 +29,26 @@ use pest::{
     error::{Error, ErrorVariant},
     Span,
 };
+use serde_json::Value as JsonValue;
 use std::{num::ParseIntError, path::PathBuf, str::ParseBoolError};
 
 #[derive(Debug, Error)]
 pub enum InputParserError {
+    #[error("{}", _1)]
+    Error(&'static str, InputSyntaxError),
+
     #[error("Program input value {} not found", _0)]
     InputNotFound(String),
 
     #[error("Cannot read from the provided file path - {:?}", _0)]
     FileReadError(PathBuf),
 
+    #[error("Invalid JSON input file - {}", _0)]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("Invalid JSON input value for `{}` - {}", _0, _1)]
+    JsonValueError(String, String),
+
     #[error("{}", _0)]
     ParseIntError(#[from] ParseIntError),
 
@@ -47,6 +58,9 @@ pub enum InputParserError {
     #[error("{}", _0)]
     SyntaxError(#[from] InputSyntaxError),
 
+    #[error("Invalid TOML input file - {}", _0)]
+    TomlError(#[from] toml::de::Error),
+
     #[error("Unable to construct program input abstract syntax tree")]
     SyntaxTreeError,
 }
@@ -54,7 +68,7 @@ pub enum InputParserError {
 impl InputParserError {
     pub fn set_path(&mut self, path: PathBuf) {
         match self {
-            InputParserError::SyntaxError(error) => {
+            InputParserError::Error(_, error) | InputParserError::SyntaxError(error) => {
                 let new_error: Error<Rule> = match error {
                     InputSyntaxError::Error(error) => {
                         let new_error = error.clone();
@@ -70,22 +84,62 @@ impl InputParserError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
+    pub fn code(&self) -> &'static str {
+        match self {
+            InputParserError::Error(code, _) => code,
+            InputParserError::InputNotFound(_) => "E0102",
+            InputParserError::FileReadError(_) => "E0103",
+            InputParserError::JsonError(_) => "E0107",
+            InputParserError::JsonValueError(_, _) => "E0108",
+            InputParserError::ParseIntError(_) => "E0104",
+            InputParserError::ParseBoolError(_) => "E0105",
+            InputParserError::SyntaxError(_) => "E0101",
+            InputParserError::TomlError(_) => "E0109",
+            InputParserError::SyntaxTreeError => "E0106",
+        }
+    }
+
+    pub fn json_value(name: &str, expected: &str, found: &JsonValue) -> Self {
+        let message = format!("expected {}, found `{}`", expected, found);
+
+        InputParserError::JsonValueError(name.to_owned(), message)
+    }
+
+    pub fn json_type(name: &str, type_: &str) -> Self {
+        let message = format!("`{}` is not a valid type", type_);
+
+        InputParserError::JsonValueError(name.to_owned(), message)
+    }
+
+    pub fn json_identifier(name: &str) -> Self {
+        let message = "expected a valid identifier".to_string();
+
+        InputParserError::JsonValueError(name.to_owned(), message)
+    }
+
+    pub fn json_length(name: &str, expected: usize, found: usize) -> Self {
+        let message = format!("expected {} elements, found {}", expected, found);
+
+        InputParserError::JsonValueError(name.to_owned(), message)
+    }
+
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        let message = format!("[{}] {}", code, message);
         let error = Error::new_from_span(ErrorVariant::CustomError { message }, span);
 
-        InputParserError::SyntaxError(InputSyntaxError::from(error))
+        InputParserError::Error(code, InputSyntaxError::from(error))
     }
 
     pub fn implicit_type(data_type: DataType, implicit: NumberValue) -> Self {
         let message = format!("expected `{}`, found `{}`", data_type.to_string(), implicit.to_string());
 
-        Self::new_from_span(message, implicit.span().clone())
+        Self::new_from_span("E0110", message, implicit.span().clone())
     }
 
     pub fn implicit_group(number: NumberValue) -> Self {
         let message = format!("group coordinates should be in (x, y)group format, found `{}`", number);
 
-        Self::new_from_span(message, number.span().clone())
+        Self::new_from_span("E0111", message, number.span().clone())
     }
 
     pub fn data_type_mismatch(data_type: DataType, value: Value) -> Self {
@@ -96,7 +150,7 @@ impl InputParserError {
         );
         let span = value.span().to_owned();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0112", message, span)
     }
 
     pub fn expression_type_mismatch(type_: Type, expression: Expression) -> Self {
@@ -107,7 +161,7 @@ impl InputParserError {
         );
         let span = expression.span().to_owned();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0113", message, span)
     }
 
     pub fn array_inline_length(number: usize, array: ArrayInlineExpression) -> Self {
@@ -118,7 +172,7 @@ impl InputParserError {
         );
         let span = array.span.to_owned();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0114", message, span)
     }
 
     pub fn array_init_length(expected: Vec<usize>, actual: Vec<usize>, span: Span) -> Self {
@@ -127,28 +181,28 @@ impl InputParserError {
             expected, actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0115", message, span)
     }
 
     pub fn input_section_header(header: Header) -> Self {
         let message = format!("the section header `{}` is not valid in an input `.in` file", header);
         let span = header.span();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0116", message, span)
     }
 
     pub fn public_section(header: Header) -> Self {
         let message = format!("the section header `{}` is not a public section", header);
         let span = header.span();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0117", message, span)
     }
 
     pub fn private_section(header: Header) -> Self {
         let message = format!("the section header `{}` is not a private section", header);
         let span = header.span();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0118", message, span)
     }
 
     pub fn table(table: Table) -> Self {
@@ -157,7 +211,7 @@ impl InputParserError {
             table
         );
 
-        Self::new_from_span(message, table.span)
+        Self::new_from_span("E0119", message, table.span)
     }
 
     pub fn tuple_length(expected: usize, actual: usize, span: Span) -> Self {
@@ -166,7 +220,7 @@ impl InputParserError {
             expected, actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0120", message, span)
     }
 
     pub fn section(header: Header) -> Self {
@@ -176,7 +230,22 @@ impl InputParserError {
         );
         let span = header.span();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0121", message, span)
+    }
+
+    pub fn implicit_circuit_member(number: NumberValue) -> Self {
+        let message = format!(
+            "circuit member values must have an explicit type, found `{}`, for example write `{}u32`",
+            number, number
+        );
+
+        Self::new_from_span("E0122", message, number.span().clone())
+    }
+
+    pub fn duplicate_circuit_member(member: Identifier) -> Self {
+        let message = format!("circuit member `{}` is given more than once", member);
+
+        Self::new_from_span("E0123", message, member.span)
     }
 }
 
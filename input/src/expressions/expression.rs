@@ -25,6 +25,7 @@ use std::fmt;
 pub enum Expression<'ast> {
     ArrayInitializer(ArrayInitializerExpression<'ast>),
     ArrayInline(ArrayInlineExpression<'ast>),
+    CircuitInline(CircuitInlineExpression<'ast>),
     Tuple(TupleExpression<'ast>),
     Value(Value<'ast>),
 }
@@ -34,6 +35,7 @@ impl<'ast> Expression<'ast> {
         match self {
             Expression::ArrayInitializer(expression) => &expression.span,
             Expression::ArrayInline(expression) => &expression.span,
+            Expression::CircuitInline(expression) => &expression.span,
             Expression::Tuple(tuple) => &tuple.span,
             Expression::Value(value) => value.span(),
         }
@@ -56,6 +58,16 @@ impl<'ast> fmt::Display for Expression<'ast> {
 
                 write!(f, "array [{}]", values)
             }
+            Expression::CircuitInline(ref circuit) => {
+                let members = circuit
+                    .members
+                    .iter()
+                    .map(|x| format!("{}: {}", x.identifier, x.expression))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                write!(f, "{} {{ {} }}", circuit.identifier, members)
+            }
             Expression::Tuple(ref tuple) => {
                 let values = tuple
                     .expressions
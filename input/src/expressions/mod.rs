@@ -20,6 +20,9 @@ pub use array_initializer_expression::*;
 pub mod array_inline_expression;
 pub use array_inline_expression::*;
 
+pub mod circuit_inline_expression;
+pub use circuit_inline_expression::*;
+
 pub mod expression;
 pub use expression::*;
 
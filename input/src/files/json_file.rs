@@ -0,0 +1,328 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Program input and state files in JSON format.
+//!
+//! A JSON input file describes the sections of a `.in` and `.state` file pair. Each section maps
+//! parameter names to the parameter `type`, written as in a `.in` file, and its `value`:
+//!
+//! ```json
+//! {
+//!     "main": {
+//!         "a": { "type": "u32", "value": 1 },
+//!         "p": { "type": "Point", "value": { "x": { "type": "u32", "value": 2 } } }
+//!     },
+//!     "registers": {
+//!         "r0": { "type": "u32", "value": 0 }
+//!     }
+//! }
+//! ```
+//!
+//! The `main` and `registers` sections are program input, the `state` section is public program state,
+//! and the `record` and `state_leaf` sections are private program state.
+//!
+//! Values are written as follows:
+//! * `bool` - a JSON boolean.
+//! * integers and `field` - a JSON number, or a string of decimal digits for values that exceed 64 bits.
+//! * `group` - a number or string for a single coordinate, or an array of two coordinates
+//!   that are each a number, a string of decimal digits, `"+"`, `"-"` or `"_"`.
+//! * `address` - a string.
+//! * arrays - nested JSON arrays, outermost dimension first.
+//! * tuples - a JSON array with one value per tuple element.
+//! * circuits - a JSON object that maps each member name to its `type` and `value`, like a section.
+//!
+//! TOML input files follow the same schema, with a table for every section and an inline table
+//! for every definition:
+//!
+//! ```toml
+//! [main]
+//! a = { type = "u32", value = 1 }
+//! p = { type = "Point", value = { x = { type = "u32", value = 2 } } }
+//!
+//! [registers]
+//! r0 = { type = "u32", value = 0 }
+//! ```
+
+use crate::{
+    ast::{parse_json_identifier, parse_json_type},
+    errors::InputParserError,
+    types::{ArrayDimensions, DataType, IntegerType, Type},
+};
+
+use from_pest::FromPest;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
+
+/// The definitions of a section in a JSON input file, sorted by name.
+pub type JsonSection = BTreeMap<String, JsonDefinition>;
+
+/// A program input and state file pair in JSON format.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JsonFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub main: Option<JsonSection>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registers: Option<JsonSection>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<JsonSection>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record: Option<JsonSection>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_leaf: Option<JsonSection>,
+}
+
+/// The type and value of a parameter or circuit member in a JSON input file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JsonDefinition {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub value: JsonValue,
+}
+
+impl JsonFile {
+    /// Parses a JSON input file.
+    pub fn parse(file: &str) -> Result<Self, InputParserError> {
+        Ok(serde_json::from_str(file)?)
+    }
+
+    /// Parses a TOML input file with the same sections and definitions as a JSON input file.
+    pub fn parse_toml(file: &str) -> Result<Self, InputParserError> {
+        Ok(toml::from_str(file)?)
+    }
+
+    /// Returns this file as pretty printed JSON.
+    pub fn to_json_string(&self) -> Result<String, InputParserError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Returns the program input sections of this file in the `.in` file format.
+    pub fn input_file_string(&self) -> Result<String, InputParserError> {
+        let mut file = String::new();
+
+        write_section(&mut file, "main", &self.main)?;
+        write_section(&mut file, "registers", &self.registers)?;
+
+        Ok(file)
+    }
+
+    /// Returns the program state sections of this file in the `.state` file format.
+    pub fn state_file_string(&self) -> Result<String, InputParserError> {
+        let mut file = String::new();
+
+        if self.state.is_some() {
+            file.push_str("[[public]]\n\n");
+            write_section(&mut file, "state", &self.state)?;
+        }
+
+        if self.record.is_some() || self.state_leaf.is_some() {
+            file.push_str("[[private]]\n\n");
+            write_section(&mut file, "record", &self.record)?;
+            write_section(&mut file, "state_leaf", &self.state_leaf)?;
+        }
+
+        Ok(file)
+    }
+}
+
+fn write_section(file: &mut String, header: &str, section: &Option<JsonSection>) -> Result<(), InputParserError> {
+    if let Some(section) = section {
+        file.push_str(&format!("[{}]\n", header));
+
+        for (name, definition) in section {
+            let path = format!("{}.{}", header, name);
+
+            check_identifier(&path, name)?;
+            let value = definition_string(&path, definition)?;
+
+            file.push_str(&format!("{}: {} = {};\n", name, definition.type_.trim(), value));
+        }
+
+        file.push('\n');
+    }
+
+    Ok(())
+}
+
+fn check_identifier(name: &str, identifier: &str) -> Result<(), InputParserError> {
+    parse_json_identifier(identifier)
+        .map(|_| ())
+        .map_err(|_| InputParserError::json_identifier(name))
+}
+
+/// Returns the value of a definition as an input file expression of the definition type.
+fn definition_string(name: &str, definition: &JsonDefinition) -> Result<String, InputParserError> {
+    let mut pairs =
+        parse_json_type(definition.type_.trim()).map_err(|_| InputParserError::json_type(name, &definition.type_))?;
+
+    // The first pair is the `json_type` rule, which contains the type and the end of input
+    let mut inner = pairs.next().ok_or(InputParserError::SyntaxTreeError)?.into_inner();
+    let type_ = Type::from_pest(&mut inner).map_err(|_| InputParserError::SyntaxTreeError)?;
+
+    value_string(name, &type_, &definition.value)
+}
+
+fn value_string(name: &str, type_: &Type, value: &JsonValue) -> Result<String, InputParserError> {
+    match type_ {
+        Type::Basic(data_type) => data_string(name, data_type, value),
+        Type::Array(array_type) => {
+            let dimensions = match &array_type.dimensions {
+                ArrayDimensions::Single(single) => vec![single.number.value.parse::<usize>()?],
+                ArrayDimensions::Multiple(multiple) => multiple
+                    .numbers
+                    .iter()
+                    .map(|number| number.value.parse::<usize>())
+                    .collect::<Result<Vec<_>, _>>()?,
+            };
+
+            array_string(name, &array_type.type_, &dimensions, value)
+        }
+        Type::Tuple(tuple_type) => {
+            let values = elements(name, "a tuple array", tuple_type.types_.len(), value)?;
+
+            let values = tuple_type
+                .types_
+                .iter()
+                .zip(values.iter())
+                .enumerate()
+                .map(|(index, (type_, value))| value_string(&format!("{}.{}", name, index), type_, value))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(format!("({})", values.join(", ")))
+        }
+        Type::Circuit(circuit_type) => {
+            let members = value
+                .as_object()
+                .ok_or_else(|| InputParserError::json_value(name, "a circuit object", value))?;
+
+            // Circuit member values carry their own type, since the input file does not declare them
+            let members = members
+                .iter()
+                .map(|(member, value)| {
+                    let name = format!("{}.{}", name, member);
+
+                    check_identifier(&name, member)?;
+                    let definition = serde_json::from_value::<JsonDefinition>(value.clone()).map_err(|_| {
+                        InputParserError::json_value(&name, "an object with a `type` and `value`", value)
+                    })?;
+
+                    Ok(format!("{}: {}", member, definition_string(&name, &definition)?))
+                })
+                .collect::<Result<Vec<_>, InputParserError>>()?;
+
+            Ok(format!("{} {{ {} }}", circuit_type.identifier, members.join(", ")))
+        }
+    }
+}
+
+fn array_string(
+    name: &str,
+    element_type: &Type,
+    dimensions: &[usize],
+    value: &JsonValue,
+) -> Result<String, InputParserError> {
+    let (length, inner_dimensions) = dimensions.split_first().ok_or(InputParserError::SyntaxTreeError)?;
+    let values = elements(name, "an array", *length, value)?;
+
+    let values = values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let name = format!("{}[{}]", name, index);
+
+            if inner_dimensions.is_empty() {
+                value_string(&name, element_type, value)
+            } else {
+                array_string(&name, element_type, inner_dimensions, value)
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(format!("[{}]", values.join(", ")))
+}
+
+fn elements<'a>(
+    name: &str,
+    expected: &str,
+    length: usize,
+    value: &'a JsonValue,
+) -> Result<&'a Vec<JsonValue>, InputParserError> {
+    let values = value
+        .as_array()
+        .ok_or_else(|| InputParserError::json_value(name, expected, value))?;
+
+    if values.len() != length {
+        return Err(InputParserError::json_length(name, length, values.len()));
+    }
+
+    Ok(values)
+}
+
+fn data_string(name: &str, data_type: &DataType, value: &JsonValue) -> Result<String, InputParserError> {
+    let string = match (data_type, value) {
+        (DataType::Address(_), JsonValue::String(address)) => {
+            Some(address.clone()).filter(|address| !address.is_empty() && address.chars().all(char::is_alphanumeric))
+        }
+        (DataType::Boolean(_), JsonValue::Bool(boolean)) => Some(boolean.to_string()),
+        (DataType::Field(_), value) => number_string(value, true).map(|number| format!("{}field", number)),
+        (DataType::Group(_), JsonValue::Array(coordinates)) if coordinates.len() == 2 => {
+            match (coordinate_string(&coordinates[0]), coordinate_string(&coordinates[1])) {
+                (Some(x), Some(y)) => Some(format!("({}, {})group", x, y)),
+                _ => None,
+            }
+        }
+        (DataType::Group(_), value) => number_string(value, true).map(|number| format!("{}group", number)),
+        (DataType::Integer(integer_type), value) => {
+            let signed = match integer_type {
+                IntegerType::Signed(_) => true,
+                IntegerType::Unsigned(_) => false,
+            };
+
+            number_string(value, signed).map(|number| format!("{}{}", number, integer_type))
+        }
+        _ => None,
+    };
+
+    string.ok_or_else(|| InputParserError::json_value(name, &format!("a `{}` value", data_type), value))
+}
+
+/// Returns the decimal digits of a JSON number or string.
+fn number_string(value: &JsonValue, signed: bool) -> Option<String> {
+    let number = match value {
+        JsonValue::Number(number) if number.is_u64() || number.is_i64() => number.to_string(),
+        JsonValue::String(string) => string.clone(),
+        _ => return None,
+    };
+
+    let digits = match signed {
+        true => number.strip_prefix('-').unwrap_or(&number),
+        false => &number,
+    };
+
+    match !digits.is_empty() && digits.chars().all(|digit| digit.is_ascii_digit()) {
+        true => Some(number),
+        false => None,
+    }
+}
+
+fn coordinate_string(value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::String(sign) if sign == "+" || sign == "-" || sign == "_" => Some(sign.clone()),
+        value => number_string(value, true),
+    }
+}
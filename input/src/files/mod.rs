@@ -17,5 +17,8 @@
 pub mod file;
 pub use file::*;
 
+pub mod json_file;
+pub use json_file::*;
+
 pub mod table_or_section;
 pub use table_or_section::*;
@@ -0,0 +1,274 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Phase 1 of the setup ceremony: a powers of tau accumulator sized for a single circuit.
+
+use crate::{
+    ceremony::utils::{
+        batch_mul,
+        hash,
+        is_valid,
+        power_pairs,
+        powers,
+        read_point,
+        read_points,
+        same_ratio,
+        write_points,
+        Hash,
+        KnowledgeProof,
+    },
+    errors::CeremonyError,
+};
+
+use snarkos_curves::bls12_377::{Fr, G1Affine, G2Affine};
+use snarkos_models::curves::{AffineCurve, ProjectiveCurve, Zero};
+use snarkos_utilities::{
+    bytes::{FromBytes, ToBytes},
+    rand::UniformRand,
+};
+
+use rand::Rng;
+use std::io::{self, Read, Write};
+
+const TAU_PERSONALIZATION: u8 = 0;
+const ALPHA_PERSONALIZATION: u8 = 1;
+const BETA_PERSONALIZATION: u8 = 2;
+
+/// The powers of the secrets `tau`, `alpha`, and `beta` accumulated over all phase 1 contributions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Accumulator {
+    /// `tau^i * G1` for `i` in `0..2n - 1`.
+    pub tau_g1: Vec<G1Affine>,
+    /// `tau^i * G2` for `i` in `0..n`.
+    pub tau_g2: Vec<G2Affine>,
+    /// `alpha * tau^i * G1` for `i` in `0..n`.
+    pub alpha_tau_g1: Vec<G1Affine>,
+    /// `beta * tau^i * G1` for `i` in `0..n`.
+    pub beta_tau_g1: Vec<G1Affine>,
+    /// `beta * G2`.
+    pub beta_g2: G2Affine,
+}
+
+impl Accumulator {
+    /// Returns the accumulator for a domain of the given size, with every secret set to one.
+    pub fn new(size: usize) -> Self {
+        let g1 = G1Affine::prime_subgroup_generator();
+        let g2 = G2Affine::prime_subgroup_generator();
+
+        Self {
+            tau_g1: vec![g1; 2 * size - 1],
+            tau_g2: vec![g2; size],
+            alpha_tau_g1: vec![g1; size],
+            beta_tau_g1: vec![g1; size],
+            beta_g2: g2,
+        }
+    }
+
+    /// Returns the size of the evaluation domain this accumulator was created for.
+    pub fn size(&self) -> usize {
+        self.tau_g2.len()
+    }
+
+    /// Multiplies the accumulated secrets by fresh random secrets.
+    /// The secrets are dropped once the contribution has been recorded.
+    pub fn contribute<R: Rng>(&mut self, digest: &Hash, rng: &mut R) -> Phase1Contribution {
+        let tau = Fr::rand(rng);
+        let alpha = Fr::rand(rng);
+        let beta = Fr::rand(rng);
+
+        let tau_powers = powers(tau, self.tau_g1.len());
+        let alpha_tau_powers = tau_powers[..self.size()]
+            .iter()
+            .map(|power| alpha * power)
+            .collect::<Vec<_>>();
+        let beta_tau_powers = tau_powers[..self.size()]
+            .iter()
+            .map(|power| beta * power)
+            .collect::<Vec<_>>();
+
+        batch_mul(&mut self.tau_g1, &tau_powers);
+        batch_mul(&mut self.tau_g2, &tau_powers);
+        batch_mul(&mut self.alpha_tau_g1, &alpha_tau_powers);
+        batch_mul(&mut self.beta_tau_g1, &beta_tau_powers);
+        self.beta_g2 = self.beta_g2.mul(beta).into_affine();
+
+        Phase1Contribution {
+            tau: KnowledgeProof::new(tau, digest, TAU_PERSONALIZATION, rng),
+            alpha: KnowledgeProof::new(alpha, digest, ALPHA_PERSONALIZATION, rng),
+            beta: KnowledgeProof::new(beta, digest, BETA_PERSONALIZATION, rng),
+            tau_g1: self.tau_g1[1],
+            alpha_g1: self.alpha_tau_g1[0],
+            beta_g1: self.beta_tau_g1[0],
+        }
+    }
+
+    /// Verifies that every contribution multiplied the secrets of the one before it by secrets known
+    /// to the contributor, and that the accumulator consists of consistent powers of the final secrets.
+    /// Returns the digest of the transcript after the last contribution.
+    pub fn verify<R: Rng>(
+        &self,
+        contributions: &[Phase1Contribution],
+        mut digest: Hash,
+        rng: &mut R,
+    ) -> Result<Hash, CeremonyError> {
+        let g1 = G1Affine::prime_subgroup_generator();
+        let g2 = G2Affine::prime_subgroup_generator();
+
+        let mut before = (g1, g1, g1);
+        for (index, contribution) in contributions.iter().enumerate() {
+            let invalid = |reason: &str| CeremonyError::InvalidContribution(1, index + 1, reason.to_string());
+
+            let r_tau = contribution
+                .tau
+                .verify(&digest, TAU_PERSONALIZATION)
+                .ok_or_else(|| invalid("invalid proof of knowledge of tau"))?;
+            let r_alpha = contribution
+                .alpha
+                .verify(&digest, ALPHA_PERSONALIZATION)
+                .ok_or_else(|| invalid("invalid proof of knowledge of alpha"))?;
+            let r_beta = contribution
+                .beta
+                .verify(&digest, BETA_PERSONALIZATION)
+                .ok_or_else(|| invalid("invalid proof of knowledge of beta"))?;
+
+            if !contribution.tau.verify_transition(r_tau, before.0, contribution.tau_g1) {
+                return Err(invalid("tau was not updated by the proven secret"));
+            }
+            if !contribution
+                .alpha
+                .verify_transition(r_alpha, before.1, contribution.alpha_g1)
+            {
+                return Err(invalid("alpha was not updated by the proven secret"));
+            }
+            if !contribution
+                .beta
+                .verify_transition(r_beta, before.2, contribution.beta_g1)
+            {
+                return Err(invalid("beta was not updated by the proven secret"));
+            }
+
+            before = (contribution.tau_g1, contribution.alpha_g1, contribution.beta_g1);
+            digest = contribution.digest(&digest);
+        }
+
+        let invalid = |reason: &str| Err(CeremonyError::InvalidAccumulator(reason.to_string()));
+
+        let size = self.size();
+        if size < 2
+            || self.tau_g1.len() != 2 * size - 1
+            || self.alpha_tau_g1.len() != size
+            || self.beta_tau_g1.len() != size
+        {
+            return invalid("the vectors of powers have inconsistent lengths");
+        }
+        if (self.tau_g1[1], self.alpha_tau_g1[0], self.beta_tau_g1[0]) != before {
+            return invalid("the accumulator does not match the last contribution");
+        }
+        if self.tau_g1[0] != g1 || self.tau_g2[0] != g2 {
+            return invalid("the zeroth powers of tau are not the generators");
+        }
+        if self.beta_g2.is_zero() || !is_valid(&self.beta_g2) {
+            return invalid("beta in G2 is not a valid point");
+        }
+        if !same_ratio(power_pairs(&self.tau_g1, rng), (self.tau_g2[0], self.tau_g2[1])) {
+            return invalid("the powers of tau in G1 are inconsistent");
+        }
+        if !same_ratio((self.tau_g1[0], self.tau_g1[1]), power_pairs(&self.tau_g2, rng)) {
+            return invalid("the powers of tau in G2 are inconsistent");
+        }
+        if !same_ratio(power_pairs(&self.alpha_tau_g1, rng), (self.tau_g2[0], self.tau_g2[1])) {
+            return invalid("the powers of alpha * tau are inconsistent");
+        }
+        if !same_ratio(power_pairs(&self.beta_tau_g1, rng), (self.tau_g2[0], self.tau_g2[1])) {
+            return invalid("the powers of beta * tau are inconsistent");
+        }
+        if !same_ratio((g1, self.beta_tau_g1[0]), (g2, self.beta_g2)) {
+            return invalid("beta in G1 and G2 are inconsistent");
+        }
+
+        Ok(digest)
+    }
+}
+
+impl ToBytes for Accumulator {
+    fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write_points(&self.tau_g1, &mut writer)?;
+        write_points(&self.tau_g2, &mut writer)?;
+        write_points(&self.alpha_tau_g1, &mut writer)?;
+        write_points(&self.beta_tau_g1, &mut writer)?;
+        self.beta_g2.write(&mut writer)
+    }
+}
+
+impl FromBytes for Accumulator {
+    fn read<R: Read>(mut reader: R) -> io::Result<Self> {
+        Ok(Self {
+            tau_g1: read_points(&mut reader)?,
+            tau_g2: read_points(&mut reader)?,
+            alpha_tau_g1: read_points(&mut reader)?,
+            beta_tau_g1: read_points(&mut reader)?,
+            beta_g2: read_point(&mut reader)?,
+        })
+    }
+}
+
+/// The public record of a phase 1 contribution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Phase1Contribution {
+    pub tau: KnowledgeProof,
+    pub alpha: KnowledgeProof,
+    pub beta: KnowledgeProof,
+    /// `tau * G1` after the contribution.
+    pub tau_g1: G1Affine,
+    /// `alpha * G1` after the contribution.
+    pub alpha_g1: G1Affine,
+    /// `beta * G1` after the contribution.
+    pub beta_g1: G1Affine,
+}
+
+impl Phase1Contribution {
+    /// Returns the digest of the transcript after this contribution.
+    pub fn digest(&self, before: &Hash) -> Hash {
+        let mut bytes = vec![];
+        self.write(&mut bytes).expect("writing to a vector cannot fail");
+
+        hash(&[before, &bytes])
+    }
+}
+
+impl ToBytes for Phase1Contribution {
+    fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.tau.write(&mut writer)?;
+        self.alpha.write(&mut writer)?;
+        self.beta.write(&mut writer)?;
+        self.tau_g1.write(&mut writer)?;
+        self.alpha_g1.write(&mut writer)?;
+        self.beta_g1.write(&mut writer)
+    }
+}
+
+impl FromBytes for Phase1Contribution {
+    fn read<R: Read>(mut reader: R) -> io::Result<Self> {
+        Ok(Self {
+            tau: FromBytes::read(&mut reader)?,
+            alpha: FromBytes::read(&mut reader)?,
+            beta: FromBytes::read(&mut reader)?,
+            tau_g1: read_point(&mut reader)?,
+            alpha_g1: read_point(&mut reader)?,
+            beta_g1: read_point(&mut reader)?,
+        })
+    }
+}
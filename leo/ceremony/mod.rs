@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A multi-party trusted setup ceremony for Groth16 program parameters.
+//!
+//! Phase 1 accumulates powers of the secrets `tau`, `alpha`, and `beta`, sized for the program circuit.
+//! Phase 2 derives the program parameters from the accumulator and randomizes `delta`.
+//! The toxic waste is unknown as long as a single participant of each phase discards their secrets.
+
+pub mod accumulator;
+pub use self::accumulator::*;
+
+pub mod parameters;
+pub use self::parameters::*;
+
+pub mod transcript;
+pub use self::transcript::*;
+
+pub mod utils;
+pub use self::utils::*;
@@ -0,0 +1,293 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Phase 2 of the setup ceremony: circuit specific Groth16 parameters randomized by `delta`.
+
+use crate::{
+    ceremony::{
+        accumulator::Accumulator,
+        utils::{hash, is_valid, lagrange_basis, merge_pairs, read_point, same_ratio, Hash, KnowledgeProof},
+    },
+    errors::CeremonyError,
+};
+
+use snarkos_algorithms::{
+    fft::EvaluationDomain,
+    snark::groth16::{KeypairAssembly, Parameters, VerifyingKey},
+};
+use snarkos_curves::bls12_377::{Bls12_377, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use snarkos_models::{
+    curves::{AffineCurve, Field, One, PrimeField, ProjectiveCurve, Zero},
+    gadgets::r1cs::{ConstraintSynthesizer, ConstraintSystem, Index},
+};
+use snarkos_utilities::{
+    bytes::{FromBytes, ToBytes},
+    rand::UniformRand,
+};
+
+use rand::Rng;
+use std::io::{self, Read, Write};
+
+const DELTA_PERSONALIZATION: u8 = 3;
+
+/// Synthesizes the circuit into its rank-1 constraint system, without any variable assignments.
+pub fn synthesize<C: ConstraintSynthesizer<Fr>>(circuit: C) -> Result<KeypairAssembly<Bls12_377>, CeremonyError> {
+    let mut assembly = KeypairAssembly {
+        num_inputs: 0,
+        num_aux: 0,
+        num_constraints: 0,
+        at: vec![],
+        bt: vec![],
+        ct: vec![],
+    };
+
+    // Allocate the "one" input variable
+    assembly.alloc_input(|| "", || Ok(Fr::one()))?;
+
+    circuit.generate_constraints(&mut assembly)?;
+
+    Ok(assembly)
+}
+
+/// Returns the evaluation domain of the quadratic arithmetic program of the given constraint system.
+pub fn domain(assembly: &KeypairAssembly<Bls12_377>) -> Result<EvaluationDomain<Fr>, CeremonyError> {
+    EvaluationDomain::new(assembly.num_constraints + assembly.num_inputs).ok_or(CeremonyError::PolynomialDegreeTooLarge)
+}
+
+/// Derives the Groth16 parameters of the circuit from the phase 1 accumulator, with `gamma = delta = 1`.
+/// Every participant derives the same parameters, so this step requires no trust.
+pub fn prepare(
+    accumulator: &Accumulator,
+    assembly: &KeypairAssembly<Bls12_377>,
+) -> Result<Parameters<Bls12_377>, CeremonyError> {
+    let domain = domain(assembly)?;
+    let size = domain.size();
+    if accumulator.size() != size {
+        return Err(CeremonyError::DomainSizeMismatch(accumulator.size(), size));
+    }
+
+    // Evaluate the Lagrange polynomials of the domain at tau, in the exponent
+    let lagrange_g1 = lagrange_basis(&domain, &accumulator.tau_g1[..size]);
+    let lagrange_g2 = lagrange_basis(&domain, &accumulator.tau_g2);
+    let lagrange_alpha_g1 = lagrange_basis(&domain, &accumulator.alpha_tau_g1);
+    let lagrange_beta_g1 = lagrange_basis(&domain, &accumulator.beta_tau_g1);
+
+    let num_variables = assembly.num_inputs + assembly.num_aux;
+    let mut a_query = vec![G1Projective::zero(); num_variables];
+    let mut b_g1_query = vec![G1Projective::zero(); num_variables];
+    let mut b_g2_query = vec![G2Projective::zero(); num_variables];
+    // beta * a_i(tau) + alpha * b_i(tau) + c_i(tau)
+    let mut abc_query = vec![G1Projective::zero(); num_variables];
+
+    for i in 0..assembly.num_inputs {
+        a_query[i].add_assign_mixed(&lagrange_g1[assembly.num_constraints + i]);
+        abc_query[i].add_assign_mixed(&lagrange_beta_g1[assembly.num_constraints + i]);
+    }
+
+    let index = |index: Index| match index {
+        Index::Input(i) => i,
+        Index::Aux(i) => assembly.num_inputs + i,
+    };
+
+    for constraint in 0..assembly.num_constraints {
+        for &(coeff, variable) in &assembly.at[constraint] {
+            let variable = index(variable);
+            a_query[variable] += &lagrange_g1[constraint].mul(coeff.into_repr());
+            abc_query[variable] += &lagrange_beta_g1[constraint].mul(coeff.into_repr());
+        }
+        for &(coeff, variable) in &assembly.bt[constraint] {
+            let variable = index(variable);
+            b_g1_query[variable] += &lagrange_g1[constraint].mul(coeff.into_repr());
+            b_g2_query[variable] += &lagrange_g2[constraint].mul(coeff.into_repr());
+            abc_query[variable] += &lagrange_alpha_g1[constraint].mul(coeff.into_repr());
+        }
+        for &(coeff, variable) in &assembly.ct[constraint] {
+            abc_query[index(variable)] += &lagrange_g1[constraint].mul(coeff.into_repr());
+        }
+    }
+
+    // Z(tau) * tau^i = tau^(i + n) - tau^i
+    let mut h_query = (0..size - 1)
+        .map(|i| {
+            let mut h = accumulator.tau_g1[i + size].into_projective();
+            h.add_assign_mixed(&-accumulator.tau_g1[i]);
+            h
+        })
+        .collect::<Vec<_>>();
+
+    G1Projective::batch_normalization(&mut a_query);
+    G1Projective::batch_normalization(&mut b_g1_query);
+    G2Projective::batch_normalization(&mut b_g2_query);
+    G1Projective::batch_normalization(&mut abc_query);
+    G1Projective::batch_normalization(&mut h_query);
+
+    let into_affine = |points: &[G1Projective]| points.iter().map(|point| point.into_affine()).collect::<Vec<_>>();
+
+    let g1 = G1Affine::prime_subgroup_generator();
+    let g2 = G2Affine::prime_subgroup_generator();
+
+    Ok(Parameters {
+        vk: VerifyingKey {
+            alpha_g1: accumulator.alpha_tau_g1[0],
+            beta_g2: accumulator.beta_g2,
+            gamma_g2: g2,
+            delta_g2: g2,
+            gamma_abc_g1: into_affine(&abc_query[..assembly.num_inputs]),
+        },
+        beta_g1: accumulator.beta_tau_g1[0],
+        delta_g1: g1,
+        a_query: into_affine(&a_query),
+        b_g1_query: into_affine(&b_g1_query),
+        b_g2_query: b_g2_query.iter().map(|point| point.into_affine()).collect(),
+        h_query: into_affine(&h_query),
+        l_query: into_affine(&abc_query[assembly.num_inputs..]),
+    })
+}
+
+/// Multiplies `delta` of the parameters by a fresh random secret.
+/// The secret is dropped once the contribution has been recorded.
+pub fn contribute<R: Rng>(parameters: &mut Parameters<Bls12_377>, digest: &Hash, rng: &mut R) -> Phase2Contribution {
+    let delta = Fr::rand(rng);
+    let delta_inverse = delta.inverse().expect("a random scalar is nonzero");
+
+    let mul = |points: &mut Vec<G1Affine>, scalar: Fr| {
+        let mut projective = points
+            .iter()
+            .map(|point| point.mul(scalar.into_repr()))
+            .collect::<Vec<_>>();
+        G1Projective::batch_normalization(&mut projective);
+        *points = projective.iter().map(|point| point.into_affine()).collect();
+    };
+
+    parameters.delta_g1 = parameters.delta_g1.mul(delta.into_repr()).into_affine();
+    parameters.vk.delta_g2 = parameters.vk.delta_g2.mul(delta.into_repr()).into_affine();
+    mul(&mut parameters.h_query, delta_inverse);
+    mul(&mut parameters.l_query, delta_inverse);
+
+    Phase2Contribution {
+        delta: KnowledgeProof::new(delta, digest, DELTA_PERSONALIZATION, rng),
+        delta_g1: parameters.delta_g1,
+    }
+}
+
+/// Verifies that the parameters were derived from the initial parameters by the given contributions,
+/// each of which multiplied `delta` by a secret known to the contributor.
+/// Returns the digest of the transcript after the last contribution.
+pub fn verify<R: Rng>(
+    initial: &Parameters<Bls12_377>,
+    parameters: &Parameters<Bls12_377>,
+    contributions: &[Phase2Contribution],
+    mut digest: Hash,
+    rng: &mut R,
+) -> Result<Hash, CeremonyError> {
+    let mut delta_g1 = initial.delta_g1;
+    for (index, contribution) in contributions.iter().enumerate() {
+        let invalid = |reason: &str| CeremonyError::InvalidContribution(2, index + 1, reason.to_string());
+
+        let r = contribution
+            .delta
+            .verify(&digest, DELTA_PERSONALIZATION)
+            .ok_or_else(|| invalid("invalid proof of knowledge of delta"))?;
+
+        if !contribution.delta.verify_transition(r, delta_g1, contribution.delta_g1) {
+            return Err(invalid("delta was not updated by the proven secret"));
+        }
+
+        delta_g1 = contribution.delta_g1;
+        digest = contribution.digest(&digest);
+    }
+
+    let invalid = |reason: &str| Err(CeremonyError::InvalidParameters(reason.to_string()));
+
+    if parameters.vk.alpha_g1 != initial.vk.alpha_g1
+        || parameters.vk.beta_g2 != initial.vk.beta_g2
+        || parameters.vk.gamma_g2 != initial.vk.gamma_g2
+        || parameters.vk.gamma_abc_g1 != initial.vk.gamma_abc_g1
+        || parameters.beta_g1 != initial.beta_g1
+        || parameters.a_query != initial.a_query
+        || parameters.b_g1_query != initial.b_g1_query
+        || parameters.b_g2_query != initial.b_g2_query
+    {
+        return invalid("the parameters were not derived from the phase 1 accumulator and the circuit");
+    }
+    if parameters.h_query.len() != initial.h_query.len() || parameters.l_query.len() != initial.l_query.len() {
+        return invalid("the parameters have the wrong number of query elements");
+    }
+    if parameters.delta_g1 != delta_g1 {
+        return invalid("the parameters do not match the last contribution");
+    }
+    if !is_valid(&parameters.delta_g1)
+        || !is_valid(&parameters.vk.delta_g2)
+        || !parameters.h_query.iter().chain(&parameters.l_query).all(is_valid)
+    {
+        return invalid("the parameters contain invalid points");
+    }
+
+    let g1 = G1Affine::prime_subgroup_generator();
+    let g2 = G2Affine::prime_subgroup_generator();
+
+    if !same_ratio((g1, parameters.delta_g1), (g2, parameters.vk.delta_g2)) {
+        return invalid("delta in G1 and G2 are inconsistent");
+    }
+    if !same_ratio(
+        merge_pairs(&parameters.h_query, &initial.h_query, rng),
+        (g2, parameters.vk.delta_g2),
+    ) {
+        return invalid("the H query was not divided by delta");
+    }
+    if !same_ratio(
+        merge_pairs(&parameters.l_query, &initial.l_query, rng),
+        (g2, parameters.vk.delta_g2),
+    ) {
+        return invalid("the L query was not divided by delta");
+    }
+
+    Ok(digest)
+}
+
+/// The public record of a phase 2 contribution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Phase2Contribution {
+    pub delta: KnowledgeProof,
+    /// `delta * G1` after the contribution.
+    pub delta_g1: G1Affine,
+}
+
+impl Phase2Contribution {
+    /// Returns the digest of the transcript after this contribution.
+    pub fn digest(&self, before: &Hash) -> Hash {
+        let mut bytes = vec![];
+        self.write(&mut bytes).expect("writing to a vector cannot fail");
+
+        hash(&[before, &bytes])
+    }
+}
+
+impl ToBytes for Phase2Contribution {
+    fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.delta.write(&mut writer)?;
+        self.delta_g1.write(&mut writer)
+    }
+}
+
+impl FromBytes for Phase2Contribution {
+    fn read<R: Read>(mut reader: R) -> io::Result<Self> {
+        Ok(Self {
+            delta: FromBytes::read(&mut reader)?,
+            delta_g1: read_point(&mut reader)?,
+        })
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The transcript of a setup ceremony, passed from participant to participant.
+
+use crate::{
+    ceremony::{
+        accumulator::{Accumulator, Phase1Contribution},
+        parameters::{self, Phase2Contribution},
+        utils::{hash, Hash},
+    },
+    errors::CeremonyError,
+};
+
+use snarkos_algorithms::snark::groth16::{KeypairAssembly, Parameters};
+use snarkos_curves::bls12_377::Bls12_377;
+use snarkos_utilities::bytes::{FromBytes, ToBytes};
+
+use rand::Rng;
+use std::io::{self, Read, Write};
+
+static TRANSCRIPT_MAGIC: &[u8] = b"leo-ceremony-v1\n";
+
+/// The maximum length of the build checksum in a transcript, well above the 64 hex digits of a SHA-256 digest.
+const MAX_CHECKSUM_LEN: u32 = 256;
+
+/// The phase 2 parameters and the contributions made to them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Phase2 {
+    pub parameters: Parameters<Bls12_377>,
+    pub contributions: Vec<Phase2Contribution>,
+}
+
+/// A ceremony transcript for a single program.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transcript {
+    /// The checksum of the program build the ceremony was initialized for.
+    pub checksum: String,
+    pub accumulator: Accumulator,
+    pub phase1: Vec<Phase1Contribution>,
+    pub phase2: Option<Phase2>,
+}
+
+impl Transcript {
+    /// Returns a new transcript for the given program build and constraint system.
+    pub fn new(checksum: String, assembly: &KeypairAssembly<Bls12_377>) -> Result<Self, CeremonyError> {
+        let size = parameters::domain(assembly)?.size();
+
+        Ok(Self {
+            checksum,
+            accumulator: Accumulator::new(size),
+            phase1: vec![],
+            phase2: None,
+        })
+    }
+
+    /// Returns the digest every contribution is chained to.
+    pub fn initial_digest(&self) -> Hash {
+        hash(&[
+            TRANSCRIPT_MAGIC,
+            self.checksum.as_bytes(),
+            &(self.accumulator.size() as u64).to_le_bytes(),
+        ])
+    }
+
+    /// Returns the digest of the transcript after the last contribution.
+    pub fn digest(&self) -> Hash {
+        let digest = self.phase1.iter().fold(self.initial_digest(), |digest, contribution| {
+            contribution.digest(&digest)
+        });
+
+        match &self.phase2 {
+            Some(phase2) => phase2
+                .contributions
+                .iter()
+                .fold(digest, |digest, contribution| contribution.digest(&digest)),
+            None => digest,
+        }
+    }
+
+    /// Adds a contribution to the current phase of the ceremony.
+    /// Returns the digest of the transcript after the contribution.
+    pub fn contribute<R: Rng>(&mut self, rng: &mut R) -> Hash {
+        let digest = self.digest();
+
+        match &mut self.phase2 {
+            Some(phase2) => {
+                let contribution = parameters::contribute(&mut phase2.parameters, &digest, rng);
+                let digest = contribution.digest(&digest);
+                phase2.contributions.push(contribution);
+                digest
+            }
+            None => {
+                let contribution = self.accumulator.contribute(&digest, rng);
+                let digest = contribution.digest(&digest);
+                self.phase1.push(contribution);
+                digest
+            }
+        }
+    }
+
+    /// Closes phase 1 and derives the initial phase 2 parameters for the given constraint system.
+    pub fn prepare(&mut self, assembly: &KeypairAssembly<Bls12_377>) -> Result<(), CeremonyError> {
+        if self.phase2.is_some() {
+            return Err(CeremonyError::AlreadyPrepared);
+        }
+        if self.phase1.is_empty() {
+            return Err(CeremonyError::MissingContributions(1));
+        }
+
+        self.phase2 = Some(Phase2 {
+            parameters: parameters::prepare(&self.accumulator, assembly)?,
+            contributions: vec![],
+        });
+
+        Ok(())
+    }
+
+    /// Verifies every contribution in the transcript against the given program build and constraint system.
+    /// Returns the digest of the transcript after the last contribution.
+    pub fn verify<R: Rng>(
+        &self,
+        checksum: &str,
+        assembly: &KeypairAssembly<Bls12_377>,
+        rng: &mut R,
+    ) -> Result<Hash, CeremonyError> {
+        if self.checksum != checksum {
+            return Err(CeremonyError::ChecksumMismatch(
+                self.checksum.clone(),
+                checksum.to_string(),
+            ));
+        }
+
+        let size = parameters::domain(assembly)?.size();
+        if self.accumulator.size() != size {
+            return Err(CeremonyError::DomainSizeMismatch(self.accumulator.size(), size));
+        }
+
+        let digest = self.accumulator.verify(&self.phase1, self.initial_digest(), rng)?;
+
+        match &self.phase2 {
+            Some(phase2) => {
+                let initial = parameters::prepare(&self.accumulator, assembly)?;
+
+                parameters::verify(&initial, &phase2.parameters, &phase2.contributions, digest, rng)
+            }
+            None => Ok(digest),
+        }
+    }
+
+    /// Returns the final parameters if both phases of the ceremony received contributions.
+    pub fn into_parameters(self) -> Result<Parameters<Bls12_377>, CeremonyError> {
+        match self.phase2 {
+            Some(phase2) if !phase2.contributions.is_empty() => Ok(phase2.parameters),
+            Some(_) => Err(CeremonyError::MissingContributions(2)),
+            None => Err(CeremonyError::NotPrepared),
+        }
+    }
+}
+
+impl ToBytes for Transcript {
+    fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(TRANSCRIPT_MAGIC)?;
+
+        (self.checksum.len() as u32).write(&mut writer)?;
+        writer.write_all(self.checksum.as_bytes())?;
+
+        self.accumulator.write(&mut writer)?;
+
+        (self.phase1.len() as u32).write(&mut writer)?;
+        for contribution in &self.phase1 {
+            contribution.write(&mut writer)?;
+        }
+
+        match &self.phase2 {
+            Some(phase2) => {
+                true.write(&mut writer)?;
+                phase2.parameters.write(&mut writer)?;
+
+                (phase2.contributions.len() as u32).write(&mut writer)?;
+                for contribution in &phase2.contributions {
+                    contribution.write(&mut writer)?;
+                }
+            }
+            None => false.write(&mut writer)?,
+        }
+
+        Ok(())
+    }
+}
+
+impl FromBytes for Transcript {
+    fn read<R: Read>(mut reader: R) -> io::Result<Self> {
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message);
+
+        let mut magic = vec![0u8; TRANSCRIPT_MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        if magic != TRANSCRIPT_MAGIC {
+            return Err(invalid("not a Leo ceremony transcript"));
+        }
+
+        // The length is untrusted, so bound it before allocating the checksum
+        let checksum_len: u32 = FromBytes::read(&mut reader)?;
+        if checksum_len > MAX_CHECKSUM_LEN {
+            return Err(invalid("the checksum is too long"));
+        }
+        let mut checksum = vec![0u8; checksum_len as usize];
+        reader.read_exact(&mut checksum)?;
+        let checksum = String::from_utf8(checksum).map_err(|_| invalid("the checksum is not valid UTF-8"))?;
+
+        let accumulator = FromBytes::read(&mut reader)?;
+
+        let phase1_len: u32 = FromBytes::read(&mut reader)?;
+        let phase1 = (0..phase1_len)
+            .map(|_| FromBytes::read(&mut reader))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let phase2 = match bool::read(&mut reader)? {
+            true => {
+                let parameters = Parameters::read(&mut reader, true)?;
+
+                let phase2_len: u32 = FromBytes::read(&mut reader)?;
+                let contributions = (0..phase2_len)
+                    .map(|_| FromBytes::read(&mut reader))
+                    .collect::<io::Result<Vec<_>>>()?;
+
+                Some(Phase2 {
+                    parameters,
+                    contributions,
+                })
+            }
+            false => None,
+        };
+
+        Ok(Self {
+            checksum,
+            accumulator,
+            phase1,
+            phase2,
+        })
+    }
+}
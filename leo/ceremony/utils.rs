@@ -0,0 +1,280 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Cryptographic helpers shared by both phases of the setup ceremony.
+
+use snarkos_algorithms::{fft::EvaluationDomain, msm::VariableBaseMSM};
+use snarkos_curves::bls12_377::{Bls12_377, Fr, G1Affine, G1Projective, G2Affine};
+use snarkos_models::curves::{AffineCurve, Field, One, PairingEngine, PrimeField, ProjectiveCurve, Zero};
+use snarkos_utilities::{
+    bytes::{FromBytes, ToBytes},
+    rand::UniformRand,
+};
+
+use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
+use sha2::{Digest, Sha256};
+use std::io::{self, Read, Write};
+
+/// A SHA-256 digest of the transcript up to and including a contribution.
+pub type Hash = [u8; 32];
+
+/// Returns the SHA-256 digest of the concatenation of the given byte strings.
+pub fn hash(parts: &[&[u8]]) -> Hash {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+
+    let mut digest = [0u8; 32];
+    digest.copy_from_slice(&hasher.finalize());
+    digest
+}
+
+/// Returns the lowercase hexadecimal encoding of the given digest.
+pub fn to_hex(digest: &Hash) -> String {
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Deterministically maps a digest to a point in G2 whose discrete logarithm is unknown.
+pub fn hash_to_g2(digest: &[u8]) -> G2Affine {
+    let mut rng = StdRng::from_seed(hash(&[digest]));
+    let mut bytes = [0u8; 96];
+
+    loop {
+        rng.fill_bytes(&mut bytes);
+
+        if let Some(point) = G2Affine::from_random_bytes(&bytes) {
+            let point = point.mul_by_cofactor();
+            if !point.is_zero() {
+                return point;
+            }
+        }
+    }
+}
+
+/// Returns `true` if `g1.1 / g1.0 == g2.1 / g2.0`, i.e. both pairs share the same discrete logarithm.
+pub fn same_ratio(g1: (G1Affine, G1Affine), g2: (G2Affine, G2Affine)) -> bool {
+    Bls12_377::pairing(g1.0, g2.1) == Bls12_377::pairing(g1.1, g2.0)
+}
+
+/// Compresses two equally long vectors into a single pair using a random linear combination,
+/// such that the pair has the same ratio as every `(v1[i], v2[i])` with overwhelming probability.
+pub fn merge_pairs<G: AffineCurve<ScalarField = Fr>, R: Rng>(v1: &[G], v2: &[G], rng: &mut R) -> (G, G) {
+    assert_eq!(v1.len(), v2.len());
+
+    let scalars = (0..v1.len()).map(|_| Fr::rand(rng).into_repr()).collect::<Vec<_>>();
+
+    (
+        VariableBaseMSM::multi_scalar_mul(v1, &scalars).into_affine(),
+        VariableBaseMSM::multi_scalar_mul(v2, &scalars).into_affine(),
+    )
+}
+
+/// Compresses consecutive powers `v[i], v[i + 1]` into a single pair sharing their common ratio.
+pub fn power_pairs<G: AffineCurve<ScalarField = Fr>, R: Rng>(v: &[G], rng: &mut R) -> (G, G) {
+    merge_pairs(&v[..v.len() - 1], &v[1..], rng)
+}
+
+/// Returns the powers `x^0, x^1, ..., x^(n - 1)`.
+pub fn powers(x: Fr, n: usize) -> Vec<Fr> {
+    let mut powers = Vec::with_capacity(n);
+    let mut power = Fr::one();
+    for _ in 0..n {
+        powers.push(power);
+        power *= &x;
+    }
+    powers
+}
+
+/// Multiplies each point by the scalar at the same position.
+pub fn batch_mul<G: AffineCurve<ScalarField = Fr>>(points: &mut [G], scalars: &[Fr]) {
+    let mut projective = points
+        .iter()
+        .zip(scalars)
+        .map(|(point, scalar)| point.mul(scalar.into_repr()))
+        .collect::<Vec<_>>();
+    G::Projective::batch_normalization(&mut projective);
+
+    for (point, product) in points.iter_mut().zip(projective) {
+        *point = product.into_affine();
+    }
+}
+
+/// Converts the points `[tau^i]` into the points `[L_i(tau)]` of the Lagrange basis over the given domain
+/// by running an inverse FFT in the exponent.
+pub fn lagrange_basis<G: AffineCurve<ScalarField = Fr>>(domain: &EvaluationDomain<Fr>, powers: &[G]) -> Vec<G> {
+    assert_eq!(powers.len(), domain.size());
+
+    let mut points = powers.iter().map(|point| point.into_projective()).collect::<Vec<_>>();
+    group_fft(&mut points, domain.group_gen_inv, domain.log_size_of_group);
+
+    for point in points.iter_mut() {
+        point.mul_assign(domain.size_inv.into_repr());
+    }
+    G::Projective::batch_normalization(&mut points);
+
+    points.into_iter().map(|point| point.into_affine()).collect()
+}
+
+/// An in-place radix-2 FFT over group elements.
+fn group_fft<G: ProjectiveCurve<ScalarField = Fr>>(points: &mut [G], omega: Fr, log_n: u32) {
+    fn bitreverse(mut n: usize, l: u32) -> usize {
+        let mut r = 0;
+        for _ in 0..l {
+            r = (r << 1) | (n & 1);
+            n >>= 1;
+        }
+        r
+    }
+
+    let n = points.len();
+    assert_eq!(n, 1 << log_n);
+
+    for k in 0..n {
+        let rk = bitreverse(k, log_n);
+        if k < rk {
+            points.swap(rk, k);
+        }
+    }
+
+    let mut m = 1;
+    for _ in 0..log_n {
+        let w_m = omega.pow([(n / (2 * m)) as u64]);
+
+        let mut k = 0;
+        while k < n {
+            let mut w = Fr::one();
+            for j in 0..m {
+                let mut t = points[k + j + m];
+                t.mul_assign(w.into_repr());
+                let mut tmp = points[k + j];
+                tmp -= &t;
+                points[k + j + m] = tmp;
+                points[k + j] += &t;
+                w *= &w_m;
+            }
+
+            k += 2 * m;
+        }
+
+        m *= 2;
+    }
+}
+
+/// Returns `true` if the point is on the curve and in the prime order subgroup.
+pub fn is_valid<G: AffineCurve>(point: &G) -> bool {
+    point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()
+}
+
+/// Reads a point and rejects it if it is not in the prime order subgroup.
+pub fn read_point<G: AffineCurve, R: Read>(reader: R) -> io::Result<G> {
+    let point = G::read(reader)?;
+    if !is_valid(&point) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "point is not in the correct subgroup",
+        ));
+    }
+
+    Ok(point)
+}
+
+/// Writes a length-prefixed vector of points.
+pub fn write_points<G: AffineCurve, W: Write>(points: &[G], mut writer: W) -> io::Result<()> {
+    (points.len() as u32).write(&mut writer)?;
+    for point in points {
+        point.write(&mut writer)?;
+    }
+
+    Ok(())
+}
+
+/// Reads a length-prefixed vector of points, rejecting any point outside of the prime order subgroup.
+pub fn read_points<G: AffineCurve, R: Read>(mut reader: R) -> io::Result<Vec<G>> {
+    let len: u32 = FromBytes::read(&mut reader)?;
+
+    (0..len).map(|_| read_point(&mut reader)).collect()
+}
+
+/// A proof of knowledge of a scalar `x`, bound to the transcript digest it was created for.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KnowledgeProof {
+    /// A random point `s`.
+    pub s: G1Affine,
+    /// The point `s * x`.
+    pub s_x: G1Affine,
+    /// The point `r * x`, where `r` is derived from the digest and `s`, `s_x`.
+    pub r_x: G2Affine,
+}
+
+impl KnowledgeProof {
+    pub fn new<R: Rng>(x: Fr, digest: &Hash, personalization: u8, rng: &mut R) -> Self {
+        let s = G1Projective::rand(rng).into_affine();
+        let s_x = s.mul(x.into_repr()).into_affine();
+        let r_x = Self::challenge(digest, personalization, &s, &s_x)
+            .mul(x.into_repr())
+            .into_affine();
+
+        Self { s, s_x, r_x }
+    }
+
+    /// Returns the point `r` for the given digest, personalization, and random points.
+    fn challenge(digest: &Hash, personalization: u8, s: &G1Affine, s_x: &G1Affine) -> G2Affine {
+        let mut bytes = digest.to_vec();
+        bytes.push(personalization);
+        s.write(&mut bytes).expect("writing to a vector cannot fail");
+        s_x.write(&mut bytes).expect("writing to a vector cannot fail");
+
+        hash_to_g2(&bytes)
+    }
+
+    /// Returns `r` if the contributor knows `x` such that `s_x = s * x` and `r_x = r * x`.
+    pub fn verify(&self, digest: &Hash, personalization: u8) -> Option<G2Affine> {
+        if self.s.is_zero() || self.s_x.is_zero() || self.r_x.is_zero() {
+            return None;
+        }
+
+        let r = Self::challenge(digest, personalization, &self.s, &self.s_x);
+
+        match same_ratio((self.s, self.s_x), (r, self.r_x)) {
+            true => Some(r),
+            false => None,
+        }
+    }
+
+    /// Returns `true` if `after = before * x` for the `x` known by the contributor.
+    pub fn verify_transition(&self, r: G2Affine, before: G1Affine, after: G1Affine) -> bool {
+        !after.is_zero() && same_ratio((before, after), (r, self.r_x))
+    }
+}
+
+impl ToBytes for KnowledgeProof {
+    fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.s.write(&mut writer)?;
+        self.s_x.write(&mut writer)?;
+        self.r_x.write(&mut writer)
+    }
+}
+
+impl FromBytes for KnowledgeProof {
+    fn read<R: Read>(mut reader: R) -> io::Result<Self> {
+        Ok(Self {
+            s: read_point(&mut reader)?,
+            s_x: read_point(&mut reader)?,
+            r_x: read_point(&mut reader)?,
+        })
+    }
+}
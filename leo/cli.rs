@@ -107,9 +107,11 @@ pub trait CLI {
     #[cfg_attr(tarpaulin, skip)]
     fn process(arguments: &ArgMatches) -> Result<(), CLIError> {
         // Set logging environment
+        let message_format = logger::MessageFormat::from_arguments(arguments);
+
         match arguments.is_present("debug") {
-            true => logger::init_logger("leo", 2),
-            false => logger::init_logger("leo", 1),
+            true => logger::init_logger("leo", 2, message_format),
+            false => logger::init_logger("leo", 1, message_format),
         }
 
         if arguments.subcommand().0 != "update" {
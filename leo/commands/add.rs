@@ -19,30 +19,26 @@
 //
 //    leo add -a author -p package_name -v version
 //    leo add -a author -p package_name
+//    leo add -a author -p package_name --offline
 //
 
-use crate::{cli::CLI, cli_types::*, config::*, errors::AddError::*};
-use leo_package::{
-    imports::{ImportsDirectory, IMPORTS_DIRECTORY_NAME},
-    root::Manifest,
+use crate::{
+    cli::CLI,
+    cli_types::*,
+    commands::{FetchCommand, PackageSources},
+    errors::AddError::*,
 };
+use leo_package::root::{Dependency, Manifest};
 
-use std::{
-    collections::HashMap,
-    convert::TryFrom,
-    env::current_dir,
-    fs::{create_dir_all, File},
-    io::{Read, Write},
-};
-
-pub const ADD_URL: &str = "v1/package/fetch";
+use std::{convert::TryFrom, env::current_dir};
 
 #[derive(Debug)]
 pub struct AddCommand;
 
 impl CLI for AddCommand {
     // Format: author, package_name, version
-    type Options = (Option<String>, Option<String>, Option<String>);
+    // Format: author, package name, version, offline
+    type Options = (Option<String>, Option<String>, Option<String>, bool);
     type Output = ();
 
     const ABOUT: AboutType = "Install a package from the Aleo Package Manager";
@@ -56,7 +52,7 @@ impl CLI for AddCommand {
             1u64,
         ),
     ];
-    const FLAGS: &'static [FlagType] = &[];
+    const FLAGS: &'static [FlagType] = &["[offline] --offline 'Only uses cached registry packages'"];
     const NAME: NameType = "add";
     const OPTIONS: &'static [OptionType] = &[
         // (argument, conflicts, possible_values, requires)
@@ -79,12 +75,15 @@ impl CLI for AddCommand {
     const SUBCOMMANDS: &'static [SubCommandType] = &[];
 
     fn parse(arguments: &clap::ArgMatches) -> Result<Self::Options, crate::errors::CLIError> {
+        let offline = arguments.is_present("offline");
+
         // TODO update to new package manager API without an author field
         if arguments.is_present("author") && arguments.is_present("package") {
             return Ok((
                 arguments.value_of("author").map(|s| s.to_string()),
                 arguments.value_of("package").map(|s| s.to_string()),
                 arguments.value_of("version").map(|s| s.to_string()),
+                offline,
             ));
         }
 
@@ -99,9 +98,9 @@ impl CLI for AddCommand {
                 let author = values[0].to_string();
                 let package = values[1].to_string();
 
-                Ok((Some(author), Some(package), None))
+                Ok((Some(author), Some(package), None, offline))
             }
-            None => Ok((None, None, None)),
+            None => Ok((None, None, None, offline)),
         }
     }
 
@@ -110,73 +109,28 @@ impl CLI for AddCommand {
         let span = tracing::span!(tracing::Level::INFO, "Adding");
         let _enter = span.enter();
 
-        let token = read_token()?;
-
         let path = current_dir()?;
         // Enforce that the current directory is a leo package
         Manifest::try_from(&path)?;
 
-        let (response, package_name) = match options {
-            (Some(author), Some(package_name), version) => {
-                let client = reqwest::blocking::Client::new();
-                let url = format!("{}{}", PACKAGE_MANAGER_URL, ADD_URL);
-
-                let mut json = HashMap::new();
-                json.insert("author", author);
-                json.insert("package_name", package_name.clone());
-
-                if let Some(version) = version {
-                    json.insert("version", version);
-                }
-
-                match client.post(&url).bearer_auth(token).json(&json).send() {
-                    Ok(response) => (response, package_name),
-                    //Cannot connect to the server
-                    Err(_error) => {
-                        return Err(
-                            ConnectionUnavailable("Could not connect to the Aleo Package Manager".into()).into(),
-                        );
-                    }
-                }
-            }
+        let (author, package_name, version, offline) = match options {
+            (Some(author), Some(package_name), version, offline) => (author, package_name, version, offline),
             _ => return Err(MissingAuthorOrPackageName.into()),
         };
 
-        let mut path = current_dir()?;
-        ImportsDirectory::create(&path)?;
-        path.push(IMPORTS_DIRECTORY_NAME);
-        path.push(package_name);
-        create_dir_all(&path)?;
-
-        let bytes = response.bytes()?;
-        let reader = std::io::Cursor::new(bytes);
+        let mut sources = PackageSources::new(&path, offline)?;
 
-        let mut zip_arhive = match zip::ZipArchive::new(reader) {
-            Ok(zip) => zip,
-            Err(error) => return Err(ZipError(error.to_string().into()).into()),
+        // Without a version, require a version compatible with the latest one
+        let requirement = match version {
+            Some(version) => version,
+            None => sources.download(&author, &package_name, None)?.version.to_string(),
         };
 
-        for i in 0..zip_arhive.len() {
-            let file = match zip_arhive.by_index(i) {
-                Ok(file) => file,
-                Err(error) => return Err(ZipError(error.to_string().into()).into()),
-            };
-
-            let file_name = file.name();
-
-            let mut file_path = path.clone();
-            file_path.push(file_name);
+        let key = format!("{}/{}", author, package_name);
+        Manifest::add_dependency(&path, &key, &Dependency::Registry(requirement))?;
 
-            if file_name.ends_with("/") {
-                create_dir_all(file_path)?;
-            } else {
-                if let Some(parent_directory) = path.parent() {
-                    create_dir_all(parent_directory)?;
-                }
-
-                File::create(file_path)?.write_all(&file.bytes().map(|e| e.unwrap()).collect::<Vec<u8>>())?;
-            }
-        }
+        // Resolve and install the new dependency together with the existing ones
+        FetchCommand::fetch(&mut sources, false)?;
 
         tracing::info!("Successfully added a package\n");
         Ok(())
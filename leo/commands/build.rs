@@ -19,12 +19,13 @@ use crate::{
     cli_types::*,
     errors::CLIError,
     synthesizer::{CircuitSynthesizer, SerializedCircuit},
+    workspace::{fetch_member_imports, for_each_member},
 };
 use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
 use leo_package::{
     inputs::*,
-    outputs::{ChecksumFile, CircuitFile, OutputsDirectory, OUTPUTS_DIRECTORY_NAME},
-    root::Manifest,
+    outputs::{BuildManifestFile, ChecksumFile, CircuitFile, OutputsDirectory},
+    root::{Manifest, Workspace},
     source::{LibraryFile, MainFile, LIBRARY_FILENAME, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
 
@@ -34,27 +35,57 @@ use snarkos_models::gadgets::r1cs::ConstraintSystem;
 use clap::ArgMatches;
 use std::{convert::TryFrom, env::current_dir, time::Instant};
 
-#[derive(Debug)]
-pub struct BuildCommand;
-
-impl CLI for BuildCommand {
-    type Options = ();
-    type Output = Option<(Compiler<Fq, EdwardsGroupType>, bool)>;
+/// The input and state files a program runs on.
+/// Defaults to `inputs/<package_name>.in` and `inputs/<package_name>.state`.
+#[derive(Clone, Debug, Default)]
+pub struct InputSelection {
+    pub input: Option<String>,
+    pub state: Option<String>,
+}
 
-    const ABOUT: AboutType = "Compile the current package as a program";
-    const ARGUMENTS: &'static [ArgumentType] = &[];
-    const FLAGS: &'static [FlagType] = &[];
-    const NAME: NameType = "build";
-    const OPTIONS: &'static [OptionType] = &[];
-    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+impl InputSelection {
+    pub const OPTIONS: [OptionType; 2] = [
+        // (argument, conflicts, possible_values, requires)
+        (
+            "[input] --input=[input] 'Runs the program on inputs/<input>.in and names the output and proof files after it'",
+            &[],
+            &[],
+            &[],
+        ),
+        (
+            "[state] --state=[state] 'Runs the program on inputs/<state>.state instead of the state file named after the input'",
+            &[],
+            &[],
+            &[],
+        ),
+    ];
 
     #[cfg_attr(tarpaulin, skip)]
-    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
-        Ok(())
+    pub fn parse(arguments: &ArgMatches) -> Self {
+        Self {
+            input: arguments.value_of("input").map(|input| input.to_string()),
+            state: arguments.value_of("state").map(|state| state.to_string()),
+        }
     }
 
+    /// Returns the name of the input file.
+    pub fn input_name(&self, package_name: &str) -> String {
+        self.input.clone().unwrap_or_else(|| package_name.to_string())
+    }
+
+    /// Returns the name of the state file, which defaults to the name of the input file.
+    pub fn state_name(&self, package_name: &str) -> String {
+        self.state.clone().unwrap_or_else(|| self.input_name(package_name))
+    }
+}
+
+#[derive(Debug)]
+pub struct BuildCommand;
+
+impl BuildCommand {
+    /// Compiles the package in the current directory with the selected input and state files.
     #[cfg_attr(tarpaulin, skip)]
-    fn output(_options: Self::Options) -> Result<Self::Output, CLIError> {
+    pub fn build(selection: &InputSelection) -> Result<<Self as CLI>::Output, CLIError> {
         // Begin "Compiling" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Compiling");
         let enter = span.enter();
@@ -64,6 +95,8 @@ impl CLI for BuildCommand {
         // Get the package name
         let manifest = Manifest::try_from(&path)?;
         let package_name = manifest.get_package_name();
+        let input_name = selection.input_name(&package_name);
+        let state_name = selection.state_name(&package_name);
 
         // Sanitize the package path to the root directory
         let mut package_path = path.clone();
@@ -71,9 +104,8 @@ impl CLI for BuildCommand {
             package_path.pop();
         }
 
-        // Construct the path to the output directory
-        let mut output_directory = package_path.clone();
-        output_directory.push(OUTPUTS_DIRECTORY_NAME);
+        // Construct the path to the output directory, which is shared by the members of a workspace
+        let output_directory = Workspace::outputs_directory(&package_path)?;
 
         tracing::info!("Starting...");
 
@@ -102,35 +134,72 @@ impl CLI for BuildCommand {
         // Compile the main.leo file along with constraints
         if MainFile::exists_at(&package_path) {
             // Create the output directory
-            OutputsDirectory::create(&package_path)?;
+            OutputsDirectory::create(&output_directory)?;
 
             // Construct the path to the main file in the source directory
             let mut main_file_path = package_path.clone();
             main_file_path.push(SOURCE_DIRECTORY_NAME);
             main_file_path.push(MAIN_FILENAME);
 
-            // Load the input file at `package_name.in`
-            let (input_string, input_path) = InputFile::new(&package_name).read_from(&path)?;
+            // Load the JSON input file at `input_name.json` or the TOML input file at `input_name.toml`
+            // if it exists and no state file is selected, since it replaces both input files
+            let json_input_file = JsonInputFile::new(&input_name);
+            let toml_input_file = TomlInputFile::new(&input_name);
+            let use_json_input = selection.state.is_none() && json_input_file.exists_at(&path);
+            let use_toml_input = selection.state.is_none() && toml_input_file.exists_at(&path);
+            let (input_string, input_path, state_string, state_path) = if use_json_input {
+                let (input_string, state_string, json_path) = json_input_file.read_from(&path)?;
 
-            // Load the state file at `package_name.in`
-            let (state_string, state_path) = StateFile::new(&package_name).read_from(&path)?;
+                (input_string, json_path.clone(), state_string, json_path)
+            } else if use_toml_input {
+                let (input_string, state_string, toml_path) = toml_input_file.read_from(&path)?;
+
+                (input_string, toml_path.clone(), state_string, toml_path)
+            } else {
+                // Load the input file at `input_name.in`
+                let (input_string, input_path) = InputFile::new(&input_name).read_from(&path)?;
+
+                // Load the state file at `state_name.state`
+                let (state_string, state_path) = StateFile::new(&state_name).read_from(&path)?;
+
+                (input_string, input_path, state_string, state_path)
+            };
 
             // Log compilation of files to console
             tracing::info!("Compiling main program... ({:?})", main_file_path);
 
-            // Load the program at `main_file_path`
-            let program = Compiler::<Fq, EdwardsGroupType>::parse_program_with_input(
+            // Load the program at `main_file_path`, writing its output to the output file named after the input
+            let mut program = Compiler::<Fq, EdwardsGroupType>::parse_program_without_input(
                 package_name.clone(),
                 main_file_path.clone(),
-                output_directory,
-                &input_string,
-                input_path,
-                &state_string,
-                state_path,
-            )?;
+                output_directory.clone(),
+            )?
+            .with_input(&input_name, &input_string, input_path, &state_string, state_path)?;
 
-            // Compute the current program checksum
-            let program_checksum = program.checksum()?;
+            // Enforce the local data commitment of the program state if the manifest enables it
+            if manifest.get_enforce_local_data() {
+                program.enforce_local_data();
+            }
+
+            // Compute the current program checksum from the sources, inputs, and compiler version
+            let build_manifest = program.build_manifest()?;
+            let program_checksum = build_manifest.checksum();
+
+            // Count the constraints of the unoptimized program without repeating its console output
+            let unoptimized_constraints = tracing::dispatcher::with_default(&tracing::Dispatch::none(), || {
+                let mut cs = CircuitSynthesizer::<Bls12_377> {
+                    at: vec![],
+                    bt: vec![],
+                    ct: vec![],
+                    input_assignment: vec![],
+                    aux_assignment: vec![],
+                };
+
+                program.clone().compile_constraints(&mut cs).ok().map(|_| cs.num_constraints())
+            });
+
+            // Fold constant expressions and remove dead branches
+            program.optimize();
 
             // Generate the program on the constraint system and verify correctness
             {
@@ -147,6 +216,15 @@ impl CLI for BuildCommand {
                 tracing::debug!("Compiled constraints - {:#?}", output);
                 tracing::debug!("Number of constraints - {:#?}", cs.num_constraints());
 
+                if let Some(unoptimized_constraints) = unoptimized_constraints {
+                    tracing::info!(
+                        "Optimized constraints - {} saved ({} to {})",
+                        unoptimized_constraints.saturating_sub(cs.num_constraints()),
+                        unoptimized_constraints,
+                        cs.num_constraints()
+                    );
+                }
+
                 // Serialize the circuit
                 let circuit_object = SerializedCircuit::from(cs);
                 let json = circuit_object.to_json_string().unwrap();
@@ -154,10 +232,10 @@ impl CLI for BuildCommand {
 
                 // Write serialized circuit to circuit `.json` file.
                 let circuit_file = CircuitFile::new(&package_name);
-                circuit_file.write_to(&path, json)?;
+                circuit_file.write_to(&output_directory, json)?;
 
                 // Check that we can read the serialized circuit file
-                let serialized = circuit_file.read_from(&package_path)?;
+                let serialized = circuit_file.read_from(&output_directory)?;
 
                 // Deserialize the circuit
                 let deserialized = SerializedCircuit::from_json_string(&serialized).unwrap();
@@ -167,8 +245,8 @@ impl CLI for BuildCommand {
 
             // If a checksum file exists, check if it differs from the new checksum
             let checksum_file = ChecksumFile::new(&package_name);
-            let checksum_differs = if checksum_file.exists_at(&package_path) {
-                let previous_checksum = checksum_file.read_from(&package_path)?;
+            let checksum_differs = if checksum_file.exists_at(&output_directory) {
+                let previous_checksum = checksum_file.read_from(&output_directory)?;
                 program_checksum != previous_checksum
             } else {
                 // By default, the checksum differs if there is no checksum to compare against
@@ -178,11 +256,16 @@ impl CLI for BuildCommand {
             // If checksum differs, compile the program
             if checksum_differs {
                 // Write the new checksum to the output directory
-                checksum_file.write_to(&path, program_checksum)?;
+                checksum_file.write_to(&output_directory, program_checksum)?;
 
-                tracing::debug!("Checksum saved ({:?})", path);
+                tracing::debug!("Checksum saved ({:?})", output_directory);
             }
 
+            // Write the build manifest listing everything that went into the checksum
+            BuildManifestFile::new(&package_name).write_to(&output_directory, build_manifest.to_json_string())?;
+
+            tracing::debug!("Build manifest saved ({:?})", output_directory);
+
             tracing::info!("Complete");
 
             // Drop "Compiling" context for console logging
@@ -203,3 +286,35 @@ impl CLI for BuildCommand {
         Ok(None)
     }
 }
+
+impl CLI for BuildCommand {
+    type Options = InputSelection;
+    type Output = Option<(Compiler<Fq, EdwardsGroupType>, bool)>;
+
+    const ABOUT: AboutType = "Compile the current package as a program";
+    const ARGUMENTS: &'static [ArgumentType] = &[];
+    const FLAGS: &'static [FlagType] = &[];
+    const NAME: NameType = "build";
+    const OPTIONS: &'static [OptionType] = &InputSelection::OPTIONS;
+    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok(InputSelection::parse(arguments))
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
+        // Build every member of a workspace, after the members it imports
+        let workspace = for_each_member(|member| {
+            fetch_member_imports(member)?;
+            Self::build(&options).map(|_| ())
+        })?;
+
+        if workspace {
+            return Ok(None);
+        }
+
+        Self::build(&options)
+    }
+}
@@ -0,0 +1,325 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    ceremony::{self, Transcript},
+    cli::*,
+    cli_types::*,
+    commands::{BuildCommand, InputSelection},
+    errors::{CLIError, CeremonyError, RunError},
+    proving::ProvingKey,
+};
+use leo_package::{
+    outputs::{CeremonyFile, ProvingKeyFile, VerificationKeyFile},
+    proving_system::ProvingSystem,
+    root::{Manifest, Workspace},
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+
+use snarkos_utilities::bytes::{FromBytes, ToBytes};
+
+use clap::ArgMatches;
+use rand::thread_rng;
+use std::{
+    convert::TryFrom,
+    env::current_dir,
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+/// A step of the trusted setup ceremony.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CeremonyAction {
+    Init,
+    Contribute,
+    Prepare,
+    Verify,
+    Finalize,
+}
+
+impl CeremonyAction {
+    pub const NAMES: &'static [&'static str] = &["init", "contribute", "prepare", "verify", "finalize"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "init" => Some(CeremonyAction::Init),
+            "contribute" => Some(CeremonyAction::Contribute),
+            "prepare" => Some(CeremonyAction::Prepare),
+            "verify" => Some(CeremonyAction::Verify),
+            "finalize" => Some(CeremonyAction::Finalize),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CeremonyCommand;
+
+impl CLI for CeremonyCommand {
+    // (action, transcript)
+    type Options = (CeremonyAction, Option<PathBuf>);
+    type Output = ();
+
+    const ABOUT: AboutType = "Run a multi-party trusted setup ceremony for the program keys";
+    const ARGUMENTS: &'static [ArgumentType] = &[
+        // (name, description, possible_values, required, index)
+        (
+            "action",
+            "init: start a transcript for the program, contribute: add fresh randomness to the transcript, \
+             prepare: close phase 1 and derive the program parameters, verify: check every contribution, \
+             finalize: write the proving and verification keys",
+            CeremonyAction::NAMES,
+            true,
+            1u64,
+        ),
+    ];
+    const FLAGS: &'static [FlagType] = &[];
+    const NAME: NameType = "ceremony";
+    const OPTIONS: &'static [OptionType] = &[
+        // (argument, conflicts, possible_values, requires)
+        (
+            "[transcript] --transcript=[path] 'Uses the transcript at the given path instead of the one in the outputs directory'",
+            &[],
+            &[],
+            &[],
+        ),
+    ];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        let action = arguments
+            .value_of("action")
+            .and_then(CeremonyAction::from_name)
+            .expect("clap validates the possible values");
+        let transcript = arguments.value_of("transcript").map(PathBuf::from);
+
+        Ok((action, transcript))
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
+        let (action, transcript) = options;
+
+        // A contribution only requires the transcript, so participants do not need the program package
+        if action == CeremonyAction::Contribute {
+            let (ceremony_file, path) = match transcript {
+                // An explicit transcript path is used as is, the package name only names the default transcript
+                Some(path) => (CeremonyFile::new(&file_stem(&path)), path),
+                None => {
+                    let path = current_dir()?;
+                    (CeremonyFile::new(&Manifest::try_from(&path)?.get_package_name()), path)
+                }
+            };
+
+            return contribute(&ceremony_file, &path);
+        }
+
+        // Get the package name
+        let path = current_dir()?;
+        let manifest = Manifest::try_from(&path)?;
+        let package_name = manifest.get_package_name();
+
+        // Get the output directory, which is shared by the members of a workspace
+        let output_directory = Workspace::outputs_directory(&path)?;
+
+        let ceremony_file = CeremonyFile::new(&package_name);
+        let transcript_path = transcript.unwrap_or_else(|| path.clone());
+
+        // The ceremony only produces Groth16 parameters
+        let proving_system = manifest.get_proving_system();
+        if action == CeremonyAction::Finalize && proving_system != ProvingSystem::Groth16 {
+            return Err(CeremonyError::UnsupportedProvingSystem(proving_system).into());
+        }
+
+        let program = match BuildCommand::output(InputSelection::default())? {
+            Some((program, _checksum_differs)) => program,
+            None => {
+                let mut main_file_path = path.clone();
+                main_file_path.push(SOURCE_DIRECTORY_NAME);
+                main_file_path.push(MAIN_FILENAME);
+
+                return Err(CLIError::RunError(RunError::MainFileDoesNotExist(
+                    main_file_path.into_os_string(),
+                )));
+            }
+        };
+
+        // Begin "Ceremony" context for console logging
+        let span = tracing::span!(tracing::Level::INFO, "Ceremony");
+        let enter = span.enter();
+
+        let start = Instant::now();
+
+        let checksum = program.checksum()?;
+        let assembly = ceremony::synthesize(program)?;
+
+        match action {
+            CeremonyAction::Init => {
+                if ceremony_file.exists_at(&transcript_path) {
+                    let full_path = ceremony_file.full_path(&transcript_path);
+                    return Err(CeremonyError::TranscriptExists(full_path).into());
+                }
+
+                let transcript = Transcript::new(checksum, &assembly)?;
+                tracing::info!(
+                    "Initialized a transcript with {} powers of tau",
+                    transcript.accumulator.size()
+                );
+
+                write_transcript(&ceremony_file, &transcript_path, &transcript)?;
+            }
+            CeremonyAction::Contribute => unreachable!(),
+            CeremonyAction::Prepare => {
+                let mut transcript = read_transcript(&ceremony_file, &transcript_path)?;
+
+                tracing::info!("Verifying phase 1...");
+                transcript.verify(&checksum, &assembly, &mut thread_rng())?;
+
+                tracing::info!("Deriving the program parameters...");
+                transcript.prepare(&assembly)?;
+
+                write_transcript(&ceremony_file, &transcript_path, &transcript)?;
+            }
+            CeremonyAction::Verify => {
+                let transcript = read_transcript(&ceremony_file, &transcript_path)?;
+
+                tracing::info!("Verifying...");
+                let digest = transcript.verify(&checksum, &assembly, &mut thread_rng())?;
+
+                log_contributions(&transcript);
+                tracing::info!("Transcript digest {}", ceremony::to_hex(&digest));
+            }
+            CeremonyAction::Finalize => {
+                let transcript = read_transcript(&ceremony_file, &transcript_path)?;
+
+                tracing::info!("Verifying...");
+                let digest = transcript.verify(&checksum, &assembly, &mut thread_rng())?;
+                tracing::info!("Transcript digest {}", ceremony::to_hex(&digest));
+
+                let proving_key = ProvingKey::Groth16(transcript.into_parameters()?);
+
+                // Write the proving key file to the output directory
+                let proving_key_file = ProvingKeyFile::new(&package_name);
+                tracing::info!(
+                    "Saving proving key ({:?})",
+                    proving_key_file.full_path(&output_directory)
+                );
+                let proving_key_bytes = proving_key.to_bytes()?;
+                let _ = proving_key_file.write_to(&output_directory, ProvingSystem::Groth16, &proving_key_bytes)?;
+                tracing::info!("Complete");
+
+                // Write the verification key file to the output directory
+                let verification_key_file = VerificationKeyFile::new(&package_name);
+                tracing::info!(
+                    "Saving verification key ({:?})",
+                    verification_key_file.full_path(&output_directory)
+                );
+                let verification_key = proving_key.verification_key_bytes()?;
+                let _ = verification_key_file.write_to(&output_directory, ProvingSystem::Groth16, &verification_key)?;
+                tracing::info!("Complete");
+            }
+        }
+
+        let end = start.elapsed().as_millis();
+
+        // Drop "Ceremony" context for console logging
+        drop(enter);
+
+        // Begin "Done" context for console logging
+        tracing::span!(tracing::Level::INFO, "Done").in_scope(|| {
+            tracing::info!("Finished in {:?} milliseconds\n", end);
+        });
+
+        Ok(())
+    }
+}
+
+/// Adds a contribution with fresh randomness to the transcript at the given path.
+fn contribute(ceremony_file: &CeremonyFile, path: &PathBuf) -> Result<(), CLIError> {
+    // Begin "Ceremony" context for console logging
+    let span = tracing::span!(tracing::Level::INFO, "Ceremony");
+    let enter = span.enter();
+
+    let start = Instant::now();
+
+    let mut transcript = read_transcript(ceremony_file, path)?;
+
+    let phase = match transcript.phase2 {
+        Some(_) => 2,
+        None => 1,
+    };
+    tracing::info!("Contributing to phase {}...", phase);
+
+    let digest = transcript.contribute(&mut thread_rng());
+
+    write_transcript(ceremony_file, path, &transcript)?;
+
+    tracing::info!("Your contribution digest is {}", ceremony::to_hex(&digest));
+    tracing::info!("Publish it so others can find your contribution in the verified transcript");
+
+    let end = start.elapsed().as_millis();
+
+    // Drop "Ceremony" context for console logging
+    drop(enter);
+
+    // Begin "Done" context for console logging
+    tracing::span!(tracing::Level::INFO, "Done").in_scope(|| {
+        tracing::info!("Finished in {:?} milliseconds\n", end);
+    });
+
+    Ok(())
+}
+
+fn read_transcript(ceremony_file: &CeremonyFile, path: &PathBuf) -> Result<Transcript, CLIError> {
+    let bytes = ceremony_file.read_from(path)?;
+
+    Transcript::read(bytes.as_slice()).map_err(|error| CeremonyError::InvalidTranscript(error.to_string()).into())
+}
+
+fn write_transcript(ceremony_file: &CeremonyFile, path: &PathBuf, transcript: &Transcript) -> Result<(), CLIError> {
+    let mut bytes = vec![];
+    transcript.write(&mut bytes)?;
+
+    let full_path = ceremony_file.write_to(path, &bytes)?;
+    tracing::info!("Saved transcript ({:?})", full_path);
+
+    Ok(())
+}
+
+/// Logs the digest after each contribution, so participants can check that their contribution is included.
+fn log_contributions(transcript: &Transcript) {
+    let mut digest = transcript.initial_digest();
+
+    for (index, contribution) in transcript.phase1.iter().enumerate() {
+        digest = contribution.digest(&digest);
+        tracing::info!("Phase 1 contribution {}: {}", index + 1, ceremony::to_hex(&digest));
+    }
+
+    if let Some(phase2) = &transcript.phase2 {
+        for (index, contribution) in phase2.contributions.iter().enumerate() {
+            digest = contribution.digest(&digest);
+            tracing::info!("Phase 2 contribution {}: {}", index + 1, ceremony::to_hex(&digest));
+        }
+    }
+}
+
+/// Returns the file name of the transcript without its extension.
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
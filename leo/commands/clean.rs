@@ -14,14 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{cli::*, cli_types::*, errors::CLIError};
+use crate::{cli::*, cli_types::*, errors::CLIError, workspace::for_each_member};
 use leo_package::{
-    outputs::{ChecksumFile, ProofFile, ProvingKeyFile, VerificationKeyFile},
-    root::Manifest,
+    outputs::{BuildManifestFile, ChecksumFile, ProofFile, ProvingKeyFile, VerificationKeyFile},
+    root::{Manifest, Workspace},
 };
 
 use clap::ArgMatches;
-use leo_compiler::OutputFile;
+use leo_compiler::{CoverageFile, JsonOutputFile, OutputFile};
 use leo_package::outputs::CircuitFile;
 use std::{convert::TryFrom, env::current_dir};
 
@@ -46,6 +46,19 @@ impl CLI for CleanCommand {
 
     #[cfg_attr(tarpaulin, skip)]
     fn output(_options: Self::Options) -> Result<Self::Output, CLIError> {
+        // Clean every member of a workspace
+        if for_each_member(|_member| Self::clean())? {
+            return Ok(());
+        }
+
+        Self::clean()
+    }
+}
+
+impl CleanCommand {
+    /// Removes the output files of the package in the current directory.
+    #[cfg_attr(tarpaulin, skip)]
+    pub fn clean() -> Result<(), CLIError> {
         // Begin "Clean" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Cleaning");
         let enter = span.enter();
@@ -54,23 +67,35 @@ impl CLI for CleanCommand {
         let path = current_dir()?;
         let package_name = Manifest::try_from(&path)?.get_package_name();
 
+        // Get the output directory, which is shared by the members of a workspace
+        let output_directory = Workspace::outputs_directory(&path)?;
+
         // Remove the checksum from the output directory
-        ChecksumFile::new(&package_name).remove(&path)?;
+        ChecksumFile::new(&package_name).remove(&output_directory)?;
+
+        // Remove the build manifest from the output directory
+        BuildManifestFile::new(&package_name).remove(&output_directory)?;
 
         // Remove the serialized circuit from the output directory
-        CircuitFile::new(&package_name).remove(&path)?;
+        CircuitFile::new(&package_name).remove(&output_directory)?;
 
         // Remove the program output file from the output directory
-        OutputFile::new(&package_name).remove(&path)?;
+        OutputFile::new(&package_name).remove(&output_directory)?;
+
+        // Remove the program output file in JSON format from the output directory
+        JsonOutputFile::new(&package_name).remove(&output_directory)?;
+
+        // Remove the test coverage report from the output directory
+        CoverageFile::new(&package_name).remove(&output_directory)?;
 
         // Remove the proving key from the output directory
-        ProvingKeyFile::new(&package_name).remove(&path)?;
+        ProvingKeyFile::new(&package_name).remove(&output_directory)?;
 
         // Remove the verification key from the output directory
-        VerificationKeyFile::new(&package_name).remove(&path)?;
+        VerificationKeyFile::new(&package_name).remove(&output_directory)?;
 
         // Remove the proof from the output directory
-        ProofFile::new(&package_name).remove(&path)?;
+        ProofFile::new(&package_name).remove(&output_directory)?;
 
         // Drop "Compiling" context for console logging
         drop(enter);
@@ -17,7 +17,7 @@
 use crate::{
     cli::*,
     cli_types::*,
-    commands::BuildCommand,
+    commands::{BuildCommand, InputSelection},
     errors::{CLIError, RunError},
 };
 use leo_package::{
@@ -48,14 +48,14 @@ impl CLI for DeployCommand {
     }
 
     #[cfg_attr(tarpaulin, skip)]
-    fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
+    fn output(_options: Self::Options) -> Result<Self::Output, CLIError> {
         // Begin "Deploy" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Deploying");
         let _enter = span.enter();
 
         let path = current_dir()?;
 
-        match BuildCommand::output(options)? {
+        match BuildCommand::output(InputSelection::default())? {
             Some((_program, _checksum_differs)) => {
                 // Get the package name
                 let _package_name = Manifest::try_from(&path)?.get_package_name();
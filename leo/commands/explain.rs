@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    cli::*,
+    cli_types::*,
+    errors::{explain, CLIError, ExplainError},
+};
+
+use clap::ArgMatches;
+
+#[derive(Debug)]
+pub struct ExplainCommand;
+
+impl CLI for ExplainCommand {
+    type Options = String;
+    type Output = ();
+
+    const ABOUT: AboutType = "Explain an error code printed next to an error message";
+    const ARGUMENTS: &'static [ArgumentType] = &[
+        // (name, description, possible_values, required, index)
+        ("CODE", "Sets the error code to explain, such as E0325", &[], true, 1u64),
+    ];
+    const FLAGS: &'static [FlagType] = &[];
+    const NAME: NameType = "explain";
+    const OPTIONS: &'static [OptionType] = &[];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok(arguments.value_of("CODE").unwrap_or_default().to_string())
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn output(code: Self::Options) -> Result<Self::Output, CLIError> {
+        let explanation = explain(&code).ok_or_else(|| ExplainError::UnknownCode(code))?;
+
+        println!("{}", explanation);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,481 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//
+// Usage:
+//
+//    leo fetch
+//    leo fetch --locked
+//    leo fetch --offline
+//
+
+use crate::{
+    cli::*,
+    cli_types::*,
+    errors::{CLIError, FetchError, RegistryError},
+    registry::{open_registry, registry_url, unzip, Registry, RegistryCache},
+};
+use leo_package::{
+    errors::ResolverError,
+    imports::ImportsDirectory,
+    resolver::{PackageRegistry, PackageSummary, Resolution, Resolver},
+    root::{Dependency, LockFile, LockedPackage, Manifest},
+};
+
+use clap::ArgMatches;
+use semver::{Version, VersionReq};
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    env::{current_dir, temp_dir},
+    fs,
+    path::PathBuf,
+    process::Command,
+};
+
+/// Provides the packages that dependencies resolve to from the package registry,
+/// git repositories and the local filesystem.
+///
+/// Candidates are downloaded into a staging directory until the resolver has selected them.
+/// Locked packages that are installed with their locked contents are read from the imports directory,
+/// and are the only candidate for their dependency, so a locked package is never downloaded again.
+///
+/// Registry archives are kept in the registry cache. Offline, only cached archives and installed packages are used.
+pub struct PackageSources {
+    path: PathBuf,
+    lock_file: LockFile,
+    registry: Box<dyn Registry>,
+    cache: RegistryCache,
+    offline: bool,
+    staging_directory: PathBuf,
+    downloads: HashMap<String, PackageSummary>,
+    checkouts: usize,
+}
+
+impl PackageSources {
+    /// Creates the package sources for the package at the given path.
+    pub fn new(path: &PathBuf, offline: bool) -> Result<Self, CLIError> {
+        let lock_file = match LockFile::exists_at(path) {
+            true => LockFile::try_from(path)?,
+            false => LockFile::new(),
+        };
+
+        Ok(Self {
+            path: path.clone(),
+            lock_file,
+            registry: open_registry(&registry_url(path)?),
+            cache: RegistryCache::default(),
+            offline,
+            staging_directory: temp_dir().join(format!("leo-fetch-{}", std::process::id())),
+            downloads: HashMap::new(),
+            checkouts: 0,
+        })
+    }
+
+    /// Uses the given registry cache instead of the one in the Leo config directory.
+    pub fn with_cache(mut self, cache: RegistryCache) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Downloads a package from the registry, or its latest version if no version is given.
+    /// Cached versions are not downloaded again.
+    pub fn download(
+        &mut self,
+        author: &str,
+        package_name: &str,
+        version: Option<&str>,
+    ) -> Result<PackageSummary, CLIError> {
+        let key = format!("{}/{}", author, package_name);
+        let download = format!("{}@{}", key, version.unwrap_or("latest"));
+
+        if let Some(summary) = self.downloads.get(&download) {
+            return Ok(summary.clone());
+        }
+
+        // Offline, the latest version is the latest cached version
+        let version = match version {
+            None if self.offline => match self.cache.versions(author, package_name)?.pop() {
+                Some(latest) => Some(latest.to_string()),
+                None => return Err(RegistryError::NotCached(key, "latest".into()).into()),
+            },
+            version => version.map(|version| version.to_string()),
+        };
+
+        let cached = match &version {
+            Some(version) => self.cache.archive(author, package_name, version)?,
+            None => None,
+        };
+
+        let archive = match cached {
+            Some(archive) => archive,
+            None if self.offline => {
+                return Err(RegistryError::NotCached(key, format!("v{}", version.unwrap_or_default())).into());
+            }
+            None => self.registry.archive(author, package_name, version.as_deref())?,
+        };
+
+        let mut directory = self.staging_directory.join("registry");
+        directory.push(author);
+        directory.push(package_name);
+        directory.push(version.as_deref().unwrap_or("latest"));
+
+        unzip(&archive, &directory)?;
+
+        let summary = PackageSummary::from_directory(package_name, Dependency::registry_source(&key), &directory)?;
+        self.cache.insert(author, package_name, &summary.version, &archive)?;
+        self.downloads
+            .insert(format!("{}@{}", key, summary.version), summary.clone());
+        self.downloads.insert(download, summary.clone());
+
+        Ok(summary)
+    }
+
+    /// Returns the locked package with the given source if it is installed with its locked contents.
+    fn installed(&self, package_name: &str, source: &str) -> Result<Option<PackageSummary>, CLIError> {
+        let package = match self.lock_file.get(package_name) {
+            Some(package) if package.source == source => package,
+            _ => return Ok(None),
+        };
+
+        let import = ImportsDirectory::import_path(&self.path, package_name);
+        if !import.is_dir() || LockFile::checksum(&import)? != package.checksum {
+            return Ok(None);
+        }
+
+        Ok(Some(PackageSummary::from_directory(
+            package_name,
+            package.source.clone(),
+            &import,
+        )?))
+    }
+
+    /// Clones a git repository into the staging directory and checks out the given reference.
+    fn checkout(
+        &mut self,
+        package_name: &str,
+        url: &str,
+        reference: Option<&str>,
+        source: &str,
+    ) -> Result<PackageSummary, CLIError> {
+        self.checkouts += 1;
+
+        let directory = self
+            .staging_directory
+            .join("git")
+            .join(format!("{}-{}", package_name, self.checkouts));
+        if directory.exists() {
+            fs::remove_dir_all(&directory)?;
+        }
+
+        // References are validated in the manifest, but a locked commit could still be read as an option
+        if let Some(reference) = reference.filter(|reference| reference.starts_with('-')) {
+            return Err(FetchError::GitError(format!("invalid reference `{}`", reference)).into());
+        }
+
+        let directory_path = directory.to_string_lossy().to_string();
+        git(None, &["clone", "--quiet", "--", url, directory_path.as_str()])?;
+        if let Some(reference) = reference {
+            git(Some(&directory), &["checkout", "--quiet", reference, "--"])?;
+        }
+        let commit = git(Some(&directory), &["rev-parse", "HEAD"])?;
+
+        Ok(PackageSummary::from_directory(
+            package_name,
+            format!("{}#{}", source, commit),
+            &directory,
+        )?)
+    }
+}
+
+impl PackageRegistry for PackageSources {
+    type Error = CLIError;
+
+    fn query(&mut self, key: &str, dependency: &Dependency) -> Result<Vec<PackageSummary>, Self::Error> {
+        let package_name = Dependency::import_name(key);
+        let source = dependency.source(key);
+        let locked = self
+            .lock_file
+            .get(package_name)
+            .filter(|package| package.unresolved_source() == source)
+            .cloned();
+
+        match dependency {
+            Dependency::Registry(requirement) => {
+                let (author, _) = Dependency::registry_key(key).unwrap_or((key, package_name));
+                let version_requirement = VersionReq::parse(requirement).map_err(|error| {
+                    ResolverError::InvalidRequirement(key.into(), requirement.clone(), error.to_string())
+                })?;
+
+                // Keep the locked version while it matches the requirement
+                if let Some(locked) = locked {
+                    let matches = Version::parse(&locked.version)
+                        .map(|version| version_requirement.matches(&version))
+                        .unwrap_or(false);
+
+                    if matches {
+                        return match self.installed(package_name, &locked.source)? {
+                            Some(summary) => Ok(vec![summary]),
+                            None => Ok(vec![self.download(
+                                author,
+                                package_name,
+                                Some(locked.version.as_str()),
+                            )?]),
+                        };
+                    }
+                }
+
+                // Offer every matching version if the registry can list them. Offline, those are the cached versions.
+                let versions = match self.offline {
+                    true => {
+                        let versions = self.cache.versions(author, package_name)?;
+                        if versions.is_empty() {
+                            return Err(RegistryError::NotCached(key.into(), requirement.clone()).into());
+                        }
+
+                        Some(versions)
+                    }
+                    false => self.registry.versions(author, package_name)?,
+                };
+
+                if let Some(versions) = versions {
+                    let mut summaries = vec![];
+                    for version in versions.iter().filter(|version| version_requirement.matches(version)) {
+                        summaries.push(self.download(author, package_name, Some(version.to_string().as_str()))?);
+                    }
+
+                    return Ok(summaries);
+                }
+
+                // The package manager serves the latest version, or a version by its exact number.
+                // The lowest version a requirement like `0.1.2` allows is offered as well,
+                // in case the latest version is not compatible.
+                let mut summaries = vec![self.download(author, package_name, None)?];
+
+                let minimum = requirement.trim_start_matches(|c: char| "=^~ ".contains(c));
+                if let Ok(minimum) = Version::parse(minimum) {
+                    if minimum != summaries[0].version && version_requirement.matches(&minimum) {
+                        summaries.push(self.download(author, package_name, Some(minimum.to_string().as_str()))?);
+                    }
+                }
+
+                Ok(summaries)
+            }
+            Dependency::Path { path } => Ok(vec![PackageSummary::from_directory(
+                package_name,
+                source,
+                &self.path.join(path),
+            )?]),
+            Dependency::Git { git, branch, tag, rev } => {
+                // Keep the locked commit while the repository and reference are unchanged
+                if let Some(locked) = &locked {
+                    if let Some(summary) = self.installed(package_name, &locked.source)? {
+                        return Ok(vec![summary]);
+                    }
+                }
+
+                if self.offline {
+                    return Err(RegistryError::NotInstalled(package_name.into()).into());
+                }
+
+                let reference = match &locked {
+                    Some(locked) => locked.git_commit(),
+                    None => rev.as_deref().or(tag.as_deref()).or(branch.as_deref()),
+                };
+
+                Ok(vec![self.checkout(package_name, git, reference, &source)?])
+            }
+        }
+    }
+}
+
+impl Drop for PackageSources {
+    fn drop(&mut self) {
+        if self.staging_directory.exists() {
+            let _ = fs::remove_dir_all(&self.staging_directory);
+        }
+    }
+}
+
+/// Runs a git command and returns its trimmed standard output.
+fn git(directory: Option<&PathBuf>, arguments: &[&str]) -> Result<String, FetchError> {
+    let mut command = Command::new("git");
+    if let Some(directory) = directory {
+        command.current_dir(directory);
+    }
+
+    let output = command
+        .args(arguments)
+        .output()
+        .map_err(|error| FetchError::GitError(error.to_string()))?;
+
+    if !output.status.success() {
+        return Err(FetchError::GitError(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[derive(Debug)]
+pub struct FetchCommand;
+
+impl FetchCommand {
+    /// Resolves the dependencies declared in the manifest, installs them into the imports directory
+    /// and writes the lock file. With `locked`, fails instead of changing the lock file.
+    pub fn fetch(sources: &mut PackageSources, locked: bool) -> Result<(), CLIError> {
+        let path = sources.path.clone();
+        let lock_file = sources.lock_file.clone();
+
+        if locked && !LockFile::exists_at(&path) {
+            return Err(FetchError::MissingLockFile.into());
+        }
+
+        let manifest = Manifest::try_from(&path)?;
+        let dependencies = manifest.get_dependencies()?;
+
+        let resolution = Resolver::new(sources, &lock_file).resolve(&manifest.get_package_name(), &dependencies)?;
+
+        if locked {
+            if let Some(package_name) = Self::outdated_package(&resolution, &lock_file) {
+                return Err(FetchError::LockFileOutdated(package_name).into());
+            }
+        }
+
+        ImportsDirectory::create(&path)?;
+
+        let mut resolved = LockFile::new();
+        resolved.dependencies = resolution.dependencies.clone();
+
+        for package in resolution.packages.iter() {
+            let summary = &package.summary;
+            let import = ImportsDirectory::import_path(&path, &summary.name);
+
+            if summary.path != import {
+                ImportsDirectory::copy_import(&path, &summary.name, &summary.path)?;
+            }
+
+            let locked_package = LockedPackage {
+                name: summary.name.clone(),
+                version: summary.version.to_string(),
+                source: summary.source.clone(),
+                checksum: LockFile::checksum(&import)?,
+                dependencies: package.dependencies.clone(),
+            };
+
+            // Registry and git packages are immutable, so their contents must not change once locked.
+            // Path packages can change at any time, which only `--locked` rejects.
+            if let Some(previous) = lock_file.get(&summary.name) {
+                let is_same_package =
+                    previous.source == locked_package.source && previous.version == locked_package.version;
+
+                if is_same_package && previous.checksum != locked_package.checksum {
+                    if !summary.source.starts_with("path+") {
+                        return Err(FetchError::ChecksumMismatch(
+                            summary.name.clone(),
+                            previous.checksum.clone(),
+                            locked_package.checksum,
+                        )
+                        .into());
+                    }
+                    if locked {
+                        return Err(FetchError::LockFileOutdated(summary.name.clone()).into());
+                    }
+                }
+            }
+
+            tracing::info!("{} v{} ({})", summary.name, summary.version, summary.source);
+            resolved.insert(locked_package);
+        }
+
+        // Remove the imports of locked packages that are no longer in the dependency graph
+        for package in lock_file.packages.iter() {
+            if resolved.get(&package.name).is_none() && ImportsDirectory::import_path(&path, &package.name).exists() {
+                ImportsDirectory::remove_import(&path, &package.name)?;
+            }
+        }
+
+        resolved.write_to(&path)?;
+        sources.lock_file = resolved;
+
+        tracing::info!("Successfully fetched {} packages\n", sources.lock_file.packages.len());
+
+        Ok(())
+    }
+
+    /// Returns the name of a package that the resolution would change in the lock file.
+    fn outdated_package(resolution: &Resolution, lock_file: &LockFile) -> Option<String> {
+        let changed = resolution.packages.iter().find(|package| {
+            let summary = &package.summary;
+
+            match lock_file.get(&summary.name) {
+                Some(locked) => {
+                    locked.version != summary.version.to_string()
+                        || locked.source != summary.source
+                        || locked.dependencies != package.dependencies
+                }
+                None => true,
+            }
+        });
+
+        if let Some(package) = changed {
+            return Some(package.summary.name.clone());
+        }
+
+        lock_file
+            .packages
+            .iter()
+            .find(|package| resolution.get(&package.name).is_none())
+            .map(|package| package.name.clone())
+            .or_else(|| match resolution.dependencies != lock_file.dependencies {
+                true => resolution.dependencies.first().cloned(),
+                false => None,
+            })
+    }
+}
+
+impl CLI for FetchCommand {
+    type Options = (bool, bool);
+    type Output = ();
+
+    const ABOUT: AboutType = "Install the dependencies declared in Leo.toml at the versions in Leo.lock";
+    const ARGUMENTS: &'static [ArgumentType] = &[];
+    const FLAGS: &'static [FlagType] = &[
+        "[locked] --locked 'Fails instead of updating Leo.lock'",
+        "[offline] --offline 'Only uses cached registry packages and installed git packages'",
+    ];
+    const NAME: NameType = "fetch";
+    const OPTIONS: &'static [OptionType] = &[];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok((arguments.is_present("locked"), arguments.is_present("offline")))
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn output((locked, offline): Self::Options) -> Result<Self::Output, CLIError> {
+        // Begin "Fetching" context for console logging
+        let span = tracing::span!(tracing::Level::INFO, "Fetching");
+        let _enter = span.enter();
+
+        let path = current_dir()?;
+        let mut sources = PackageSources::new(&path, offline)?;
+
+        Self::fetch(&mut sources, locked)
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    cli::*,
+    cli_types::*,
+    errors::{CLIError, FmtError},
+};
+use leo_ast::LeoAst;
+use leo_package::{root::Manifest, source::SourceDirectory};
+
+use clap::ArgMatches;
+use std::{convert::TryFrom, env::current_dir, fs};
+
+#[derive(Debug)]
+pub struct FmtCommand;
+
+impl CLI for FmtCommand {
+    type Options = bool;
+    type Output = ();
+
+    const ABOUT: AboutType = "Formats the Leo files in the package";
+    const ARGUMENTS: &'static [ArgumentType] = &[];
+    const FLAGS: &'static [FlagType] = &["[check] --check 'Checks the formatting of the files without modifying them'"];
+    const NAME: NameType = "fmt";
+    const OPTIONS: &'static [OptionType] = &[];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok(arguments.is_present("check"))
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn output(check: Self::Options) -> Result<Self::Output, CLIError> {
+        // Begin "Formatting" context for console logging
+        let span = tracing::span!(tracing::Level::INFO, "Formatting");
+        let enter = span.enter();
+
+        let path = current_dir()?;
+
+        // Ensure the current directory is a Leo package
+        let _package_name = Manifest::try_from(&path)?.get_package_name();
+
+        let mut unformatted_files = vec![];
+        for file_path in SourceDirectory::files(&path)? {
+            let program_string = LeoAst::load_file(&file_path)?;
+            let ast = LeoAst::new(&file_path, &program_string)?;
+
+            let formatted = ast.to_formatted_string();
+            if formatted == program_string {
+                continue;
+            }
+
+            if check {
+                tracing::info!("{:?} is not formatted", file_path);
+                unformatted_files.push(file_path.into_os_string());
+            } else {
+                fs::write(&file_path, formatted)
+                    .map_err(|error| FmtError::Writing(file_path.clone().into_os_string(), error))?;
+                tracing::info!("Formatted {:?}", file_path);
+            }
+        }
+
+        // Drop "Formatting" context for console logging
+        drop(enter);
+
+        if !unformatted_files.is_empty() {
+            return Err(FmtError::UnformattedFiles(unformatted_files).into());
+        }
+
+        tracing::span!(tracing::Level::INFO, "Done").in_scope(|| {
+            tracing::info!("Program files formatted\n");
+        });
+
+        Ok(())
+    }
+}
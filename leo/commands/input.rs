@@ -0,0 +1,208 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    cli::*,
+    cli_types::*,
+    errors::{CLIError, InputError, RunError},
+    workspace::{fetch_member_imports, for_each_member},
+};
+use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
+use leo_package::{
+    errors::InputFileError,
+    inputs::{
+        InputsDirectory,
+        JsonInputFile,
+        TomlInputFile,
+        INPUTS_DIRECTORY_NAME,
+        INPUT_FILE_EXTENSION,
+        JSON_INPUT_FILE_EXTENSION,
+        TOML_INPUT_FILE_EXTENSION,
+    },
+    root::{Manifest, Workspace},
+    source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+use leo_typed::Diagnostic;
+
+use snarkos_curves::edwards_bls12::Fq;
+
+use clap::ArgMatches;
+use std::{convert::TryFrom, env::current_dir, fs, path::PathBuf};
+
+#[derive(Debug)]
+pub struct InputCommand;
+
+impl CLI for InputCommand {
+    type Options = bool;
+    type Output = ();
+
+    const ABOUT: AboutType = "Check the input files against the main function";
+    const ARGUMENTS: &'static [ArgumentType] = &[];
+    const FLAGS: &'static [FlagType] =
+        &["[template] --template 'Prints placeholder input and state files for the inputs of the main function'"];
+    const NAME: NameType = "input";
+    const OPTIONS: &'static [OptionType] = &[];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok(arguments.is_present("template"))
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn output(template: Self::Options) -> Result<Self::Output, CLIError> {
+        if template {
+            return Self::template();
+        }
+
+        // Check the input files of every member of a workspace that has a main program
+        let workspace = for_each_member(|member| {
+            if !MainFile::exists_at(&member.path) {
+                return Ok(());
+            }
+
+            fetch_member_imports(member)?;
+            Self::check()
+        })?;
+
+        if workspace {
+            return Ok(());
+        }
+
+        Self::check()
+    }
+}
+
+impl InputCommand {
+    /// Parses the main program of the package in the current directory.
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse_main() -> Result<Compiler<Fq, EdwardsGroupType>, CLIError> {
+        let path = current_dir()?;
+        let package_name = Manifest::try_from(&path)?.get_package_name();
+
+        let mut main_file_path = path.clone();
+        main_file_path.push(SOURCE_DIRECTORY_NAME);
+        main_file_path.push(MAIN_FILENAME);
+
+        if !MainFile::exists_at(&path) {
+            return Err(CLIError::RunError(RunError::MainFileDoesNotExist(
+                main_file_path.into_os_string(),
+            )));
+        }
+
+        let output_directory = Workspace::outputs_directory(&path)?;
+
+        Ok(Compiler::<Fq, EdwardsGroupType>::parse_program_without_input(
+            package_name,
+            main_file_path,
+            output_directory,
+        )?)
+    }
+
+    /// Prints an input file for the main function of the package in the current directory,
+    /// followed by a state file if the main function takes `input`.
+    #[cfg_attr(tarpaulin, skip)]
+    pub fn template() -> Result<(), CLIError> {
+        let path = current_dir()?;
+        let package_name = Manifest::try_from(&path)?.get_package_name();
+
+        let program = Self::parse_main()?;
+        let signature = program.main_signature()?;
+
+        print!("{}", signature.input_template(&package_name));
+
+        if let Some(state_template) = signature.state_template(&package_name) {
+            print!("\n{}", state_template);
+        }
+
+        Ok(())
+    }
+
+    /// Checks every `.in`, `.json` and `.toml` input file of the package in the current directory
+    /// against the main function.
+    #[cfg_attr(tarpaulin, skip)]
+    pub fn check() -> Result<(), CLIError> {
+        // Begin "Checking" context for console logging
+        let span = tracing::span!(tracing::Level::INFO, "Checking");
+        let enter = span.enter();
+
+        let path = current_dir()?;
+        let package_name = Manifest::try_from(&path)?.get_package_name();
+        let program = Self::parse_main()?;
+
+        let extensions = [
+            INPUT_FILE_EXTENSION,
+            JSON_INPUT_FILE_EXTENSION,
+            TOML_INPUT_FILE_EXTENSION,
+        ];
+
+        let mut input_files = vec![];
+        if path.join(INPUTS_DIRECTORY_NAME).exists() {
+            input_files = InputsDirectory::files(&path)?
+                .into_iter()
+                .filter(|file_path| {
+                    let file_name = file_path.to_string_lossy();
+                    extensions.iter().any(|extension| file_name.ends_with(extension))
+                })
+                .collect::<Vec<_>>();
+            input_files.sort();
+        }
+
+        let mut mismatched_files = vec![];
+        for file_path in input_files.iter() {
+            let input_string = Self::read_input_file(&package_name, file_path)?;
+
+            let errors = program.check_input_file(&input_string, file_path)?;
+            if errors.is_empty() {
+                tracing::info!("{:?} matches the main function", file_path);
+                continue;
+            }
+
+            for error in errors.iter() {
+                tracing::error!("{}\n", error);
+                Diagnostic::from(error).emit();
+            }
+
+            mismatched_files.push(file_path.clone().into_os_string());
+        }
+
+        // Drop "Checking" context for console logging
+        drop(enter);
+
+        if !mismatched_files.is_empty() {
+            return Err(InputError::MismatchedInputFiles(mismatched_files).into());
+        }
+
+        tracing::span!(tracing::Level::INFO, "Done").in_scope(|| {
+            tracing::info!("Checked {} input files\n", input_files.len());
+        });
+
+        Ok(())
+    }
+
+    /// Reads an input file, returning JSON and TOML input files in the `.in` file format.
+    pub fn read_input_file(package_name: &str, file_path: &PathBuf) -> Result<String, CLIError> {
+        let file_name = file_path.to_string_lossy();
+
+        if file_name.ends_with(JSON_INPUT_FILE_EXTENSION) {
+            Ok(JsonInputFile::new(package_name).read_from(file_path)?.0)
+        } else if file_name.ends_with(TOML_INPUT_FILE_EXTENSION) {
+            Ok(TomlInputFile::new(package_name).read_from(file_path)?.0)
+        } else {
+            Ok(fs::read_to_string(file_path).map_err(|_| InputFileError::FileReadError(file_path.clone()))?)
+        }
+    }
+}
@@ -17,12 +17,13 @@
 use crate::{
     cli::*,
     cli_types::*,
-    commands::BuildCommand,
+    commands::{BuildCommand, InputSelection},
     errors::{CLIError, RunError},
+    workspace::{fetch_member_imports, for_each_member},
 };
 use leo_package::{
     root::Manifest,
-    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+    source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
 
 use clap::ArgMatches;
@@ -48,14 +49,36 @@ impl CLI for LintCommand {
     }
 
     #[cfg_attr(tarpaulin, skip)]
-    fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
+    fn output(_options: Self::Options) -> Result<Self::Output, CLIError> {
+        // Lint every member of a workspace that has a main program, after the members it imports
+        let workspace = for_each_member(|member| {
+            if !MainFile::exists_at(&member.path) {
+                return Ok(());
+            }
+
+            fetch_member_imports(member)?;
+            Self::lint()
+        })?;
+
+        if workspace {
+            return Ok(());
+        }
+
+        Self::lint()
+    }
+}
+
+impl LintCommand {
+    /// Lints the package in the current directory.
+    #[cfg_attr(tarpaulin, skip)]
+    pub fn lint() -> Result<(), CLIError> {
         // Begin "Linting" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Linting");
         let _enter = span.enter();
 
         let path = current_dir()?;
 
-        match BuildCommand::output(options)? {
+        match BuildCommand::build(&InputSelection::default())? {
             Some((_program, _checksum_differs)) => {
                 // Get the package name
                 let _package_name = Manifest::try_from(&path)?.get_package_name();
@@ -26,9 +26,10 @@ use crate::{
     cli_types::*,
     config::*,
     errors::LoginError::{CannotGetToken, NoConnectionFound, NoCredentialsProvided, WrongLoginOrPassword},
+    registry::{registry_url, HttpRegistry},
 };
 
-use std::collections::HashMap;
+use std::{collections::HashMap, env::current_dir};
 
 pub const LOGIN_URL: &str = "v1/account/authenticate";
 
@@ -87,7 +88,7 @@ impl CLI for LoginCommand {
             // Login using username and password
             (None, Some(username), Some(password)) => {
                 let client = reqwest::blocking::Client::new();
-                let url = format!("{}{}", PACKAGE_MANAGER_URL, LOGIN_URL);
+                let url = HttpRegistry::new(&registry_url(&current_dir()?)?).endpoint(LOGIN_URL);
 
                 let mut json = HashMap::new();
                 json.insert("email_username", username);
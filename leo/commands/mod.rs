@@ -20,15 +20,30 @@ pub use self::add::*;
 pub mod build;
 pub use self::build::*;
 
+pub mod ceremony;
+pub use self::ceremony::*;
+
 pub mod clean;
 pub use self::clean::*;
 
 pub mod deploy;
 pub use self::deploy::*;
 
+pub mod explain;
+pub use self::explain::*;
+
+pub mod fetch;
+pub use self::fetch::*;
+
+pub mod fmt;
+pub use self::fmt::*;
+
 pub mod init;
 pub use self::init::*;
 
+pub mod input;
+pub use self::input::*;
+
 pub mod lint;
 pub use self::lint::*;
 
@@ -50,6 +65,9 @@ pub use self::run::*;
 pub mod setup;
 pub use self::setup::*;
 
+pub mod state;
+pub use self::state::*;
+
 pub mod test;
 pub use self::test::*;
 
@@ -14,63 +14,112 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{cli::*, cli_types::*, commands::SetupCommand, errors::CLIError};
-use leo_package::{outputs::ProofFile, root::Manifest};
-
-use snarkos_algorithms::snark::groth16::{Groth16, PreparedVerifyingKey, Proof};
-use snarkos_curves::bls12_377::{Bls12_377, Fr};
-use snarkos_models::algorithms::SNARK;
+use crate::{
+    cli::*,
+    cli_types::*,
+    commands::{InputSelection, SetupCommand},
+    errors::{CLIError, ProveError},
+    proving::{self, PreparedVerifyingKey, Proof, ProvingKey},
+};
+use leo_compiler::{
+    compiler::Compiler,
+    group::targets::edwards_bls12::EdwardsGroupType,
+    JsonOutputFile,
+    OutputBytes,
+    OutputFile,
+};
+use leo_package::{
+    inputs::{InputFile, InputPair, InputPairs, JsonInputFile, StateFile, TomlInputFile},
+    outputs::ProofFile,
+    proving_system::ProvingSystem,
+    root::{Manifest, Workspace},
+};
+
+use snarkos_curves::bls12_377::Fr;
+use snarkos_models::gadgets::r1cs::TestConstraintSystem;
 
 use clap::ArgMatches;
 use rand::thread_rng;
-use std::{convert::TryFrom, env::current_dir, time::Instant};
+use rayon::prelude::*;
+use std::{convert::TryFrom, env::current_dir, path::Path, time::Instant};
 
 #[derive(Debug)]
 pub struct ProveCommand;
 
 impl CLI for ProveCommand {
-    type Options = ();
-    type Output = (Proof<Bls12_377>, PreparedVerifyingKey<Bls12_377>);
+    // ((proving_system, selection), batch)
+    type Options = ((Option<ProvingSystem>, InputSelection), bool);
+    type Output = Option<(Proof, PreparedVerifyingKey, Vec<Fr>)>;
 
     const ABOUT: AboutType = "Run the program and produce a proof";
     const ARGUMENTS: &'static [ArgumentType] = &[];
-    const FLAGS: &'static [FlagType] = &[];
+    const FLAGS: &'static [FlagType] = &["[batch] --batch 'Prove every input file in the inputs directory'"];
     const NAME: NameType = "prove";
-    const OPTIONS: &'static [OptionType] = &[];
+    const OPTIONS: &'static [OptionType] = SetupCommand::OPTIONS;
     const SUBCOMMANDS: &'static [SubCommandType] = &[];
 
     #[cfg_attr(tarpaulin, skip)]
-    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
-        Ok(())
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        let setup_options = SetupCommand::parse(arguments)?;
+        let batch = arguments.is_present("batch");
+
+        Ok((setup_options, batch))
     }
 
     #[cfg_attr(tarpaulin, skip)]
     fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
-        let (program, parameters, prepared_verifying_key) = SetupCommand::output(options)?;
+        let (setup_options, batch) = options;
+        let selection = setup_options.1.clone();
+
+        // A batch proves every input pair, so it cannot be combined with a selected input or state file
+        if batch && (selection.input.is_some() || selection.state.is_some()) {
+            return Err(ProveError::BatchSelection.into());
+        }
+
+        let (program, proving_key, prepared_verifying_key) = SetupCommand::output(setup_options)?;
+
+        if batch {
+            prove_batch(program, &proving_key, &prepared_verifying_key)?;
+            return Ok(None);
+        }
 
         // Begin "Proving" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Proving");
         let enter = span.enter();
 
-        // Get the package name
+        // Get the name of the input, which names the proof and output files
         let path = current_dir()?;
         let package_name = Manifest::try_from(&path)?.get_package_name();
+        let input_name = selection.input_name(&package_name);
+        let output_directory = Workspace::outputs_directory(&path)?;
 
         tracing::info!("Starting...");
 
         // Start the timer
         let start = Instant::now();
 
+        // Read the public inputs before the program is consumed by the prover
+        let public_inputs = program.public_inputs()?;
+
         let rng = &mut thread_rng();
-        let program_proof = Groth16::<Bls12_377, _, Vec<Fr>>::prove(&parameters, program, rng)?;
+        let program_proof = proving::prove(&proving_key, program, rng)?;
 
         // Finish the timer
         let end = start.elapsed().as_millis();
 
         // Write the proof file to the output directory
-        let mut proof = vec![];
-        program_proof.write(&mut proof)?;
-        ProofFile::new(&package_name).write_to(&path, &proof)?;
+        let proof = program_proof.to_bytes()?;
+        ProofFile::new(&input_name).write_to(&output_directory, program_proof.proving_system(), &proof)?;
+
+        // Write the program output in JSON format as well if the program input was given in JSON or TOML format
+        let structured_input =
+            JsonInputFile::new(&input_name).exists_at(&path) || TomlInputFile::new(&input_name).exists_at(&path);
+        if selection.state.is_none() && structured_input {
+            let output = OutputFile::new(&input_name).read_from(&output_directory)?;
+            let json = OutputBytes::from(output.into_bytes()).to_json()?;
+
+            JsonOutputFile::new(&input_name).write(&output_directory, &json)?;
+        }
 
         // Drop "Proving" context for console logging
         drop(enter);
@@ -80,6 +129,127 @@ impl CLI for ProveCommand {
             tracing::info!("Finished in {:?} milliseconds\n", end);
         });
 
-        Ok((program_proof, prepared_verifying_key))
+        Ok(Some((program_proof, prepared_verifying_key, public_inputs)))
+    }
+}
+
+/// Proves the program for every input pair in the inputs directory in parallel,
+/// writing a proof file and an output file named after each input pair.
+fn prove_batch(
+    program: Compiler<Fr, EdwardsGroupType>,
+    proving_key: &ProvingKey,
+    prepared_verifying_key: &PreparedVerifyingKey,
+) -> Result<(), CLIError> {
+    // Begin "Proving" context for console logging
+    let span = tracing::span!(tracing::Level::INFO, "Proving");
+    let enter = span.enter();
+
+    let path = current_dir()?;
+    let input_pairs = InputPairs::try_from(&path)?;
+    let output_directory = Workspace::outputs_directory(&path)?;
+
+    let mut input_names = input_pairs.pairs.keys().cloned().collect::<Vec<_>>();
+    input_names.sort();
+
+    tracing::info!("Starting {} proofs...", input_names.len());
+
+    // Start the timer
+    let start = Instant::now();
+
+    let results = input_names
+        .par_iter()
+        .map(|input_name| {
+            let input_pair = &input_pairs.pairs[input_name];
+
+            // Silence the console output of the program, since the proofs run concurrently
+            let result = tracing::dispatcher::with_default(&tracing::Dispatch::none(), || {
+                prove_input(
+                    &program,
+                    proving_key,
+                    prepared_verifying_key,
+                    &path,
+                    input_name,
+                    input_pair,
+                )
+            })
+            .and_then(|proof| {
+                let proof_bytes = proof.to_bytes().map_err(|error| error.to_string())?;
+
+                ProofFile::new(input_name)
+                    .write_to(&output_directory, proof.proving_system(), &proof_bytes)
+                    .map_err(|error| error.to_string())
+            });
+
+            (input_name, result)
+        })
+        .collect::<Vec<_>>();
+
+    // Finish the timer
+    let end = start.elapsed().as_millis();
+
+    let mut failures = 0;
+    for (input_name, result) in &results {
+        match result {
+            Ok(()) => tracing::info!("{} ... ok", input_name),
+            Err(error) => {
+                tracing::error!("{} ... failed\n\n{}\n", input_name, error);
+                failures += 1;
+            }
+        }
+    }
+
+    // Drop "Proving" context for console logging
+    drop(enter);
+
+    if failures > 0 {
+        return Err(ProveError::BatchFailures(failures, results.len()).into());
+    }
+
+    // Begin "Done" context for console logging
+    tracing::span!(tracing::Level::INFO, "Done").in_scope(|| {
+        tracing::info!("Proved {} inputs in {:?} milliseconds\n", results.len(), end);
+    });
+
+    Ok(())
+}
+
+/// Proves and verifies the program for a single input pair, writing its output to the output file named after the input.
+/// Returns the reason the input pair could not be proven on failure.
+fn prove_input(
+    program: &Compiler<Fr, EdwardsGroupType>,
+    proving_key: &ProvingKey,
+    prepared_verifying_key: &PreparedVerifyingKey,
+    path: &Path,
+    input_name: &str,
+    input_pair: &InputPair,
+) -> Result<Proof, String> {
+    let program = program
+        .with_input(
+            input_name,
+            &input_pair.input_file,
+            path.join(InputFile::new(input_name).filename()),
+            &input_pair.state_file,
+            path.join(StateFile::new(input_name).filename()),
+        )
+        .map_err(|error| error.to_string())?;
+
+    let rng = &mut thread_rng();
+    let proof = match proving::prove(proving_key, program.clone(), rng) {
+        Ok(proof) => proof,
+        Err(error) => {
+            // The prover only reports that synthesis failed, so synthesize again to recover the cause
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            return Err(match program.generate_constraints_helper(&mut cs) {
+                Ok(_) => error.to_string(),
+                Err(error) => error.to_string(),
+            });
+        }
+    };
+
+    let public_inputs = program.public_inputs().map_err(|error| error.to_string())?;
+    if !proving::verify(prepared_verifying_key, &proof, &public_inputs).map_err(|error| error.to_string())? {
+        return Err("the proof is invalid, the program constraints are not satisfied by the input".to_string());
     }
+
+    Ok(proof)
 }
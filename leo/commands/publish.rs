@@ -17,13 +17,14 @@
 use crate::{
     cli::*,
     cli_types::*,
-    commands::{BuildCommand, LoginCommand},
-    config::{read_token, PACKAGE_MANAGER_URL},
+    commands::{BuildCommand, InputSelection, LoginCommand},
+    config::read_token,
     errors::{
         commands::PublishError::{ConnectionUnavalaible, PackageNotPublished},
         CLIError,
         PublishError::{MissingPackageDescription, MissingPackageLicense, MissingPackageRemote},
     },
+    registry::{registry_url, FileRegistry, HttpRegistry},
 };
 use leo_package::{
     outputs::OutputsDirectory,
@@ -52,7 +53,7 @@ impl CLI for PublishCommand {
     type Options = ();
     type Output = Option<String>;
 
-    const ABOUT: AboutType = "Publish the current package to the Aleo Package Manager";
+    const ABOUT: AboutType = "Publish the current package to the package registry";
     const ARGUMENTS: &'static [ArgumentType] = &[];
     const FLAGS: &'static [FlagType] = &[];
     const NAME: NameType = "publish";
@@ -67,7 +68,7 @@ impl CLI for PublishCommand {
     #[cfg_attr(tarpaulin, skip)]
     fn output(_options: Self::Options) -> Result<Self::Output, CLIError> {
         // Build all program files.
-        let _output = BuildCommand::output(())?;
+        let _output = BuildCommand::output(InputSelection::default())?;
 
         // Begin "Publishing" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Publishing");
@@ -106,6 +107,20 @@ impl CLI for PublishCommand {
 
         zip_file.write(&path)?;
 
+        // Publish to a registry directory by copying the archive into it
+        let url = registry_url(&path)?;
+        if let Some(registry) = FileRegistry::from_url(&url) {
+            let archive = registry.publish(
+                &package_remote.author,
+                &package_name,
+                &package_version,
+                &zip_file.get_file_path(&path),
+            )?;
+
+            tracing::info!("Package published successfully to {:?}", archive);
+            return Ok(None);
+        }
+
         let form_data = Form::new()
             .text("name", package_name.clone())
             .text("remote", format!("{}/{}", package_remote.author, package_name))
@@ -138,7 +153,7 @@ impl CLI for PublishCommand {
 
         // Make a request to publish a package
         let response = client
-            .post(format!("{}{}", PACKAGE_MANAGER_URL, PUBLISH_URL).as_str())
+            .post(HttpRegistry::new(&url).endpoint(PUBLISH_URL).as_str())
             .headers(headers)
             .multipart(form_data)
             .send();
@@ -15,10 +15,13 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{cli::*, cli_types::*, errors::CLIError};
-use leo_package::LeoPackage;
+use leo_package::{
+    root::{LockFile, Manifest},
+    LeoPackage,
+};
 
 use clap::ArgMatches;
-use std::env::current_dir;
+use std::{convert::TryFrom, env::current_dir};
 
 #[derive(Debug)]
 pub struct RemoveCommand;
@@ -61,6 +64,18 @@ impl CLI for RemoveCommand {
 
         if let Some(package_name) = options {
             LeoPackage::remove_imported_package(&package_name, &path)?;
+
+            // Stop tracking the package in the manifest and the lock file
+            if Manifest::exists_at(&path) {
+                Manifest::remove_dependency(&path, &package_name)?;
+            }
+            if LockFile::exists_at(&path) {
+                let mut lock_file = LockFile::try_from(&path)?;
+                lock_file.dependencies.retain(|name| name != &package_name);
+                lock_file.remove(&package_name);
+                lock_file.write_to(&path)?;
+            }
+
             tracing::info!("Successfully removed package \"{}\"\n", package_name);
         }
 
@@ -17,19 +17,19 @@
 use crate::{
     cli::*,
     cli_types::*,
-    commands::BuildCommand,
+    commands::{BuildCommand, InputSelection},
     errors::{CLIError, RunError},
+    proving::{self, PreparedVerifyingKey, ProvingKey},
 };
 use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
 use leo_package::{
     outputs::{ProvingKeyFile, VerificationKeyFile},
-    root::Manifest,
+    proving_system::ProvingSystem,
+    root::{Manifest, Workspace},
     source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
 
-use snarkos_algorithms::snark::groth16::{Groth16, Parameters, PreparedVerifyingKey, VerifyingKey};
-use snarkos_curves::bls12_377::{Bls12_377, Fr};
-use snarkos_models::algorithms::snark::SNARK;
+use snarkos_curves::bls12_377::Fr;
 
 use clap::ArgMatches;
 use rand::thread_rng;
@@ -39,53 +39,74 @@ use std::{convert::TryFrom, env::current_dir, time::Instant};
 pub struct SetupCommand;
 
 impl CLI for SetupCommand {
-    type Options = ();
-    type Output = (
-        Compiler<Fr, EdwardsGroupType>,
-        Parameters<Bls12_377>,
-        PreparedVerifyingKey<Bls12_377>,
-    );
+    // (proving_system, selection)
+    type Options = (Option<ProvingSystem>, InputSelection);
+    type Output = (Compiler<Fr, EdwardsGroupType>, ProvingKey, PreparedVerifyingKey);
 
     const ABOUT: AboutType = "Run a program setup";
     const ARGUMENTS: &'static [ArgumentType] = &[];
     const FLAGS: &'static [FlagType] = &[];
     const NAME: NameType = "setup";
-    const OPTIONS: &'static [OptionType] = &[];
+    const OPTIONS: &'static [OptionType] = &[
+        // (argument, conflicts, possible_values, requires)
+        (
+            "[proving-system] --proving-system=[proving-system] 'Overrides the proving system selected in Leo.toml'",
+            &[],
+            ProvingSystem::NAMES,
+            &[],
+        ),
+        InputSelection::OPTIONS[0],
+        InputSelection::OPTIONS[1],
+    ];
     const SUBCOMMANDS: &'static [SubCommandType] = &[];
 
     #[cfg_attr(tarpaulin, skip)]
-    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
-        Ok(())
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        let proving_system = arguments
+            .value_of("proving-system")
+            .and_then(ProvingSystem::from_name);
+        let selection = InputSelection::parse(arguments);
+
+        Ok((proving_system, selection))
     }
 
     #[cfg_attr(tarpaulin, skip)]
     fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
+        let (proving_system, selection) = options;
+
         // Get the package name
         let path = current_dir()?;
-        let package_name = Manifest::try_from(&path)?.get_package_name();
+        let manifest = Manifest::try_from(&path)?;
+        let package_name = manifest.get_package_name();
+
+        // Get the output directory, which is shared by the members of a workspace
+        let output_directory = Workspace::outputs_directory(&path)?;
 
-        match BuildCommand::output(options)? {
+        // Use the proving system given on the command line, or the one selected in the manifest
+        let proving_system = proving_system.unwrap_or_else(|| manifest.get_proving_system());
+
+        match BuildCommand::output(selection)? {
             Some((program, checksum_differs)) => {
                 // Begin "Setup" context for console logging
                 let span = tracing::span!(tracing::Level::INFO, "Setup");
                 let enter = span.enter();
 
-                // Check if a proving key and verification key already exists
-                let keys_exist = ProvingKeyFile::new(&package_name).exists_at(&path)
-                    && VerificationKeyFile::new(&package_name).exists_at(&path);
+                // Check if a proving key and verification key already exist for the proving system
+                let keys_exist = ProvingKeyFile::new(&package_name).proving_system_at(&output_directory)
+                    == Some(proving_system)
+                    && VerificationKeyFile::new(&package_name).proving_system_at(&output_directory)
+                        == Some(proving_system);
 
-                // If keys do not exist or the checksum differs, run the program setup
                 // If keys do not exist or the checksum differs, run the program setup
                 let (end, proving_key, prepared_verifying_key) = if !keys_exist || checksum_differs {
-                    tracing::info!("Starting...");
+                    tracing::info!("Starting {} setup...", proving_system);
 
                     // Start the timer for setup
                     let setup_start = Instant::now();
 
                     // Run the program setup operation
                     let rng = &mut thread_rng();
-                    let (proving_key, prepared_verifying_key) =
-                        Groth16::<Bls12_377, Compiler<Fr, _>, Vec<Fr>>::setup(program.clone(), rng).unwrap();
+                    let (proving_key, prepared_verifying_key) = proving::setup(proving_system, program.clone(), rng)?;
 
                     // End the timer
                     let end = setup_start.elapsed().as_millis();
@@ -93,40 +114,46 @@ impl CLI for SetupCommand {
                     // TODO (howardwu): Convert parameters to a 'proving key' struct for serialization.
                     // Write the proving key file to the output directory
                     let proving_key_file = ProvingKeyFile::new(&package_name);
-                    tracing::info!("Saving proving key ({:?})", proving_key_file.full_path(&path));
-                    let mut proving_key_bytes = vec![];
-                    proving_key.write(&mut proving_key_bytes)?;
-                    let _ = proving_key_file.write_to(&path, &proving_key_bytes)?;
+                    tracing::info!(
+                        "Saving proving key ({:?})",
+                        proving_key_file.full_path(&output_directory)
+                    );
+                    let proving_key_bytes = proving_key.to_bytes()?;
+                    let _ = proving_key_file.write_to(&output_directory, proving_system, &proving_key_bytes)?;
                     tracing::info!("Complete");
 
                     // Write the verification key file to the output directory
                     let verification_key_file = VerificationKeyFile::new(&package_name);
-                    tracing::info!("Saving verification key ({:?})", verification_key_file.full_path(&path));
-                    let mut verification_key = vec![];
-                    proving_key.vk.write(&mut verification_key)?;
-                    let _ = verification_key_file.write_to(&path, &verification_key)?;
+                    tracing::info!(
+                        "Saving verification key ({:?})",
+                        verification_key_file.full_path(&output_directory)
+                    );
+                    let verification_key = proving_key.verification_key_bytes()?;
+                    let _ = verification_key_file.write_to(&output_directory, proving_system, &verification_key)?;
                     tracing::info!("Complete");
 
                     (end, proving_key, prepared_verifying_key)
                 } else {
-                    tracing::info!("Detected saved setup");
+                    tracing::info!("Detected saved {} setup", proving_system);
 
                     // Start the timer for setup
                     let setup_start = Instant::now();
 
                     // Read the proving key file from the output directory
                     tracing::info!("Loading proving key...");
-                    let proving_key_bytes = ProvingKeyFile::new(&package_name).read_from(&path)?;
-                    let proving_key = Parameters::<Bls12_377>::read(proving_key_bytes.as_slice(), true)?;
+                    let proving_key_bytes =
+                        ProvingKeyFile::new(&package_name).read_from(&output_directory, proving_system)?;
+                    let proving_key = ProvingKey::read(proving_system, proving_key_bytes.as_slice())?;
                     tracing::info!("Complete");
 
                     // Read the verification key file from the output directory
                     tracing::info!("Loading verification key...");
-                    let verifying_key_bytes = VerificationKeyFile::new(&package_name).read_from(&path)?;
-                    let verifying_key = VerifyingKey::<Bls12_377>::read(verifying_key_bytes.as_slice())?;
+                    let verifying_key_bytes =
+                        VerificationKeyFile::new(&package_name).read_from(&output_directory, proving_system)?;
 
                     // Derive the prepared verifying key file from the verifying key
-                    let prepared_verifying_key = PreparedVerifyingKey::<Bls12_377>::from(verifying_key);
+                    let prepared_verifying_key =
+                        PreparedVerifyingKey::read(proving_system, verifying_key_bytes.as_slice())?;
                     tracing::info!("Complete");
 
                     // End the timer
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    cli::*,
+    cli_types::*,
+    errors::{CLIError, StateError},
+};
+use leo_input::LeoInputParser;
+use leo_package::{
+    inputs::{InputsDirectory, StateFile},
+    root::Manifest,
+};
+use leo_state::{generate_local_data, LocalDataFields, LocalDataGenerationError};
+use leo_typed::Input as TypedInput;
+
+use snarkos_dpc::base_dpc::{instantiated::Components, parameters::SystemParameters};
+use snarkos_objects::{AccountAddress, AccountPrivateKey};
+
+use clap::ArgMatches;
+use rand::{rngs::StdRng, SeedableRng};
+use std::{convert::TryFrom, env::current_dir, str::FromStr};
+
+#[derive(Debug)]
+pub struct StateCommand;
+
+impl CLI for StateCommand {
+    // (private_key, seed)
+    type Options = (Option<String>, Option<u64>);
+    type Output = ();
+
+    const ABOUT: AboutType = "Generate a record and its local data commitment for the state file";
+    const ARGUMENTS: &'static [ArgumentType] = &[];
+    const FLAGS: &'static [FlagType] = &[];
+    const NAME: NameType = "state";
+    const OPTIONS: &'static [OptionType] = &[
+        // (argument, conflicts, possible_values, requires)
+        (
+            "[private-key] --private-key=[private-key] 'Uses the account of the given private key as the record owner, instead of generating a new account'",
+            &[],
+            &[],
+            &[],
+        ),
+        (
+            "[seed] --seed=[seed] 'Seeds the random number generator to make the generated state reproducible'",
+            &[],
+            &[],
+            &[],
+        ),
+    ];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        let private_key = arguments.value_of("private-key").map(|s| s.to_string());
+        let seed = match arguments.value_of("seed") {
+            Some(seed) => Some(
+                seed.parse::<u64>()
+                    .map_err(|_| StateError::InvalidSeed(seed.to_string()))?,
+            ),
+            None => None,
+        };
+
+        Ok((private_key, seed))
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
+        let (private_key, seed) = options;
+
+        // Get the package name
+        let path = current_dir()?;
+        let package_name = Manifest::try_from(&path)?.get_package_name();
+
+        // Begin "Generating" context for console logging
+        let span = tracing::span!(tracing::Level::INFO, "Generating");
+        let enter = span.enter();
+
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let system_parameters = SystemParameters::<Components>::load().map_err(StateError::SystemParametersError)?;
+
+        // Use the given account, or create a new one
+        let generated = private_key.is_none();
+        let account_private_key = match private_key {
+            Some(private_key) => {
+                AccountPrivateKey::<Components>::from_str(&private_key).map_err(StateError::InvalidPrivateKey)?
+            }
+            None => AccountPrivateKey::<Components>::new(
+                &system_parameters.account_signature,
+                &system_parameters.account_commitment,
+                &mut rng,
+            )
+            .map_err(|error| StateError::from(LocalDataGenerationError::from(error)))?,
+        };
+        let owner = AccountAddress::<Components>::from_private_key(
+            &system_parameters.account_signature,
+            &system_parameters.account_commitment,
+            &system_parameters.account_encryption,
+            &account_private_key,
+        )
+        .map_err(StateError::InvalidPrivateKey)?;
+
+        // Keep the record fields of an existing state file
+        let state_file = StateFile::new(&package_name);
+        let fields = if state_file.exists_at(&path) {
+            let (state_string, state_path) = state_file.read_from(&path)?;
+
+            let state_syntax_tree = LeoInputParser::parse_file(&state_string).map_err(|mut error| {
+                error.set_path(state_path.clone());
+                error
+            })?;
+            let mut state = TypedInput::new();
+            state.parse_state(state_syntax_tree).map_err(|mut error| {
+                error.set_path(state_path);
+                error
+            })?;
+
+            LocalDataFields::try_from(&state).map_err(StateError::from)?
+        } else {
+            LocalDataFields::default()
+        };
+
+        let local_data = generate_local_data(&system_parameters, &account_private_key, &fields, &mut rng)
+            .map_err(StateError::from)?;
+
+        // Write the state file
+        InputsDirectory::create(&path)?;
+        state_file.write_state_to(&path, &local_data.to_state_string(&package_name))?;
+
+        tracing::info!("Record owner {}", owner);
+
+        // The record can only be spent with the private key of its owner
+        if generated {
+            tracing::info!(
+                "Generated private key {} for the record owner, keep it secret",
+                account_private_key
+            );
+        }
+
+        // Drop "Generating" context for console logging
+        drop(enter);
+
+        tracing::span!(tracing::Level::INFO, "Done").in_scope(|| {
+            tracing::info!("Generated {}\n", state_file.filename());
+        });
+
+        Ok(())
+    }
+}
@@ -18,12 +18,13 @@ use crate::{
     cli::*,
     cli_types::*,
     errors::{CLIError, TestError::ProgramFileDoesNotExist},
+    workspace::{fetch_member_imports, for_each_member},
 };
 use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
 use leo_package::{
     inputs::*,
-    outputs::{OutputsDirectory, OUTPUTS_DIRECTORY_NAME},
-    root::Manifest,
+    outputs::OutputsDirectory,
+    root::{Manifest, Workspace},
     source::{LibraryFile, MainFile, LIBRARY_FILENAME, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
 
@@ -53,6 +54,24 @@ impl CLI for TestCommand {
 
     #[cfg_attr(tarpaulin, skip)]
     fn output(_options: Self::Options) -> Result<Self::Output, CLIError> {
+        // Test every member of a workspace
+        let workspace = for_each_member(|member| {
+            fetch_member_imports(member)?;
+            Self::test()
+        })?;
+
+        if workspace {
+            return Ok(());
+        }
+
+        Self::test()
+    }
+}
+
+impl TestCommand {
+    /// Runs the tests of the package in the current directory.
+    #[cfg_attr(tarpaulin, skip)]
+    pub fn test() -> Result<(), CLIError> {
         let path = current_dir()?;
 
         // Get the package name
@@ -77,12 +96,11 @@ impl CLI for TestCommand {
             return Err(ProgramFileDoesNotExist(package_path.into()).into());
         }
 
-        // Construct the path to the output directory;
-        let mut output_directory = package_path.clone();
-        output_directory.push(OUTPUTS_DIRECTORY_NAME);
+        // Construct the path to the output directory, which is shared by the members of a workspace
+        let output_directory = Workspace::outputs_directory(&package_path)?;
 
         // Create the output directory
-        OutputsDirectory::create(&package_path)?;
+        OutputsDirectory::create(&output_directory)?;
 
         // Begin "Test" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Test");
@@ -92,14 +110,18 @@ impl CLI for TestCommand {
         let start = Instant::now();
 
         // Parse the current main program file
+        // The program is tested unoptimized, so the coverage report includes dead branches as uncovered
         let program = Compiler::<Fq, EdwardsGroupType>::parse_program_without_input(
             package_name.clone(),
             file_path.clone(),
             output_directory,
         )?;
 
-        // Parse all inputs as input pairs
-        let pairs = InputPairs::try_from(&package_path)?;
+        // Parse all inputs as input pairs, a library package may not have an inputs directory
+        let pairs = match package_path.join(INPUTS_DIRECTORY_NAME).exists() {
+            true => InputPairs::try_from(&package_path)?,
+            false => InputPairs::new(),
+        };
 
         // Run tests
         let temporary_program = program.clone();
@@ -14,7 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{cli::CLI, cli_types::*, commands::BuildCommand, errors::CLIError};
+use crate::{
+    cli::CLI,
+    cli_types::*,
+    commands::{BuildCommand, InputSelection},
+    errors::CLIError,
+};
 use clap::ArgMatches;
 use notify::{watcher, DebouncedEvent, RecursiveMode, Watcher};
 use std::{sync::mpsc::channel, time::Duration};
@@ -57,7 +62,7 @@ impl CLI for WatchCommand {
             match rx.recv() {
                 // See changes on the write event
                 Ok(DebouncedEvent::Write(_write)) => {
-                    let options = ();
+                    let options = InputSelection::default();
                     match BuildCommand::output(options) {
                         Ok(_output) => {
                             tracing::info!("Built successfully");
@@ -60,15 +60,33 @@ impl Default for Update {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RegistryConfig {
+    /// The URL of an Aleo Package Manager, or the path of a registry directory.
+    /// A relative path is relative to the Leo config directory.
+    pub url: String,
+}
+
+impl Default for RegistryConfig {
+    fn default() -> Self {
+        Self {
+            url: PACKAGE_MANAGER_URL.to_string(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Config {
     pub update: Update,
+    #[serde(default)]
+    pub registry: RegistryConfig,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
             update: Update::default(),
+            registry: RegistryConfig::default(),
         }
     }
 }
@@ -17,6 +17,7 @@
 use crate::errors::*;
 use leo_compiler::errors::OutputFileError;
 use leo_package::errors::*;
+use leo_typed::Diagnostic;
 
 #[derive(Debug, Error)]
 pub enum CLIError {
@@ -29,6 +30,15 @@ pub enum CLIError {
     #[error("{}", _0)]
     ZipFileError(ZipFileError),
 
+    #[error("{}", _0)]
+    BuildManifestFileError(BuildManifestFileError),
+
+    #[error("{}", _0)]
+    CeremonyError(CeremonyError),
+
+    #[error("{}", _0)]
+    CeremonyFileError(CeremonyFileError),
+
     #[error("{}", _0)]
     ChecksumFileError(ChecksumFileError),
 
@@ -38,6 +48,15 @@ pub enum CLIError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("{}", _0)]
+    ExplainError(ExplainError),
+
+    #[error("{}", _0)]
+    FetchError(FetchError),
+
+    #[error("{}", _0)]
+    FmtError(FmtError),
+
     #[error("{}", _0)]
     GitignoreError(GitignoreError),
 
@@ -47,15 +66,24 @@ pub enum CLIError {
     #[error("{}", _0)]
     ImportsDirectoryError(ImportsDirectoryError),
 
+    #[error("{}", _0)]
+    InputError(InputError),
+
     #[error("{}", _0)]
     InputsDirectoryError(InputsDirectoryError),
 
     #[error("{}", _0)]
     InputFileError(InputFileError),
 
+    #[error("{}", _0)]
+    JsonInputFileError(JsonInputFileError),
+
     #[error("{}", _0)]
     LibraryFileError(LibraryFileError),
 
+    #[error("{}", _0)]
+    LockFileError(LockFileError),
+
     #[error("{}", _0)]
     LoginError(LoginError),
 
@@ -80,6 +108,9 @@ pub enum CLIError {
     #[error("{}", _0)]
     ProofFileError(ProofFileError),
 
+    #[error("{}", _0)]
+    ProveError(ProveError),
+
     #[error("{}", _0)]
     ProvingKeyFileError(ProvingKeyFileError),
 
@@ -89,6 +120,12 @@ pub enum CLIError {
     #[error("{}", _0)]
     READMEError(READMEError),
 
+    #[error("{}", _0)]
+    RegistryError(RegistryError),
+
+    #[error("{}", _0)]
+    ResolverError(ResolverError),
+
     #[error("{}", _0)]
     RunError(RunError),
 
@@ -98,12 +135,18 @@ pub enum CLIError {
     #[error("{}", _0)]
     SourceDirectoryError(SourceDirectoryError),
 
+    #[error("{}", _0)]
+    StateError(StateError),
+
     #[error("{}", _0)]
     StateFileError(StateFileError),
 
     #[error("{}", _0)]
     TestError(TestError),
 
+    #[error("{}", _0)]
+    TomlInputFileError(TomlInputFileError),
+
     #[error("TomlSerError: {0}")]
     TomlSerError(#[from] toml::ser::Error),
 
@@ -112,13 +155,70 @@ pub enum CLIError {
 
     #[error("{}", _0)]
     VerificationKeyFileError(VerificationKeyFileError),
+
+    #[error("{}", _0)]
+    WorkspaceError(WorkspaceError),
+}
+
+impl CLIError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            CLIError::AddError(error) => error.code(),
+            CLIError::BuildError(error) => error.code(),
+            CLIError::ZipFileError(error) => error.code(),
+            CLIError::BuildManifestFileError(error) => error.code(),
+            CLIError::CeremonyError(error) => error.code(),
+            CLIError::CeremonyFileError(error) => error.code(),
+            CLIError::ChecksumFileError(error) => error.code(),
+            CLIError::CircuitFileError(error) => error.code(),
+            CLIError::Crate(_, _) => "E0890",
+            CLIError::ExplainError(error) => error.code(),
+            CLIError::FetchError(error) => error.code(),
+            CLIError::FmtError(error) => error.code(),
+            CLIError::GitignoreError(error) => error.code(),
+            CLIError::InitError(error) => error.code(),
+            CLIError::ImportsDirectoryError(error) => error.code(),
+            CLIError::InputError(error) => error.code(),
+            CLIError::InputsDirectoryError(error) => error.code(),
+            CLIError::InputFileError(error) => error.code(),
+            CLIError::JsonInputFileError(error) => error.code(),
+            CLIError::LibraryFileError(error) => error.code(),
+            CLIError::LockFileError(error) => error.code(),
+            CLIError::LoginError(error) => error.code(),
+            CLIError::MainFileError(error) => error.code(),
+            CLIError::ManifestError(error) => error.code(),
+            CLIError::NewError(error) => error.code(),
+            CLIError::OutputFileError(error) => error.code(),
+            CLIError::OutputsDirectoryError(error) => error.code(),
+            CLIError::PackageError(error) => error.code(),
+            CLIError::ProofFileError(error) => error.code(),
+            CLIError::ProveError(error) => error.code(),
+            CLIError::ProvingKeyFileError(error) => error.code(),
+            CLIError::PublishError(error) => error.code(),
+            CLIError::READMEError(error) => error.code(),
+            CLIError::RegistryError(error) => error.code(),
+            CLIError::ResolverError(error) => error.code(),
+            CLIError::RunError(error) => error.code(),
+            CLIError::SNARKError(_) => "E0891",
+            CLIError::SourceDirectoryError(error) => error.code(),
+            CLIError::StateError(error) => error.code(),
+            CLIError::StateFileError(error) => error.code(),
+            CLIError::TestError(error) => error.code(),
+            CLIError::TomlInputFileError(error) => error.code(),
+            CLIError::TomlSerError(_) => "E0892",
+            CLIError::TomlDeError(_) => "E0893",
+            CLIError::VerificationKeyFileError(error) => error.code(),
+            CLIError::WorkspaceError(error) => error.code(),
+        }
+    }
 }
 
 macro_rules! impl_cli_error {
     ($($t:tt), +) => {
         $(impl From<$t> for CLIError {
             fn from(error: $t) -> Self {
-                tracing::error!("{}\n", error);
+                tracing::error!("[{}] {}\n", error.code(), error);
+                Diagnostic::error(error.to_string()).with_code(error.code()).emit();
 
                 CLIError::$t(error)
             }
@@ -129,14 +229,23 @@ macro_rules! impl_cli_error {
 impl_cli_error!(
     AddError,
     BuildError,
+    BuildManifestFileError,
+    CeremonyError,
+    CeremonyFileError,
     CircuitFileError,
     ChecksumFileError,
+    ExplainError,
+    FetchError,
+    FmtError,
     GitignoreError,
     ImportsDirectoryError,
     InitError,
+    InputError,
     InputsDirectoryError,
     InputFileError,
+    JsonInputFileError,
     LibraryFileError,
+    LockFileError,
     LoginError,
     MainFileError,
     ManifestError,
@@ -145,20 +254,27 @@ impl_cli_error!(
     OutputsDirectoryError,
     PackageError,
     ProofFileError,
+    ProveError,
     ProvingKeyFileError,
     PublishError,
     READMEError,
+    RegistryError,
+    ResolverError,
     RunError,
     SourceDirectoryError,
+    StateError,
     StateFileError,
     TestError,
+    TomlInputFileError,
     VerificationKeyFileError,
+    WorkspaceError,
     ZipFileError
 );
 
 impl From<clap::Error> for CLIError {
     fn from(error: clap::Error) -> Self {
         tracing::error!("{}\n", error);
+        Diagnostic::error(error.to_string()).with_code("E0890").emit();
         CLIError::Crate("clap", format!("{}", error))
     }
 }
@@ -166,13 +282,27 @@ impl From<clap::Error> for CLIError {
 impl From<leo_compiler::errors::CompilerError> for CLIError {
     fn from(error: leo_compiler::errors::CompilerError) -> Self {
         tracing::error!("{}\n", error);
+        for diagnostic in error.diagnostics() {
+            diagnostic.emit();
+        }
         CLIError::Crate("leo-compiler", "Program failed due to previous error".into())
     }
 }
 
+impl From<leo_ast::ParserError> for CLIError {
+    fn from(error: leo_ast::ParserError) -> Self {
+        tracing::error!("{}\n", error);
+        for diagnostic in Diagnostic::from_parser_error(&error) {
+            diagnostic.emit();
+        }
+        CLIError::Crate("leo-ast", "Program failed due to previous error".into())
+    }
+}
+
 impl From<leo_input::errors::InputParserError> for CLIError {
     fn from(error: leo_input::errors::InputParserError) -> Self {
         tracing::error!("{}\n", error);
+        Diagnostic::from(&error).emit();
         CLIError::Crate("leo-input", "Program failed due to previous error".into())
     }
 }
@@ -180,6 +310,7 @@ impl From<leo_input::errors::InputParserError> for CLIError {
 impl From<reqwest::Error> for CLIError {
     fn from(error: reqwest::Error) -> Self {
         tracing::error!("{}\n", error);
+        Diagnostic::error(error.to_string()).with_code("E0890").emit();
         CLIError::Crate("rewquest", format!("{}", error))
     }
 }
@@ -187,6 +318,7 @@ impl From<reqwest::Error> for CLIError {
 impl From<snarkos_errors::algorithms::snark::SNARKError> for CLIError {
     fn from(error: snarkos_errors::algorithms::snark::SNARKError) -> Self {
         tracing::error!("{}\n", error);
+        Diagnostic::error(error.to_string()).with_code("E0890").emit();
         CLIError::Crate("snarkos_errors", format!("{}", error))
     }
 }
@@ -194,6 +326,7 @@ impl From<snarkos_errors::algorithms::snark::SNARKError> for CLIError {
 impl From<snarkos_errors::gadgets::SynthesisError> for CLIError {
     fn from(error: snarkos_errors::gadgets::SynthesisError) -> Self {
         tracing::error!("{}\n", error);
+        Diagnostic::error(error.to_string()).with_code("E0890").emit();
         CLIError::Crate("snarkos_errors", format!("{}", error))
     }
 }
@@ -201,6 +334,7 @@ impl From<snarkos_errors::gadgets::SynthesisError> for CLIError {
 impl From<serde_json::error::Error> for CLIError {
     fn from(error: serde_json::error::Error) -> Self {
         tracing::error!("{}\n", error);
+        Diagnostic::error(error.to_string()).with_code("E0890").emit();
         CLIError::Crate("serde_json", format!("{}", error))
     }
 }
@@ -208,6 +342,7 @@ impl From<serde_json::error::Error> for CLIError {
 impl From<std::io::Error> for CLIError {
     fn from(error: std::io::Error) -> Self {
         tracing::error!("{}\n", error);
+        Diagnostic::error(error.to_string()).with_code("E0890").emit();
         CLIError::Crate("std::io", format!("{}", error))
     }
 }
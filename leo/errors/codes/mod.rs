@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Explanations of the error codes that are printed next to error messages.
+
+/// Includes the explanation of each error code from the markdown file of the same name.
+macro_rules! register_error_codes {
+    ($($code:ident,)*) => {
+        /// The error codes and their explanations, ordered by code.
+        pub const ERROR_CODES: &[(&str, &str)] = &[$((stringify!($code), include_str!(concat!(stringify!($code), ".md"))),)*];
+    };
+}
+
+register_error_codes!(
+    E0001, E0002, E0003, E0004, E0005, E0101, E0102, E0103, E0104, E0105, E0106, E0107, E0108, E0109, E0110, E0111,
+    E0112, E0113, E0114, E0115, E0116, E0117, E0118, E0119, E0120, E0121, E0122, E0123, E0201, E0202, E0203, E0204,
+    E0205, E0206, E0207, E0208, E0209, E0210, E0211, E0212, E0213, E0220, E0221, E0222, E0223, E0224, E0225, E0226,
+    E0227, E0228, E0229, E0230, E0231, E0232, E0233, E0234, E0235, E0236, E0237, E0238, E0239, E0240, E0241, E0242,
+    E0243, E0244, E0245, E0250, E0251, E0252, E0253, E0254, E0301, E0302, E0303, E0304, E0305, E0306, E0307, E0308,
+    E0309, E0310, E0311, E0312, E0313, E0314, E0315, E0316, E0317, E0318, E0319, E0320, E0321, E0322, E0323, E0324,
+    E0325, E0326, E0327, E0328, E0329, E0330, E0331, E0332, E0350, E0351, E0352, E0353, E0354, E0355, E0356, E0357,
+    E0358, E0359, E0360, E0361, E0362, E0363, E0364, E0365, E0366, E0367, E0401, E0402, E0403, E0404, E0405, E0410,
+    E0411, E0412, E0413, E0420, E0421, E0422, E0423, E0424, E0425, E0430, E0431, E0432, E0433, E0434, E0435, E0436,
+    E0437, E0438, E0439, E0440, E0450, E0451, E0452, E0453, E0454, E0455, E0456, E0457, E0458, E0470, E0471, E0501,
+    E0502, E0503, E0504, E0505, E0506, E0507, E0508, E0550, E0560, E0561, E0562, E0563, E0564, E0570, E0571, E0572,
+    E0580, E0581, E0600, E0601, E0602, E0603, E0604, E0605, E0606, E0607, E0608, E0609, E0610, E0611, E0612, E0613,
+    E0614, E0615, E0616, E0617, E0618, E0620, E0621, E0622, E0623, E0625, E0626, E0627, E0628, E0630, E0631, E0632,
+    E0633, E0634, E0635, E0636, E0637, E0638, E0639, E0640, E0641, E0642, E0643, E0644, E0645, E0646, E0647, E0648,
+    E0649, E0650, E0651, E0652, E0653, E0654, E0655, E0656, E0657, E0660, E0661, E0662, E0663, E0664, E0665, E0670,
+    E0671, E0672, E0673, E0674, E0675, E0680, E0681, E0682, E0683, E0684, E0685, E0686, E0690, E0691, E0692, E0693,
+    E0694, E0695, E0700, E0701, E0702, E0705, E0706, E0707, E0708, E0709, E0710, E0711, E0715, E0716, E0717, E0720,
+    E0721, E0722, E0723, E0724, E0725, E0726, E0730, E0731, E0732, E0733, E0734, E0735, E0736, E0740, E0741, E0742,
+    E0745, E0746, E0747, E0750, E0751, E0752, E0753, E0754, E0755, E0756, E0760, E0761, E0762, E0763, E0764, E0770,
+    E0771, E0772, E0773, E0774, E0800, E0801, E0802, E0803, E0805, E0810, E0811, E0812, E0813, E0814, E0815, E0816,
+    E0817, E0818, E0819, E0820, E0821, E0822, E0825, E0826, E0830, E0831, E0832, E0835, E0836, E0837, E0838, E0840,
+    E0841, E0842, E0845, E0846, E0850, E0851, E0852, E0853, E0854, E0855, E0860, E0865, E0870, E0871, E0873, E0874,
+    E0875, E0876, E0877, E0878, E0879, E0880, E0881, E0882, E0883, E0884, E0890, E0891, E0892, E0893,
+);
+
+/// Returns the explanation of an error code.
+/// The code is case insensitive, and the leading `E` and zeros may be omitted.
+pub fn explain(code: &str) -> Option<&'static str> {
+    let code = code.trim();
+    let number = code
+        .strip_prefix('E')
+        .or_else(|| code.strip_prefix('e'))
+        .unwrap_or(code);
+    let code = format!("E{:04}", number.parse::<u32>().ok()?);
+
+    ERROR_CODES
+        .iter()
+        .find(|(error_code, _)| *error_code == code)
+        .map(|(_, explanation)| *explanation)
+}
@@ -30,3 +30,14 @@ pub enum AddError {
     #[error("{:?}", _0)]
     ZipError(OsString),
 }
+
+impl AddError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            AddError::ConnectionUnavailable(_) => "E0800",
+            AddError::MissingAuthorOrPackageName => "E0801",
+            AddError::InvalidRemote => "E0802",
+            AddError::ZipError(_) => "E0803",
+        }
+    }
+}
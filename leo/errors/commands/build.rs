@@ -26,3 +26,12 @@ pub enum BuildError {
     #[error("{}", _0)]
     ManifestError(#[from] ManifestError),
 }
+
+impl BuildError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            BuildError::MainFileDoesNotExist(_) => "E0805",
+            BuildError::ManifestError(error) => error.code(),
+        }
+    }
+}
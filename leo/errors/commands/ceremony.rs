@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_package::{errors::CeremonyFileError, proving_system::ProvingSystem};
+
+use snarkos_errors::gadgets::SynthesisError;
+
+use std::path::PathBuf;
+
+#[derive(Debug, Error)]
+pub enum CeremonyError {
+    #[error("phase 2 has already been prepared, phase 1 contributions are closed")]
+    AlreadyPrepared,
+
+    #[error("{}", _0)]
+    CeremonyFileError(#[from] CeremonyFileError),
+
+    #[error(
+        "the transcript was initialized for checksum {}, but the current build has checksum {}",
+        _0,
+        _1
+    )]
+    ChecksumMismatch(String, String),
+
+    #[error("{}: {}", _0, _1)]
+    Crate(&'static str, String),
+
+    #[error("the transcript has {} powers of tau, but the circuit requires {}", _0, _1)]
+    DomainSizeMismatch(usize, usize),
+
+    #[error("the phase 1 accumulator is invalid: {}", _0)]
+    InvalidAccumulator(String),
+
+    #[error("phase {} contribution {} is invalid: {}", _0, _1, _2)]
+    InvalidContribution(u8, usize, String),
+
+    #[error("the phase 2 parameters are invalid: {}", _0)]
+    InvalidParameters(String),
+
+    #[error("the transcript is malformed: {}", _0)]
+    InvalidTranscript(String),
+
+    #[error("phase {} requires at least one contribution", _0)]
+    MissingContributions(u8),
+
+    #[error("phase 2 has not been prepared yet, run `leo ceremony prepare` first")]
+    NotPrepared,
+
+    #[error("the circuit is too large for a ceremony over the BLS12-377 scalar field")]
+    PolynomialDegreeTooLarge,
+
+    #[error("a transcript already exists at {:?}", _0)]
+    TranscriptExists(PathBuf),
+
+    #[error(
+        "the ceremony produces {} parameters, but the package selects {}",
+        ProvingSystem::Groth16,
+        _0
+    )]
+    UnsupportedProvingSystem(ProvingSystem),
+}
+
+impl CeremonyError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            CeremonyError::AlreadyPrepared => "E0810",
+            CeremonyError::CeremonyFileError(error) => error.code(),
+            CeremonyError::ChecksumMismatch(_, _) => "E0811",
+            CeremonyError::Crate(_, _) => "E0812",
+            CeremonyError::DomainSizeMismatch(_, _) => "E0813",
+            CeremonyError::InvalidAccumulator(_) => "E0814",
+            CeremonyError::InvalidContribution(_, _, _) => "E0815",
+            CeremonyError::InvalidParameters(_) => "E0816",
+            CeremonyError::InvalidTranscript(_) => "E0817",
+            CeremonyError::MissingContributions(_) => "E0818",
+            CeremonyError::NotPrepared => "E0819",
+            CeremonyError::PolynomialDegreeTooLarge => "E0820",
+            CeremonyError::TranscriptExists(_) => "E0821",
+            CeremonyError::UnsupportedProvingSystem(_) => "E0822",
+        }
+    }
+}
+
+impl From<std::io::Error> for CeremonyError {
+    fn from(error: std::io::Error) -> Self {
+        CeremonyError::Crate("std::io", format!("{}", error))
+    }
+}
+
+impl From<SynthesisError> for CeremonyError {
+    fn from(error: SynthesisError) -> Self {
+        // The `Display` implementation of `SynthesisError` recurses infinitely for most variants
+        CeremonyError::Crate("snarkos_errors", format!("{:?}", error))
+    }
+}
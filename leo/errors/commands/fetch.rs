@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+#[derive(Debug, Error)]
+pub enum FetchError {
+    #[error("checksum mismatch for package `{}`: expected {}, found {}", _0, _1, _2)]
+    ChecksumMismatch(String, String, String),
+
+    #[error("git: {}", _0)]
+    GitError(String),
+
+    #[error("`Leo.lock` needs to be updated for package `{}`, but `--locked` was passed", _0)]
+    LockFileOutdated(String),
+
+    #[error("`Leo.lock` does not exist, but `--locked` was passed")]
+    MissingLockFile,
+}
+
+impl FetchError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            FetchError::ChecksumMismatch(_, _, _) => "E0870",
+            FetchError::GitError(_) => "E0871",
+            FetchError::LockFileOutdated(_) => "E0873",
+            FetchError::MissingLockFile => "E0874",
+        }
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{ffi::OsString, io};
+
+#[derive(Debug, Error)]
+pub enum FmtError {
+    #[error("cannot write formatted file {:?}: {}", _0, _1)]
+    Writing(OsString, io::Error),
+
+    #[error("the following files are not formatted: {:?}", _0)]
+    UnformattedFiles(Vec<OsString>),
+}
+
+impl FmtError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            FmtError::Writing(_, _) => "E0825",
+            FmtError::UnformattedFiles(_) => "E0826",
+        }
+    }
+}
@@ -32,3 +32,14 @@ pub enum InitError {
     #[error("package name is missing - {:?}", _0)]
     ProjectNameInvalid(OsString),
 }
+
+impl InitError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            InitError::CreatingRootDirectory(_, _) => "E0830",
+            InitError::DirectoryDoesNotExist(_) => "E0831",
+            InitError::ManifestError(error) => error.code(),
+            InitError::ProjectNameInvalid(_) => "E0832",
+        }
+    }
+}
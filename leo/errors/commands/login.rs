@@ -28,3 +28,14 @@ pub enum LoginError {
     #[error("Wrong login or password")]
     WrongLoginOrPassword,
 }
+
+impl LoginError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            LoginError::CannotGetToken => "E0835",
+            LoginError::NoConnectionFound => "E0836",
+            LoginError::NoCredentialsProvided => "E0837",
+            LoginError::WrongLoginOrPassword => "E0838",
+        }
+    }
+}
@@ -20,20 +20,41 @@ pub use self::add::*;
 pub mod build;
 pub use self::build::*;
 
+pub mod ceremony;
+pub use self::ceremony::*;
+
+pub mod explain;
+pub use self::explain::*;
+
+pub mod fetch;
+pub use self::fetch::*;
+
+pub mod fmt;
+pub use self::fmt::*;
+
 pub mod init;
 pub use self::init::*;
 
+pub mod input;
+pub use self::input::*;
+
 pub mod login;
 pub use self::login::*;
 
 pub mod new;
 pub use self::new::*;
 
+pub mod prove;
+pub use self::prove::*;
+
 pub mod publish;
 pub use self::publish::*;
 
 pub mod run;
 pub use self::run::*;
 
+pub mod state;
+pub use self::state::*;
+
 pub mod test;
 pub use self::test::*;
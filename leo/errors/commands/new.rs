@@ -32,3 +32,14 @@ pub enum NewError {
     #[error("package name is missing - {:?}", _0)]
     ProjectNameInvalid(OsString),
 }
+
+impl NewError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            NewError::CreatingRootDirectory(_, _) => "E0840",
+            NewError::DirectoryAlreadyExists(_) => "E0841",
+            NewError::ManifestError(error) => error.code(),
+            NewError::ProjectNameInvalid(_) => "E0842",
+        }
+    }
+}
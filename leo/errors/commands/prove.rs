@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+#[derive(Debug, Error)]
+pub enum ProveError {
+    #[error("{} of {} inputs failed to prove", _0, _1)]
+    BatchFailures(usize, usize),
+
+    #[error("--batch proves every input and cannot be combined with --input or --state")]
+    BatchSelection,
+}
+
+impl ProveError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ProveError::BatchFailures(_, _) => "E0845",
+            ProveError::BatchSelection => "E0846",
+        }
+    }
+}
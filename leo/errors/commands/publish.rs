@@ -33,3 +33,15 @@ pub enum PublishError {
     #[error("package not published {:?}", _0)]
     PackageNotPublished(OsString),
 }
+
+impl PublishError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            PublishError::ConnectionUnavalaible(_) => "E0850",
+            PublishError::MissingPackageDescription => "E0851",
+            PublishError::MissingPackageLicense => "E0852",
+            PublishError::MissingPackageRemote => "E0853",
+            PublishError::PackageNotPublished(_) => "E0854",
+        }
+    }
+}
@@ -26,3 +26,12 @@ pub enum RunError {
     #[error("{}", _0)]
     ManifestError(#[from] ManifestError),
 }
+
+impl RunError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            RunError::MainFileDoesNotExist(_) => "E0855",
+            RunError::ManifestError(error) => error.code(),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_state::LocalDataGenerationError;
+
+use snarkos_errors::objects::AccountError;
+
+use std::io::Error as IOError;
+
+#[derive(Debug, Error)]
+pub enum StateError {
+    #[error("invalid private key: {}", _0)]
+    InvalidPrivateKey(AccountError),
+
+    #[error("invalid seed `{}`, expected an unsigned 64 bit integer", _0)]
+    InvalidSeed(String),
+
+    #[error("{}", _0)]
+    LocalDataGenerationError(#[from] LocalDataGenerationError),
+
+    #[error("failed to load the DPC system parameters: {}", _0)]
+    SystemParametersError(IOError),
+}
+
+impl StateError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            StateError::InvalidPrivateKey(_) => "E0876",
+            StateError::InvalidSeed(_) => "E0877",
+            StateError::LocalDataGenerationError(_) => "E0878",
+            StateError::SystemParametersError(_) => "E0879",
+        }
+    }
+}
@@ -20,3 +20,11 @@ pub enum TestError {
     #[error("could not find main or library file in  {:?}", _0)]
     ProgramFileDoesNotExist(OsString),
 }
+
+impl TestError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            TestError::ProgramFileDoesNotExist(_) => "E0860",
+        }
+    }
+}
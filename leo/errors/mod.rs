@@ -17,5 +17,11 @@
 pub mod cli;
 pub use self::cli::*;
 
+pub mod codes;
+pub use self::codes::*;
+
 pub mod commands;
 pub use self::commands::*;
+
+pub mod registry;
+pub use self::registry::*;
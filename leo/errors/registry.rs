@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{io, path::PathBuf};
+
+#[derive(Debug, Error)]
+pub enum RegistryError {
+    #[error("package `{}` ({}) is not in the registry cache, and `--offline` was passed", _0, _1)]
+    NotCached(String, String),
+
+    #[error("git dependency `{}` is not installed, and `--offline` was passed", _0)]
+    NotInstalled(String),
+
+    #[error("package `{}` ({}) was not found in the registry at `{}`", _0, _1, _2)]
+    PackageNotFound(String, String, String),
+
+    #[error("reading {:?}: {}", _0, _1)]
+    Reading(PathBuf, io::Error),
+
+    #[error("writing {:?}: {}", _0, _1)]
+    Writing(PathBuf, io::Error),
+}
+
+impl RegistryError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            RegistryError::NotCached(_, _) => "E0880",
+            RegistryError::NotInstalled(_) => "E0881",
+            RegistryError::PackageNotFound(_, _, _) => "E0882",
+            RegistryError::Reading(_, _) => "E0883",
+            RegistryError::Writing(_, _) => "E0884",
+        }
+    }
+}
@@ -17,6 +17,7 @@
 #[macro_use]
 extern crate thiserror;
 
+pub mod ceremony;
 pub mod cli;
 pub mod cli_types;
 pub mod commands;
@@ -24,5 +25,11 @@ pub mod commands;
 pub mod config;
 pub mod errors;
 pub mod logger;
+pub mod proving;
+pub mod registry;
 pub mod synthesizer;
 pub mod updater;
+pub mod workspace;
+
+#[cfg(test)]
+mod tests;
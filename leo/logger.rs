@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use leo_typed::DIAGNOSTIC_TARGET;
+
+use clap::ArgMatches;
 use colored::Colorize;
 use std::fmt;
 use tracing::{event::Event, subscriber::Subscriber};
@@ -160,6 +163,11 @@ where
     fn format_event(&self, ctx: &FmtContext<'_, S, N>, writer: &mut dyn fmt::Write, event: &Event<'_>) -> fmt::Result {
         let meta = event.metadata();
 
+        // Diagnostics are only printed for `--message-format json`
+        if meta.target() == DIAGNOSTIC_TARGET {
+            return Ok(());
+        }
+
         if self.display_level {
             fn colored_string(level: &tracing::Level, message: &str) -> colored::ColoredString {
                 match *level {
@@ -194,21 +202,72 @@ where
     }
 }
 
+/// Prints the diagnostics emitted by the compiler as json and omits all other events.
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticFormat;
+
+impl<S, N> FormatEvent<S, N> for DiagnosticFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(&self, ctx: &FmtContext<'_, S, N>, writer: &mut dyn fmt::Write, event: &Event<'_>) -> fmt::Result {
+        if event.metadata().target() != DIAGNOSTIC_TARGET {
+            return Ok(());
+        }
+
+        ctx.format_fields(writer, event)?;
+        writeln!(writer)
+    }
+}
+
+/// The format of the messages printed by a command.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// Human readable logs.
+    Human,
+    /// One json diagnostic per line for each error or warning.
+    Json,
+}
+
+impl MessageFormat {
+    pub fn from_arguments(arguments: &ArgMatches) -> Self {
+        match arguments.value_of("message-format") {
+            Some("json") => MessageFormat::Json,
+            _ => MessageFormat::Human,
+        }
+    }
+}
+
 /// Initialize logger with custom format and verbosity.
-pub fn init_logger(_app_name: &'static str, verbosity: usize) {
-    let subscriber = FmtSubscriber::builder()
-        // all spans/events with a level higher than TRACE (e.g, debug, info, warn, etc.)
-        // will be written to stdout.
-        .with_max_level(match verbosity {
-            0 => tracing::Level::WARN,
-            1 => tracing::Level::INFO,
-            2 => tracing::Level::DEBUG,
-            _ => tracing::Level::TRACE
-        })
-        .without_time()
-        .with_target(false)
-        .event_format(Format::default())
-        .finish();
-
-    tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
+pub fn init_logger(_app_name: &'static str, verbosity: usize, message_format: MessageFormat) {
+    let level = match verbosity {
+        0 => tracing::Level::WARN,
+        1 => tracing::Level::INFO,
+        2 => tracing::Level::DEBUG,
+        _ => tracing::Level::TRACE,
+    };
+
+    match message_format {
+        MessageFormat::Human => {
+            let subscriber = FmtSubscriber::builder()
+                // all spans/events with a level higher than TRACE (e.g, debug, info, warn, etc.)
+                // will be written to stdout.
+                .with_max_level(level)
+                .without_time()
+                .with_target(false)
+                .event_format(Format::default())
+                .finish();
+
+            tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
+        }
+        MessageFormat::Json => {
+            let subscriber = FmtSubscriber::builder()
+                .with_max_level(level)
+                .event_format(DiagnosticFormat)
+                .finish();
+
+            tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
+        }
+    }
 }
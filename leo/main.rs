@@ -30,11 +30,20 @@ fn main() -> Result<(), CLIError> {
             AppSettings::DisableHelpSubcommand,
             AppSettings::DisableVersion,
         ])
-        .args(&[Arg::with_name("debug")
-            .short("d")
-            .long("debug")
-            .help("Enables debugging mode")
-            .global(true)])
+        .args(&[
+            Arg::with_name("debug")
+                .short("d")
+                .long("debug")
+                .help("Enables debugging mode")
+                .global(true),
+            Arg::with_name("message-format")
+                .long("message-format")
+                .help("Prints errors and warnings as human readable text or as one json diagnostic per line")
+                .takes_value(true)
+                .possible_values(&["human", "json"])
+                .default_value("human")
+                .global(true),
+        ])
         .subcommands(vec![
             NewCommand::new().display_order(0),
             InitCommand::new().display_order(1),
@@ -52,6 +61,12 @@ fn main() -> Result<(), CLIError> {
             CleanCommand::new().display_order(13),
             LintCommand::new().display_order(14),
             UpdateCommand::new().display_order(15),
+            FmtCommand::new().display_order(16),
+            CeremonyCommand::new().display_order(17),
+            ExplainCommand::new().display_order(18),
+            FetchCommand::new().display_order(19),
+            InputCommand::new().display_order(20),
+            StateCommand::new().display_order(21),
         ])
         .set_term_width(0);
 
@@ -75,11 +90,19 @@ fn main() -> Result<(), CLIError> {
         ("clean", Some(arguments)) => CleanCommand::process(arguments),
         ("lint", Some(arguments)) => LintCommand::process(arguments),
         ("update", Some(arguments)) => UpdateCommand::process(arguments),
+        ("fmt", Some(arguments)) => FmtCommand::process(arguments),
+        ("ceremony", Some(arguments)) => CeremonyCommand::process(arguments),
+        ("explain", Some(arguments)) => ExplainCommand::process(arguments),
+        ("fetch", Some(arguments)) => FetchCommand::process(arguments),
+        ("input", Some(arguments)) => InputCommand::process(arguments),
+        ("state", Some(arguments)) => StateCommand::process(arguments),
         _ => {
             // Set logging environment
+            let message_format = logger::MessageFormat::from_arguments(&arguments);
+
             match arguments.is_present("debug") {
-                true => logger::init_logger("leo", 2),
-                false => logger::init_logger("leo", 1),
+                true => logger::init_logger("leo", 2, message_format),
+                false => logger::init_logger("leo", 1, message_format),
             }
 
             Updater::print_cli();
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Dispatches program setup, proving, and verification to the selected proving system.
+
+use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
+use leo_package::proving_system::ProvingSystem;
+
+use snarkos_algorithms::snark::{gm17, groth16};
+use snarkos_curves::bls12_377::{Bls12_377, Fr};
+use snarkos_errors::algorithms::snark::SNARKError;
+use snarkos_models::algorithms::SNARK;
+
+use rand::Rng;
+use std::io;
+
+type Program = Compiler<Fr, EdwardsGroupType>;
+type Groth16 = groth16::Groth16<Bls12_377, Program, Vec<Fr>>;
+type GM17 = gm17::GM17<Bls12_377, Program, Vec<Fr>>;
+
+/// The parameters used to produce proofs of a program.
+pub enum ProvingKey {
+    Groth16(groth16::Parameters<Bls12_377>),
+    GM17(gm17::Parameters<Bls12_377>),
+}
+
+impl ProvingKey {
+    pub fn proving_system(&self) -> ProvingSystem {
+        match self {
+            ProvingKey::Groth16(_) => ProvingSystem::Groth16,
+            ProvingKey::GM17(_) => ProvingSystem::GM17,
+        }
+    }
+
+    pub fn read(proving_system: ProvingSystem, bytes: &[u8]) -> io::Result<Self> {
+        Ok(match proving_system {
+            ProvingSystem::Groth16 => ProvingKey::Groth16(groth16::Parameters::read(bytes, true)?),
+            ProvingSystem::GM17 => ProvingKey::GM17(gm17::Parameters::read(bytes, true)?),
+        })
+    }
+
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        let mut bytes = vec![];
+        match self {
+            ProvingKey::Groth16(parameters) => parameters.write(&mut bytes)?,
+            ProvingKey::GM17(parameters) => parameters.write(&mut bytes)?,
+        };
+
+        Ok(bytes)
+    }
+
+    /// Returns the serialized verification key contained in the proving key.
+    pub fn verification_key_bytes(&self) -> io::Result<Vec<u8>> {
+        let mut bytes = vec![];
+        match self {
+            ProvingKey::Groth16(parameters) => parameters.vk.write(&mut bytes)?,
+            ProvingKey::GM17(parameters) => parameters.vk.write(&mut bytes)?,
+        };
+
+        Ok(bytes)
+    }
+}
+
+/// The verification key of a program, prepared for verifying proofs.
+pub enum PreparedVerifyingKey {
+    Groth16(groth16::PreparedVerifyingKey<Bls12_377>),
+    GM17(gm17::PreparedVerifyingKey<Bls12_377>),
+}
+
+impl PreparedVerifyingKey {
+    pub fn proving_system(&self) -> ProvingSystem {
+        match self {
+            PreparedVerifyingKey::Groth16(_) => ProvingSystem::Groth16,
+            PreparedVerifyingKey::GM17(_) => ProvingSystem::GM17,
+        }
+    }
+
+    /// Reads a serialized verification key and prepares it.
+    pub fn read(proving_system: ProvingSystem, bytes: &[u8]) -> io::Result<Self> {
+        Ok(match proving_system {
+            ProvingSystem::Groth16 => PreparedVerifyingKey::Groth16(groth16::VerifyingKey::read(bytes)?.into()),
+            ProvingSystem::GM17 => PreparedVerifyingKey::GM17(gm17::VerifyingKey::read(bytes)?.into()),
+        })
+    }
+}
+
+/// A proof of a program execution.
+pub enum Proof {
+    Groth16(groth16::Proof<Bls12_377>),
+    GM17(gm17::Proof<Bls12_377>),
+}
+
+impl Proof {
+    pub fn proving_system(&self) -> ProvingSystem {
+        match self {
+            Proof::Groth16(_) => ProvingSystem::Groth16,
+            Proof::GM17(_) => ProvingSystem::GM17,
+        }
+    }
+
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        let mut bytes = vec![];
+        match self {
+            Proof::Groth16(proof) => proof.write(&mut bytes)?,
+            Proof::GM17(proof) => proof.write(&mut bytes)?,
+        };
+
+        Ok(bytes)
+    }
+}
+
+/// Runs the program setup with the given proving system.
+pub fn setup<R: Rng>(
+    proving_system: ProvingSystem,
+    program: Program,
+    rng: &mut R,
+) -> Result<(ProvingKey, PreparedVerifyingKey), SNARKError> {
+    Ok(match proving_system {
+        ProvingSystem::Groth16 => {
+            let (proving_key, prepared_verifying_key) = Groth16::setup(program, rng)?;
+
+            (
+                ProvingKey::Groth16(proving_key),
+                PreparedVerifyingKey::Groth16(prepared_verifying_key),
+            )
+        }
+        ProvingSystem::GM17 => {
+            let (proving_key, prepared_verifying_key) = GM17::setup(program, rng)?;
+
+            (
+                ProvingKey::GM17(proving_key),
+                PreparedVerifyingKey::GM17(prepared_verifying_key),
+            )
+        }
+    })
+}
+
+/// Produces a proof of the program with the proving system of the given proving key.
+pub fn prove<R: Rng>(proving_key: &ProvingKey, program: Program, rng: &mut R) -> Result<Proof, SNARKError> {
+    Ok(match proving_key {
+        ProvingKey::Groth16(parameters) => Proof::Groth16(Groth16::prove(parameters, program, rng)?),
+        ProvingKey::GM17(parameters) => Proof::GM17(GM17::prove(parameters, program, rng)?),
+    })
+}
+
+/// Verifies a proof of the program against the public inputs of the program.
+/// Returns an error if the proof and verification key were produced by different proving systems.
+pub fn verify(
+    prepared_verifying_key: &PreparedVerifyingKey,
+    proof: &Proof,
+    public_inputs: &[Fr],
+) -> Result<bool, SNARKError> {
+    let public_inputs = public_inputs.to_vec();
+
+    match (prepared_verifying_key, proof) {
+        (PreparedVerifyingKey::Groth16(key), Proof::Groth16(proof)) => Groth16::verify(key, &public_inputs, proof),
+        (PreparedVerifyingKey::GM17(key), Proof::GM17(proof)) => GM17::verify(key, &public_inputs, proof),
+        (key, proof) => Err(SNARKError::Message(format!(
+            "cannot verify a {} proof with a {} verification key",
+            proof.proving_system(),
+            key.proving_system()
+        ))),
+    }
+}
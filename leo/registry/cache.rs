@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    config::LEO_CONFIG_DIRECTORY,
+    errors::{CLIError, RegistryError},
+    registry::archive_stems,
+};
+
+use semver::Version;
+use sha2::{Digest, Sha256};
+use std::{fs, path::PathBuf};
+
+pub const REGISTRY_CACHE_DIRECTORY: &str = "registry";
+
+/// The package archives downloaded from registries, at `~/.leo/registry` by default.
+///
+/// Archives are stored at `<author>/<package>/<version>-<hash>.zip`, where the hash is the SHA-256 digest of the archive,
+/// so the archives of one version served by different registries do not replace each other.
+pub struct RegistryCache {
+    directory: PathBuf,
+}
+
+impl Default for RegistryCache {
+    fn default() -> Self {
+        Self::new(LEO_CONFIG_DIRECTORY.join(REGISTRY_CACHE_DIRECTORY))
+    }
+}
+
+impl RegistryCache {
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// Returns the cached versions of a package.
+    pub fn versions(&self, author: &str, package_name: &str) -> Result<Vec<Version>, CLIError> {
+        let mut versions = self
+            .archives(author, package_name)?
+            .into_iter()
+            .map(|(version, _)| version)
+            .collect::<Vec<_>>();
+        versions.sort();
+        versions.dedup();
+
+        Ok(versions)
+    }
+
+    /// Returns the most recently cached archive of a package version.
+    pub fn archive(&self, author: &str, package_name: &str, version: &str) -> Result<Option<Vec<u8>>, CLIError> {
+        let version = match Version::parse(version) {
+            Ok(version) => version,
+            Err(_) => return Ok(None),
+        };
+
+        let mut archives = vec![];
+        for (archive_version, path) in self.archives(author, package_name)? {
+            if archive_version == version {
+                let metadata = fs::metadata(&path).map_err(|error| RegistryError::Reading(path.clone(), error))?;
+                archives.push((metadata.modified().ok(), path));
+            }
+        }
+
+        match archives.into_iter().max() {
+            Some((_, path)) => Ok(Some(
+                fs::read(&path).map_err(|error| RegistryError::Reading(path, error))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Stores the archive of a package version, returning its path in the cache.
+    pub fn insert(
+        &self,
+        author: &str,
+        package_name: &str,
+        version: &Version,
+        archive: &[u8],
+    ) -> Result<PathBuf, CLIError> {
+        let directory = self.package_directory(author, package_name);
+        let path = directory.join(format!("{}-{:x}.zip", version, Sha256::digest(archive)));
+
+        if !path.exists() {
+            fs::create_dir_all(&directory).map_err(|error| RegistryError::Writing(directory.clone(), error))?;
+
+            // Write to a temporary file first, so an interrupted write never leaves a truncated archive behind
+            let temporary = path.with_extension(format!("zip.{}", std::process::id()));
+            fs::write(&temporary, archive).map_err(|error| RegistryError::Writing(temporary.clone(), error))?;
+            fs::rename(&temporary, &path).map_err(|error| RegistryError::Writing(path.clone(), error))?;
+        }
+
+        Ok(path)
+    }
+
+    fn package_directory(&self, author: &str, package_name: &str) -> PathBuf {
+        self.directory.join(author).join(package_name)
+    }
+
+    /// Returns the version and path of every cached archive of a package.
+    fn archives(&self, author: &str, package_name: &str) -> Result<Vec<(Version, PathBuf)>, CLIError> {
+        let directory = self.package_directory(author, package_name);
+
+        let mut archives = vec![];
+        for stem in archive_stems(&directory)? {
+            // The hash never contains a `-`, unlike pre-release versions
+            let version = stem
+                .rsplit_once('-')
+                .and_then(|(version, _)| Version::parse(version).ok());
+
+            if let Some(version) = version {
+                archives.push((version, directory.join(format!("{}.zip", stem))));
+            }
+        }
+
+        Ok(archives)
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::{CLIError, PublishError::PackageNotPublished, RegistryError},
+    registry::{archive_stems, HttpRegistry, Registry},
+};
+
+use semver::Version;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A registry directory, with the archive of every package version at `<author>/<package>/<version>.zip`.
+///
+/// Any directory can be used as a registry, for example to host internal packages on a shared drive.
+pub struct FileRegistry {
+    directory: PathBuf,
+}
+
+impl FileRegistry {
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// Returns the registry directory at the given URL, or `None` for an `http` or `https` URL.
+    pub fn from_url(url: &str) -> Option<Self> {
+        match HttpRegistry::is_http(url) {
+            true => None,
+            false => Some(Self::new(PathBuf::from(url.trim_start_matches("file://")))),
+        }
+    }
+
+    /// Returns the path of the archive of the given package version.
+    pub fn archive_path(&self, author: &str, package_name: &str, version: &str) -> PathBuf {
+        let mut path = self.directory.join(author);
+        path.push(package_name);
+        path.push(format!("{}.zip", version));
+        path
+    }
+
+    /// Copies the archive of a package version into the registry.
+    /// A published version cannot be replaced.
+    pub fn publish(
+        &self,
+        author: &str,
+        package_name: &str,
+        version: &str,
+        archive: &Path,
+    ) -> Result<PathBuf, CLIError> {
+        let path = self.archive_path(author, package_name, version);
+        if path.exists() {
+            return Err(PackageNotPublished(format!("version {} is already published", version).into()).into());
+        }
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|error| RegistryError::Writing(parent.to_owned(), error))?;
+        }
+        fs::copy(archive, &path).map_err(|error| RegistryError::Writing(path.clone(), error))?;
+
+        Ok(path)
+    }
+}
+
+impl Registry for FileRegistry {
+    fn versions(&self, author: &str, package_name: &str) -> Result<Option<Vec<Version>>, CLIError> {
+        let stems = archive_stems(&self.directory.join(author).join(package_name))?;
+
+        Ok(Some(
+            stems.iter().filter_map(|stem| Version::parse(stem).ok()).collect(),
+        ))
+    }
+
+    fn archive(&self, author: &str, package_name: &str, version: Option<&str>) -> Result<Vec<u8>, CLIError> {
+        let not_found = |version: String| {
+            RegistryError::PackageNotFound(
+                format!("{}/{}", author, package_name),
+                version,
+                self.directory.to_string_lossy().to_string(),
+            )
+        };
+
+        let version = match version {
+            Some(version) => version.to_string(),
+            None => match self
+                .versions(author, package_name)?
+                .unwrap_or_default()
+                .into_iter()
+                .max()
+            {
+                Some(latest) => latest.to_string(),
+                None => return Err(not_found("latest".into()).into()),
+            },
+        };
+
+        let path = self.archive_path(author, package_name, &version);
+        if !path.exists() {
+            return Err(not_found(format!("v{}", version)).into());
+        }
+
+        Ok(fs::read(&path).map_err(|error| RegistryError::Reading(path, error))?)
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    config::read_token,
+    errors::{AddError::ConnectionUnavailable, CLIError},
+    registry::Registry,
+};
+
+use semver::Version;
+use std::collections::HashMap;
+
+pub const ADD_URL: &str = "v1/package/fetch";
+
+/// The Aleo Package Manager at a URL.
+pub struct HttpRegistry {
+    url: String,
+}
+
+impl HttpRegistry {
+    pub fn new(url: &str) -> Self {
+        match url.ends_with('/') {
+            true => Self { url: url.to_string() },
+            false => Self {
+                url: format!("{}/", url),
+            },
+        }
+    }
+
+    /// Returns `true` if the given registry URL is an `http` or `https` URL.
+    pub fn is_http(url: &str) -> bool {
+        url.starts_with("http://") || url.starts_with("https://")
+    }
+
+    /// Returns the URL of the given endpoint of the package manager API.
+    pub fn endpoint(&self, endpoint: &str) -> String {
+        format!("{}{}", self.url, endpoint)
+    }
+}
+
+impl Registry for HttpRegistry {
+    fn versions(&self, _author: &str, _package_name: &str) -> Result<Option<Vec<Version>>, CLIError> {
+        // The package manager API serves a version by its number, but does not list them
+        Ok(None)
+    }
+
+    fn archive(&self, author: &str, package_name: &str, version: Option<&str>) -> Result<Vec<u8>, CLIError> {
+        let token = read_token()?;
+
+        let client = reqwest::blocking::Client::new();
+        let url = self.endpoint(ADD_URL);
+
+        let mut json = HashMap::new();
+        json.insert("author", author.to_string());
+        json.insert("package_name", package_name.to_string());
+
+        if let Some(version) = version {
+            json.insert("version", version.to_string());
+        }
+
+        let response = match client.post(&url).bearer_auth(token).json(&json).send() {
+            Ok(response) => response,
+            //Cannot connect to the server
+            Err(_error) => {
+                return Err(ConnectionUnavailable("Could not connect to the Aleo Package Manager".into()).into());
+            }
+        };
+
+        Ok(response.bytes()?.to_vec())
+    }
+}
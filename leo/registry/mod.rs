@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod cache;
+pub use self::cache::*;
+
+pub mod file;
+pub use self::file::*;
+
+pub mod http;
+pub use self::http::*;
+
+use crate::{
+    config::{Config, LEO_CONFIG_DIRECTORY},
+    errors::{AddError::ZipError, CLIError, RegistryError},
+};
+use leo_package::root::Manifest;
+
+use semver::Version;
+use std::{
+    convert::TryFrom,
+    fs::{self, create_dir_all, remove_dir_all, File},
+    io,
+    path::{Path, PathBuf},
+};
+
+/// A source of package archives.
+pub trait Registry {
+    /// Returns the versions of a package in the registry, or `None` if the registry cannot list them.
+    fn versions(&self, author: &str, package_name: &str) -> Result<Option<Vec<Version>>, CLIError>;
+
+    /// Returns the archive of a package version, or of the latest version if no version is given.
+    fn archive(&self, author: &str, package_name: &str, version: Option<&str>) -> Result<Vec<u8>, CLIError>;
+}
+
+/// Returns the registry at the given URL, an Aleo Package Manager for `http` and `https` URLs
+/// and a registry directory otherwise.
+pub fn open_registry(url: &str) -> Box<dyn Registry> {
+    match FileRegistry::from_url(url) {
+        Some(registry) => Box::new(registry),
+        None => Box::new(HttpRegistry::new(url)),
+    }
+}
+
+/// Returns the URL of the registry used by the package at the given path.
+///
+/// The `[registry]` in the package manifest takes precedence over the one in the Leo config,
+/// which is also used outside of a package.
+/// Relative registry paths are resolved against the directory of the file declaring them.
+pub fn registry_url(path: &PathBuf) -> Result<String, CLIError> {
+    let manifest_url = match Manifest::exists_at(path) {
+        true => Manifest::try_from(path)?.get_registry_url(),
+        false => None,
+    };
+
+    let (url, base) = match manifest_url {
+        Some(url) => (url, path.clone()),
+        None => (Config::read_config()?.registry.url, LEO_CONFIG_DIRECTORY.clone()),
+    };
+
+    match HttpRegistry::is_http(&url) {
+        true => Ok(url),
+        false => Ok(base
+            .join(url.trim_start_matches("file://"))
+            .to_string_lossy()
+            .to_string()),
+    }
+}
+
+/// Extracts a package archive into the given directory, replacing any previous contents of the directory.
+pub fn unzip(archive: &[u8], directory: &PathBuf) -> Result<(), CLIError> {
+    if directory.exists() {
+        remove_dir_all(directory)?;
+    }
+    create_dir_all(directory)?;
+
+    let reader = std::io::Cursor::new(archive);
+
+    let mut zip_archive = match zip::ZipArchive::new(reader) {
+        Ok(zip) => zip,
+        Err(error) => return Err(ZipError(error.to_string().into()).into()),
+    };
+
+    for i in 0..zip_archive.len() {
+        let mut file = match zip_archive.by_index(i) {
+            Ok(file) => file,
+            Err(error) => return Err(ZipError(error.to_string().into()).into()),
+        };
+
+        // The sanitized name has no `..` components or leading `/`, so every file is extracted inside the directory
+        #[allow(deprecated)]
+        let file_path = directory.join(file.sanitized_name());
+
+        if file.is_dir() {
+            create_dir_all(file_path)?;
+        } else {
+            if let Some(parent_directory) = file_path.parent() {
+                create_dir_all(parent_directory)?;
+            }
+
+            io::copy(&mut file, &mut File::create(file_path)?)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the file stems of the `.zip` archives in the given directory,
+/// or nothing if the directory does not exist.
+pub(crate) fn archive_stems(directory: &Path) -> Result<Vec<String>, RegistryError> {
+    if !directory.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let entries = fs::read_dir(directory).map_err(|error| RegistryError::Reading(directory.to_owned(), error))?;
+
+    let mut stems = vec![];
+    for entry in entries {
+        let path = entry
+            .map_err(|error| RegistryError::Reading(directory.to_owned(), error))?
+            .path();
+
+        if path.extension().map(|extension| extension == "zip").unwrap_or(false) {
+            if let Some(stem) = path.file_stem() {
+                stems.push(stem.to_string_lossy().to_string());
+            }
+        }
+    }
+
+    Ok(stems)
+}
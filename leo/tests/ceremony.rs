@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    ceremony::{self, Transcript},
+    errors::CeremonyError,
+    proving::{self, PreparedVerifyingKey, Proof, ProvingKey},
+};
+use leo_package::proving_system::ProvingSystem;
+
+use snarkos_algorithms::snark::groth16::{create_random_proof, KeypairAssembly};
+use snarkos_curves::bls12_377::{Bls12_377, Fr, G1Affine};
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::{AffineCurve, ProjectiveCurve},
+    gadgets::r1cs::{ConstraintSynthesizer, ConstraintSystem},
+};
+use snarkos_utilities::{
+    bytes::{FromBytes, ToBytes},
+    rand::UniformRand,
+};
+
+use rand::thread_rng;
+
+const CHECKSUM: &str = "checksum";
+
+/// Proves knowledge of two factors of the public input.
+/// Small enough to run every step of the ceremony in an unoptimized build.
+struct Multiply {
+    a: Option<Fr>,
+    b: Option<Fr>,
+}
+
+impl ConstraintSynthesizer<Fr> for Multiply {
+    fn generate_constraints<CS: ConstraintSystem<Fr>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let a = cs.alloc(|| "a", || self.a.ok_or(SynthesisError::AssignmentMissing))?;
+        let b = cs.alloc(|| "b", || self.b.ok_or(SynthesisError::AssignmentMissing))?;
+        let c = cs.alloc_input(
+            || "c",
+            || Ok(self.a.ok_or(SynthesisError::AssignmentMissing)? * &self.b.ok_or(SynthesisError::AssignmentMissing)?),
+        )?;
+
+        cs.enforce(|| "a * b = c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+
+        Ok(())
+    }
+}
+
+fn assembly() -> KeypairAssembly<Bls12_377> {
+    ceremony::synthesize(Multiply { a: None, b: None }).unwrap()
+}
+
+/// Passes the transcript to the next participant through its serialized form.
+fn pass(transcript: &Transcript) -> Transcript {
+    let mut bytes = vec![];
+    transcript.write(&mut bytes).unwrap();
+
+    Transcript::read(bytes.as_slice()).unwrap()
+}
+
+/// Returns a prepared transcript with two contributions to each phase.
+fn contributed_transcript() -> Transcript {
+    let rng = &mut thread_rng();
+    let assembly = assembly();
+
+    let mut transcript = Transcript::new(CHECKSUM.to_string(), &assembly).unwrap();
+    transcript.contribute(rng);
+    let mut transcript = pass(&transcript);
+    transcript.contribute(rng);
+
+    let mut transcript = pass(&transcript);
+    transcript.verify(CHECKSUM, &assembly, rng).unwrap();
+    transcript.prepare(&assembly).unwrap();
+
+    let mut transcript = pass(&transcript);
+    transcript.contribute(rng);
+    let mut transcript = pass(&transcript);
+    transcript.contribute(rng);
+
+    pass(&transcript)
+}
+
+#[test]
+fn test_round_trip() {
+    let rng = &mut thread_rng();
+
+    let transcript = contributed_transcript();
+    let digest = transcript.verify(CHECKSUM, &assembly(), rng).unwrap();
+    assert_eq!(digest, transcript.digest());
+
+    // Finalize
+    let parameters = transcript.into_parameters().unwrap();
+    let proving_key = ProvingKey::Groth16(parameters.clone());
+    let verification_key = proving_key.verification_key_bytes().unwrap();
+    let prepared_verifying_key = PreparedVerifyingKey::read(ProvingSystem::Groth16, &verification_key).unwrap();
+
+    let a = Fr::rand(rng);
+    let b = Fr::rand(rng);
+    let circuit = Multiply { a: Some(a), b: Some(b) };
+    let proof = Proof::Groth16(create_random_proof(circuit, &parameters, rng).unwrap());
+
+    assert!(proving::verify(&prepared_verifying_key, &proof, &[a * &b]).unwrap());
+    assert!(!proving::verify(&prepared_verifying_key, &proof, &[a]).unwrap());
+}
+
+#[test]
+fn test_tampered_phase1_contribution() {
+    let mut transcript = contributed_transcript();
+    let tau_g1 = transcript.phase1[0].tau_g1.into_projective();
+    transcript.phase1[0].tau_g1 = (tau_g1 + &G1Affine::prime_subgroup_generator().into_projective()).into_affine();
+
+    match transcript.verify(CHECKSUM, &assembly(), &mut thread_rng()) {
+        Err(CeremonyError::InvalidContribution(1, 1, _)) => {}
+        result => panic!("expected an invalid phase 1 contribution, found {:?}", result),
+    }
+}
+
+#[test]
+fn test_tampered_phase2_parameters() {
+    let mut transcript = contributed_transcript();
+    transcript.phase2.as_mut().unwrap().parameters.delta_g1 = G1Affine::prime_subgroup_generator();
+
+    assert!(transcript.verify(CHECKSUM, &assembly(), &mut thread_rng()).is_err());
+}
+
+#[test]
+fn test_reordered_contributions() {
+    let mut transcript = contributed_transcript();
+    transcript.phase1.swap(0, 1);
+
+    match transcript.verify(CHECKSUM, &assembly(), &mut thread_rng()) {
+        Err(CeremonyError::InvalidContribution(1, 1, _)) => {}
+        result => panic!("expected an invalid phase 1 contribution, found {:?}", result),
+    }
+
+    let mut transcript = contributed_transcript();
+    transcript.phase2.as_mut().unwrap().contributions.swap(0, 1);
+
+    match transcript.verify(CHECKSUM, &assembly(), &mut thread_rng()) {
+        Err(CeremonyError::InvalidContribution(2, 1, _)) => {}
+        result => panic!("expected an invalid phase 2 contribution, found {:?}", result),
+    }
+}
+
+#[test]
+fn test_checksum_mismatch() {
+    let transcript = contributed_transcript();
+
+    match transcript.verify("other", &assembly(), &mut thread_rng()) {
+        Err(CeremonyError::ChecksumMismatch(_, _)) => {}
+        result => panic!("expected a checksum mismatch, found {:?}", result),
+    }
+}
+
+#[test]
+fn test_checksum_too_long() {
+    let mut bytes = b"leo-ceremony-v1\n".to_vec();
+    bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+
+    assert!(Transcript::read(bytes.as_slice()).is_err());
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::{explain, ERROR_CODES};
+
+use std::{collections::BTreeSet, fs, path::Path};
+
+/// The source directories of the crates that define error codes.
+const SOURCE_DIRECTORIES: &[&str] = &[
+    "ast/src",
+    "compiler/src",
+    "core/src",
+    "gadgets/src",
+    "input/src",
+    "leo",
+    "package/src",
+    "state/src",
+    "typed/src",
+];
+
+/// Adds every error code literal such as `"E0001"` in the Rust files of the given directory to `codes`.
+fn collect_codes(directory: &Path, codes: &mut BTreeSet<String>) {
+    for entry in fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+
+        if path.is_dir() {
+            // Tests refer to the codes they expect, not to the codes that are returned
+            if !path.ends_with("tests") {
+                collect_codes(&path, codes);
+            }
+        } else if path.extension() == Some("rs".as_ref()) {
+            let source = fs::read_to_string(&path).unwrap();
+
+            for (start, _) in source.match_indices("\"E") {
+                let code = &source[start + 1..];
+                let digits = code[1..].chars().take_while(char::is_ascii_digit).count();
+
+                if digits == 4 && code[5..].starts_with('"') {
+                    codes.insert(code[..5].to_string());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_error_codes_registered() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    let mut returned = BTreeSet::new();
+    for directory in SOURCE_DIRECTORIES {
+        collect_codes(&root.join(directory), &mut returned);
+    }
+    let registered = ERROR_CODES
+        .iter()
+        .map(|(code, _)| code.to_string())
+        .collect::<BTreeSet<_>>();
+
+    let unregistered = returned.difference(&registered).collect::<Vec<_>>();
+    assert!(
+        unregistered.is_empty(),
+        "codes without an explanation: {:?}",
+        unregistered
+    );
+
+    let unused = registered.difference(&returned).collect::<Vec<_>>();
+    assert!(
+        unused.is_empty(),
+        "explanations of codes that are never returned: {:?}",
+        unused
+    );
+}
+
+#[test]
+fn test_error_codes_sorted() {
+    assert!(ERROR_CODES.windows(2).all(|codes| codes[0].0 < codes[1].0));
+}
+
+#[test]
+fn test_error_code_explanations() {
+    for (code, explanation) in ERROR_CODES {
+        assert!(explanation.contains("```"), "{} has no example", code);
+        assert!(
+            explanation.ends_with('\n') && !explanation.ends_with("\n\n"),
+            "{} does not end with a newline",
+            code
+        );
+    }
+}
+
+#[test]
+fn test_explain() {
+    assert_eq!(explain("E0001"), Some(ERROR_CODES[0].1));
+    assert_eq!(explain("e1"), Some(ERROR_CODES[0].1));
+    assert_eq!(explain("E9999"), None);
+    assert_eq!(explain("E"), None);
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::InputCommand;
+use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
+
+use snarkos_curves::edwards_bls12::Fq;
+
+use std::{env::temp_dir, fs, path::PathBuf};
+
+const MAIN: &str = "function main(a: u32, b: bool) -> u32 {\n    return a\n}\n";
+
+const JSON_INPUT: &str = r#"{
+    "main": {
+        "a": { "type": "u8", "value": 1 },
+        "c": { "type": "bool", "value": true }
+    }
+}"#;
+
+const TOML_INPUT: &str = r#"[main]
+a = { type = "u8", value = 1 }
+c = { type = "bool", value = true }
+"#;
+
+/// Returns the errors from checking the given input file against the main function.
+fn check(file_name: &str, contents: &str) -> Vec<&'static str> {
+    let directory = temp_dir().join(format!("leo-input-{}-{}", file_name, std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+
+    let main_file_path = directory.join("main.leo");
+    let input_path = directory.join(file_name);
+    fs::write(&main_file_path, MAIN).unwrap();
+    fs::write(&input_path, contents).unwrap();
+
+    let program =
+        Compiler::<Fq, EdwardsGroupType>::parse_program_without_input("test".into(), main_file_path, PathBuf::new())
+            .unwrap();
+    let input_string = InputCommand::read_input_file("test", &input_path).unwrap();
+    let errors = program.check_input_file(&input_string, &input_path).unwrap();
+
+    fs::remove_dir_all(&directory).unwrap();
+
+    errors.iter().map(|error| error.code()).collect()
+}
+
+#[test]
+fn test_check_json_input() {
+    // `a` is mistyped, `b` is missing, and `c` is extra
+    assert_eq!(check("test.json", JSON_INPUT), vec!["E0243", "E0228", "E0242"]);
+}
+
+#[test]
+fn test_check_toml_input() {
+    assert_eq!(check("test.toml", TOML_INPUT), vec!["E0243", "E0228", "E0242"]);
+}
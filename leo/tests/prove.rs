@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    cli::CLI,
+    commands::{InputSelection, ProveCommand},
+    errors::{CLIError, ProveError},
+};
+
+#[test]
+fn test_input_name() {
+    let default = InputSelection::default();
+    let selected = InputSelection {
+        input: Some("scenario".to_string()),
+        state: None,
+    };
+
+    // The input name also names the output and proof files
+    assert_eq!(default.input_name("token"), "token");
+    assert_eq!(selected.input_name("token"), "scenario");
+    assert_eq!(selected.state_name("token"), "scenario");
+}
+
+#[test]
+fn test_batch_with_selection() {
+    for (input, state) in &[(Some("scenario"), None), (None, Some("scenario"))] {
+        let selection = InputSelection {
+            input: input.map(|input| input.to_string()),
+            state: state.map(|state| state.to_string()),
+        };
+
+        match ProveCommand::output(((None, selection), true)) {
+            Err(CLIError::ProveError(ProveError::BatchSelection)) => {}
+            _ => panic!("expected a batch selection error"),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    commands::PackageSources,
+    errors::{CLIError, PublishError, RegistryError},
+    registry::{registry_url, unzip, FileRegistry, Registry, RegistryCache},
+};
+
+use semver::Version;
+use std::{
+    env::temp_dir,
+    fs,
+    io::{Cursor, Write},
+    path::PathBuf,
+};
+use zip::{write::FileOptions, ZipWriter};
+
+/// Returns an empty directory for a test.
+fn test_directory(name: &str) -> PathBuf {
+    let directory = temp_dir().join(format!("leo-registry-{}-{}", name, std::process::id()));
+    if directory.exists() {
+        fs::remove_dir_all(&directory).unwrap();
+    }
+    fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+/// Returns an archive with the given files.
+fn archive(files: &[(&str, &str)]) -> Vec<u8> {
+    let mut writer = ZipWriter::new(Cursor::new(vec![]));
+    for (name, contents) in files {
+        writer.start_file(*name, FileOptions::default()).unwrap();
+        writer.write_all(contents.as_bytes()).unwrap();
+    }
+    writer.finish().unwrap().into_inner()
+}
+
+/// Returns the archive of a package version.
+fn package_archive(package_name: &str, version: &str) -> Vec<u8> {
+    let manifest = format!("[project]\nname = \"{}\"\nversion = \"{}\"\n", package_name, version);
+    archive(&[("Leo.toml", &manifest), ("src/main.leo", "function main() {}\n")])
+}
+
+#[test]
+fn test_cache_insert_and_lookup() {
+    let cache = RegistryCache::new(test_directory("cache"));
+    let release = package_archive("cached", "1.0.0");
+    let pre_release = package_archive("cached", "1.1.0-beta.1");
+
+    let path = cache
+        .insert("author", "cached", &Version::parse("1.0.0").unwrap(), &release)
+        .unwrap();
+    cache
+        .insert(
+            "author",
+            "cached",
+            &Version::parse("1.1.0-beta.1").unwrap(),
+            &pre_release,
+        )
+        .unwrap();
+
+    // Inserting the same archive again keeps the cached archive
+    let again = cache
+        .insert("author", "cached", &Version::parse("1.0.0").unwrap(), &release)
+        .unwrap();
+    assert_eq!(path, again);
+
+    assert_eq!(cache.versions("author", "cached").unwrap(), vec![
+        Version::parse("1.0.0").unwrap(),
+        Version::parse("1.1.0-beta.1").unwrap(),
+    ]);
+    assert_eq!(cache.archive("author", "cached", "1.0.0").unwrap(), Some(release));
+    assert_eq!(
+        cache.archive("author", "cached", "1.1.0-beta.1").unwrap(),
+        Some(pre_release)
+    );
+    assert_eq!(cache.archive("author", "cached", "2.0.0").unwrap(), None);
+    assert_eq!(cache.archive("author", "cached", "latest").unwrap(), None);
+    assert!(cache.versions("author", "missing").unwrap().is_empty());
+}
+
+#[test]
+fn test_offline_not_cached() {
+    let package = test_directory("offline");
+    fs::write(
+        package.join("Leo.toml"),
+        "[project]\nname = \"offline\"\nversion = \"0.1.0\"\n\n[registry]\nurl = \"registry\"\n",
+    )
+    .unwrap();
+
+    let cache = RegistryCache::new(package.join("cache"));
+    let mut sources = PackageSources::new(&package, true).unwrap().with_cache(cache);
+
+    for version in &[None, Some("1.0.0")] {
+        match sources.download("author", "uncached", *version) {
+            Err(CLIError::RegistryError(RegistryError::NotCached(key, _))) => assert_eq!(key, "author/uncached"),
+            _ => panic!("expected a not cached error"),
+        }
+    }
+
+    // Cached versions are used offline
+    let cache = RegistryCache::new(package.join("cache"));
+    cache
+        .insert(
+            "author",
+            "uncached",
+            &Version::parse("1.0.0").unwrap(),
+            &package_archive("uncached", "1.0.0"),
+        )
+        .unwrap();
+
+    let summary = sources.download("author", "uncached", None).unwrap();
+    assert_eq!(summary.version, Version::parse("1.0.0").unwrap());
+}
+
+#[test]
+fn test_file_registry_publish_and_fetch() {
+    let directory = test_directory("file");
+    let registry = FileRegistry::new(directory.join("registry"));
+
+    let archive = package_archive("published", "1.0.0");
+    let archive_path = directory.join("published.zip");
+    fs::write(&archive_path, &archive).unwrap();
+
+    registry.publish("author", "published", "1.0.0", &archive_path).unwrap();
+
+    assert_eq!(
+        registry.versions("author", "published").unwrap(),
+        Some(vec![Version::parse("1.0.0").unwrap()])
+    );
+    assert_eq!(registry.archive("author", "published", None).unwrap(), archive);
+    assert_eq!(registry.archive("author", "published", Some("1.0.0")).unwrap(), archive);
+
+    match registry.publish("author", "published", "1.0.0", &archive_path) {
+        Err(CLIError::PublishError(PublishError::PackageNotPublished(_))) => {}
+        _ => panic!("expected a published version to be kept"),
+    }
+    match registry.archive("author", "published", Some("2.0.0")) {
+        Err(CLIError::RegistryError(RegistryError::PackageNotFound(_, _, _))) => {}
+        _ => panic!("expected a package not found error"),
+    }
+}
+
+#[test]
+fn test_unzip_outside_directory() {
+    let directory = test_directory("unzip");
+    let package = directory.join("package");
+
+    unzip(
+        &archive(&[("../escaped.leo", ""), ("/absolute.leo", ""), ("src/main.leo", "")]),
+        &package,
+    )
+    .unwrap();
+
+    assert!(package.join("escaped.leo").exists());
+    assert!(package.join("absolute.leo").exists());
+    assert!(package.join("src/main.leo").exists());
+    assert!(!directory.join("escaped.leo").exists());
+}
+
+#[test]
+fn test_registry_url_invalid_manifest() {
+    let package = test_directory("manifest");
+    fs::write(package.join("Leo.toml"), "[project\n").unwrap();
+
+    match registry_url(&package) {
+        Err(CLIError::ManifestError(_)) => {}
+        _ => panic!("expected a manifest error"),
+    }
+}
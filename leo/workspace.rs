@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    commands::{FetchCommand, PackageSources},
+    errors::CLIError,
+};
+use leo_package::root::{Workspace, WorkspaceMember};
+
+use std::env::{current_dir, set_current_dir};
+
+/// Runs a command for every member of the workspace rooted at the current directory,
+/// with the directory of the member as the current directory.
+///
+/// Members run after the members they depend on.
+/// Returns `false` without running the command if the current directory is not a workspace root.
+pub fn for_each_member<F>(mut command: F) -> Result<bool, CLIError>
+where
+    F: FnMut(&WorkspaceMember) -> Result<(), CLIError>,
+{
+    let root = current_dir()?;
+
+    let workspace = match Workspace::read_from(&root)? {
+        Some(workspace) => workspace,
+        None => return Ok(false),
+    };
+
+    for member in workspace.members(&root)? {
+        tracing::span!(tracing::Level::INFO, "Workspace").in_scope(|| {
+            tracing::info!("Package {} ({:?})", member.name, member.path);
+        });
+
+        set_current_dir(&member.path)?;
+        let result = command(&member);
+        set_current_dir(&root)?;
+
+        result?;
+    }
+
+    Ok(true)
+}
+
+/// Refreshes the copies of the workspace members imported by the given member,
+/// so that it compiles against their current sources.
+pub fn fetch_member_imports(member: &WorkspaceMember) -> Result<(), CLIError> {
+    if member.dependencies.is_empty() {
+        return Ok(());
+    }
+
+    FetchCommand::fetch(&mut PackageSources::new(&member.path, false)?, false)
+}
@@ -18,6 +18,9 @@ use std::{ffi::OsString, fs::FileType, io};
 
 #[derive(Debug, Error)]
 pub enum ImportsDirectoryError {
+    #[error("copying: {}", _0)]
+    Copying(io::Error),
+
     #[error("creating: {}", _0)]
     Creating(io::Error),
 
@@ -45,3 +48,20 @@ pub enum ImportsDirectoryError {
     #[error("removing: {}", _0)]
     Removing(io::Error),
 }
+
+impl ImportsDirectoryError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ImportsDirectoryError::Copying(_) => "E0609",
+            ImportsDirectoryError::Creating(_) => "E0600",
+            ImportsDirectoryError::GettingFileEntry(_) => "E0601",
+            ImportsDirectoryError::GettingFileExtension(_) => "E0602",
+            ImportsDirectoryError::GettingFileType(_, _) => "E0603",
+            ImportsDirectoryError::ImportDoesNotExist(_) => "E0604",
+            ImportsDirectoryError::InvalidFileExtension(_, _) => "E0605",
+            ImportsDirectoryError::InvalidFileType(_, _) => "E0606",
+            ImportsDirectoryError::Reading(_) => "E0607",
+            ImportsDirectoryError::Removing(_) => "E0608",
+        }
+    }
+}
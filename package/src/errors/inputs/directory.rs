@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{InputFileError, StateFileError};
+use crate::{InputFileError, JsonInputFileError, StateFileError, TomlInputFileError};
 
 use std::{ffi::OsString, fs::FileType, io};
 
@@ -23,6 +23,9 @@ pub enum InputsDirectoryError {
     #[error("creating: {}", _0)]
     Creating(io::Error),
 
+    #[error("input {:?} is given by more than one JSON, TOML, input or state file", _0)]
+    DuplicateInput(String),
+
     #[error("file entry getting: {}", _0)]
     GettingFileEntry(io::Error),
 
@@ -38,6 +41,9 @@ pub enum InputsDirectoryError {
     #[error("{}", _0)]
     InputFileError(#[from] InputFileError),
 
+    #[error("{}", _0)]
+    JsonInputFileError(#[from] JsonInputFileError),
+
     #[error("invalid file {:?} extension: {:?}", _0, _1)]
     InvalidFileExtension(String, OsString),
 
@@ -49,4 +55,27 @@ pub enum InputsDirectoryError {
 
     #[error("{}", _0)]
     StateFileError(#[from] StateFileError),
+
+    #[error("{}", _0)]
+    TomlInputFileError(#[from] TomlInputFileError),
+}
+
+impl InputsDirectoryError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            InputsDirectoryError::Creating(_) => "E0610",
+            InputsDirectoryError::DuplicateInput(_) => "E0618",
+            InputsDirectoryError::GettingFileEntry(_) => "E0611",
+            InputsDirectoryError::GettingFileExtension(_) => "E0612",
+            InputsDirectoryError::GettingFileName(_) => "E0613",
+            InputsDirectoryError::GettingFileType(_, _) => "E0614",
+            InputsDirectoryError::InputFileError(error) => error.code(),
+            InputsDirectoryError::JsonInputFileError(error) => error.code(),
+            InputsDirectoryError::InvalidFileExtension(_, _) => "E0615",
+            InputsDirectoryError::InvalidFileType(_, _) => "E0616",
+            InputsDirectoryError::Reading(_) => "E0617",
+            InputsDirectoryError::StateFileError(error) => error.code(),
+            InputsDirectoryError::TomlInputFileError(error) => error.code(),
+        }
+    }
 }
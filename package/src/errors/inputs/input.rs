@@ -31,6 +31,17 @@ pub enum InputFileError {
     Writing(io::Error),
 }
 
+impl InputFileError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            InputFileError::Crate(_, _) => "E0620",
+            InputFileError::Creating(_) => "E0621",
+            InputFileError::FileReadError(_) => "E0622",
+            InputFileError::Writing(_) => "E0623",
+        }
+    }
+}
+
 impl From<std::io::Error> for InputFileError {
     fn from(error: std::io::Error) -> Self {
         InputFileError::Crate("std::io", format!("{}", error))
@@ -20,5 +20,11 @@ pub use directory::*;
 pub mod input;
 pub use input::*;
 
+pub mod json;
+pub use json::*;
+
 pub mod state;
 pub use state::*;
+
+pub mod toml_input;
+pub use toml_input::*;
@@ -31,6 +31,17 @@ pub enum StateFileError {
     Writing(io::Error),
 }
 
+impl StateFileError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            StateFileError::Crate(_, _) => "E0625",
+            StateFileError::Creating(_) => "E0626",
+            StateFileError::FileReadError(_) => "E0627",
+            StateFileError::Writing(_) => "E0628",
+        }
+    }
+}
+
 impl From<std::io::Error> for StateFileError {
     fn from(error: std::io::Error) -> Self {
         StateFileError::Crate("std::io", format!("{}", error))
@@ -26,6 +26,9 @@ pub use outputs::*;
 pub mod package;
 pub use self::package::*;
 
+pub mod resolver;
+pub use self::resolver::*;
+
 pub mod root;
 pub use self::root::*;
 
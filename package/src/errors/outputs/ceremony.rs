@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{io, path::PathBuf};
+
+#[derive(Debug, Error)]
+pub enum CeremonyFileError {
+    #[error("{}: {}", _0, _1)]
+    Crate(&'static str, String),
+
+    #[error("creating: {}", _0)]
+    Creating(io::Error),
+
+    #[error("Cannot read from the provided file path - {:?}", _0)]
+    FileReadError(PathBuf),
+
+    #[error("Cannot remove the provided file - {:?}", _0)]
+    FileRemovalError(PathBuf),
+
+    #[error("writing: {}", _0)]
+    Writing(io::Error),
+}
+
+impl CeremonyFileError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            CeremonyFileError::Crate(_, _) => "E0635",
+            CeremonyFileError::Creating(_) => "E0636",
+            CeremonyFileError::FileReadError(_) => "E0637",
+            CeremonyFileError::FileRemovalError(_) => "E0638",
+            CeremonyFileError::Writing(_) => "E0639",
+        }
+    }
+}
+
+impl From<std::io::Error> for CeremonyFileError {
+    fn from(error: std::io::Error) -> Self {
+        CeremonyFileError::Crate("std::io", format!("{}", error))
+    }
+}
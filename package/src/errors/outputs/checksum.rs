@@ -34,6 +34,18 @@ pub enum ChecksumFileError {
     Writing(io::Error),
 }
 
+impl ChecksumFileError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ChecksumFileError::Crate(_, _) => "E0640",
+            ChecksumFileError::Creating(_) => "E0641",
+            ChecksumFileError::FileReadError(_) => "E0642",
+            ChecksumFileError::FileRemovalError(_) => "E0643",
+            ChecksumFileError::Writing(_) => "E0644",
+        }
+    }
+}
+
 impl From<std::io::Error> for ChecksumFileError {
     fn from(error: std::io::Error) -> Self {
         ChecksumFileError::Crate("std::io", format!("{}", error))
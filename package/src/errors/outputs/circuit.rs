@@ -34,6 +34,18 @@ pub enum CircuitFileError {
     Writing(io::Error),
 }
 
+impl CircuitFileError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            CircuitFileError::Crate(_, _) => "E0645",
+            CircuitFileError::Creating(_) => "E0646",
+            CircuitFileError::FileReadError(_) => "E0647",
+            CircuitFileError::FileRemovalError(_) => "E0648",
+            CircuitFileError::Writing(_) => "E0649",
+        }
+    }
+}
+
 impl From<std::io::Error> for CircuitFileError {
     fn from(error: std::io::Error) -> Self {
         CircuitFileError::Crate("std::io", format!("{}", error))
@@ -42,3 +42,18 @@ pub enum OutputsDirectoryError {
     #[error("removing: {}", _0)]
     Removing(io::Error),
 }
+
+impl OutputsDirectoryError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            OutputsDirectoryError::Creating(_) => "E0650",
+            OutputsDirectoryError::GettingFileEntry(_) => "E0651",
+            OutputsDirectoryError::GettingFileExtension(_) => "E0652",
+            OutputsDirectoryError::GettingFileType(_, _) => "E0653",
+            OutputsDirectoryError::InvalidFileExtension(_, _) => "E0654",
+            OutputsDirectoryError::InvalidFileType(_, _) => "E0655",
+            OutputsDirectoryError::Reading(_) => "E0656",
+            OutputsDirectoryError::Removing(_) => "E0657",
+        }
+    }
+}
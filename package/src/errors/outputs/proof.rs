@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::proving_system::ProvingSystem;
+
 use std::{io, path::PathBuf};
 
 #[derive(Debug, Error)]
@@ -30,10 +32,26 @@ pub enum ProofFileError {
     #[error("Cannot remove the provided file - {:?}", _0)]
     FileRemovalError(PathBuf),
 
+    #[error("{:?} was expected to be generated by {}, found {}", _0, _1, _2)]
+    ProvingSystemMismatch(PathBuf, ProvingSystem, String),
+
     #[error("writing: {}", _0)]
     Writing(io::Error),
 }
 
+impl ProofFileError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ProofFileError::Crate(_, _) => "E0660",
+            ProofFileError::Creating(_) => "E0661",
+            ProofFileError::FileReadError(_) => "E0662",
+            ProofFileError::FileRemovalError(_) => "E0663",
+            ProofFileError::ProvingSystemMismatch(_, _, _) => "E0664",
+            ProofFileError::Writing(_) => "E0665",
+        }
+    }
+}
+
 impl From<std::io::Error> for ProofFileError {
     fn from(error: std::io::Error) -> Self {
         ProofFileError::Crate("std::io", format!("{}", error))
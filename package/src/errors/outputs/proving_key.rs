@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::proving_system::ProvingSystem;
+
 use std::{io, path::PathBuf};
 
 #[derive(Debug, Error)]
@@ -30,10 +32,26 @@ pub enum ProvingKeyFileError {
     #[error("Cannot remove the provided file - {:?}", _0)]
     FileRemovalError(PathBuf),
 
+    #[error("{:?} was expected to be generated by {}, found {}", _0, _1, _2)]
+    ProvingSystemMismatch(PathBuf, ProvingSystem, String),
+
     #[error("writing: {}", _0)]
     Writing(io::Error),
 }
 
+impl ProvingKeyFileError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ProvingKeyFileError::Crate(_, _) => "E0670",
+            ProvingKeyFileError::Creating(_) => "E0671",
+            ProvingKeyFileError::FileReadError(_) => "E0672",
+            ProvingKeyFileError::FileRemovalError(_) => "E0673",
+            ProvingKeyFileError::ProvingSystemMismatch(_, _, _) => "E0674",
+            ProvingKeyFileError::Writing(_) => "E0675",
+        }
+    }
+}
+
 impl From<std::io::Error> for ProvingKeyFileError {
     fn from(error: std::io::Error) -> Self {
         ProvingKeyFileError::Crate("std::io", format!("{}", error))
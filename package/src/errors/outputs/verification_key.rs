@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::proving_system::ProvingSystem;
+
 use std::{io, path::PathBuf};
 
 #[derive(Debug, Error)]
@@ -33,10 +35,27 @@ pub enum VerificationKeyFileError {
     #[error("Verification key file was corrupted")]
     IncorrectVerificationKey,
 
+    #[error("{:?} was expected to be generated by {}, found {}", _0, _1, _2)]
+    ProvingSystemMismatch(PathBuf, ProvingSystem, String),
+
     #[error("writing: {}", _0)]
     Writing(io::Error),
 }
 
+impl VerificationKeyFileError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            VerificationKeyFileError::Crate(_, _) => "E0680",
+            VerificationKeyFileError::Creating(_) => "E0681",
+            VerificationKeyFileError::FileReadError(_) => "E0682",
+            VerificationKeyFileError::FileRemovalError(_) => "E0683",
+            VerificationKeyFileError::IncorrectVerificationKey => "E0684",
+            VerificationKeyFileError::ProvingSystemMismatch(_, _, _) => "E0685",
+            VerificationKeyFileError::Writing(_) => "E0686",
+        }
+    }
+}
+
 impl From<std::io::Error> for VerificationKeyFileError {
     fn from(error: std::io::Error) -> Self {
         VerificationKeyFileError::Crate("std::io", format!("{}", error))
@@ -15,6 +15,17 @@ pub enum PackageError {
     Removing(&'static str, io::Error),
 }
 
+impl PackageError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            PackageError::Crate(_, _) => "E0690",
+            PackageError::Creating(_, _) => "E0691",
+            PackageError::FailedToInitialize(_, _) => "E0692",
+            PackageError::Removing(_, _) => "E0693",
+        }
+    }
+}
+
 impl From<std::io::Error> for PackageError {
     fn from(error: std::io::Error) -> Self {
         PackageError::Crate("std::io", format!("{}", error))
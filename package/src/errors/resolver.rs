@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+#[derive(Debug, Error)]
+pub enum ResolverError {
+    #[error("failed to select a version of `{}` that satisfies all requirements:\n{}", _0, _1.join("\n"))]
+    Conflict(String, Vec<String>),
+
+    #[error("invalid version requirement `{}` for `{}`: {}", _1, _0, _2)]
+    InvalidRequirement(String, String, String),
+
+    #[error("package `{}` cannot be used as a dependency: {}", _0, _1)]
+    InvalidPackage(String, String),
+
+    #[error("no version of `{}` is available that matches the requirement:\n{}", _0, _1)]
+    NoMatchingVersion(String, String),
+
+    #[error(
+        "package `{}` from `{}` cannot depend on `{}` by path, only local packages can",
+        _0,
+        _1,
+        _2
+    )]
+    RemotePathDependency(String, String, String),
+}
+
+impl ResolverError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ResolverError::Conflict(_, _) => "E0760",
+            ResolverError::InvalidRequirement(_, _, _) => "E0761",
+            ResolverError::InvalidPackage(_, _) => "E0762",
+            ResolverError::NoMatchingVersion(_, _) => "E0763",
+            ResolverError::RemotePathDependency(_, _, _) => "E0764",
+        }
+    }
+}
@@ -28,6 +28,16 @@ pub enum GitignoreError {
     Writing(io::Error),
 }
 
+impl GitignoreError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            GitignoreError::Crate(_, _) => "E0700",
+            GitignoreError::Creating(_) => "E0701",
+            GitignoreError::Writing(_) => "E0702",
+        }
+    }
+}
+
 impl From<std::io::Error> for GitignoreError {
     fn from(error: std::io::Error) -> Self {
         GitignoreError::Crate("std::io", format!("{}", error))
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{io, path::PathBuf};
+
+#[derive(Debug, Error)]
+pub enum LockFileError {
+    #[error("checksum of {:?}: {}", _0, _1)]
+    Checksum(PathBuf, String),
+
+    #[error("`{}` creating: {}", _0, _1)]
+    Creating(&'static str, io::Error),
+
+    #[error("`{}` opening: {}", _0, _1)]
+    Opening(&'static str, io::Error),
+
+    #[error("`{}` parsing: {}", _0, _1)]
+    Parsing(&'static str, toml::de::Error),
+
+    #[error("`{}` reading: {}", _0, _1)]
+    Reading(&'static str, io::Error),
+
+    #[error("`{}` serializing: {}", _0, _1)]
+    Serializing(&'static str, toml::ser::Error),
+
+    #[error("`{}` writing: {}", _0, _1)]
+    Writing(&'static str, io::Error),
+}
+
+impl LockFileError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            LockFileError::Checksum(_, _) => "E0750",
+            LockFileError::Creating(_, _) => "E0751",
+            LockFileError::Opening(_, _) => "E0752",
+            LockFileError::Parsing(_, _) => "E0753",
+            LockFileError::Reading(_, _) => "E0754",
+            LockFileError::Serializing(_, _) => "E0755",
+            LockFileError::Writing(_, _) => "E0756",
+        }
+    }
+}
@@ -21,6 +21,9 @@ pub enum ManifestError {
     #[error("`{}` creating: {}", _0, _1)]
     Creating(&'static str, io::Error),
 
+    #[error("invalid dependency `{}`: {}", _0, _1)]
+    InvalidDependency(String, String),
+
     #[error("`{}` metadata: {}", _0, _1)]
     Metadata(&'static str, io::Error),
 
@@ -36,3 +39,17 @@ pub enum ManifestError {
     #[error("`{}` writing: {}", _0, _1)]
     Writing(&'static str, io::Error),
 }
+
+impl ManifestError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ManifestError::Creating(_, _) => "E0705",
+            ManifestError::InvalidDependency(_, _) => "E0711",
+            ManifestError::Metadata(_, _) => "E0706",
+            ManifestError::Opening(_, _) => "E0707",
+            ManifestError::Parsing(_, _) => "E0708",
+            ManifestError::Reading(_, _) => "E0709",
+            ManifestError::Writing(_, _) => "E0710",
+        }
+    }
+}
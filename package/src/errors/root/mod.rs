@@ -17,6 +17,9 @@
 pub mod gitignore;
 pub use self::gitignore::*;
 
+pub mod lock;
+pub use self::lock::*;
+
 pub mod manifest;
 pub use self::manifest::*;
 
@@ -25,3 +28,6 @@ pub use self::readme::*;
 
 pub mod zip;
 pub use self::zip::*;
+
+pub mod workspace;
+pub use self::workspace::*;
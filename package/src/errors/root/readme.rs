@@ -28,6 +28,16 @@ pub enum READMEError {
     Writing(io::Error),
 }
 
+impl READMEError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            READMEError::Crate(_, _) => "E0715",
+            READMEError::Creating(_) => "E0716",
+            READMEError::Writing(_) => "E0717",
+        }
+    }
+}
+
 impl From<std::io::Error> for READMEError {
     fn from(error: std::io::Error) -> Self {
         READMEError::Crate("std::io", format!("{}", error))
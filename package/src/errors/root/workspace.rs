@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::ManifestError;
+
+use std::io;
+
+#[derive(Debug, Error)]
+pub enum WorkspaceError {
+    #[error("workspace members depend on each other in a cycle: {}", _0.join(" -> "))]
+    DependencyCycle(Vec<String>),
+
+    #[error("workspace members `{}` and `{}` are both named `{}`, but package names must be unique in a workspace", _1, _2, _0)]
+    DuplicateMember(String, String, String),
+
+    #[error("{}", _0)]
+    ManifestError(#[from] ManifestError),
+
+    #[error("workspace member `{}` is not a Leo package, it has no `Leo.toml`", _0)]
+    MissingMember(String),
+
+    #[error("`Leo.toml` parsing: {}", _0)]
+    Parsing(toml::de::Error),
+
+    #[error("`Leo.toml` reading: {}", _0)]
+    Reading(io::Error),
+}
+
+impl WorkspaceError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            WorkspaceError::DependencyCycle(_) => "E0770",
+            WorkspaceError::DuplicateMember(_, _, _) => "E0771",
+            WorkspaceError::ManifestError(error) => error.code(),
+            WorkspaceError::MissingMember(_) => "E0772",
+            WorkspaceError::Parsing(_) => "E0773",
+            WorkspaceError::Reading(_) => "E0774",
+        }
+    }
+}
@@ -42,6 +42,20 @@ pub enum ZipFileError {
     ZipError(#[from] ZipError),
 }
 
+impl ZipFileError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ZipFileError::Crate(_, _) => "E0720",
+            ZipFileError::Creating(_) => "E0721",
+            ZipFileError::FileReadError(_) => "E0722",
+            ZipFileError::FileRemovalError(_) => "E0723",
+            ZipFileError::Writing(_) => "E0724",
+            ZipFileError::WalkDirError(_) => "E0725",
+            ZipFileError::ZipError(_) => "E0726",
+        }
+    }
+}
+
 impl From<std::io::Error> for ZipFileError {
     fn from(error: std::io::Error) -> Self {
         ZipFileError::Crate("std::io", format!("{}", error))
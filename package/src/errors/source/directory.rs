@@ -39,3 +39,17 @@ pub enum SourceDirectoryError {
     #[error("reading: {}", _0)]
     Reading(io::Error),
 }
+
+impl SourceDirectoryError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            SourceDirectoryError::Creating(_) => "E0730",
+            SourceDirectoryError::GettingFileEntry(_) => "E0731",
+            SourceDirectoryError::GettingFileExtension(_) => "E0732",
+            SourceDirectoryError::GettingFileType(_, _) => "E0733",
+            SourceDirectoryError::InvalidFileExtension(_, _) => "E0734",
+            SourceDirectoryError::InvalidFileType(_, _) => "E0735",
+            SourceDirectoryError::Reading(_) => "E0736",
+        }
+    }
+}
@@ -28,6 +28,16 @@ pub enum LibraryFileError {
     Writing(io::Error),
 }
 
+impl LibraryFileError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            LibraryFileError::Crate(_, _) => "E0740",
+            LibraryFileError::Creating(_) => "E0741",
+            LibraryFileError::Writing(_) => "E0742",
+        }
+    }
+}
+
 impl From<std::io::Error> for LibraryFileError {
     fn from(error: std::io::Error) -> Self {
         LibraryFileError::Crate("std::io", format!("{}", error))
@@ -28,6 +28,16 @@ pub enum MainFileError {
     Writing(io::Error),
 }
 
+impl MainFileError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            MainFileError::Crate(_, _) => "E0745",
+            MainFileError::Creating(_) => "E0746",
+            MainFileError::Writing(_) => "E0747",
+        }
+    }
+}
+
 impl From<std::io::Error> for MainFileError {
     fn from(error: std::io::Error) -> Self {
         MainFileError::Crate("std::io", format!("{}", error))
@@ -17,9 +17,13 @@
 use crate::errors::ImportsDirectoryError;
 
 use std::{fs, path::PathBuf};
+use walkdir::WalkDir;
 
 pub static IMPORTS_DIRECTORY_NAME: &str = "imports/";
 
+/// Directories of a package that are not copied when it is installed as an import.
+static IGNORED_DIRECTORY_NAMES: &[&str] = &[".git", "outputs"];
+
 pub struct ImportsDirectory;
 
 impl ImportsDirectory {
@@ -64,4 +68,47 @@ impl ImportsDirectory {
 
         Ok(())
     }
+
+    /// Returns the path of an imported package in the imports directory at the provided path.
+    pub fn import_path(path: &PathBuf, package_name: &str) -> PathBuf {
+        let mut path = path.to_owned();
+        if path.is_dir() && !path.ends_with(IMPORTS_DIRECTORY_NAME) {
+            path.push(PathBuf::from(IMPORTS_DIRECTORY_NAME));
+        }
+
+        path.push(PathBuf::from(package_name));
+        path
+    }
+
+    /// Copies the package at the source path into the imports directory at the provided path,
+    /// replacing any existing import with the same name.
+    pub fn copy_import(path: &PathBuf, package_name: &str, source: &PathBuf) -> Result<(), ImportsDirectoryError> {
+        let destination = Self::import_path(path, package_name);
+
+        if destination.exists() {
+            fs::remove_dir_all(&destination).map_err(ImportsDirectoryError::Removing)?;
+        }
+
+        let entries = WalkDir::new(source).into_iter().filter_entry(|entry| {
+            entry.depth() != 1
+                || !entry.file_type().is_dir()
+                || !IGNORED_DIRECTORY_NAMES
+                    .iter()
+                    .any(|name| entry.file_name().to_str() == Some(name))
+        });
+
+        for entry in entries {
+            let entry = entry.map_err(|error| ImportsDirectoryError::Copying(error.into()))?;
+            let relative_path = entry.path().strip_prefix(source).unwrap_or_else(|_| entry.path());
+            let target = destination.join(relative_path);
+
+            if entry.file_type().is_dir() {
+                fs::create_dir_all(&target).map_err(ImportsDirectoryError::Copying)?;
+            } else {
+                fs::copy(entry.path(), &target).map_err(ImportsDirectoryError::Copying)?;
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `program.json` file.
+
+use crate::{errors::JsonInputFileError, inputs::INPUTS_DIRECTORY_NAME};
+
+use leo_input::files::JsonFile;
+
+use std::{fs, path::PathBuf};
+
+pub static JSON_INPUT_FILE_EXTENSION: &str = ".json";
+
+pub struct JsonInputFile {
+    pub package_name: String,
+}
+
+impl JsonInputFile {
+    pub fn new(package_name: &str) -> Self {
+        Self {
+            package_name: package_name.to_string(),
+        }
+    }
+
+    pub fn filename(&self) -> String {
+        format!(
+            "{}{}{}",
+            INPUTS_DIRECTORY_NAME, self.package_name, JSON_INPUT_FILE_EXTENSION
+        )
+    }
+
+    pub fn exists_at(&self, path: &PathBuf) -> bool {
+        let path = self.setup_file_path(path);
+        path.exists()
+    }
+
+    /// Reads the program input and state from the given file path if it exists.
+    /// Returns the contents of the equivalent input file and state file.
+    pub fn read_from(&self, path: &PathBuf) -> Result<(String, String, PathBuf), JsonInputFileError> {
+        let path = self.setup_file_path(path);
+
+        let json = fs::read_to_string(&path).map_err(|_| JsonInputFileError::FileReadError(path.clone()))?;
+
+        let files = JsonFile::parse(&json).and_then(|file| Ok((file.input_file_string()?, file.state_file_string()?)));
+        let (input, state) = files.map_err(|error| JsonInputFileError::ParserError(path.clone(), error))?;
+
+        Ok((input, state, path))
+    }
+
+    fn setup_file_path(&self, path: &PathBuf) -> PathBuf {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            if !path.ends_with(INPUTS_DIRECTORY_NAME) {
+                path.push(PathBuf::from(INPUTS_DIRECTORY_NAME));
+            }
+            path.push(PathBuf::from(format!(
+                "{}{}",
+                self.package_name, JSON_INPUT_FILE_EXTENSION
+            )));
+        }
+        path
+    }
+}
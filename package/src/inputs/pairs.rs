@@ -15,11 +15,25 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    inputs::{InputFile, InputsDirectory, StateFile, INPUT_FILE_EXTENSION, STATE_FILE_EXTENSION},
+    inputs::{
+        InputFile,
+        InputsDirectory,
+        JsonInputFile,
+        StateFile,
+        TomlInputFile,
+        INPUT_FILE_EXTENSION,
+        JSON_INPUT_FILE_EXTENSION,
+        STATE_FILE_EXTENSION,
+        TOML_INPUT_FILE_EXTENSION,
+    },
     InputsDirectoryError,
 };
 
-use std::{collections::HashMap, convert::TryFrom, path::PathBuf};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    path::PathBuf,
+};
 
 pub struct InputPairs {
     /// Maps file names to input file pairs
@@ -45,6 +59,9 @@ impl TryFrom<&PathBuf> for InputPairs {
 
         let mut pairs = HashMap::<String, InputPair>::new();
 
+        // A JSON or TOML file gives both the input and the state of a pair
+        let mut single_file_names = HashSet::<String>::new();
+
         for file in files {
             let file_extension = file
                 .extension()
@@ -56,7 +73,29 @@ impl TryFrom<&PathBuf> for InputPairs {
                 .to_str()
                 .ok_or(InputsDirectoryError::GettingFileName(file.as_os_str().to_owned()))?;
 
-            if file_extension == INPUT_FILE_EXTENSION.trim_start_matches(".") {
+            if single_file_names.contains(file_name) {
+                return Err(InputsDirectoryError::DuplicateInput(file_name.to_owned()));
+            }
+
+            if file_extension == JSON_INPUT_FILE_EXTENSION.trim_start_matches(".") {
+                if pairs.contains_key(file_name) {
+                    return Err(InputsDirectoryError::DuplicateInput(file_name.to_owned()));
+                }
+
+                let (input_file, state_file, _path) = JsonInputFile::new(file_name).read_from(&file)?;
+
+                pairs.insert(file_name.to_owned(), InputPair { input_file, state_file });
+                single_file_names.insert(file_name.to_owned());
+            } else if file_extension == TOML_INPUT_FILE_EXTENSION.trim_start_matches(".") {
+                if pairs.contains_key(file_name) {
+                    return Err(InputsDirectoryError::DuplicateInput(file_name.to_owned()));
+                }
+
+                let (input_file, state_file, _path) = TomlInputFile::new(file_name).read_from(&file)?;
+
+                pairs.insert(file_name.to_owned(), InputPair { input_file, state_file });
+                single_file_names.insert(file_name.to_owned());
+            } else if file_extension == INPUT_FILE_EXTENSION.trim_start_matches(".") {
                 let input_file = InputFile::new(file_name).read_from(&file)?.0;
 
                 if pairs.contains_key(file_name) {
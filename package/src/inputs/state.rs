@@ -64,7 +64,16 @@ impl StateFile {
         Ok(file.write_all(self.template().as_bytes())?)
     }
 
-    fn template(&self) -> String {
+    /// Writes the given state values to a file.
+    pub fn write_state_to(&self, path: &PathBuf, state: &str) -> Result<(), StateFileError> {
+        let path = self.setup_file_path(path);
+
+        let mut file = File::create(&path)?;
+        Ok(file.write_all(state.as_bytes())?)
+    }
+
+    /// Returns a state file with a placeholder for every state value.
+    pub fn template(&self) -> String {
         format!(
             r#"// The program state for {}/src/main.leo
 [[public]]
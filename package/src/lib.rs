@@ -24,6 +24,8 @@ pub mod imports;
 pub mod inputs;
 pub mod outputs;
 pub mod package;
+pub mod proving_system;
+pub mod resolver;
 pub mod root;
 pub mod source;
 
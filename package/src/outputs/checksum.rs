@@ -16,7 +16,7 @@
 
 //! The build checksum file.
 
-use crate::{errors::ChecksumFileError, outputs::OUTPUTS_DIRECTORY_NAME};
+use crate::{errors::ChecksumFileError, outputs::{OutputsDirectory, OUTPUTS_DIRECTORY_NAME}};
 
 use serde::Deserialize;
 use std::{
@@ -76,7 +76,7 @@ impl ChecksumFile {
     fn setup_file_path(&self, path: &PathBuf) -> PathBuf {
         let mut path = path.to_owned();
         if path.is_dir() {
-            if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+            if !OutputsDirectory::is_outputs_directory(&path) {
                 path.push(PathBuf::from(OUTPUTS_DIRECTORY_NAME));
             }
             path.push(PathBuf::from(format!(
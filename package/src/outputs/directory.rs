@@ -16,7 +16,10 @@
 
 use crate::errors::OutputsDirectoryError;
 
-use std::{fs, path::PathBuf};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 pub static OUTPUTS_DIRECTORY_NAME: &str = "outputs/";
 
@@ -26,7 +29,7 @@ impl OutputsDirectory {
     /// Creates a directory at the provided path with the default directory name.
     pub fn create(path: &PathBuf) -> Result<(), OutputsDirectoryError> {
         let mut path = path.to_owned();
-        if path.is_dir() && !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+        if path.is_dir() && !Self::is_outputs_directory(&path) {
             path.push(PathBuf::from(OUTPUTS_DIRECTORY_NAME));
         }
 
@@ -36,7 +39,7 @@ impl OutputsDirectory {
     /// Removes the directory at the provided path.
     pub fn remove(path: &PathBuf) -> Result<(), OutputsDirectoryError> {
         let mut path = path.to_owned();
-        if path.is_dir() && !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+        if path.is_dir() && !Self::is_outputs_directory(&path) {
             path.push(PathBuf::from(OUTPUTS_DIRECTORY_NAME));
         }
 
@@ -46,4 +49,13 @@ impl OutputsDirectory {
 
         Ok(())
     }
+
+    /// Returns `true` if the path is an outputs directory, or the outputs directory of a workspace member,
+    /// which is a subdirectory of the outputs directory shared by the workspace.
+    pub fn is_outputs_directory(path: &Path) -> bool {
+        match path.parent() {
+            Some(parent) if parent.ends_with(OUTPUTS_DIRECTORY_NAME) => true,
+            _ => path.ends_with(OUTPUTS_DIRECTORY_NAME),
+        }
+    }
 }
@@ -14,6 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod build_manifest;
+pub use self::build_manifest::*;
+
+pub mod ceremony;
+pub use self::ceremony::*;
+
 pub mod circuit;
 pub use self::circuit::*;
 
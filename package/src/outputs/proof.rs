@@ -16,7 +16,7 @@
 
 //! The proof file.
 
-use crate::{errors::ProofFileError, outputs::OUTPUTS_DIRECTORY_NAME};
+use crate::{errors::ProofFileError, outputs::{OutputsDirectory, OUTPUTS_DIRECTORY_NAME}, proving_system::ProvingSystem};
 
 use serde::Deserialize;
 use std::{
@@ -45,19 +45,33 @@ impl ProofFile {
     }
 
     /// Reads the proof from the given file path if it exists.
-    pub fn read_from(&self, path: &PathBuf) -> Result<String, ProofFileError> {
+    /// Returns an error if the proof was not generated by the given proving system.
+    pub fn read_from(&self, path: &PathBuf, proving_system: ProvingSystem) -> Result<Vec<u8>, ProofFileError> {
         let path = self.setup_file_path(path);
 
-        let proof = fs::read_to_string(&path).map_err(|_| ProofFileError::FileReadError(path.clone()))?;
-        Ok(proof)
+        let bytes = fs::read(&path).map_err(|_| ProofFileError::FileReadError(path.clone()))?;
+        let proof = proving_system.strip_header(&bytes).map_err(|found| {
+            let found = found.map_or("no proving system".to_string(), |found| found.to_string());
+
+            ProofFileError::ProvingSystemMismatch(path.clone(), proving_system, found)
+        })?;
+
+        Ok(proof.to_vec())
+    }
+
+    /// Returns the proving system that generated the proof at the given path, if any.
+    pub fn proving_system_at(&self, path: &PathBuf) -> Option<ProvingSystem> {
+        let path = self.setup_file_path(path);
+
+        File::open(&path).ok().and_then(ProvingSystem::read_header_from)
     }
 
     /// Writes the given proof to a file.
-    pub fn write_to(&self, path: &PathBuf, proof: &[u8]) -> Result<(), ProofFileError> {
+    pub fn write_to(&self, path: &PathBuf, proving_system: ProvingSystem, proof: &[u8]) -> Result<(), ProofFileError> {
         let path = self.setup_file_path(path);
 
         let mut file = File::create(&path)?;
-        file.write_all(proof)?;
+        file.write_all(&proving_system.write_header(proof))?;
 
         tracing::info!("Saving proof... ({:?})", path);
 
@@ -79,7 +93,7 @@ impl ProofFile {
     fn setup_file_path(&self, path: &PathBuf) -> PathBuf {
         let mut path = path.to_owned();
         if path.is_dir() {
-            if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+            if !OutputsDirectory::is_outputs_directory(&path) {
                 path.push(PathBuf::from(OUTPUTS_DIRECTORY_NAME));
             }
             path.push(PathBuf::from(format!("{}{}", self.package_name, PROOF_FILE_EXTENSION)));
@@ -16,7 +16,7 @@
 
 //! The proving key file.
 
-use crate::{errors::ProvingKeyFileError, outputs::OUTPUTS_DIRECTORY_NAME};
+use crate::{errors::ProvingKeyFileError, outputs::{OutputsDirectory, OUTPUTS_DIRECTORY_NAME}, proving_system::ProvingSystem};
 
 use serde::Deserialize;
 use std::{
@@ -49,18 +49,38 @@ impl ProvingKeyFile {
     }
 
     /// Reads the proving key from the given file path if it exists.
-    pub fn read_from(&self, path: &PathBuf) -> Result<Vec<u8>, ProvingKeyFileError> {
+    /// Returns an error if the proving key was not generated by the given proving system.
+    pub fn read_from(&self, path: &PathBuf, proving_system: ProvingSystem) -> Result<Vec<u8>, ProvingKeyFileError> {
         let path = self.setup_file_path(path);
 
-        Ok(fs::read(&path).map_err(|_| ProvingKeyFileError::FileReadError(path.clone()))?)
+        let bytes = fs::read(&path).map_err(|_| ProvingKeyFileError::FileReadError(path.clone()))?;
+        let proving_key = proving_system.strip_header(&bytes).map_err(|found| {
+            let found = found.map_or("no proving system".to_string(), |found| found.to_string());
+
+            ProvingKeyFileError::ProvingSystemMismatch(path.clone(), proving_system, found)
+        })?;
+
+        Ok(proving_key.to_vec())
+    }
+
+    /// Returns the proving system that generated the proving key at the given path, if any.
+    pub fn proving_system_at(&self, path: &PathBuf) -> Option<ProvingSystem> {
+        let path = self.setup_file_path(path);
+
+        File::open(&path).ok().and_then(ProvingSystem::read_header_from)
     }
 
     /// Writes the given proving key to a file.
-    pub fn write_to(&self, path: &PathBuf, proving_key: &[u8]) -> Result<PathBuf, ProvingKeyFileError> {
+    pub fn write_to(
+        &self,
+        path: &PathBuf,
+        proving_system: ProvingSystem,
+        proving_key: &[u8],
+    ) -> Result<PathBuf, ProvingKeyFileError> {
         let path = self.setup_file_path(path);
 
         let mut file = File::create(&path)?;
-        file.write_all(proving_key)?;
+        file.write_all(&proving_system.write_header(proving_key))?;
 
         Ok(path)
     }
@@ -80,7 +100,7 @@ impl ProvingKeyFile {
     fn setup_file_path(&self, path: &PathBuf) -> PathBuf {
         let mut path = path.to_owned();
         if path.is_dir() {
-            if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+            if !OutputsDirectory::is_outputs_directory(&path) {
                 path.push(PathBuf::from(OUTPUTS_DIRECTORY_NAME));
             }
             path.push(PathBuf::from(format!(
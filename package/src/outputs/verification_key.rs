@@ -16,7 +16,7 @@
 
 //! The verification key file.
 
-use crate::{errors::VerificationKeyFileError, outputs::OUTPUTS_DIRECTORY_NAME};
+use crate::{errors::VerificationKeyFileError, outputs::{OutputsDirectory, OUTPUTS_DIRECTORY_NAME}, proving_system::ProvingSystem};
 
 use serde::Deserialize;
 use std::{
@@ -49,18 +49,42 @@ impl VerificationKeyFile {
     }
 
     /// Reads the verification key from the given file path if it exists.
-    pub fn read_from(&self, path: &PathBuf) -> Result<Vec<u8>, VerificationKeyFileError> {
+    /// Returns an error if the verification key was not generated by the given proving system.
+    pub fn read_from(
+        &self,
+        path: &PathBuf,
+        proving_system: ProvingSystem,
+    ) -> Result<Vec<u8>, VerificationKeyFileError> {
         let path = self.setup_file_path(path);
 
-        Ok(fs::read(&path).map_err(|_| VerificationKeyFileError::FileReadError(path.clone()))?)
+        let bytes = fs::read(&path).map_err(|_| VerificationKeyFileError::FileReadError(path.clone()))?;
+        let verification_key = proving_system.strip_header(&bytes).map_err(|found| {
+            let found = found.map_or("no proving system".to_string(), |found| found.to_string());
+
+            VerificationKeyFileError::ProvingSystemMismatch(path.clone(), proving_system, found)
+        })?;
+
+        Ok(verification_key.to_vec())
+    }
+
+    /// Returns the proving system that generated the verification key at the given path, if any.
+    pub fn proving_system_at(&self, path: &PathBuf) -> Option<ProvingSystem> {
+        let path = self.setup_file_path(path);
+
+        File::open(&path).ok().and_then(ProvingSystem::read_header_from)
     }
 
     /// Writes the given verification key to a file.
-    pub fn write_to(&self, path: &PathBuf, verification_key: &[u8]) -> Result<PathBuf, VerificationKeyFileError> {
+    pub fn write_to(
+        &self,
+        path: &PathBuf,
+        proving_system: ProvingSystem,
+        verification_key: &[u8],
+    ) -> Result<PathBuf, VerificationKeyFileError> {
         let path = self.setup_file_path(path);
 
         let mut file = File::create(&path)?;
-        file.write_all(verification_key)?;
+        file.write_all(&proving_system.write_header(verification_key))?;
 
         Ok(path)
     }
@@ -80,7 +104,7 @@ impl VerificationKeyFile {
     fn setup_file_path(&self, path: &PathBuf) -> PathBuf {
         let mut path = path.to_owned();
         if path.is_dir() {
-            if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+            if !OutputsDirectory::is_outputs_directory(&path) {
                 path.push(PathBuf::from(OUTPUTS_DIRECTORY_NAME));
             }
             path.push(PathBuf::from(format!(
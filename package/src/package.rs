@@ -18,6 +18,7 @@ use crate::{
     errors::PackageError,
     imports::ImportsDirectory,
     inputs::{InputFile, InputsDirectory, StateFile},
+    proving_system::ProvingSystem,
     root::{Gitignore, Manifest, README},
     source::{LibraryFile, MainFile, SourceDirectory},
 };
@@ -31,6 +32,8 @@ pub struct Package {
     pub version: String,
     pub description: Option<String>,
     pub license: Option<String>,
+    pub proving_system: Option<ProvingSystem>,
+    pub enforce_local_data: Option<bool>,
 }
 
 impl Package {
@@ -40,6 +43,8 @@ impl Package {
             version: "0.1.0".to_owned(),
             description: None,
             license: None,
+            proving_system: None,
+            enforce_local_data: None,
         }
     }
 
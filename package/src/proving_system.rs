@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The proving system used to generate the keys and proofs of a package.
+
+use serde::Deserialize;
+use std::{fmt, io::Read};
+
+/// Marks the first line of a key or proof file that names the proving system which produced it.
+static HEADER_PREFIX: &[u8] = b"leo-proving-system:";
+static HEADER_SEPARATOR: u8 = b'\n';
+static HEADER_MAX_LENGTH: u64 = 64;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProvingSystem {
+    Groth16,
+    GM17,
+}
+
+impl ProvingSystem {
+    /// The names accepted in `Leo.toml` and on the command line.
+    pub const NAMES: &'static [&'static str] = &["groth16", "gm17"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "groth16" => Some(ProvingSystem::Groth16),
+            "gm17" => Some(ProvingSystem::GM17),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProvingSystem::Groth16 => "groth16",
+            ProvingSystem::GM17 => "gm17",
+        }
+    }
+
+    /// Prepends a header naming this proving system to the given key or proof bytes.
+    pub fn write_header(&self, bytes: &[u8]) -> Vec<u8> {
+        let mut output = HEADER_PREFIX.to_vec();
+        output.extend_from_slice(self.name().as_bytes());
+        output.push(HEADER_SEPARATOR);
+        output.extend_from_slice(bytes);
+
+        output
+    }
+
+    /// Returns the proving system named in the header of the given key or proof bytes, if any.
+    pub fn read_header(bytes: &[u8]) -> Option<(Self, &[u8])> {
+        if !bytes.starts_with(HEADER_PREFIX) {
+            return None;
+        }
+
+        let bytes = &bytes[HEADER_PREFIX.len()..];
+        let end = bytes.iter().position(|byte| *byte == HEADER_SEPARATOR)?;
+        let name = std::str::from_utf8(&bytes[..end]).ok()?;
+
+        Self::from_name(name).map(|proving_system| (proving_system, &bytes[end + 1..]))
+    }
+
+    /// Returns the proving system named in the header at the start of the given reader, if any.
+    /// Only reads the header, which avoids loading large proving keys.
+    pub fn read_header_from<R: Read>(reader: R) -> Option<Self> {
+        let mut header = vec![];
+        reader.take(HEADER_MAX_LENGTH).read_to_end(&mut header).ok()?;
+
+        Self::read_header(&header).map(|(proving_system, _)| proving_system)
+    }
+
+    /// Returns the given key or proof bytes without their header if it names this proving system.
+    /// Otherwise, returns the proving system that the header names instead, if any.
+    pub fn strip_header<'a>(&self, bytes: &'a [u8]) -> Result<&'a [u8], Option<Self>> {
+        match Self::read_header(bytes) {
+            Some((proving_system, bytes)) if proving_system == *self => Ok(bytes),
+            Some((proving_system, _)) => Err(Some(proving_system)),
+            None => Err(None),
+        }
+    }
+}
+
+impl Default for ProvingSystem {
+    fn default() -> Self {
+        ProvingSystem::Groth16
+    }
+}
+
+impl fmt::Display for ProvingSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::LockFileError, imports::IMPORTS_DIRECTORY_NAME, root::LockFile};
+
+use std::{
+    collections::BTreeMap,
+    convert::TryFrom,
+    path::{Component, Path, PathBuf},
+};
+
+/// The resolved dependency graph of a package, as recorded in its lock file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DependencyGraph {
+    imports_directory: PathBuf,
+    dependencies: Vec<String>,
+    packages: BTreeMap<String, Vec<String>>,
+}
+
+impl DependencyGraph {
+    /// Creates the graph of the package at the given path from its lock file.
+    pub fn new(path: &PathBuf, lock_file: &LockFile) -> Self {
+        Self {
+            imports_directory: path.join(IMPORTS_DIRECTORY_NAME),
+            dependencies: lock_file.dependencies.clone(),
+            packages: lock_file
+                .packages
+                .iter()
+                .map(|package| (package.name.clone(), package.dependencies.clone()))
+                .collect(),
+        }
+    }
+
+    /// Loads the graph of the package at the given path, or returns `None` if the package has no lock file.
+    pub fn load(path: &PathBuf) -> Result<Option<Self>, LockFileError> {
+        if !LockFile::exists_at(path) {
+            return Ok(None);
+        }
+
+        let lock_file = LockFile::try_from(path)?;
+
+        Ok(Some(Self::new(path, &lock_file)))
+    }
+
+    /// Returns the name of the installed package containing the given path,
+    /// or `None` if the path belongs to the package itself.
+    pub fn package_at(&self, path: &Path) -> Option<String> {
+        match path.strip_prefix(&self.imports_directory).ok()?.components().next() {
+            Some(Component::Normal(name)) => Some(name.to_string_lossy().to_string()),
+            _ => None,
+        }
+    }
+
+    /// Returns the names of the packages that the package containing the given path depends on.
+    pub fn dependencies_at(&self, path: &Path) -> &[String] {
+        match self.package_at(path) {
+            Some(name) => self
+                .packages
+                .get(&name)
+                .map(|dependencies| &dependencies[..])
+                .unwrap_or(&[]),
+            None => &self.dependencies,
+        }
+    }
+
+    /// Returns the directory of the package imported from the package containing the given path,
+    /// or `None` if the importing package does not depend on it.
+    pub fn resolve(&self, path: &Path, package_name: &str) -> Option<PathBuf> {
+        self.dependencies_at(path)
+            .iter()
+            .find(|name| name.as_str() == package_name)
+            .map(|name| self.imports_directory.join(name))
+    }
+}
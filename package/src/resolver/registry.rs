@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::ResolverError,
+    root::{Dependency, Manifest},
+};
+
+use semver::Version;
+use std::{convert::TryFrom, path::PathBuf};
+
+/// A version of a package that a dependency can resolve to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PackageSummary {
+    /// The name the package is installed under in the `imports/` directory.
+    pub name: String,
+    pub version: Version,
+    /// The source of the package, including the resolved git commit.
+    pub source: String,
+    /// The local directory holding the contents of the package.
+    pub path: PathBuf,
+    /// The dependencies declared in the manifest of the package.
+    pub dependencies: Vec<(String, Dependency)>,
+}
+
+impl PackageSummary {
+    /// Reads the summary of the package in the given directory from its manifest.
+    pub fn from_directory(name: &str, source: String, path: &PathBuf) -> Result<Self, ResolverError> {
+        let manifest =
+            Manifest::try_from(path).map_err(|error| ResolverError::InvalidPackage(name.into(), error.to_string()))?;
+
+        let version = Version::parse(&manifest.get_package_version()).map_err(|error| {
+            ResolverError::InvalidPackage(
+                name.into(),
+                format!("invalid version `{}`: {}", manifest.get_package_version(), error),
+            )
+        })?;
+
+        let dependencies = manifest
+            .get_dependencies()
+            .map_err(|error| ResolverError::InvalidPackage(name.into(), error.to_string()))?;
+
+        Ok(Self {
+            name: name.into(),
+            version,
+            source,
+            path: path.clone(),
+            dependencies,
+        })
+    }
+
+    /// Returns the source of the package without the resolved git commit.
+    pub fn unresolved_source(&self) -> &str {
+        self.source.split('#').next().unwrap_or_default()
+    }
+}
+
+/// Provides the packages that dependencies can resolve to.
+pub trait PackageRegistry {
+    type Error: From<ResolverError>;
+
+    /// Returns the available versions of the dependency declared under the given key.
+    /// Versions that do not match the requirement of the dependency are ignored by the resolver.
+    fn query(&mut self, key: &str, dependency: &Dependency) -> Result<Vec<PackageSummary>, Self::Error>;
+}
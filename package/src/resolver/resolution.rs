@@ -0,0 +1,321 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Selects one version of every package in the dependency graph of a package.
+//!
+//! Packages are installed into a flat `imports/` directory, so every package name resolves to a
+//! single version that has to satisfy the requirements of all packages depending on it.
+//! The resolver activates dependencies breadth-first and backtracks to older versions on conflicts.
+
+use crate::{
+    errors::ResolverError,
+    resolver::{PackageRegistry, PackageSummary},
+    root::{Dependency, LockFile},
+};
+
+use semver::VersionReq;
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    path::{Component, Path, PathBuf},
+};
+
+/// A package selected by the resolver.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedPackage {
+    pub summary: PackageSummary,
+    /// The names of the resolved packages this package depends on.
+    pub dependencies: Vec<String>,
+}
+
+/// The packages selected for the dependency graph of a package.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Resolution {
+    /// The names of the resolved packages the package itself depends on.
+    pub dependencies: Vec<String>,
+    /// The resolved packages, sorted by name.
+    pub packages: Vec<ResolvedPackage>,
+}
+
+impl Resolution {
+    /// Returns the resolved package with the given name.
+    pub fn get(&self, package_name: &str) -> Option<&ResolvedPackage> {
+        self.packages
+            .iter()
+            .find(|package| package.summary.name == package_name)
+    }
+}
+
+/// A dependency declared by the root package or by one of the selected packages.
+#[derive(Clone, Debug)]
+struct Requirement {
+    key: String,
+    dependency: Dependency,
+    /// The packages that lead to the requirement, starting with the root package.
+    required_by: Vec<String>,
+}
+
+impl Requirement {
+    fn name(&self) -> &str {
+        Dependency::import_name(&self.key)
+    }
+
+    fn version_requirement(&self) -> Result<Option<VersionReq>, ResolverError> {
+        match &self.dependency {
+            Dependency::Registry(requirement) => VersionReq::parse(requirement).map(Some).map_err(|error| {
+                ResolverError::InvalidRequirement(self.key.clone(), requirement.clone(), error.to_string())
+            }),
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns `true` if the package is from the required source and matches the required version.
+    fn matches(&self, summary: &PackageSummary) -> Result<bool, ResolverError> {
+        let version_matches = match self.version_requirement()? {
+            Some(requirement) => requirement.matches(&summary.version),
+            None => true,
+        };
+
+        Ok(version_matches && summary.unresolved_source() == self.dependency.source(&self.key))
+    }
+
+    fn describe(&self) -> String {
+        let requirement = match &self.dependency {
+            Dependency::Registry(requirement) => VersionReq::parse(requirement)
+                .map(|requirement| requirement.to_string())
+                .unwrap_or_else(|_| requirement.clone()),
+            dependency => format!("from `{}`", dependency.source(&self.key)),
+        };
+
+        format!(
+            "    `{}` requires `{}` {}",
+            self.required_by.join(" -> "),
+            self.key,
+            requirement
+        )
+    }
+}
+
+/// A package selected for a name, and the requirements it satisfies.
+#[derive(Clone, Debug)]
+struct Activation {
+    summary: PackageSummary,
+    requirements: Vec<Requirement>,
+}
+
+enum Failure<E> {
+    /// The selected packages conflict, so another version has to be tried.
+    Conflict(ResolverError),
+    /// Resolution cannot continue.
+    Fatal(E),
+}
+
+impl<E: From<ResolverError>> Failure<E> {
+    fn fatal(error: ResolverError) -> Self {
+        Failure::Fatal(E::from(error))
+    }
+}
+
+pub struct Resolver<'a, R: PackageRegistry> {
+    registry: &'a mut R,
+    lock_file: &'a LockFile,
+    queried: HashMap<String, Vec<PackageSummary>>,
+}
+
+impl<'a, R: PackageRegistry> Resolver<'a, R> {
+    pub fn new(registry: &'a mut R, lock_file: &'a LockFile) -> Self {
+        Self {
+            registry,
+            lock_file,
+            queried: HashMap::new(),
+        }
+    }
+
+    /// Resolves the dependencies of the root package, preferring the versions in the lock file.
+    pub fn resolve(&mut self, root_name: &str, dependencies: &[(String, Dependency)]) -> Result<Resolution, R::Error> {
+        let mut pending = VecDeque::new();
+        for (key, dependency) in dependencies {
+            let dependency = match dependency {
+                Dependency::Path { path } => Dependency::Path { path: normalize(path) },
+                dependency => dependency.clone(),
+            };
+
+            pending.push_back(Requirement {
+                key: key.clone(),
+                dependency,
+                required_by: vec![root_name.to_owned()],
+            });
+        }
+
+        let activations = match self.activate(pending, BTreeMap::new()) {
+            Ok(activations) => activations,
+            Err(Failure::Conflict(error)) => return Err(R::Error::from(error)),
+            Err(Failure::Fatal(error)) => return Err(error),
+        };
+
+        let packages = activations
+            .into_iter()
+            .map(|(_, activation)| ResolvedPackage {
+                dependencies: import_names(&activation.summary.dependencies),
+                summary: activation.summary,
+            })
+            .collect();
+
+        Ok(Resolution {
+            dependencies: import_names(dependencies),
+            packages,
+        })
+    }
+
+    /// Selects a package for the next pending requirement and resolves the remaining requirements.
+    fn activate(
+        &mut self,
+        mut pending: VecDeque<Requirement>,
+        mut activations: BTreeMap<String, Activation>,
+    ) -> Result<BTreeMap<String, Activation>, Failure<R::Error>> {
+        let requirement = match pending.pop_front() {
+            Some(requirement) => requirement,
+            None => return Ok(activations),
+        };
+        let name = requirement.name().to_owned();
+
+        // A package that is already selected has to satisfy every later requirement.
+        if let Some(activation) = activations.get_mut(&name) {
+            if !requirement.matches(&activation.summary).map_err(Failure::fatal)? {
+                let mut requirements = activation
+                    .requirements
+                    .iter()
+                    .map(|requirement| requirement.describe())
+                    .collect::<Vec<_>>();
+                requirements.push(requirement.describe());
+
+                return Err(Failure::Conflict(ResolverError::Conflict(name, requirements)));
+            }
+
+            activation.requirements.push(requirement);
+            return self.activate(pending, activations);
+        }
+
+        let candidates = self.candidates(&requirement)?;
+        let mut conflict = ResolverError::NoMatchingVersion(name.clone(), requirement.describe());
+
+        for summary in candidates {
+            let mut required_by = requirement.required_by.clone();
+            required_by.push(format!("{} v{}", summary.name, summary.version));
+
+            let mut next_pending = pending.clone();
+            for (key, dependency) in summary.dependencies.iter() {
+                next_pending.push_back(Requirement {
+                    key: key.clone(),
+                    dependency: rebase(&summary, key, dependency).map_err(Failure::fatal)?,
+                    required_by: required_by.clone(),
+                });
+            }
+
+            let mut next_activations = activations.clone();
+            next_activations.insert(name.clone(), Activation {
+                summary,
+                requirements: vec![requirement.clone()],
+            });
+
+            match self.activate(next_pending, next_activations) {
+                Ok(activations) => return Ok(activations),
+                Err(Failure::Conflict(error)) => conflict = error,
+                Err(error) => return Err(error),
+            }
+        }
+
+        Err(Failure::Conflict(conflict))
+    }
+
+    /// Returns the packages matching the requirement, with the locked version first and then newest first.
+    fn candidates(&mut self, requirement: &Requirement) -> Result<Vec<PackageSummary>, Failure<R::Error>> {
+        let query = requirement.dependency.to_toml_line(&requirement.key);
+
+        if !self.queried.contains_key(&query) {
+            let summaries = self
+                .registry
+                .query(&requirement.key, &requirement.dependency)
+                .map_err(Failure::Fatal)?;
+            self.queried.insert(query.clone(), summaries);
+        }
+
+        let mut candidates = vec![];
+        for summary in self.queried[&query].iter() {
+            if requirement.matches(summary).map_err(Failure::fatal)? {
+                candidates.push(summary.clone());
+            }
+        }
+
+        let locked = self.lock_file.get(requirement.name());
+        let is_locked = |summary: &PackageSummary| {
+            locked
+                .map(|package| package.source == summary.source && package.version == summary.version.to_string())
+                .unwrap_or(false)
+        };
+
+        candidates.sort_by(|a, b| is_locked(b).cmp(&is_locked(a)).then_with(|| b.version.cmp(&a.version)));
+
+        Ok(candidates)
+    }
+}
+
+/// Returns the sorted import names of the given dependencies.
+fn import_names(dependencies: &[(String, Dependency)]) -> Vec<String> {
+    let mut names = dependencies
+        .iter()
+        .map(|(key, _)| Dependency::import_name(key).to_owned())
+        .collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Returns a dependency of a selected package with its path relative to the root package.
+fn rebase(summary: &PackageSummary, key: &str, dependency: &Dependency) -> Result<Dependency, ResolverError> {
+    match dependency {
+        Dependency::Path { path } => match summary.source.strip_prefix("path+") {
+            Some(parent) => Ok(Dependency::Path {
+                path: normalize(&PathBuf::from(parent).join(path)),
+            }),
+            None => Err(ResolverError::RemotePathDependency(
+                summary.name.clone(),
+                summary.unresolved_source().into(),
+                key.into(),
+            )),
+        },
+        dependency => Ok(dependency.clone()),
+    }
+}
+
+/// Lexically removes `.` and `..` components, so that equal paths have equal sources.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                _ => normalized.push(".."),
+            },
+            component => normalized.push(component.as_os_str()),
+        }
+    }
+
+    normalized
+}
@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::ManifestError;
+
+use serde::Deserialize;
+use std::path::PathBuf;
+
+/// A package declared in the `[dependencies]` section of a manifest.
+///
+/// ```toml
+/// [dependencies]
+/// "author/package" = "0.1.0"
+/// utils = { path = "../utils" }
+/// signatures = { git = "https://github.com/author/signatures", tag = "v0.1.0" }
+/// ```
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum Dependency {
+    /// A package published to the Aleo Package Manager, keyed by `author/package`.
+    Registry(String),
+
+    /// A package on the local filesystem, relative to the manifest.
+    Path { path: PathBuf },
+
+    /// A package in a git repository, optionally pinned to a branch, tag or revision.
+    Git {
+        git: String,
+        branch: Option<String>,
+        tag: Option<String>,
+        rev: Option<String>,
+    },
+}
+
+impl Dependency {
+    /// Returns the `(author, package)` pair of a registry dependency key such as `author/package`.
+    pub fn registry_key(key: &str) -> Option<(&str, &str)> {
+        let mut values = key.split('/');
+
+        match (values.next(), values.next(), values.next()) {
+            (Some(author), Some(package), None) if !author.is_empty() && !package.is_empty() => Some((author, package)),
+            _ => None,
+        }
+    }
+
+    /// Returns the name the dependency is installed under in the `imports/` directory.
+    pub fn import_name(key: &str) -> &str {
+        match Self::registry_key(key) {
+            Some((_, package)) => package,
+            None => key,
+        }
+    }
+
+    /// Checks that the dependency key matches the kind of dependency it declares.
+    pub fn validate(&self, key: &str) -> Result<(), ManifestError> {
+        let is_registry_key = Self::registry_key(key).is_some();
+
+        match self {
+            Dependency::Registry(_) if !is_registry_key => Err(ManifestError::InvalidDependency(
+                key.into(),
+                "registry dependencies must be declared as \"author/package\"".into(),
+            )),
+            Dependency::Path { .. } | Dependency::Git { .. } if key.contains('/') => Err(
+                ManifestError::InvalidDependency(key.into(), "dependency names cannot contain `/`".into()),
+            ),
+            Dependency::Git { branch, tag, rev, .. }
+                if [branch, tag, rev]
+                    .iter()
+                    .filter(|reference| reference.is_some())
+                    .count()
+                    > 1 =>
+            {
+                Err(ManifestError::InvalidDependency(
+                    key.into(),
+                    "only one of `branch`, `tag` or `rev` can be specified".into(),
+                ))
+            }
+            // Arguments starting with `-` would be read as options by git
+            Dependency::Git { git, branch, tag, rev }
+                if git.starts_with('-')
+                    || [branch, tag, rev].iter().any(|reference| {
+                        reference
+                            .as_deref()
+                            .map_or(false, |reference| reference.starts_with('-'))
+                    }) =>
+            {
+                Err(ManifestError::InvalidDependency(
+                    key.into(),
+                    "git urls and references cannot start with `-`".into(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the source identifier of a registry package such as `author/package`.
+    pub fn registry_source(key: &str) -> String {
+        format!("registry+{}", key)
+    }
+
+    /// Returns the source identifier recorded in the lock file, without any resolved revision.
+    pub fn source(&self, key: &str) -> String {
+        match self {
+            Dependency::Registry(_) => Self::registry_source(key),
+            Dependency::Path { path } => format!("path+{}", path.to_string_lossy()),
+            Dependency::Git { git, branch, tag, rev } => {
+                let reference = match (branch, tag, rev) {
+                    (Some(branch), _, _) => format!("?branch={}", branch),
+                    (_, Some(tag), _) => format!("?tag={}", tag),
+                    (_, _, Some(rev)) => format!("?rev={}", rev),
+                    _ => "".to_owned(),
+                };
+
+                format!("git+{}{}", git, reference)
+            }
+        }
+    }
+
+    /// Returns the dependency as a `key = value` line of the `[dependencies]` section.
+    pub fn to_toml_line(&self, key: &str) -> String {
+        let key = match key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            true => key.to_owned(),
+            false => format!("{:?}", key),
+        };
+
+        let value = match self {
+            Dependency::Registry(version) => format!("{:?}", version),
+            Dependency::Path { path } => format!("{{ path = {:?} }}", path.to_string_lossy()),
+            Dependency::Git { git, branch, tag, rev } => {
+                let mut fields = vec![format!("git = {:?}", git)];
+                if let Some(branch) = branch {
+                    fields.push(format!("branch = {:?}", branch));
+                }
+                if let Some(tag) = tag {
+                    fields.push(format!("tag = {:?}", tag));
+                }
+                if let Some(rev) = rev {
+                    fields.push(format!("rev = {:?}", rev));
+                }
+
+                format!("{{ {} }}", fields.join(", "))
+            }
+        };
+
+        format!("{} = {}", key, value)
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `Leo.lock` file.
+
+use crate::errors::LockFileError;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    convert::TryFrom,
+    fs::{self, File},
+    io::{Read, Write},
+    path::PathBuf,
+};
+use walkdir::WalkDir;
+
+pub const LOCK_FILENAME: &str = "Leo.lock";
+
+/// A dependency resolved to an exact version and content.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct LockedPackage {
+    /// The name the package is installed under in the `imports/` directory.
+    pub name: String,
+    pub version: String,
+    /// The source of the package, such as `registry+author/package`, `path+../package`
+    /// or `git+https://github.com/author/package#<commit>`.
+    pub source: String,
+    /// The hex encoded SHA-256 hash of the installed package contents.
+    pub checksum: String,
+    /// The names of the locked packages this package depends on.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
+}
+
+impl LockedPackage {
+    /// Returns the source of the package without the resolved git commit.
+    pub fn unresolved_source(&self) -> &str {
+        self.source.split('#').next().unwrap_or_default()
+    }
+
+    /// Returns the git commit the package was resolved to, if it is a git dependency.
+    pub fn git_commit(&self) -> Option<&str> {
+        match self.source.starts_with("git+") {
+            true => self.source.split('#').nth(1),
+            false => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct LockFile {
+    /// The names of the locked packages the package itself depends on.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
+    #[serde(default, rename = "package")]
+    pub packages: Vec<LockedPackage>,
+}
+
+impl LockFile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn filename() -> String {
+        LOCK_FILENAME.to_string()
+    }
+
+    pub fn exists_at(path: &PathBuf) -> bool {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            path.push(PathBuf::from(LOCK_FILENAME));
+        }
+        path.exists()
+    }
+
+    /// Returns the locked package installed under the given name.
+    pub fn get(&self, package_name: &str) -> Option<&LockedPackage> {
+        self.packages.iter().find(|package| package.name == package_name)
+    }
+
+    /// Adds the given package, replacing any locked package with the same name.
+    pub fn insert(&mut self, package: LockedPackage) {
+        self.remove(&package.name);
+        self.packages.push(package);
+        self.packages.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    /// Removes the locked package with the given name, returning it if it existed.
+    pub fn remove(&mut self, package_name: &str) -> Option<LockedPackage> {
+        let index = self.packages.iter().position(|package| package.name == package_name)?;
+        Some(self.packages.remove(index))
+    }
+
+    /// Returns the hex encoded SHA-256 hash of every file in the given directory.
+    ///
+    /// Files are hashed in path order together with their relative paths,
+    /// so the checksum does not depend on file timestamps or the order of directory entries.
+    pub fn checksum(path: &PathBuf) -> Result<String, LockFileError> {
+        let mut hasher = Sha256::new();
+
+        for entry in WalkDir::new(path).sort_by(|a, b| a.file_name().cmp(b.file_name())) {
+            let entry = entry.map_err(|error| LockFileError::Checksum(path.clone(), error.to_string()))?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+
+            let relative_path = entry.path().strip_prefix(path).unwrap_or_else(|_| entry.path());
+            let components = relative_path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>();
+            let contents =
+                fs::read(entry.path()).map_err(|error| LockFileError::Checksum(path.clone(), error.to_string()))?;
+
+            hasher.update(components.join("/").as_bytes());
+            hasher.update([0u8]);
+            hasher.update((contents.len() as u64).to_le_bytes());
+            hasher.update(&contents);
+        }
+
+        Ok(hex::encode(hasher.finalize()))
+    }
+
+    pub fn write_to(&self, path: &PathBuf) -> Result<(), LockFileError> {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            path.push(PathBuf::from(LOCK_FILENAME));
+        }
+
+        let packages = toml::to_string(self).map_err(|error| LockFileError::Serializing(LOCK_FILENAME, error))?;
+
+        let mut file = File::create(&path).map_err(|error| LockFileError::Creating(LOCK_FILENAME, error))?;
+        file.write_all(format!("{}{}", Self::template(), packages).as_bytes())
+            .map_err(|error| LockFileError::Writing(LOCK_FILENAME, error))
+    }
+
+    fn template() -> &'static str {
+        "# This file is automatically generated by Leo.\n# It is not intended for manual editing.\n\n"
+    }
+}
+
+impl TryFrom<&PathBuf> for LockFile {
+    type Error = LockFileError;
+
+    fn try_from(path: &PathBuf) -> Result<Self, Self::Error> {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            path.push(PathBuf::from(LOCK_FILENAME));
+        }
+
+        let mut file = File::open(path).map_err(|error| LockFileError::Opening(LOCK_FILENAME, error))?;
+        let mut buffer = String::new();
+        file.read_to_string(&mut buffer)
+            .map_err(|error| LockFileError::Reading(LOCK_FILENAME, error))?;
+
+        toml::from_str(&buffer).map_err(|error| LockFileError::Parsing(LOCK_FILENAME, error))
+    }
+}
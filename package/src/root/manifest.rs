@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::ManifestError, package::Package};
+use crate::{errors::ManifestError, package::Package, proving_system::ProvingSystem, root::Dependency};
 
 use serde::Deserialize;
 use std::{
+    collections::BTreeMap,
     convert::TryFrom,
     fs::File,
     io::{Read, Write},
@@ -31,10 +32,19 @@ pub struct Remote {
     pub author: String,
 }
 
+/// The package registry that the registry dependencies of a package are downloaded from.
+#[derive(Clone, Deserialize)]
+pub struct Registry {
+    /// The URL of an Aleo Package Manager, or the path of a registry directory.
+    pub url: String,
+}
+
 #[derive(Deserialize)]
 pub struct Manifest {
     pub project: Package,
     pub remote: Option<Remote>,
+    pub registry: Option<Registry>,
+    pub dependencies: Option<BTreeMap<String, Dependency>>,
 }
 
 impl Manifest {
@@ -42,6 +52,8 @@ impl Manifest {
         Self {
             project: Package::new(package_name),
             remote: None,
+            registry: None,
+            dependencies: None,
         }
     }
 
@@ -77,6 +89,32 @@ impl Manifest {
         self.remote.clone()
     }
 
+    /// Returns the URL of the registry selected in the manifest, if any.
+    pub fn get_registry_url(&self) -> Option<String> {
+        self.registry.as_ref().map(|registry| registry.url.clone())
+    }
+
+    /// Returns the dependencies declared in the manifest, sorted by their keys.
+    pub fn get_dependencies(&self) -> Result<Vec<(String, Dependency)>, ManifestError> {
+        let dependencies = self.dependencies.clone().unwrap_or_default();
+
+        for (key, dependency) in dependencies.iter() {
+            dependency.validate(key)?;
+        }
+
+        Ok(dependencies.into_iter().collect())
+    }
+
+    /// Returns the proving system selected in the manifest, or Groth16 if none is selected.
+    pub fn get_proving_system(&self) -> ProvingSystem {
+        self.project.proving_system.unwrap_or_default()
+    }
+
+    /// Returns `true` if the manifest enables the local data commitment check in the circuit.
+    pub fn get_enforce_local_data(&self) -> bool {
+        self.project.enforce_local_data.unwrap_or(false)
+    }
+
     pub fn write_to(self, path: &PathBuf) -> Result<(), ManifestError> {
         let mut path = path.to_owned();
         if path.is_dir() {
@@ -88,6 +126,119 @@ impl Manifest {
             .map_err(|error| ManifestError::Writing(MANIFEST_FILENAME, error))
     }
 
+    /// Adds the given dependency to the `[dependencies]` section of the manifest at the given path,
+    /// replacing any dependency declared with the same import name.
+    pub fn add_dependency(path: &PathBuf, key: &str, dependency: &Dependency) -> Result<(), ManifestError> {
+        dependency.validate(key)?;
+
+        let mut lines = Self::read_lines(path)?;
+        let line = dependency.to_toml_line(key);
+
+        match Self::dependencies_section(&lines) {
+            Some((start, end)) => {
+                let existing = (start..end).find(|index| {
+                    Self::dependency_key(&lines[*index])
+                        .map(|existing| Dependency::import_name(&existing) == Dependency::import_name(key))
+                        .unwrap_or(false)
+                });
+
+                match existing {
+                    Some(index) => lines[index] = line,
+                    None => {
+                        // Insert after the last declared dependency to keep trailing blank lines in place.
+                        let last = (start..end).rev().find(|index| !lines[*index].trim().is_empty());
+                        lines.insert(last.map(|index| index + 1).unwrap_or(start), line);
+                    }
+                }
+            }
+            None => {
+                if lines.last().map(|last| !last.trim().is_empty()).unwrap_or(false) {
+                    lines.push("".to_owned());
+                }
+                lines.push("[dependencies]".to_owned());
+                lines.push(line);
+            }
+        }
+
+        Self::write_lines(path, lines)
+    }
+
+    /// Removes the dependency with the given import name from the manifest at the given path.
+    /// Returns `true` if a dependency was removed.
+    pub fn remove_dependency(path: &PathBuf, package_name: &str) -> Result<bool, ManifestError> {
+        let mut lines = Self::read_lines(path)?;
+
+        let (start, end) = match Self::dependencies_section(&lines) {
+            Some(section) => section,
+            None => return Ok(false),
+        };
+
+        let existing = (start..end).find(|index| {
+            Self::dependency_key(&lines[*index])
+                .map(|key| Dependency::import_name(&key) == package_name)
+                .unwrap_or(false)
+        });
+
+        match existing {
+            Some(index) => {
+                lines.remove(index);
+                Self::write_lines(path, lines)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the range of lines after the `[dependencies]` header up to the next table header.
+    fn dependencies_section(lines: &[String]) -> Option<(usize, usize)> {
+        let header = lines.iter().position(|line| line.trim() == "[dependencies]")?;
+        let start = header + 1;
+        let end = lines[start..]
+            .iter()
+            .position(|line| line.trim_start().starts_with('['))
+            .map(|offset| start + offset)
+            .unwrap_or_else(|| lines.len());
+
+        Some((start, end))
+    }
+
+    /// Returns the unquoted key of a `key = value` line.
+    fn dependency_key(line: &str) -> Option<String> {
+        let line = line.trim();
+        if line.starts_with('#') || !line.contains('=') {
+            return None;
+        }
+
+        let key = line.split('=').next()?.trim();
+
+        Some(key.trim_matches(&['\"', '\''][..]).to_owned())
+    }
+
+    fn read_lines(path: &PathBuf) -> Result<Vec<String>, ManifestError> {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            path.push(PathBuf::from(MANIFEST_FILENAME));
+        }
+
+        let mut file = File::open(&path).map_err(|error| ManifestError::Opening(MANIFEST_FILENAME, error))?;
+        let mut buffer = String::new();
+        file.read_to_string(&mut buffer)
+            .map_err(|error| ManifestError::Reading(MANIFEST_FILENAME, error))?;
+
+        Ok(buffer.lines().map(|line| line.to_owned()).collect())
+    }
+
+    fn write_lines(path: &PathBuf, lines: Vec<String>) -> Result<(), ManifestError> {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            path.push(PathBuf::from(MANIFEST_FILENAME));
+        }
+
+        let mut file = File::create(&path).map_err(|error| ManifestError::Creating(MANIFEST_FILENAME, error))?;
+        file.write_all(format!("{}\n", lines.join("\n")).as_bytes())
+            .map_err(|error| ManifestError::Writing(MANIFEST_FILENAME, error))
+    }
+
     fn template(&self) -> String {
         format!(
             r#"[project]
@@ -137,7 +288,7 @@ impl TryFrom<&PathBuf> for Manifest {
         // Read each individual line of the toml file
         for line in buffer.lines() {
             // Determine if the old remote format is being used
-            if line.starts_with("remote") {
+            if line.split('=').next().map(|key| key.trim()) == Some("remote") {
                 let remote = line
                     .split("=") // Split the line as 'remote' = '"{author}/{package_name}"'
                     .collect::<Vec<&str>>()[1]; // Fetch just '"{author}/{package_name}"'
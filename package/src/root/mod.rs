@@ -14,9 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod dependency;
+pub use self::dependency::*;
+
 pub mod gitignore;
 pub use self::gitignore::*;
 
+pub mod lock;
+pub use self::lock::*;
+
 pub mod manifest;
 pub use self::manifest::*;
 
@@ -25,3 +31,6 @@ pub use self::readme::*;
 
 pub mod zip;
 pub use self::zip::*;
+
+pub mod workspace;
+pub use self::workspace::*;
@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::WorkspaceError,
+    outputs::OUTPUTS_DIRECTORY_NAME,
+    root::{Dependency, Manifest, MANIFEST_FILENAME},
+};
+
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The `[workspace]` section of a `Leo.toml`, listing the member packages of a workspace.
+///
+/// The manifest of a workspace root does not need a `[project]` of its own.
+/// To build the root package as a member as well, list it as `"."`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct Workspace {
+    /// The directories of the member packages, relative to the workspace root.
+    pub members: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct WorkspaceManifest {
+    workspace: Option<Workspace>,
+}
+
+/// A member package of a workspace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkspaceMember {
+    pub name: String,
+    pub path: PathBuf,
+    /// The names of the members this member depends on by path.
+    pub dependencies: Vec<String>,
+}
+
+impl Workspace {
+    /// Returns the workspace declared in the manifest at the given path,
+    /// or `None` if there is no manifest or it has no `[workspace]` section.
+    pub fn read_from(path: &Path) -> Result<Option<Self>, WorkspaceError> {
+        let manifest_path = path.join(MANIFEST_FILENAME);
+        if !manifest_path.exists() {
+            return Ok(None);
+        }
+
+        let toml = fs::read_to_string(&manifest_path).map_err(WorkspaceError::Reading)?;
+        let manifest: WorkspaceManifest = toml::from_str(&toml).map_err(WorkspaceError::Parsing)?;
+
+        Ok(manifest.workspace)
+    }
+
+    /// Returns the root of the workspace the package at the given path is a member of, if any.
+    ///
+    /// The package directory and its ancestors are searched for a workspace that lists the package.
+    pub fn find_root(path: &Path) -> Result<Option<PathBuf>, WorkspaceError> {
+        let package = match fs::canonicalize(path) {
+            Ok(package) => package,
+            Err(_) => return Ok(None),
+        };
+
+        for root in package.ancestors() {
+            if let Some(workspace) = Self::read_from(root)? {
+                let is_member = workspace
+                    .member_paths(root)
+                    .iter()
+                    .any(|member| fs::canonicalize(member).ok().as_ref() == Some(&package));
+
+                if is_member {
+                    return Ok(Some(root.to_owned()));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the outputs directory of the package at the given path.
+    ///
+    /// The members of a workspace share the outputs directory of the workspace root,
+    /// where each member writes to a subdirectory named after the package,
+    /// so the output files of inputs with the same name in different members do not clash.
+    pub fn outputs_directory(path: &PathBuf) -> Result<PathBuf, WorkspaceError> {
+        match Self::find_root(path)? {
+            Some(root) => {
+                let package_name = Manifest::try_from(path)?.get_package_name();
+
+                Ok(root.join(OUTPUTS_DIRECTORY_NAME).join(package_name))
+            }
+            None => Ok(path.join(OUTPUTS_DIRECTORY_NAME)),
+        }
+    }
+
+    /// Returns the directories of the member packages of the workspace at the given root.
+    pub fn member_paths(&self, root: &Path) -> Vec<PathBuf> {
+        self.members.iter().map(|member| root.join(member)).collect()
+    }
+
+    /// Returns the member packages of the workspace at the given root,
+    /// ordered so that every member comes after the members it depends on.
+    pub fn members(&self, root: &Path) -> Result<Vec<WorkspaceMember>, WorkspaceError> {
+        let mut members: Vec<WorkspaceMember> = vec![];
+        let mut directories = HashMap::new();
+
+        for (member, path) in self.members.iter().zip(self.member_paths(root)) {
+            if !Manifest::exists_at(&path) {
+                return Err(WorkspaceError::MissingMember(member.clone()));
+            }
+
+            let name = Manifest::try_from(&path)?.get_package_name();
+            if let Some(other) = members.iter().position(|other| other.name == name) {
+                return Err(WorkspaceError::DuplicateMember(
+                    name,
+                    self.members[other].clone(),
+                    member.clone(),
+                ));
+            }
+
+            if let Ok(directory) = fs::canonicalize(&path) {
+                directories.insert(directory, name.clone());
+            }
+
+            members.push(WorkspaceMember {
+                name,
+                path,
+                dependencies: vec![],
+            });
+        }
+
+        // Path dependencies on the directory of another member are dependencies on that member
+        for member in members.iter_mut() {
+            for (_, dependency) in Manifest::try_from(&member.path)?.get_dependencies()? {
+                if let Dependency::Path { path } = dependency {
+                    let directory = fs::canonicalize(member.path.join(path)).ok();
+
+                    if let Some(name) = directory.and_then(|directory| directories.get(&directory)) {
+                        member.dependencies.push(name.clone());
+                    }
+                }
+            }
+        }
+
+        let mut ordered = vec![];
+        for member in members.iter() {
+            Self::visit(member, &members, &mut vec![], &mut ordered)?;
+        }
+
+        Ok(ordered)
+    }
+
+    /// Appends the given member to the ordered members after its dependencies, depth first.
+    fn visit(
+        member: &WorkspaceMember,
+        members: &[WorkspaceMember],
+        stack: &mut Vec<String>,
+        ordered: &mut Vec<WorkspaceMember>,
+    ) -> Result<(), WorkspaceError> {
+        if ordered.iter().any(|visited| visited.name == member.name) {
+            return Ok(());
+        }
+
+        if let Some(start) = stack.iter().position(|name| name == &member.name) {
+            let mut cycle = stack[start..].to_vec();
+            cycle.push(member.name.clone());
+
+            return Err(WorkspaceError::DependencyCycle(cycle));
+        }
+
+        stack.push(member.name.clone());
+        for dependency in member.dependencies.iter() {
+            if let Some(dependency) = members.iter().find(|member| &member.name == dependency) {
+                Self::visit(dependency, members, stack, ordered)?;
+            }
+        }
+        stack.pop();
+
+        ordered.push(member.clone());
+
+        Ok(())
+    }
+}
@@ -23,6 +23,7 @@ use crate::{
     outputs::{
         CHECKSUM_FILE_EXTENSION,
         CIRCUIT_FILE_EXTENSION,
+        OutputsDirectory,
         OUTPUTS_DIRECTORY_NAME,
         PROOF_FILE_EXTENSION,
         PROVING_KEY_FILE_EXTENSION,
@@ -138,7 +139,7 @@ impl ZipFile {
     fn setup_file_path(&self, path: &PathBuf) -> PathBuf {
         let mut path = path.to_owned();
         if path.is_dir() {
-            if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+            if !OutputsDirectory::is_outputs_directory(&path) {
                 path.push(PathBuf::from(OUTPUTS_DIRECTORY_NAME));
             }
             path.push(PathBuf::from(format!("{}{}", self.package_name, ZIP_FILE_EXTENSION)));
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Tests for discovering input pairs in the inputs directory
+
+use crate::test_dir;
+use leo_package::{errors::InputsDirectoryError, inputs::InputPairs};
+
+use std::{convert::TryFrom, fs};
+
+const JSON_INPUT: &str = r#"{
+    "main": { "a": { "type": "u32", "value": 1 } },
+    "state": { "leaf_index": { "type": "u32", "value": 0 } }
+}"#;
+
+#[test]
+fn test_json_input_pair() {
+    let directory = test_dir();
+    fs::create_dir_all(directory.join("inputs")).unwrap();
+    fs::write(directory.join("inputs/scenario.json"), JSON_INPUT).unwrap();
+    fs::write(directory.join("inputs/other.in"), "[main]\na: u32 = 2;\n").unwrap();
+
+    let pairs = InputPairs::try_from(&directory).unwrap();
+    let pair = &pairs.pairs["scenario"];
+
+    assert_eq!(pair.input_file, "[main]\na: u32 = 1u32;\n\n");
+    assert_eq!(pair.state_file, "[[public]]\n\n[state]\nleaf_index: u32 = 0u32;\n\n");
+    assert_eq!(pairs.pairs["other"].input_file, "[main]\na: u32 = 2;\n");
+}
+
+#[test]
+fn test_json_input_pair_duplicate() {
+    let directory = test_dir();
+    fs::create_dir_all(directory.join("inputs")).unwrap();
+    fs::write(directory.join("inputs/scenario.json"), JSON_INPUT).unwrap();
+    fs::write(directory.join("inputs/scenario.state"), "").unwrap();
+
+    match InputPairs::try_from(&directory) {
+        Err(InputsDirectoryError::DuplicateInput(name)) => assert_eq!(name, "scenario"),
+        _ => panic!("expected a duplicate input error"),
+    }
+}
+
+#[test]
+fn test_json_input_pair_invalid() {
+    let directory = test_dir();
+    fs::create_dir_all(directory.join("inputs")).unwrap();
+    fs::write(
+        directory.join("inputs/scenario.json"),
+        r#"{ "main": { "a": { "type": "bool", "value": 1 } } }"#,
+    )
+    .unwrap();
+
+    let error = InputPairs::try_from(&directory).err().unwrap();
+
+    assert_eq!(error.code(), "E0108");
+}
+
+const TOML_INPUT: &str = r#"
+[main]
+a = { type = "u32", value = 1 }
+
+[state]
+leaf_index = { type = "u32", value = 0 }
+"#;
+
+#[test]
+fn test_toml_input_pair() {
+    let directory = test_dir();
+    fs::create_dir_all(directory.join("inputs")).unwrap();
+    fs::write(directory.join("inputs/scenario.toml"), TOML_INPUT).unwrap();
+
+    let pairs = InputPairs::try_from(&directory).unwrap();
+    let pair = &pairs.pairs["scenario"];
+
+    assert_eq!(pair.input_file, "[main]\na: u32 = 1u32;\n\n");
+    assert_eq!(pair.state_file, "[[public]]\n\n[state]\nleaf_index: u32 = 0u32;\n\n");
+}
+
+#[test]
+fn test_toml_input_pair_duplicate() {
+    let directory = test_dir();
+    fs::create_dir_all(directory.join("inputs")).unwrap();
+    fs::write(directory.join("inputs/scenario.json"), JSON_INPUT).unwrap();
+    fs::write(directory.join("inputs/scenario.toml"), TOML_INPUT).unwrap();
+
+    match InputPairs::try_from(&directory) {
+        Err(InputsDirectoryError::DuplicateInput(name)) => assert_eq!(name, "scenario"),
+        _ => panic!("expected a duplicate input error"),
+    }
+}
+
+#[test]
+fn test_toml_input_pair_invalid() {
+    let directory = test_dir();
+    fs::create_dir_all(directory.join("inputs")).unwrap();
+    fs::write(directory.join("inputs/scenario.toml"), "[main]\na = 1\n").unwrap();
+
+    let error = InputPairs::try_from(&directory).err().unwrap();
+
+    assert_eq!(error.code(), "E0109");
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Tests for the package lock file and installed imports
+
+use crate::test_dir;
+use leo_package::{
+    imports::ImportsDirectory,
+    root::{LockFile, LockedPackage, LOCK_FILENAME},
+};
+
+use std::{convert::TryFrom, fs, path::PathBuf};
+
+/// Create a package with a source file and build outputs at the given path.
+fn create_package(path: &PathBuf) {
+    fs::create_dir_all(path.join("src")).unwrap();
+    fs::create_dir_all(path.join("outputs")).unwrap();
+    fs::write(
+        path.join("Leo.toml"),
+        "[project]\nname = \"utils\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    fs::write(path.join("src").join("lib.leo"), "function one() -> u8 { return 1 }\n").unwrap();
+    fs::write(path.join("outputs").join("utils.sum"), "checksum").unwrap();
+}
+
+#[test]
+fn test_lock_file_round_trip() {
+    let test_directory = test_dir();
+
+    let mut lock_file = LockFile::new();
+    lock_file.insert(LockedPackage {
+        name: "utils".into(),
+        version: "0.1.0".into(),
+        source: "path+../utils".into(),
+        checksum: "00".into(),
+        dependencies: vec![],
+    });
+    lock_file.insert(LockedPackage {
+        name: "math".into(),
+        version: "0.2.0".into(),
+        source: "git+https://github.com/author/math?tag=v0.2.0#abcdef".into(),
+        checksum: "11".into(),
+        dependencies: vec![],
+    });
+    lock_file.write_to(&test_directory).unwrap();
+
+    assert!(LockFile::exists_at(&test_directory));
+    assert!(test_directory.join(LOCK_FILENAME).exists());
+
+    let read = LockFile::try_from(&test_directory).unwrap();
+    assert_eq!(read, lock_file);
+
+    // Packages are kept sorted by name.
+    assert_eq!(read.packages[0].name, "math");
+    assert_eq!(read.packages[0].git_commit(), Some("abcdef"));
+    assert_eq!(
+        read.packages[0].unresolved_source(),
+        "git+https://github.com/author/math?tag=v0.2.0"
+    );
+    assert_eq!(read.get("utils").unwrap().git_commit(), None);
+}
+
+#[test]
+fn test_copy_import_checksum() {
+    let test_directory = test_dir();
+    let source = test_directory.join("utils");
+    let package = test_directory.join("package");
+    create_package(&source);
+    fs::create_dir_all(&package).unwrap();
+
+    ImportsDirectory::copy_import(&package, "utils", &source).unwrap();
+
+    let import = ImportsDirectory::import_path(&package, "utils");
+    assert!(import.join("src").join("lib.leo").exists());
+    assert!(!import.join("outputs").exists());
+
+    // Installing the same contents again yields the same checksum.
+    let checksum = LockFile::checksum(&import).unwrap();
+    ImportsDirectory::copy_import(&package, "utils", &source).unwrap();
+    assert_eq!(LockFile::checksum(&import).unwrap(), checksum);
+
+    // Any change to the contents changes the checksum.
+    fs::write(
+        import.join("src").join("lib.leo"),
+        "function one() -> u8 { return 2 }\n",
+    )
+    .unwrap();
+    assert_ne!(LockFile::checksum(&import).unwrap(), checksum);
+}
@@ -17,7 +17,10 @@
 // Tests for package manifest
 
 use crate::test_dir;
-use leo_package::root::{Manifest, MANIFEST_FILENAME};
+use leo_package::{
+    proving_system::ProvingSystem,
+    root::{Dependency, Manifest, MANIFEST_FILENAME},
+};
 
 use std::{
     convert::TryFrom,
@@ -39,6 +42,33 @@ const NEW_REMOTE_FORMAT: &str = r#"
 author = "author"
 "#;
 
+const PROVING_SYSTEM_FORMAT: &str = r#"[project]
+name = "test-package"
+version = "0.1.0"
+proving_system = "gm17"
+"#;
+
+const DEPENDENCIES_FORMAT: &str = r#"[project]
+name = "test-package"
+version = "0.1.0"
+
+[remote]
+author = "author"
+
+[dependencies]
+"author/math" = "0.1.0"
+remote-utils = { path = "../utils" }
+signatures = { git = "https://github.com/author/signatures", tag = "v0.1.0" }
+"#;
+
+const REGISTRY_FORMAT: &str = r#"[project]
+name = "test-package"
+version = "0.1.0"
+
+[registry]
+url = "../registry"
+"#;
+
 const OLD_PROJECT_FORMAT: &str = "[package]";
 const NEW_PROJECT_FORMAT: &str = "[project]";
 
@@ -167,3 +197,138 @@ fn test_manifest_refactors() {
     // Check that the manifest file remote has been updated.
     assert!(remote_is_updated(&manifest_path));
 }
+
+#[test]
+fn test_manifest_proving_system() {
+    let test_directory = test_dir();
+    let mut manifest_path = test_directory.clone();
+    manifest_path.push(MANIFEST_FILENAME);
+
+    // A manifest without a proving system defaults to Groth16.
+    Manifest::new("test-package").write_to(&test_directory).unwrap();
+    let manifest = Manifest::try_from(&manifest_path).unwrap();
+    assert_eq!(manifest.get_proving_system(), ProvingSystem::Groth16);
+
+    // A manifest can select a different proving system.
+    let mut file = File::create(&manifest_path).unwrap();
+    file.write_all(PROVING_SYSTEM_FORMAT.as_bytes()).unwrap();
+    let manifest = Manifest::try_from(&manifest_path).unwrap();
+    assert_eq!(manifest.get_proving_system(), ProvingSystem::GM17);
+}
+
+#[test]
+fn test_manifest_registry() {
+    let test_directory = test_dir();
+    let mut manifest_path = test_directory.clone();
+    manifest_path.push(MANIFEST_FILENAME);
+
+    let manifest = Manifest::new("test-package");
+    assert_eq!(manifest.get_registry_url(), None);
+
+    let mut file = File::create(&manifest_path).unwrap();
+    file.write_all(REGISTRY_FORMAT.as_bytes()).unwrap();
+
+    let manifest = Manifest::try_from(&manifest_path).unwrap();
+    assert_eq!(manifest.get_registry_url(), Some("../registry".into()));
+}
+
+#[test]
+fn test_manifest_dependencies() {
+    let test_directory = test_dir();
+    let mut manifest_path = test_directory.clone();
+    manifest_path.push(MANIFEST_FILENAME);
+
+    let mut file = File::create(&manifest_path).unwrap();
+    file.write_all(DEPENDENCIES_FORMAT.as_bytes()).unwrap();
+
+    let manifest = Manifest::try_from(&manifest_path).unwrap();
+    assert_eq!(manifest.get_dependencies().unwrap(), vec![
+        ("author/math".to_string(), Dependency::Registry("0.1.0".into())),
+        ("remote-utils".to_string(), Dependency::Path {
+            path: "../utils".into()
+        }),
+        ("signatures".to_string(), Dependency::Git {
+            git: "https://github.com/author/signatures".into(),
+            branch: None,
+            tag: Some("v0.1.0".into()),
+            rev: None,
+        }),
+    ]);
+
+    // A dependency named like the old remote key is not mistaken for it.
+    assert_eq!(read_manifest_file(&manifest_path), DEPENDENCIES_FORMAT);
+}
+
+#[test]
+fn test_manifest_invalid_dependency() {
+    let test_directory = test_dir();
+    let mut manifest_path = test_directory.clone();
+    manifest_path.push(MANIFEST_FILENAME);
+
+    let mut file = File::create(&manifest_path).unwrap();
+    file.write_all(PROVING_SYSTEM_FORMAT.as_bytes()).unwrap();
+    file.write_all(b"\n[dependencies]\nmath = \"0.1.0\"\n").unwrap();
+
+    let manifest = Manifest::try_from(&manifest_path).unwrap();
+    assert!(manifest.get_dependencies().is_err());
+}
+
+#[test]
+fn test_manifest_git_dependency_option() {
+    let dependencies = [
+        "signatures = { git = \"--upload-pack=touch /tmp/pwned\" }",
+        "signatures = { git = \"https://github.com/author/signatures\", branch = \"--orphan\" }",
+        "signatures = { git = \"https://github.com/author/signatures\", rev = \"-b\" }",
+    ];
+
+    for dependency in dependencies.iter() {
+        let test_directory = test_dir();
+        let mut manifest_path = test_directory.clone();
+        manifest_path.push(MANIFEST_FILENAME);
+
+        let mut file = File::create(&manifest_path).unwrap();
+        file.write_all(PROVING_SYSTEM_FORMAT.as_bytes()).unwrap();
+        file.write_all(format!("\n[dependencies]\n{}\n", dependency).as_bytes())
+            .unwrap();
+
+        let manifest = Manifest::try_from(&manifest_path).unwrap();
+        assert!(manifest.get_dependencies().is_err());
+    }
+}
+
+#[test]
+fn test_manifest_add_remove_dependency() {
+    let test_directory = test_dir();
+    Manifest::new("test-package").write_to(&test_directory).unwrap();
+
+    let math = Dependency::Registry("0.1.0".into());
+    let utils = Dependency::Path {
+        path: "../utils".into(),
+    };
+
+    Manifest::add_dependency(&test_directory, "author/math", &math).unwrap();
+    Manifest::add_dependency(&test_directory, "utils", &utils).unwrap();
+
+    let manifest = Manifest::try_from(&test_directory).unwrap();
+    assert_eq!(manifest.get_dependencies().unwrap(), vec![
+        ("author/math".to_string(), math),
+        ("utils".to_string(), utils.clone()),
+    ]);
+
+    // Adding a dependency with the same import name replaces it.
+    let math = Dependency::Registry("0.2.0".into());
+    Manifest::add_dependency(&test_directory, "author/math", &math).unwrap();
+
+    let manifest = Manifest::try_from(&test_directory).unwrap();
+    assert_eq!(manifest.get_dependencies().unwrap(), vec![
+        ("author/math".to_string(), math),
+        ("utils".to_string(), utils.clone()),
+    ]);
+
+    // Dependencies are removed by their import name.
+    assert!(Manifest::remove_dependency(&test_directory, "math").unwrap());
+    assert!(!Manifest::remove_dependency(&test_directory, "math").unwrap());
+
+    let manifest = Manifest::try_from(&test_directory).unwrap();
+    assert_eq!(manifest.get_dependencies().unwrap(), vec![("utils".to_string(), utils)]);
+}
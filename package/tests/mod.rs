@@ -15,7 +15,12 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod initialize;
+pub mod inputs;
+pub mod lock;
 pub mod manifest;
+pub mod outputs;
+pub mod resolver;
+pub mod workspace;
 
 use lazy_static::lazy_static;
 use std::{
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Tests for the proving system recorded in key and proof files
+
+use crate::test_dir;
+use leo_package::{
+    errors::{ProofFileError, ProvingKeyFileError},
+    outputs::{OutputsDirectory, ProofFile, ProvingKeyFile, VerificationKeyFile},
+    proving_system::ProvingSystem,
+};
+
+use std::{fs, path::PathBuf};
+
+const PACKAGE_NAME: &str = "test-package";
+const KEY_BYTES: &[u8] = &[0, 1, 2, 3, 10, 255];
+
+fn outputs_dir() -> PathBuf {
+    let test_directory = test_dir();
+    OutputsDirectory::create(&test_directory).unwrap();
+
+    test_directory
+}
+
+#[test]
+fn test_proving_key_round_trip() {
+    let path = outputs_dir();
+    let proving_key_file = ProvingKeyFile::new(PACKAGE_NAME);
+
+    proving_key_file.write_to(&path, ProvingSystem::GM17, KEY_BYTES).unwrap();
+
+    assert_eq!(proving_key_file.proving_system_at(&path), Some(ProvingSystem::GM17));
+    assert_eq!(proving_key_file.read_from(&path, ProvingSystem::GM17).unwrap(), KEY_BYTES);
+}
+
+#[test]
+fn test_verification_key_round_trip() {
+    let path = outputs_dir();
+    let verification_key_file = VerificationKeyFile::new(PACKAGE_NAME);
+
+    verification_key_file.write_to(&path, ProvingSystem::Groth16, KEY_BYTES).unwrap();
+
+    assert_eq!(verification_key_file.proving_system_at(&path), Some(ProvingSystem::Groth16));
+    assert_eq!(verification_key_file.read_from(&path, ProvingSystem::Groth16).unwrap(), KEY_BYTES);
+}
+
+#[test]
+fn test_proving_key_mismatch() {
+    let path = outputs_dir();
+    let proving_key_file = ProvingKeyFile::new(PACKAGE_NAME);
+
+    proving_key_file.write_to(&path, ProvingSystem::Groth16, KEY_BYTES).unwrap();
+
+    match proving_key_file.read_from(&path, ProvingSystem::GM17) {
+        Err(ProvingKeyFileError::ProvingSystemMismatch(_, expected, found)) => {
+            assert_eq!(expected, ProvingSystem::GM17);
+            assert_eq!(found, "groth16");
+        }
+        _ => panic!("expected a proving system mismatch"),
+    }
+}
+
+#[test]
+fn test_proof_without_header() {
+    let path = outputs_dir();
+    let proof_file = ProofFile::new(PACKAGE_NAME);
+
+    // Write a proof in the format used before proving systems were recorded
+    let mut proof_path = path.clone();
+    proof_path.push(format!("outputs/{}.proof", PACKAGE_NAME));
+    fs::write(&proof_path, KEY_BYTES).unwrap();
+
+    assert_eq!(proof_file.proving_system_at(&path), None);
+    match proof_file.read_from(&path, ProvingSystem::Groth16) {
+        Err(ProofFileError::ProvingSystemMismatch(_, _, found)) => assert_eq!(found, "no proving system"),
+        _ => panic!("expected a proving system mismatch"),
+    }
+}
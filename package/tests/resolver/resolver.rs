@@ -0,0 +1,236 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Tests for dependency resolution and the resolved dependency graph
+
+use leo_package::{
+    errors::ResolverError,
+    resolver::{DependencyGraph, PackageRegistry, PackageSummary, Resolution, Resolver},
+    root::{Dependency, LockFile, LockedPackage},
+};
+
+use semver::Version;
+use std::path::{Path, PathBuf};
+
+/// A registry with a fixed set of packages.
+struct TestRegistry {
+    packages: Vec<PackageSummary>,
+}
+
+impl PackageRegistry for TestRegistry {
+    type Error = ResolverError;
+
+    fn query(&mut self, key: &str, _dependency: &Dependency) -> Result<Vec<PackageSummary>, Self::Error> {
+        Ok(self
+            .packages
+            .iter()
+            .filter(|package| package.name == Dependency::import_name(key))
+            .cloned()
+            .collect())
+    }
+}
+
+fn registry_package(name: &str, version: &str, dependencies: &[(&str, &str)]) -> PackageSummary {
+    PackageSummary {
+        name: name.into(),
+        version: Version::parse(version).unwrap(),
+        source: format!("registry+aleo/{}", name),
+        path: PathBuf::new(),
+        dependencies: dependencies
+            .iter()
+            .map(|(key, requirement)| (key.to_string(), Dependency::Registry(requirement.to_string())))
+            .collect(),
+    }
+}
+
+fn requirements(dependencies: &[(&str, &str)]) -> Vec<(String, Dependency)> {
+    dependencies
+        .iter()
+        .map(|(key, requirement)| (key.to_string(), Dependency::Registry(requirement.to_string())))
+        .collect()
+}
+
+fn resolve(
+    packages: Vec<PackageSummary>,
+    lock_file: &LockFile,
+    dependencies: &[(&str, &str)],
+) -> Result<Resolution, ResolverError> {
+    let mut registry = TestRegistry { packages };
+    Resolver::new(&mut registry, lock_file).resolve("app", &requirements(dependencies))
+}
+
+fn selected_version(resolution: &Resolution, name: &str) -> String {
+    resolution.get(name).unwrap().summary.version.to_string()
+}
+
+fn math_versions() -> Vec<PackageSummary> {
+    vec![
+        registry_package("math", "0.1.0", &[]),
+        registry_package("math", "0.1.1", &[]),
+        registry_package("math", "0.1.5", &[]),
+        registry_package("math", "0.2.0", &[]),
+    ]
+}
+
+#[test]
+fn test_resolve_transitive() {
+    let mut packages = math_versions();
+    packages.push(registry_package("geometry", "0.3.0", &[("aleo/math", "0.1.2")]));
+
+    let resolution = resolve(packages, &LockFile::new(), &[
+        ("aleo/math", "0.1.0"),
+        ("aleo/geometry", "0.3"),
+    ])
+    .unwrap();
+
+    // The newest version compatible with both requirements is selected.
+    assert_eq!(selected_version(&resolution, "math"), "0.1.5");
+    assert_eq!(selected_version(&resolution, "geometry"), "0.3.0");
+
+    assert_eq!(resolution.dependencies, vec!["geometry", "math"]);
+    assert_eq!(resolution.get("geometry").unwrap().dependencies, vec!["math"]);
+    assert!(resolution.get("math").unwrap().dependencies.is_empty());
+}
+
+#[test]
+fn test_resolve_backtracks() {
+    let mut packages = math_versions();
+    packages.push(registry_package("geometry", "0.3.0", &[("aleo/math", "0.1")]));
+    packages.push(registry_package("geometry", "0.3.1", &[("aleo/math", "0.2")]));
+
+    let resolution = resolve(packages, &LockFile::new(), &[
+        ("aleo/math", "0.1"),
+        ("aleo/geometry", "0.3"),
+    ])
+    .unwrap();
+
+    // The newest geometry requires a math version that conflicts with the root package.
+    assert_eq!(selected_version(&resolution, "geometry"), "0.3.0");
+    assert_eq!(selected_version(&resolution, "math"), "0.1.5");
+}
+
+#[test]
+fn test_resolve_conflict() {
+    let mut packages = math_versions();
+    packages.push(registry_package("geometry", "0.3.0", &[("aleo/math", "0.2")]));
+
+    let error = resolve(packages, &LockFile::new(), &[
+        ("aleo/math", "0.1"),
+        ("aleo/geometry", "0.3"),
+    ])
+    .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "failed to select a version of `math` that satisfies all requirements:\n    \
+         `app` requires `aleo/math` ^0.1\n    \
+         `app -> geometry v0.3.0` requires `aleo/math` ^0.2"
+    );
+}
+
+#[test]
+fn test_resolve_no_matching_version() {
+    let error = resolve(math_versions(), &LockFile::new(), &[("aleo/math", "0.3")]).unwrap_err();
+
+    match error {
+        ResolverError::NoMatchingVersion(name, _) => assert_eq!(name, "math"),
+        error => panic!("unexpected error {}", error),
+    }
+}
+
+#[test]
+fn test_resolve_prefers_locked_version() {
+    let mut lock_file = LockFile::new();
+    lock_file.insert(LockedPackage {
+        name: "math".into(),
+        version: "0.1.1".into(),
+        source: "registry+aleo/math".into(),
+        checksum: "00".into(),
+        dependencies: vec![],
+    });
+
+    let resolution = resolve(math_versions(), &lock_file, &[("aleo/math", "0.1")]).unwrap();
+    assert_eq!(selected_version(&resolution, "math"), "0.1.1");
+
+    // A locked version that no longer matches the requirement is replaced.
+    let resolution = resolve(math_versions(), &lock_file, &[("aleo/math", "0.2")]).unwrap();
+    assert_eq!(selected_version(&resolution, "math"), "0.2.0");
+}
+
+#[test]
+fn test_resolve_relative_path_dependencies() {
+    let path_package = |name: &str, path: &str, dependencies: Vec<(String, Dependency)>| PackageSummary {
+        name: name.into(),
+        version: Version::parse("0.1.0").unwrap(),
+        source: format!("path+{}", path),
+        path: PathBuf::new(),
+        dependencies,
+    };
+
+    let helpers = ("helpers".to_string(), Dependency::Path {
+        path: "../helpers".into(),
+    });
+    let packages = vec![
+        path_package("utils", "../utils", vec![helpers]),
+        path_package("helpers", "../helpers", vec![]),
+    ];
+
+    let dependencies = vec![("utils".to_string(), Dependency::Path {
+        path: "./../utils".into(),
+    })];
+
+    let mut registry = TestRegistry { packages };
+    let resolution = Resolver::new(&mut registry, &LockFile::new())
+        .resolve("app", &dependencies)
+        .unwrap();
+
+    // Path dependencies of path packages are relative to the package declaring them.
+    assert_eq!(resolution.get("helpers").unwrap().summary.source, "path+../helpers");
+    assert_eq!(resolution.get("utils").unwrap().dependencies, vec!["helpers"]);
+}
+
+#[test]
+fn test_dependency_graph() {
+    let mut lock_file = LockFile::new();
+    lock_file.dependencies = vec!["geometry".into()];
+    for (name, dependencies) in vec![("geometry", vec!["math".to_string()]), ("math", vec![])] {
+        lock_file.insert(LockedPackage {
+            name: name.into(),
+            version: "0.1.0".into(),
+            source: format!("registry+aleo/{}", name),
+            checksum: "00".into(),
+            dependencies,
+        });
+    }
+
+    let root = PathBuf::from("/app");
+    let graph = DependencyGraph::new(&root, &lock_file);
+
+    let geometry = Path::new("/app/imports/geometry/src/lib.leo");
+    assert_eq!(graph.package_at(geometry), Some("geometry".into()));
+    assert_eq!(graph.package_at(Path::new("/app/src/main.leo")), None);
+
+    // Packages can only import their own dependencies.
+    assert_eq!(
+        graph.resolve(Path::new("/app/src/main.leo"), "geometry"),
+        Some(PathBuf::from("/app/imports/geometry"))
+    );
+    assert_eq!(graph.resolve(Path::new("/app/src/main.leo"), "math"), None);
+    assert_eq!(
+        graph.resolve(geometry, "math"),
+        Some(PathBuf::from("/app/imports/math"))
+    );
+}
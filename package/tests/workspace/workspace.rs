@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Tests for workspaces of multiple packages
+
+use crate::test_dir;
+use leo_package::{
+    errors::WorkspaceError,
+    outputs::OutputsDirectory,
+    root::{Dependency, Manifest, Workspace},
+};
+
+use std::{fs, path::PathBuf};
+
+/// Create a workspace at the given path with the given members and their path dependencies.
+fn create_workspace(path: &PathBuf, members: &[(&str, &[&str])]) {
+    let names = members
+        .iter()
+        .map(|(name, _)| format!("\"{}\"", name))
+        .collect::<Vec<_>>();
+    fs::write(
+        path.join("Leo.toml"),
+        format!("[workspace]\nmembers = [{}]\n", names.join(", ")),
+    )
+    .unwrap();
+
+    for (name, dependencies) in members {
+        let member = path.join(name);
+        fs::create_dir_all(&member).unwrap();
+        Manifest::new(name).write_to(&member).unwrap();
+
+        for dependency in dependencies.iter() {
+            let path = Dependency::Path {
+                path: PathBuf::from("..").join(dependency),
+            };
+            Manifest::add_dependency(&member, dependency, &path).unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_workspace_members_in_dependency_order() {
+    let root = test_dir();
+    create_workspace(&root, &[
+        ("app", &["geometry"]),
+        ("geometry", &["math"]),
+        ("math", &[]),
+    ]);
+
+    let workspace = Workspace::read_from(&root).unwrap().unwrap();
+    let members = workspace.members(&root).unwrap();
+
+    let names = members.iter().map(|member| member.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["math", "geometry", "app"]);
+    assert_eq!(members[2].dependencies, vec!["geometry"]);
+    assert_eq!(members[2].path, root.join("app"));
+}
+
+#[test]
+fn test_workspace_dependency_cycle() {
+    let root = test_dir();
+    create_workspace(&root, &[("a", &["b"]), ("b", &["a"])]);
+
+    let workspace = Workspace::read_from(&root).unwrap().unwrap();
+    match workspace.members(&root) {
+        Err(WorkspaceError::DependencyCycle(cycle)) => assert_eq!(cycle, vec!["a", "b", "a"]),
+        _ => panic!("expected a dependency cycle"),
+    }
+}
+
+#[test]
+fn test_workspace_missing_member() {
+    let root = test_dir();
+    create_workspace(&root, &[("math", &[])]);
+    fs::write(root.join("Leo.toml"), "[workspace]\nmembers = [\"math\", \"missing\"]\n").unwrap();
+
+    let workspace = Workspace::read_from(&root).unwrap().unwrap();
+    match workspace.members(&root) {
+        Err(WorkspaceError::MissingMember(member)) => assert_eq!(member, "missing"),
+        _ => panic!("expected a missing member"),
+    }
+}
+
+#[test]
+fn test_workspace_outputs_directory() {
+    let root = test_dir();
+    create_workspace(&root, &[("math", &[])]);
+
+    // Members write to their own subdirectory of the outputs directory of the workspace root
+    let outputs = Workspace::outputs_directory(&root.join("math")).unwrap();
+    assert!(outputs.ends_with("outputs/math"));
+    assert_eq!(
+        fs::canonicalize(outputs.parent().unwrap().parent().unwrap()).unwrap(),
+        fs::canonicalize(&root).unwrap()
+    );
+    assert!(OutputsDirectory::is_outputs_directory(&outputs));
+
+    // Other packages keep their own outputs directory
+    let package = test_dir();
+    Manifest::new("utils").write_to(&package).unwrap();
+    assert_eq!(Workspace::outputs_directory(&package).unwrap(), package.join("outputs/"));
+
+    // A package manifest without a `[workspace]` section is not a workspace
+    assert_eq!(Workspace::read_from(&package).unwrap(), None);
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::InputValueError;
+
+use snarkos_errors::{
+    algorithms::{CRHError, CommitmentError, MerkleError},
+    dpc::DPCError,
+    objects::AccountError,
+};
+
+use std::{io::Error as IOError, num::ParseIntError, str::ParseBoolError};
+
+#[derive(Debug, Error)]
+pub enum LocalDataGenerationError {
+    #[error("{}", _0)]
+    AccountError(#[from] AccountError),
+
+    #[error("{}", _0)]
+    CommitmentError(#[from] CommitmentError),
+
+    #[error("{}", _0)]
+    CRHError(#[from] CRHError),
+
+    #[error("{}", _0)]
+    DPCError(#[from] DPCError),
+
+    #[error("{}", _0)]
+    InputValueError(#[from] InputValueError),
+
+    #[error("{}", _0)]
+    IOError(#[from] IOError),
+
+    #[error("`{}` must have {} bytes, found {}", _0, _1, _2)]
+    InvalidLength(String, usize, usize),
+
+    #[error("leaf index {} is out of range, the local data tree has {} leaves", _0, _1)]
+    InvalidLeafIndex(u32, usize),
+
+    #[error("the local data tree has {} leaves, found {}", _0, _1)]
+    InvalidLeafCount(usize, usize),
+
+    #[error("{}", _0)]
+    MerkleError(#[from] MerkleError),
+
+    #[error("{}", _0)]
+    ParseBoolError(#[from] ParseBoolError),
+
+    #[error("{}", _0)]
+    ParseIntError(#[from] ParseIntError),
+}
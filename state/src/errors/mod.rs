@@ -29,5 +29,8 @@ pub use self::state_values::*;
 pub mod local_data_commitment;
 pub use self::local_data_commitment::*;
 
+pub mod local_data_generation;
+pub use self::local_data_generation::*;
+
 pub mod record_commitment;
 pub use self::record_commitment::*;
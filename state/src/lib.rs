@@ -20,6 +20,9 @@ extern crate thiserror;
 pub mod errors;
 pub use self::errors::*;
 
+pub mod local_data;
+pub use self::local_data::*;
+
 pub mod local_data_commitment;
 pub use self::local_data_commitment::*;
 
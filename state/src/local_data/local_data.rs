@@ -0,0 +1,215 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{DPCRecordValues, LocalDataFields, LocalDataGenerationError, StateLeafValues, StateValues};
+
+use snarkos_algorithms::commitment_tree::CommitmentMerkleTree;
+use snarkos_dpc::base_dpc::{
+    instantiated::{Components, LocalDataCRH, LocalDataCommitment, SerialNumberNonce},
+    parameters::SystemParameters,
+    record_payload::RecordPayload,
+    DPC,
+};
+use snarkos_models::{
+    algorithms::{CommitmentScheme, CRH},
+    dpc::{DPCComponents, Record},
+};
+use snarkos_objects::{AccountAddress, AccountPrivateKey};
+use snarkos_utilities::{bytes::ToBytes, rand::UniformRand, to_bytes};
+
+use rand::Rng;
+use std::convert::TryFrom;
+
+/// The number of leaves in the local data Merkle tree of a transaction.
+pub const LOCAL_DATA_LEAVES: usize = Components::NUM_INPUT_RECORDS + Components::NUM_OUTPUT_RECORDS;
+
+/// The `[state]`, `[record]` and `[state_leaf]` values of a record in a transaction.
+pub struct LocalData {
+    pub state: StateValues,
+    pub record: DPCRecordValues,
+    pub state_leaf: StateLeafValues,
+}
+
+/// Generates a record owned by the account of the private key from the given fields,
+/// and places its local data commitment at `leaf_index` in a new local data Merkle tree.
+/// The other leaves of the tree commit to random data.
+///
+/// The result passes `verify_record_commitment` and `verify_local_data_commitment`.
+pub fn generate_local_data<R: Rng>(
+    system_parameters: &SystemParameters<Components>,
+    account_private_key: &AccountPrivateKey<Components>,
+    fields: &LocalDataFields,
+    rng: &mut R,
+) -> Result<LocalData, LocalDataGenerationError> {
+    fields.check_lengths()?;
+
+    let leaf_index = fields.leaf_index as usize;
+    if leaf_index >= LOCAL_DATA_LEAVES {
+        return Err(LocalDataGenerationError::InvalidLeafIndex(
+            fields.leaf_index,
+            LOCAL_DATA_LEAVES,
+        ));
+    }
+
+    // Derive the record owner from the private key
+    let owner = AccountAddress::<Components>::from_private_key(
+        &system_parameters.account_signature,
+        &system_parameters.account_commitment,
+        &system_parameters.account_encryption,
+        account_private_key,
+    )?;
+
+    // Generate the record and its commitment
+    let sn_nonce_input: [u8; 32] = rng.gen();
+    let sn_nonce = SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &sn_nonce_input)?;
+    let record = DPC::<Components>::generate_record(
+        system_parameters,
+        &sn_nonce,
+        &owner,
+        fields.is_dummy,
+        fields.value,
+        &RecordPayload::from_bytes(&fields.payload),
+        &fields.birth_program_id,
+        &fields.death_program_id,
+        rng,
+    )?;
+    let (serial_number, _randomizer) = DPC::<Components>::generate_sn(system_parameters, &record, account_private_key)?;
+
+    let serial_number = to_bytes![serial_number]?;
+    let commitment = to_bytes![record.commitment()]?;
+
+    // Input records commit to their serial number, output records do not
+    let is_death = leaf_index < Components::NUM_INPUT_RECORDS;
+    let input_bytes = if is_death {
+        to_bytes![serial_number, commitment, fields.memo, fields.network_id]?
+    } else {
+        to_bytes![commitment, fields.memo, fields.network_id]?
+    };
+
+    // Commit to the local data of the record and of random placeholder records
+    let mut leaves = vec![];
+    let mut leaf_randomness = vec![];
+    for index in 0..LOCAL_DATA_LEAVES {
+        let randomness = <LocalDataCommitment as CommitmentScheme>::Randomness::rand(rng);
+
+        let leaf_input = if index == leaf_index {
+            input_bytes.clone()
+        } else {
+            (0..input_bytes.len()).map(|_| rng.gen()).collect::<Vec<u8>>()
+        };
+
+        let leaf = LocalDataCommitment::commit(&system_parameters.local_data_commitment, &leaf_input, &randomness)?;
+
+        if index == leaf_index {
+            leaf_randomness = to_bytes![randomness]?;
+        }
+        leaves.push(leaf);
+    }
+
+    let leaves = <[_; LOCAL_DATA_LEAVES]>::try_from(leaves)
+        .map_err(|leaves: Vec<_>| LocalDataGenerationError::InvalidLeafCount(LOCAL_DATA_LEAVES, leaves.len()))?;
+    let local_data_merkle_tree = CommitmentMerkleTree::<LocalDataCommitment, LocalDataCRH>::new(
+        system_parameters.local_data_crh.clone(),
+        &leaves,
+    )?;
+    let path = local_data_merkle_tree.generate_proof(&leaves[leaf_index])?;
+
+    Ok(LocalData {
+        state: StateValues {
+            leaf_index: fields.leaf_index,
+            root: to_bytes![local_data_merkle_tree.root()]?,
+        },
+        record: DPCRecordValues {
+            serial_number,
+            owner,
+            is_dummy: fields.is_dummy,
+            value: fields.value,
+            payload: fields.payload.clone(),
+            birth_program_id: fields.birth_program_id.clone(),
+            death_program_id: fields.death_program_id.clone(),
+            serial_number_nonce: to_bytes![record.serial_number_nonce()]?,
+            commitment,
+            commitment_randomness: to_bytes![record.commitment_randomness()]?,
+        },
+        state_leaf: StateLeafValues {
+            path: to_bytes![path]?,
+            memo: fields.memo.clone(),
+            network_id: fields.network_id,
+            leaf_randomness,
+        },
+    })
+}
+
+impl LocalData {
+    /// Returns the local data in the `.state` file format.
+    pub fn to_state_string(&self, package_name: &str) -> String {
+        let record = &self.record;
+
+        format!(
+            r#"// The program state for {}/src/main.leo
+[[public]]
+
+[state]
+leaf_index: u32 = {};
+root: {};
+
+[[private]]
+
+[record]
+serial_number: {};
+commitment: {};
+owner: address = {};
+is_dummy: bool = {};
+value: u64 = {};
+payload: {};
+birth_program_id: {};
+death_program_id: {};
+serial_number_nonce: {};
+commitment_randomness: {};
+
+[state_leaf]
+path: {};
+memo: {};
+network_id: u8 = {};
+leaf_randomness: {};
+"#,
+            package_name,
+            self.state.leaf_index,
+            bytes_definition(&self.state.root),
+            bytes_definition(&record.serial_number),
+            bytes_definition(&record.commitment),
+            record.owner,
+            record.is_dummy,
+            record.value,
+            bytes_definition(&record.payload),
+            bytes_definition(&record.birth_program_id),
+            bytes_definition(&record.death_program_id),
+            bytes_definition(&record.serial_number_nonce),
+            bytes_definition(&record.commitment_randomness),
+            bytes_definition(&self.state_leaf.path),
+            bytes_definition(&self.state_leaf.memo),
+            self.state_leaf.network_id,
+            bytes_definition(&self.state_leaf.leaf_randomness),
+        )
+    }
+}
+
+/// Returns the type and value of a byte array definition, like `[u8; 2] = [1, 2]`.
+fn bytes_definition(bytes: &[u8]) -> String {
+    let values = bytes.iter().map(|byte| byte.to_string()).collect::<Vec<_>>().join(", ");
+
+    format!("[u8; {}] = [{}]", bytes.len(), values)
+}
@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{find_input, input_to_integer_string, input_to_u8_vec, LocalDataGenerationError};
+use leo_typed::Input as TypedInput;
+
+use std::convert::TryFrom;
+
+static LEAF_INDEX_PARAMETER_STRING: &str = "leaf_index";
+static IS_DUMMY_PARAMETER_STRING: &str = "is_dummy";
+static VALUE_PARAMETER_STRING: &str = "value";
+static PAYLOAD_PARAMETER_STRING: &str = "payload";
+static BIRTH_PROGRAM_ID_PARAMETER_STRING: &str = "birth_program_id";
+static DEATH_PROGRAM_ID_PARAMETER_STRING: &str = "death_program_id";
+static MEMO_PARAMETER_STRING: &str = "memo";
+static NETWORK_ID_PARAMETER_STRING: &str = "network_id";
+
+pub const PAYLOAD_SIZE: usize = 32;
+pub const PROGRAM_ID_SIZE: usize = 48;
+pub const MEMO_SIZE: usize = 32;
+
+/// The values of a record and its local data leaf that are chosen by the program author.
+/// Every other value of the `.state` file is derived from them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalDataFields {
+    pub leaf_index: u32,
+    pub is_dummy: bool,
+    pub value: u64,
+    pub payload: Vec<u8>,
+    pub birth_program_id: Vec<u8>,
+    pub death_program_id: Vec<u8>,
+    pub memo: Vec<u8>,
+    pub network_id: u8,
+}
+
+impl Default for LocalDataFields {
+    fn default() -> Self {
+        Self {
+            leaf_index: 0,
+            is_dummy: false,
+            value: 0,
+            payload: vec![0; PAYLOAD_SIZE],
+            birth_program_id: vec![0; PROGRAM_ID_SIZE],
+            death_program_id: vec![0; PROGRAM_ID_SIZE],
+            memo: vec![0; MEMO_SIZE],
+            network_id: 0,
+        }
+    }
+}
+
+impl LocalDataFields {
+    /// Returns an error if a byte array field does not have the length of its DPC counterpart.
+    pub fn check_lengths(&self) -> Result<(), LocalDataGenerationError> {
+        let fields = [
+            (PAYLOAD_PARAMETER_STRING, &self.payload, PAYLOAD_SIZE),
+            (
+                BIRTH_PROGRAM_ID_PARAMETER_STRING,
+                &self.birth_program_id,
+                PROGRAM_ID_SIZE,
+            ),
+            (
+                DEATH_PROGRAM_ID_PARAMETER_STRING,
+                &self.death_program_id,
+                PROGRAM_ID_SIZE,
+            ),
+            (MEMO_PARAMETER_STRING, &self.memo, MEMO_SIZE),
+        ];
+
+        for (name, bytes, size) in fields.iter() {
+            if bytes.len() != *size {
+                return Err(LocalDataGenerationError::InvalidLength(
+                    name.to_string(),
+                    *size,
+                    bytes.len(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&TypedInput> for LocalDataFields {
+    type Error = LocalDataGenerationError;
+
+    /// Reads the fields from the `[state]`, `[record]` and `[state_leaf]` sections of a parsed `.state` file.
+    fn try_from(input: &TypedInput) -> Result<Self, Self::Error> {
+        let state = input.get_state().values();
+        let record = input.get_record().values();
+        let state_leaf = input.get_state_leaf().values();
+
+        // Lookup leaf index
+        let leaf_index_value = find_input(LEAF_INDEX_PARAMETER_STRING.to_owned(), &state)?;
+        let leaf_index = input_to_integer_string(leaf_index_value)?.parse::<u32>()?;
+
+        // Lookup record is_dummy
+        let is_dummy_value = find_input(IS_DUMMY_PARAMETER_STRING.to_owned(), &record)?;
+        let is_dummy = is_dummy_value.to_string().parse::<bool>()?;
+
+        // Lookup record value
+        let value_value = find_input(VALUE_PARAMETER_STRING.to_owned(), &record)?;
+        let value = input_to_integer_string(value_value)?.parse::<u64>()?;
+
+        // Lookup record payload
+        let payload_value = find_input(PAYLOAD_PARAMETER_STRING.to_owned(), &record)?;
+        let payload = input_to_u8_vec(payload_value)?;
+
+        // Lookup record birth program id
+        let birth_program_id_value = find_input(BIRTH_PROGRAM_ID_PARAMETER_STRING.to_owned(), &record)?;
+        let birth_program_id = input_to_u8_vec(birth_program_id_value)?;
+
+        // Lookup record death program id
+        let death_program_id_value = find_input(DEATH_PROGRAM_ID_PARAMETER_STRING.to_owned(), &record)?;
+        let death_program_id = input_to_u8_vec(death_program_id_value)?;
+
+        // Lookup memo
+        let memo_value = find_input(MEMO_PARAMETER_STRING.to_owned(), &state_leaf)?;
+        let memo = input_to_u8_vec(memo_value)?;
+
+        // Lookup network id
+        let network_id_value = find_input(NETWORK_ID_PARAMETER_STRING.to_owned(), &state_leaf)?;
+        let network_id = input_to_integer_string(network_id_value)?.parse::<u8>()?;
+
+        Ok(Self {
+            leaf_index,
+            is_dummy,
+            value,
+            payload,
+            birth_program_id,
+            death_program_id,
+            memo,
+            network_id,
+        })
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_input::LeoInputParser;
+use leo_state::{generate_local_data, verify_local_data_commitment, LocalDataFields};
+use leo_typed::Input;
+
+use snarkos_dpc::base_dpc::instantiated::*;
+use snarkos_models::{dpc::DPCScheme, objects::AccountScheme};
+use snarkos_objects::Account;
+
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn test_generate_local_data() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // Generate parameters for the record commitment scheme
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+
+    let account = Account::new(
+        &system_parameters.account_signature,
+        &system_parameters.account_commitment,
+        &system_parameters.account_encryption,
+        &mut rng,
+    )
+    .unwrap();
+
+    // Generate an input record and an output record
+    for leaf_index in [0, 3].iter() {
+        let fields = LocalDataFields {
+            leaf_index: *leaf_index,
+            value: 10,
+            memo: vec![1; 32],
+            ..LocalDataFields::default()
+        };
+
+        let local_data = generate_local_data(&system_parameters, &account.private_key, &fields, &mut rng).unwrap();
+
+        // Parse the generated state file
+        let file_string = local_data.to_state_string("test");
+        let file = LeoInputParser::parse_file(&file_string).unwrap();
+
+        let mut program_input = Input::new();
+        program_input.parse_state(file).unwrap();
+
+        // check the record commitment and the local data commitment are consistent
+        assert!(verify_local_data_commitment(&system_parameters, &program_input).unwrap());
+    }
+}
+
+#[test]
+fn test_generate_local_data_invalid_leaf_index() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+
+    let account = Account::new(
+        &system_parameters.account_signature,
+        &system_parameters.account_commitment,
+        &system_parameters.account_encryption,
+        &mut rng,
+    )
+    .unwrap();
+
+    let fields = LocalDataFields {
+        leaf_index: 4,
+        ..LocalDataFields::default()
+    };
+
+    assert!(generate_local_data(&system_parameters, &account.private_key, &fields, &mut rng).is_err());
+}
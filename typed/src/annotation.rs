@@ -14,53 +14,77 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Circuit, Function, Identifier, Import, InputVariable, TestFunction};
+use crate::{Circuit, Enum, Function, Identifier, Import, InputVariable, TestFunction};
 use leo_ast::{
-    annotations::{Annotation, AnnotationArguments, AnnotationName},
+    annotations::{Annotation, AnnotationArgument, AnnotationName},
     definitions::{AnnotatedDefinition, Definition},
 };
 
 use std::collections::HashMap;
 
+/// The number of generated inputs for a test with a `@fuzz` annotation and no `iterations` argument.
+pub const DEFAULT_FUZZ_ITERATIONS: usize = 256;
+
 pub fn load_annotation(
     annotated_definition: AnnotatedDefinition,
     _imports: &mut Vec<Import>,
     _circuits: &mut HashMap<Identifier, Circuit>,
+    enums: &mut HashMap<Identifier, Enum>,
     _functions: &mut HashMap<Identifier, Function>,
     tests: &mut HashMap<Identifier, TestFunction>,
     _expected: &mut Vec<InputVariable>,
 ) {
-    let ast_annotation = annotated_definition.annotation;
-    let ast_definition = *annotated_definition.definition;
+    // Collect nested annotations of the same definition
+    let mut ast_annotations = vec![annotated_definition.annotation];
+    let mut ast_definition = *annotated_definition.definition;
+
+    while let Definition::Annotated(nested) = ast_definition {
+        ast_annotations.push(nested.annotation);
+        ast_definition = *nested.definition;
+    }
 
     match ast_definition {
         Definition::Import(_) => unimplemented!("annotated imports are not supported yet"),
         Definition::Circuit(_) => unimplemented!("annotated circuits are not supported yet"),
+        // Annotations only apply to tests, so annotated enums are loaded without them
+        Definition::Enum(ast_enum) => {
+            enums.insert(Identifier::from(ast_enum.identifier.clone()), Enum::from(ast_enum));
+        }
         Definition::Function(_) => unimplemented!("annotated functions are not supported yet"),
         Definition::TestFunction(ast_test) => {
-            let test = TestFunction::from(ast_test);
-            load_annotated_test(test, ast_annotation, tests)
+            let mut test = TestFunction::from(ast_test);
+
+            for ast_annotation in ast_annotations {
+                test = load_annotated_test(test, ast_annotation);
+            }
+
+            if test.should_fail && test.fuzz_iterations.is_some() {
+                panic!("test should_fail annotation cannot be combined with a fuzz annotation")
+            }
+
+            tests.insert(test.function.identifier.clone(), test);
         }
-        Definition::Annotated(_) => unimplemented!("nested annotations are not supported yet"),
+        Definition::Annotated(_) => unreachable!("nested annotations are collected above"),
     }
 }
 
-pub fn load_annotated_test(test: TestFunction, annotation: Annotation, tests: &mut HashMap<Identifier, TestFunction>) {
+pub fn load_annotated_test(test: TestFunction, annotation: Annotation) -> TestFunction {
     let name = annotation.name;
-    let ast_arguments = annotation.arguments;
+    let arguments = annotation
+        .arguments
+        .map(|ast_arguments| ast_arguments.arguments)
+        .unwrap_or_default();
 
     match name {
-        AnnotationName::Context(_) => load_annotated_test_context(test, ast_arguments, tests),
+        AnnotationName::Context(_) => load_annotated_test_context(test, arguments),
+        AnnotationName::Fuzz(_) => load_annotated_test_fuzz(test, arguments),
+        AnnotationName::Ignore(_) => load_annotated_test_ignore(test, arguments),
+        AnnotationName::Inputs(_) => load_annotated_test_inputs(test, arguments),
+        AnnotationName::ShouldFail(_) => load_annotated_test_should_fail(test, arguments),
     }
 }
 
-pub fn load_annotated_test_context(
-    mut test: TestFunction,
-    ast_arguments: AnnotationArguments,
-    tests: &mut HashMap<Identifier, TestFunction>,
-) {
-    let arguments = ast_arguments.arguments;
-
+pub fn load_annotated_test_context(mut test: TestFunction, arguments: Vec<AnnotationArgument>) -> TestFunction {
     if arguments.len() != 1 {
         panic!("text context annotation must have one argument identifier")
     }
@@ -70,5 +94,68 @@ pub fn load_annotated_test_context(
 
     test.input_file = Some(input_file);
 
-    tests.insert(test.function.identifier.clone(), test);
+    test
+}
+
+pub fn load_annotated_test_fuzz(mut test: TestFunction, arguments: Vec<AnnotationArgument>) -> TestFunction {
+    let mut iterations = DEFAULT_FUZZ_ITERATIONS;
+
+    for argument in arguments {
+        let (name, value) = annotation_key_value(&argument.value);
+
+        match (name, value) {
+            ("iterations", Some(value)) => {
+                iterations = value
+                    .parse::<usize>()
+                    .unwrap_or_else(|_| panic!("test fuzz annotation iterations must be a number, found `{}`", value))
+            }
+            _ => panic!("unknown test fuzz annotation argument `{}`", argument.value),
+        }
+    }
+
+    test.fuzz_iterations = Some(iterations);
+
+    test
+}
+
+pub fn load_annotated_test_ignore(mut test: TestFunction, arguments: Vec<AnnotationArgument>) -> TestFunction {
+    if !arguments.is_empty() {
+        panic!("test ignore annotation must not have arguments")
+    }
+
+    test.ignore = true;
+
+    test
+}
+
+pub fn load_annotated_test_inputs(mut test: TestFunction, arguments: Vec<AnnotationArgument>) -> TestFunction {
+    if test.inputs.is_some() {
+        panic!("test inputs annotation must only be declared once")
+    }
+
+    // Each argument is a main input declaration `name: type = value`
+    let inputs = arguments.into_iter().map(|argument| argument.value).collect();
+
+    test.inputs = Some(inputs);
+
+    test
+}
+
+pub fn load_annotated_test_should_fail(mut test: TestFunction, arguments: Vec<AnnotationArgument>) -> TestFunction {
+    if !arguments.is_empty() {
+        panic!("test should_fail annotation must not have arguments")
+    }
+
+    test.should_fail = true;
+
+    test
+}
+
+/// Splits an annotation argument `name = value` into its name and value.
+fn annotation_key_value(argument: &str) -> (&str, Option<&str>) {
+    let mut parts = argument.splitn(2, '=');
+    let name = parts.next().unwrap_or_default().trim();
+    let value = parts.next().map(|value| value.trim());
+
+    (name, value)
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CircuitMember, Identifier};
+use crate::{CircuitMember, ConstParameter, Identifier};
 use leo_ast::circuits::Circuit as AstCircuit;
 
 use serde::{Deserialize, Serialize};
@@ -23,25 +23,49 @@ use std::fmt;
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Circuit {
     pub circuit_name: Identifier,
+    pub const_parameters: Vec<ConstParameter>,
     pub members: Vec<CircuitMember>,
 }
 
 impl<'ast> From<AstCircuit<'ast>> for Circuit {
     fn from(circuit: AstCircuit<'ast>) -> Self {
         let circuit_name = Identifier::from(circuit.identifier);
+        let const_parameters = circuit
+            .const_parameters
+            .into_iter()
+            .map(|parameter| ConstParameter::from(parameter))
+            .collect();
         let members = circuit
             .members
             .into_iter()
             .map(|member| CircuitMember::from(member))
             .collect();
 
-        Self { circuit_name, members }
+        Self {
+            circuit_name,
+            const_parameters,
+            members,
+        }
     }
 }
 
 impl Circuit {
+    pub fn is_generic(&self) -> bool {
+        !self.const_parameters.is_empty()
+    }
+
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "circuit {} {{ \n", self.circuit_name)?;
+        write!(f, "circuit {}", self.circuit_name)?;
+        if self.is_generic() {
+            let const_parameters = self
+                .const_parameters
+                .iter()
+                .map(|x| format!("{}", x))
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "<{}>", const_parameters)?;
+        }
+        write!(f, " {{ \n")?;
         for field in self.members.iter() {
             write!(f, "    {}\n", field)?;
         }
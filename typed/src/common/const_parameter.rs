@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, IntegerType, Span};
+use leo_ast::common::ConstParameter as AstConstParameter;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A const generic parameter of a function or circuit, e.g. `const N: u32`
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConstParameter {
+    pub identifier: Identifier,
+    pub type_: IntegerType,
+    pub span: Span,
+}
+
+impl<'ast> From<AstConstParameter<'ast>> for ConstParameter {
+    fn from(parameter: AstConstParameter<'ast>) -> Self {
+        ConstParameter {
+            identifier: Identifier::from(parameter.identifier),
+            type_: IntegerType::from(parameter.type_),
+            span: Span::from(parameter.span),
+        }
+    }
+}
+
+impl ConstParameter {
+    fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "const {}: {}", self.identifier, self.type_)
+    }
+}
+
+impl fmt::Display for ConstParameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.format(f)
+    }
+}
+
+impl fmt::Debug for ConstParameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.format(f)
+    }
+}
@@ -17,6 +17,9 @@
 pub mod assignee;
 pub use assignee::*;
 
+pub mod const_parameter;
+pub use const_parameter::*;
+
 pub mod declare;
 pub use declare::*;
 
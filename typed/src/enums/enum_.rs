@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{EnumVariant, Identifier};
+use leo_ast::enums::Enum as AstEnum;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Enum {
+    pub enum_name: Identifier,
+    pub variants: Vec<EnumVariant>,
+}
+
+impl<'ast> From<AstEnum<'ast>> for Enum {
+    fn from(enum_: AstEnum<'ast>) -> Self {
+        let enum_name = Identifier::from(enum_.identifier);
+        let variants = enum_
+            .variants
+            .into_iter()
+            .map(|variant| EnumVariant::from(variant))
+            .collect();
+
+        Self { enum_name, variants }
+    }
+}
+
+impl Enum {
+    /// Returns the position of the variant with the given name, which is used as its tag.
+    pub fn variant_index(&self, variant: &Identifier) -> Option<usize> {
+        self.variants.iter().position(|defined| defined.identifier.name == variant.name)
+    }
+
+    fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "enum {} {{ \n", self.enum_name)?;
+        for variant in self.variants.iter() {
+            write!(f, "    {},\n", variant)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl fmt::Debug for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.format(f)
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, Type};
+use leo_ast::enums::EnumVariant as AstEnumVariant;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnumVariant {
+    pub identifier: Identifier,
+    pub types: Vec<Type>,
+}
+
+impl<'ast> From<AstEnumVariant<'ast>> for EnumVariant {
+    fn from(variant: AstEnumVariant<'ast>) -> Self {
+        Self {
+            identifier: Identifier::from(variant.identifier),
+            types: variant.types.into_iter().map(|type_| Type::from(type_)).collect(),
+        }
+    }
+}
+
+impl fmt::Display for EnumVariant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.identifier)?;
+        if !self.types.is_empty() {
+            let types = self.types.iter().map(|x| format!("{}", x)).collect::<Vec<_>>().join(", ");
+
+            write!(f, "({})", types)?;
+        }
+        Ok(())
+    }
+}
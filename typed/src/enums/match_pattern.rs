@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, Span};
+use leo_ast::enums::MatchPattern as AstMatchPattern;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchPattern {
+    // (enum_name, variant_name, payload_bindings, span)
+    Variant(Identifier, Identifier, Vec<Identifier>, Span),
+    Wildcard(Span),
+}
+
+impl MatchPattern {
+    pub fn span(&self) -> &Span {
+        match self {
+            MatchPattern::Variant(_, _, _, span) => span,
+            MatchPattern::Wildcard(span) => span,
+        }
+    }
+}
+
+impl<'ast> From<AstMatchPattern<'ast>> for MatchPattern {
+    fn from(pattern: AstMatchPattern<'ast>) -> Self {
+        match pattern {
+            AstMatchPattern::Wildcard(wildcard) => MatchPattern::Wildcard(Span::from(wildcard.span)),
+            AstMatchPattern::Variant(variant) => MatchPattern::Variant(
+                Identifier::from(variant.enum_name),
+                Identifier::from(variant.variant),
+                variant
+                    .bindings
+                    .into_iter()
+                    .map(|binding| Identifier::from(binding))
+                    .collect(),
+                Span::from(variant.span),
+            ),
+        }
+    }
+}
+
+impl fmt::Display for MatchPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MatchPattern::Variant(ref enum_name, ref variant, ref bindings, ref _span) => {
+                write!(f, "{}::{}", enum_name, variant)?;
+                if !bindings.is_empty() {
+                    let bindings = bindings.iter().map(|x| format!("{}", x)).collect::<Vec<_>>().join(", ");
+
+                    write!(f, "({})", bindings)?;
+                }
+                Ok(())
+            }
+            MatchPattern::Wildcard(ref _span) => write!(f, "_"),
+        }
+    }
+}
+
+impl fmt::Debug for MatchPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
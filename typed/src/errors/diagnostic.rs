@@ -0,0 +1,257 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Error, Span};
+use leo_ast::{ParserError, SyntaxError};
+use leo_input::{InputParserError, SyntaxError as InputSyntaxError};
+
+use pest::{
+    error::{Error as PestError, LineColLocation},
+    RuleType,
+};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Target of the tracing events that carry a diagnostic as a json string.
+/// Subscribers print these events for `--message-format json` and skip them otherwise.
+pub const DIAGNOSTIC_TARGET: &str = "leo_diagnostic";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// Line and column range of a diagnostic. Lines and columns start at 1.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticRange {
+    pub line_start: usize,
+    pub column_start: usize,
+    pub line_end: usize,
+    pub column_end: usize,
+}
+
+impl From<&Span> for DiagnosticRange {
+    fn from(span: &Span) -> Self {
+        Self {
+            line_start: span.line,
+            column_start: span.start,
+            line_end: span.line,
+            column_end: span.end,
+        }
+    }
+}
+
+/// A secondary location that explains a diagnostic.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelatedSpan {
+    pub file: Option<String>,
+    pub range: DiagnosticRange,
+    pub message: String,
+}
+
+/// Machine-readable compiler error or warning
+///
+/// {"severity":"error","code":"E0325","message":"undefined value `x`","file":"src/main.leo",
+///  "range":{"line_start":2,"column_start":9,"line_end":2,"column_end":10},"related":[]}
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: Option<String>,
+    pub message: String,
+    pub file: Option<String>,
+    pub range: Option<DiagnosticRange>,
+    pub related: Vec<RelatedSpan>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: String) -> Self {
+        Self {
+            severity,
+            code: None,
+            message,
+            file: None,
+            range: None,
+            related: vec![],
+        }
+    }
+
+    pub fn error(message: String) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    pub fn warning(message: String) -> Self {
+        Self::new(Severity::Warning, message)
+    }
+
+    /// Sets the stable error code of the diagnostic.
+    pub fn with_code(mut self, code: &str) -> Self {
+        self.code = Some(code.to_owned());
+        self
+    }
+
+    /// Sets the file of the diagnostic if it is not known yet.
+    pub fn set_path(&mut self, path: PathBuf) {
+        if self.file.is_none() {
+            self.file = Some(path.to_string_lossy().to_string());
+        }
+    }
+
+    pub fn add_related(&mut self, related: RelatedSpan) {
+        self.related.push(related);
+    }
+
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string(self).expect("diagnostics are always serializable")
+    }
+
+    /// Emits the diagnostic as a tracing event with the `DIAGNOSTIC_TARGET` target.
+    pub fn emit(&self) {
+        match self.severity {
+            Severity::Error => tracing::error!(target: DIAGNOSTIC_TARGET, "{}", self.to_json_string()),
+            Severity::Warning => tracing::warn!(target: DIAGNOSTIC_TARGET, "{}", self.to_json_string()),
+        }
+    }
+
+    /// Converts a parser error into one diagnostic for each of its syntax errors.
+    pub fn from_parser_error(error: &ParserError) -> Vec<Self> {
+        match error {
+            ParserError::SyntaxErrors(errors) => errors
+                .iter()
+                .map(|syntax_error| match syntax_error {
+                    SyntaxError::Error(pest_error) => Diagnostic::from_pest_error(pest_error).with_code(error.code()),
+                })
+                .collect(),
+            _ => vec![Diagnostic::from(error)],
+        }
+    }
+
+    /// Converts a pest syntax error into a diagnostic.
+    fn from_pest_error<R: RuleType>(error: &PestError<R>) -> Self {
+        let range = match error.line_col {
+            LineColLocation::Pos((line, column)) => DiagnosticRange {
+                line_start: line,
+                column_start: column,
+                line_end: line,
+                column_end: column + 1,
+            },
+            LineColLocation::Span((line_start, column_start), (line_end, column_end)) => DiagnosticRange {
+                line_start,
+                column_start,
+                line_end,
+                column_end,
+            },
+        };
+
+        // The pest error formatter is the only way to read the message and path of the error
+        //   --> path:line:column
+        //   ...
+        //   = message
+        let formatted = error.to_string();
+        let file = formatted.lines().next().and_then(|location| {
+            let location = location.trim().trim_start_matches("-->").trim();
+            let mut parts = location.rsplitn(3, ':');
+
+            parts.nth(2).map(|path| path.to_owned())
+        });
+        let message = formatted
+            .lines()
+            .last()
+            .map(|line| line.trim().trim_start_matches('=').trim().to_owned())
+            .unwrap_or_else(|| formatted.clone());
+
+        Self {
+            file,
+            range: Some(range),
+            ..Self::error(message)
+        }
+    }
+}
+
+impl From<&Error> for Diagnostic {
+    fn from(error: &Error) -> Self {
+        // The error stores a debug formatted path
+        let file = error.path.as_ref().map(|path| path.trim_matches('"').to_owned());
+
+        Self {
+            code: Some(error.code.to_owned()),
+            file,
+            range: Some(DiagnosticRange {
+                line_start: error.line,
+                column_start: error.start,
+                line_end: error.line,
+                column_end: error.end,
+            }),
+            ..Self::error(error.message.clone())
+        }
+    }
+}
+
+impl From<&ParserError> for Diagnostic {
+    fn from(error: &ParserError) -> Self {
+        match error {
+            ParserError::SyntaxError(SyntaxError::Error(error)) => Diagnostic::from_pest_error(error),
+            ParserError::SyntaxErrors(errors) if !errors.is_empty() => match &errors[0] {
+                SyntaxError::Error(error) => Diagnostic::from_pest_error(error),
+            },
+            error => Diagnostic::error(error.to_string()),
+        }
+        .with_code(error.code())
+    }
+}
+
+impl From<&InputParserError> for Diagnostic {
+    fn from(error: &InputParserError) -> Self {
+        match error {
+            InputParserError::Error(code, InputSyntaxError::Error(error)) => {
+                let mut diagnostic = Diagnostic::from_pest_error(error);
+
+                // The code is already part of the formatted pest message
+                let prefix = format!("[{}] ", code);
+                if diagnostic.message.starts_with(&prefix) {
+                    diagnostic.message = diagnostic.message[prefix.len()..].to_owned();
+                }
+
+                diagnostic
+            }
+            InputParserError::SyntaxError(InputSyntaxError::Error(error)) => Diagnostic::from_pest_error(error),
+            error => Diagnostic::error(error.to_string()),
+        }
+        .with_code(error.code())
+    }
+}
+
+#[test]
+fn test_diagnostic() {
+    let err = Error {
+        code: "E0325",
+        path: Some("\"file.leo\"".to_string()),
+        line: 2,
+        start: 8,
+        end: 9,
+        text: "let a = x;".to_string(),
+        message: "undefined value `x`".to_string(),
+    };
+
+    assert_eq!(
+        Diagnostic::from(&err).to_json_string(),
+        concat!(
+            r#"{"severity":"error","code":"E0325","message":"undefined value `x`","file":"file.leo","#,
+            r#""range":{"line_start":2,"column_start":8,"line_end":2,"column_end":9},"related":[]}"#
+        )
+    );
+}
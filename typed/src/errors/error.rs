@@ -26,9 +26,11 @@ pub const INDENT: &'static str = "    ";
 ///    2 | let a = x;
 ///      |         ^
 ///      |
-///      = undefined value `x`
+///      = [E0325] undefined value `x`
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Error {
+    /// Stable error code that is explained by `leo explain`
+    pub code: &'static str,
     /// File path where error occurred
     pub path: Option<String>,
     /// Line number
@@ -44,8 +46,9 @@ pub struct Error {
 }
 
 impl Error {
-    pub fn new_from_span(message: String, span: Span) -> Self {
+    pub fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
         Self {
+            code,
             path: None,
             line: span.line,
             start: span.start,
@@ -55,8 +58,9 @@ impl Error {
         }
     }
 
-    pub fn new_from_span_with_path(message: String, span: Span, path: PathBuf) -> Self {
+    pub fn new_from_span_with_path(code: &'static str, message: String, span: Span, path: PathBuf) -> Self {
         Self {
+            code,
             path: Some(format!("{:?}", path)),
             line: span.line,
             start: span.start,
@@ -80,7 +84,7 @@ impl Error {
              {line:width$} | {text}\n\
              {indent     } | {underline}\n\
              {indent     } |\n\
-             {indent     } = {message}",
+             {indent     } = [{code}] {message}",
             indent = INDENT,
             width = INDENT.len(),
             path = path,
@@ -88,6 +92,7 @@ impl Error {
             start = self.start,
             text = self.text,
             underline = underline,
+            code = self.code,
             message = self.message,
         )
     }
@@ -129,6 +134,7 @@ impl std::error::Error for Error {
 #[test]
 fn test_error() {
     let err = Error {
+        code: "E0325",
         path: Some("file.leo".to_string()),
         line: 2,
         start: 8,
@@ -145,7 +151,7 @@ fn test_error() {
             "   2 | let a = x;",
             "     |         ^",
             "     |",
-            "     = undefined value `x`",
+            "     = [E0325] undefined value `x`",
         ]
         .join("\n")
     );
@@ -16,3 +16,6 @@
 
 pub mod error;
 pub use error::*;
+
+pub mod diagnostic;
+pub use diagnostic::*;
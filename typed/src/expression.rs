@@ -19,6 +19,7 @@ use crate::{
     GroupValue,
     Identifier,
     IntegerType,
+    MatchArm,
     RangeOrExpression,
     Span,
     SpreadOrExpression,
@@ -32,6 +33,7 @@ use leo_ast::{
         BinaryExpression,
         CircuitInlineExpression,
         Expression as AstExpression,
+        MatchExpression,
         PostfixExpression,
         TernaryExpression,
         UnaryExpression,
@@ -50,7 +52,10 @@ use leo_ast::{
 };
 use leo_input::{types::ArrayDimensions as InputArrayDimensions, values::PositiveNumber as InputAstPositiveNumber};
 
-use leo_ast::{expressions::TupleExpression, types::ArrayDimensions};
+use leo_ast::{
+    expressions::TupleExpression,
+    types::{ArrayDimensions, ArrayTypeDimensions},
+};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -89,10 +94,15 @@ pub enum Expression {
     // Conditionals
     // (conditional, first_value, second_value, span)
     IfElse(Box<Expression>, Box<Expression>, Box<Expression>, Span),
+    // (matched_value, arms, span)
+    Match(Box<Expression>, Vec<MatchArm>, Span),
 
     // Arrays
     // (array_elements, span)
     Array(Vec<Box<SpreadOrExpression>>, Span),
+    // An array initializer whose length is a const parameter, expanded when the function is monomorphized
+    // (element, const_parameter, span)
+    GenericArray(Box<Expression>, Identifier, Span),
     // (array_name, range, span)
     ArrayAccess(Box<Expression>, Box<RangeOrExpression>, Span),
 
@@ -139,7 +149,9 @@ impl Expression {
             Expression::Lt(_, _, old_span) => *old_span = new_span.clone(),
 
             Expression::IfElse(_, _, _, old_span) => *old_span = new_span.clone(),
+            Expression::Match(_, _, old_span) => *old_span = new_span.clone(),
             Expression::Array(_, old_span) => *old_span = new_span.clone(),
+            Expression::GenericArray(_, _, old_span) => *old_span = new_span.clone(),
             Expression::ArrayAccess(_, _, old_span) => *old_span = new_span.clone(),
 
             Expression::Tuple(_, old_span) => *old_span = new_span.clone(),
@@ -230,6 +242,11 @@ impl<'ast> fmt::Display for Expression {
             Expression::IfElse(ref first, ref second, ref third, ref _span) => {
                 write!(f, "if {} then {} else {} fi", first, second, third)
             }
+            Expression::Match(ref expression, ref arms, ref _span) => {
+                let arms = arms.iter().map(|x| format!("{}", x)).collect::<Vec<_>>().join(", ");
+
+                write!(f, "match {} {{ {} }}", expression, arms)
+            }
 
             // Arrays
             Expression::Array(ref array, ref _span) => {
@@ -242,6 +259,7 @@ impl<'ast> fmt::Display for Expression {
                 }
                 write!(f, "]")
             }
+            Expression::GenericArray(ref element, ref parameter, ref _span) => write!(f, "[{}; {}]", element, parameter),
             Expression::ArrayAccess(ref array, ref index, ref _span) => write!(f, "{}[{}]", array, index),
 
             // Tuples
@@ -372,6 +390,7 @@ impl<'ast> From<AstExpression<'ast>> for Expression {
             AstExpression::Unary(expression) => Expression::from(expression),
             AstExpression::Binary(expression) => Expression::from(expression),
             AstExpression::Ternary(expression) => Expression::from(expression),
+            AstExpression::Match(expression) => Expression::from(expression),
             AstExpression::ArrayInline(expression) => Expression::from(expression),
             AstExpression::ArrayInitializer(expression) => Expression::from(expression),
             AstExpression::Tuple(expression) => Expression::from(expression),
@@ -500,6 +519,16 @@ impl<'ast> From<TernaryExpression<'ast>> for Expression {
     }
 }
 
+impl<'ast> From<MatchExpression<'ast>> for Expression {
+    fn from(expression: MatchExpression<'ast>) -> Self {
+        Expression::Match(
+            Box::new(Expression::from(*expression.expression)),
+            expression.arms.into_iter().map(|arm| MatchArm::from(arm)).collect(),
+            Span::from(expression.span),
+        )
+    }
+}
+
 impl<'ast> From<ArrayInlineExpression<'ast>> for Expression {
     fn from(array: ArrayInlineExpression<'ast>) -> Self {
         Expression::Array(
@@ -515,7 +544,16 @@ impl<'ast> From<ArrayInlineExpression<'ast>> for Expression {
 
 impl<'ast> From<ArrayInitializerExpression<'ast>> for Expression {
     fn from(array: ArrayInitializerExpression<'ast>) -> Self {
-        let dimensions = Expression::get_array_dimensions(array.dimensions);
+        let dimensions = match array.dimensions {
+            ArrayTypeDimensions::Dimensions(dimensions) => Expression::get_array_dimensions(dimensions),
+            ArrayTypeDimensions::Const(dimension) => {
+                return Expression::GenericArray(
+                    Box::new(Expression::from(*array.expression)),
+                    Identifier::from(dimension.identifier),
+                    Span::from(array.span),
+                );
+            }
+        };
         let expression = Box::new(SpreadOrExpression::from(*array.expression));
 
         let mut elements = vec![];
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Identifier, InputVariable, Span, Statement, Type};
+use crate::{ConstParameter, Identifier, InputVariable, Span, Statement, Type};
 use leo_ast::functions::Function as AstFunction;
 
 use serde::{Deserialize, Serialize};
@@ -23,6 +23,7 @@ use std::fmt;
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Function {
     pub identifier: Identifier,
+    pub const_parameters: Vec<ConstParameter>,
     pub input: Vec<InputVariable>,
     pub returns: Option<Type>,
     pub statements: Vec<Statement>,
@@ -32,6 +33,11 @@ pub struct Function {
 impl<'ast> From<AstFunction<'ast>> for Function {
     fn from(function: AstFunction<'ast>) -> Self {
         let function_name = Identifier::from(function.identifier);
+        let const_parameters = function
+            .const_parameters
+            .into_iter()
+            .map(|parameter| ConstParameter::from(parameter))
+            .collect();
         let parameters = function
             .parameters
             .into_iter()
@@ -46,6 +52,7 @@ impl<'ast> From<AstFunction<'ast>> for Function {
 
         Function {
             identifier: function_name,
+            const_parameters,
             input: parameters,
             returns,
             statements,
@@ -59,8 +66,21 @@ impl Function {
         self.identifier.name.clone()
     }
 
+    pub fn is_generic(&self) -> bool {
+        !self.const_parameters.is_empty()
+    }
+
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "function {}", self.identifier)?;
+        if self.is_generic() {
+            let const_parameters = self
+                .const_parameters
+                .iter()
+                .map(|x| format!("{}", x))
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "<{}>", const_parameters)?;
+        }
         let parameters = self
             .input
             .iter()
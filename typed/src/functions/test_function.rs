@@ -23,13 +23,21 @@ use serde::{Deserialize, Serialize};
 pub struct TestFunction {
     pub function: Function,
     pub input_file: Option<Identifier>,
+    pub fuzz_iterations: Option<usize>,
+    pub inputs: Option<Vec<String>>,
+    pub should_fail: bool,
+    pub ignore: bool,
 }
 
 impl<'ast> From<AstTestFunction<'ast>> for TestFunction {
     fn from(test: AstTestFunction) -> Self {
         TestFunction {
             function: Function::from(test.function),
-            input_file: None, // pass custom input file with `@context` annotation
+            input_file: None,      // pass custom input file with `@context` annotation
+            fuzz_iterations: None, // generate random inputs with `@fuzz` annotation
+            inputs: None,          // pass main function input inline with `@inputs` annotation
+            should_fail: false,    // expect an error or unsatisfied constraints with `@should_fail` annotation
+            ignore: false,         // skip the test with `@ignore` annotation
         }
     }
 }
@@ -16,7 +16,7 @@
 
 use crate::{InputValue, MainInput, ProgramInput, ProgramState, Record, Registers, State, StateLeaf};
 use leo_input::{
-    files::{File, TableOrSection},
+    files::{File, JsonFile, TableOrSection},
     InputParserError,
 };
 
@@ -111,4 +111,34 @@ impl Input {
     pub fn get_state_leaf(&self) -> &StateLeaf {
         self.program_state.get_state_leaf()
     }
+
+    /// Returns the input and state sections in the JSON input file format.
+    pub fn to_json(&self) -> JsonFile {
+        let main = self.program_input.main.to_json();
+
+        JsonFile {
+            main: Some(main).filter(|main| !main.is_empty()),
+            registers: self.get_registers().to_json(),
+            state: self.get_state().to_json(),
+            record: self.get_record().to_json(),
+            state_leaf: self.get_state_leaf().to_json(),
+        }
+    }
+
+    /// Returns the declarations of every input section that determine the shape of the circuit.
+    /// Input values are omitted so that changing them does not invalidate the proving keys.
+    pub fn shape(&self) -> Vec<String> {
+        let sections = vec![
+            ("main", self.program_input.main.shape()),
+            ("registers", self.get_registers().shape()),
+            ("record", self.get_record().shape()),
+            ("state", self.get_state().shape()),
+            ("state_leaf", self.get_state_leaf().shape()),
+        ];
+
+        sections
+            .into_iter()
+            .flat_map(|(section, shape)| shape.into_iter().map(move |line| format!("{}.{}", section, line)))
+            .collect()
+    }
 }
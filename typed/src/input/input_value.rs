@@ -17,11 +17,28 @@
 use crate::{Expression as TypedExpression, GroupValue};
 use leo_input::{
     errors::InputParserError,
-    expressions::{ArrayInitializerExpression, ArrayInlineExpression, Expression, TupleExpression},
-    types::{ArrayType, DataType, IntegerType, TupleType, Type},
-    values::{Address, AddressValue, BooleanValue, FieldValue, GroupValue as InputGroupValue, NumberValue, Value},
+    expressions::{
+        ArrayInitializerExpression,
+        ArrayInlineExpression,
+        CircuitInlineExpression,
+        Expression,
+        TupleExpression,
+    },
+    files::JsonDefinition,
+    types::{ArrayType, CircuitType, DataType, IntegerType, TupleType, Type},
+    values::{
+        Address,
+        AddressValue,
+        BooleanValue,
+        FieldValue,
+        GroupValue as InputGroupValue,
+        IntegerValue,
+        NumberValue,
+        Value,
+    },
 };
 
+use serde_json::Value as JsonValue;
 use std::fmt;
 
 #[derive(Clone, PartialEq, Eq)]
@@ -33,6 +50,7 @@ pub enum InputValue {
     Integer(IntegerType, String),
     Array(Vec<InputValue>),
     Tuple(Vec<InputValue>),
+    Circuit(String, Vec<(String, InputValue)>),
 }
 
 impl InputValue {
@@ -98,10 +116,102 @@ impl InputValue {
                 InputValue::from_array_initializer(array_type, initializer)
             }
             (Type::Tuple(tuple_type), Expression::Tuple(tuple)) => InputValue::from_tuple(tuple_type, tuple),
+            (Type::Circuit(circuit_type), Expression::CircuitInline(inline)) => {
+                InputValue::from_circuit_inline(circuit_type, inline)
+            }
             (type_, expression) => Err(InputParserError::expression_type_mismatch(type_, expression)),
         }
     }
 
+    /// Converts an expression without a declared type, such as a circuit member value.
+    /// The value must carry its own type, so implicitly typed numbers are rejected.
+    fn from_untyped_expression(expression: Expression) -> Result<Self, InputParserError> {
+        match expression {
+            Expression::Value(value) => InputValue::from_untyped_value(value),
+            Expression::ArrayInline(inline) => {
+                let elements = inline
+                    .expressions
+                    .into_iter()
+                    .map(InputValue::from_untyped_expression)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(InputValue::Array(elements))
+            }
+            Expression::ArrayInitializer(initializer) => {
+                let dimensions = TypedExpression::get_input_array_dimensions(initializer.dimensions);
+                let mut value = InputValue::from_untyped_expression(*initializer.expression)?;
+
+                // The innermost dimension is the last one
+                for dimension in dimensions.into_iter().rev() {
+                    value = InputValue::Array(vec![value; dimension]);
+                }
+
+                Ok(value)
+            }
+            Expression::Tuple(tuple) => {
+                let values = tuple
+                    .expressions
+                    .into_iter()
+                    .map(InputValue::from_untyped_expression)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(InputValue::Tuple(values))
+            }
+            Expression::CircuitInline(inline) => InputValue::from_circuit_members(inline),
+        }
+    }
+
+    fn from_untyped_value(value: Value) -> Result<Self, InputParserError> {
+        match value {
+            Value::Address(address) => Ok(InputValue::from_address_value(address)),
+            Value::Boolean(boolean) => InputValue::from_boolean(boolean),
+            Value::Field(field) => Ok(InputValue::from_field(field)),
+            Value::Group(group) => Ok(InputValue::from_group(group)),
+            Value::Integer(integer) => {
+                let integer_type = match &integer {
+                    IntegerValue::Signed(integer) => IntegerType::Signed(integer.type_.clone()),
+                    IntegerValue::Unsigned(integer) => IntegerType::Unsigned(integer.type_.clone()),
+                };
+
+                InputValue::from_number(integer_type, integer.to_string())
+            }
+            Value::Implicit(implicit) => Err(InputParserError::implicit_circuit_member(implicit)),
+        }
+    }
+
+    pub(crate) fn from_circuit_inline(
+        circuit_type: CircuitType,
+        inline: CircuitInlineExpression,
+    ) -> Result<Self, InputParserError> {
+        // Return an error if the circuit literal does not match the declared circuit type.
+        if circuit_type.identifier.value != inline.identifier.value {
+            return Err(InputParserError::expression_type_mismatch(
+                Type::Circuit(circuit_type),
+                Expression::CircuitInline(inline),
+            ));
+        }
+
+        InputValue::from_circuit_members(inline)
+    }
+
+    /// Converts the members of a circuit literal.
+    /// The member types are only known from the circuit definition in the program,
+    /// which checks them when the value is allocated.
+    fn from_circuit_members(inline: CircuitInlineExpression) -> Result<Self, InputParserError> {
+        let mut members: Vec<(String, InputValue)> = vec![];
+        for member in inline.members.into_iter() {
+            if members.iter().any(|(name, _)| name == &member.identifier.value) {
+                return Err(InputParserError::duplicate_circuit_member(member.identifier));
+            }
+
+            let value = InputValue::from_untyped_expression(member.expression)?;
+
+            members.push((member.identifier.value, value))
+        }
+
+        Ok(InputValue::Circuit(inline.identifier.value, members))
+    }
+
     pub(crate) fn from_array_inline(
         mut array_type: ArrayType,
         inline: ArrayInlineExpression,
@@ -238,6 +348,103 @@ impl InputValue {
 
         Ok(InputValue::Tuple(values))
     }
+
+    /// Returns the type of this value with array lengths but without the value itself.
+    /// Two values with the same shape are allocated into the same circuit variables.
+    pub fn shape(&self) -> String {
+        match self {
+            InputValue::Address(_) => "address".to_string(),
+            InputValue::Boolean(_) => "bool".to_string(),
+            InputValue::Field(_) => "field".to_string(),
+            InputValue::Group(_) => "group".to_string(),
+            InputValue::Integer(type_, _) => type_.to_string(),
+            InputValue::Array(array) => {
+                let element = array
+                    .first()
+                    .map(|value| value.shape())
+                    .unwrap_or_else(|| "_".to_string());
+
+                format!("[{}; {}]", element, array.len())
+            }
+            InputValue::Tuple(tuple) => {
+                let values = tuple.iter().map(|value| value.shape()).collect::<Vec<_>>().join(", ");
+
+                format!("({})", values)
+            }
+            InputValue::Circuit(name, members) => {
+                // Circuit members are allocated in the order of the circuit definition
+                let mut members = members
+                    .iter()
+                    .map(|(member, value)| format!("{}: {}", member, value.shape()))
+                    .collect::<Vec<_>>();
+                members.sort();
+
+                format!("{} {{ {} }}", name, members.join(", "))
+            }
+        }
+    }
+
+    /// Returns the type of this value as written in an input file.
+    pub fn input_type(&self) -> String {
+        match self {
+            InputValue::Array(array) => {
+                let element = array
+                    .first()
+                    .map(|value| value.input_type())
+                    .unwrap_or_else(|| "_".to_string());
+
+                format!("[{}; {}]", element, array.len())
+            }
+            InputValue::Tuple(tuple) => {
+                let types = tuple
+                    .iter()
+                    .map(|value| value.input_type())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("({})", types)
+            }
+            InputValue::Circuit(name, _) => name.clone(),
+            value => value.shape(),
+        }
+    }
+
+    /// Returns this value in the JSON input file format.
+    pub fn to_json(&self) -> JsonValue {
+        match self {
+            InputValue::Address(address) => JsonValue::from(address.clone()),
+            InputValue::Boolean(boolean) => JsonValue::from(*boolean),
+            InputValue::Field(field) => JsonValue::from(field.clone()),
+            InputValue::Group(GroupValue::Single(number, _)) => JsonValue::from(number.clone()),
+            InputValue::Group(GroupValue::Tuple(tuple)) => {
+                JsonValue::from(vec![tuple.x.to_string(), tuple.y.to_string()])
+            }
+            // Integers that do not fit in a JSON number are written as strings
+            InputValue::Integer(_, number) => number
+                .parse::<i64>()
+                .map(JsonValue::from)
+                .or_else(|_| number.parse::<u64>().map(JsonValue::from))
+                .unwrap_or_else(|_| JsonValue::from(number.clone())),
+            InputValue::Array(values) | InputValue::Tuple(values) => {
+                JsonValue::from(values.iter().map(|value| value.to_json()).collect::<Vec<_>>())
+            }
+            InputValue::Circuit(_, members) => JsonValue::Object(
+                members
+                    .iter()
+                    .map(|(member, value)| (member.clone(), value.to_json_definition()))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn to_json_definition(&self) -> JsonValue {
+        let definition = JsonDefinition {
+            type_: self.input_type(),
+            value: self.to_json(),
+        };
+
+        serde_json::to_value(definition).unwrap_or(JsonValue::Null)
+    }
 }
 
 // Recursively fetch all dimensions from the array type
@@ -269,6 +476,15 @@ impl fmt::Display for InputValue {
 
                 write!(f, "({})", values)
             }
+            InputValue::Circuit(ref name, ref members) => {
+                let members = members
+                    .iter()
+                    .map(|(member, value)| format!("{}: {}", member, value))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                write!(f, "{} {{ {} }}", name, members)
+            }
         }
     }
 }
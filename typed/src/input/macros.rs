@@ -70,6 +70,42 @@ macro_rules! input_section_impl {
             pub fn values(&self) -> HashMap<Parameter, Option<InputValue>> {
                 self.values.clone()
             }
+
+            /// Returns the sorted `name: type` declarations of this section if it is present.
+            pub fn shape(&self) -> Vec<String> {
+                let mut shape = self
+                    .values
+                    .keys()
+                    .map(|parameter| format!("{}: {}", parameter.variable, parameter.type_))
+                    .collect::<Vec<_>>();
+                shape.sort();
+
+                shape
+            }
+
+            /// Returns the definitions of this section in the JSON input file format if it is present.
+            pub fn to_json(&self) -> Option<leo_input::files::JsonSection> {
+                if !self.is_present {
+                    return None;
+                }
+
+                let section = self
+                    .values
+                    .iter()
+                    .filter_map(|(parameter, value)| {
+                        value.as_ref().map(|value| {
+                            let definition = leo_input::files::JsonDefinition {
+                                type_: parameter.input_type(),
+                                value: value.to_json(),
+                            };
+
+                            (parameter.variable.name.clone(), definition)
+                        })
+                    })
+                    .collect();
+
+                Some(section)
+            }
         }
     )*)
 }
@@ -24,6 +24,13 @@ pub struct Parameter {
     pub span: Span,
 }
 
+impl Parameter {
+    /// Returns the type of this parameter as written in an input file.
+    pub fn input_type(&self) -> String {
+        self.type_.input_type()
+    }
+}
+
 impl<'ast> From<AstParameter<'ast>> for Parameter {
     fn from(parameter: AstParameter<'ast>) -> Self {
         Self {
@@ -15,7 +15,11 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::InputValue;
-use leo_input::{definitions::Definition, InputParserError};
+use leo_input::{
+    definitions::Definition,
+    files::{JsonDefinition, JsonSection},
+    InputParserError,
+};
 use std::collections::HashMap;
 
 #[derive(Clone, PartialEq, Eq)]
@@ -64,4 +68,36 @@ impl MainInput {
     pub fn get(&self, name: &String) -> Option<Option<InputValue>> {
         self.input.get(name).map(|input| input.clone())
     }
+
+    /// Returns the sorted `name: shape` of each main function input.
+    pub fn shape(&self) -> Vec<String> {
+        let mut shape = self
+            .input
+            .iter()
+            .map(|(name, value)| match value {
+                Some(value) => format!("{}: {}", name, value.shape()),
+                None => name.clone(),
+            })
+            .collect::<Vec<_>>();
+        shape.sort();
+
+        shape
+    }
+
+    /// Returns the main function inputs in the JSON input file format.
+    pub fn to_json(&self) -> JsonSection {
+        self.input
+            .iter()
+            .filter_map(|(name, value)| {
+                value.as_ref().map(|value| {
+                    let definition = JsonDefinition {
+                        type_: value.input_type(),
+                        value: value.to_json(),
+                    };
+
+                    (name.clone(), definition)
+                })
+            })
+            .collect()
+    }
 }
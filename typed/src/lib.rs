@@ -29,6 +29,9 @@ pub use self::common::*;
 pub mod console;
 pub use self::console::*;
 
+pub mod enums;
+pub use self::enums::*;
+
 pub mod errors;
 pub use self::errors::*;
 
@@ -17,7 +17,7 @@
 //! A typed Leo program consists of import, circuit, and function definitions.
 //! Each defined type consists of typed statements and expressions.
 
-use crate::{load_annotation, Circuit, Function, Identifier, Import, InputVariable, TestFunction};
+use crate::{load_annotation, Circuit, Enum, Function, Identifier, Import, InputVariable, TestFunction};
 use leo_ast::{definitions::Definition, files::File};
 
 use serde::{Deserialize, Serialize};
@@ -30,6 +30,7 @@ pub struct Program {
     pub expected_input: Vec<InputVariable>,
     pub imports: Vec<Import>,
     pub circuits: HashMap<Identifier, Circuit>,
+    pub enums: HashMap<Identifier, Enum>,
     pub functions: HashMap<Identifier, Function>,
     pub tests: HashMap<Identifier, TestFunction>,
 }
@@ -41,6 +42,7 @@ impl<'ast> Program {
     pub fn from(program_name: &str, program_ast: &File<'ast>) -> Self {
         let mut imports = vec![];
         let mut circuits = HashMap::new();
+        let mut enums = HashMap::new();
         let mut functions = HashMap::new();
         let mut tests = HashMap::new();
         let mut expected_input = vec![];
@@ -54,6 +56,9 @@ impl<'ast> Program {
                 Definition::Circuit(circuit) => {
                     circuits.insert(Identifier::from(circuit.identifier.clone()), Circuit::from(circuit));
                 }
+                Definition::Enum(enum_) => {
+                    enums.insert(Identifier::from(enum_.identifier.clone()), Enum::from(enum_));
+                }
                 Definition::Function(function_def) => {
                     let function = Function::from(function_def);
                     if function.identifier.name.eq(MAIN_FUNCTION_NAME) {
@@ -70,6 +75,7 @@ impl<'ast> Program {
                         annotated_definition,
                         &mut imports,
                         &mut circuits,
+                        &mut enums,
                         &mut functions,
                         &mut tests,
                         &mut expected_input,
@@ -82,6 +88,7 @@ impl<'ast> Program {
             expected_input,
             imports,
             circuits,
+            enums,
             functions,
             tests,
         }
@@ -95,6 +102,7 @@ impl Program {
             expected_input: vec![],
             imports: vec![],
             circuits: HashMap::new(),
+            enums: HashMap::new(),
             functions: HashMap::new(),
             tests: HashMap::new(),
         }
@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{MatchPattern, Statement};
+use leo_ast::statements::MatchStatementArm as AstMatchStatementArm;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchStatementArm {
+    pub pattern: MatchPattern,
+    pub statements: Vec<Statement>,
+}
+
+impl<'ast> From<AstMatchStatementArm<'ast>> for MatchStatementArm {
+    fn from(arm: AstMatchStatementArm<'ast>) -> Self {
+        Self {
+            pattern: MatchPattern::from(arm.pattern),
+            statements: arm
+                .statements
+                .into_iter()
+                .map(|statement| Statement::from(statement))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for MatchStatementArm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} => {{\n", self.pattern)?;
+        for statement in self.statements.iter() {
+            write!(f, "\t\t{}\n", statement)?;
+        }
+        write!(f, "\t}}")
+    }
+}
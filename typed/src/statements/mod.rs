@@ -20,5 +20,8 @@ pub use conditional_nested_or_end_statement::*;
 pub mod conditional_statement;
 pub use conditional_statement::*;
 
+pub mod match_statement_arm;
+pub use match_statement_arm::*;
+
 pub mod statement;
 pub use statement::*;
@@ -14,7 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Assignee, ConditionalStatement, ConsoleFunctionCall, Declare, Expression, Identifier, Span, Variables};
+use crate::{
+    Assignee,
+    ConditionalStatement,
+    ConsoleFunctionCall,
+    Declare,
+    Expression,
+    Identifier,
+    MatchStatementArm,
+    Span,
+    Variables,
+};
 use leo_ast::{
     console::ConsoleFunctionCall as AstConsoleFunctionCall,
     operations::AssignOperation,
@@ -23,6 +33,7 @@ use leo_ast::{
         DefinitionStatement,
         ExpressionStatement,
         ForStatement,
+        MatchStatement,
         ReturnStatement,
         Statement as AstStatement,
     },
@@ -39,10 +50,26 @@ pub enum Statement {
     Assign(Assignee, Expression, Span),
     Conditional(ConditionalStatement, Span),
     Iteration(Identifier, Expression, Expression, Vec<Statement>, Span),
+    Match(Expression, Vec<MatchStatementArm>, Span),
     Console(ConsoleFunctionCall),
     Expression(Expression, Span),
 }
 
+impl Statement {
+    pub fn span(&self) -> &Span {
+        match self {
+            Statement::Return(_, span) => span,
+            Statement::Definition(_, _, _, span) => span,
+            Statement::Assign(_, _, span) => span,
+            Statement::Conditional(_, span) => span,
+            Statement::Iteration(_, _, _, _, span) => span,
+            Statement::Match(_, _, span) => span,
+            Statement::Console(console) => &console.span,
+            Statement::Expression(_, span) => span,
+        }
+    }
+}
+
 impl<'ast> From<ReturnStatement<'ast>> for Statement {
     fn from(statement: ReturnStatement<'ast>) -> Self {
         Statement::Return(Expression::from(statement.expression), Span::from(statement.span))
@@ -154,6 +181,20 @@ impl<'ast> From<ForStatement<'ast>> for Statement {
     }
 }
 
+impl<'ast> From<MatchStatement<'ast>> for Statement {
+    fn from(statement: MatchStatement<'ast>) -> Self {
+        Statement::Match(
+            Expression::from(statement.expression),
+            statement
+                .arms
+                .into_iter()
+                .map(|arm| MatchStatementArm::from(arm))
+                .collect(),
+            Span::from(statement.span),
+        )
+    }
+}
+
 impl<'ast> From<AstConsoleFunctionCall<'ast>> for Statement {
     fn from(function_call: AstConsoleFunctionCall<'ast>) -> Self {
         Statement::Console(ConsoleFunctionCall::from(function_call))
@@ -182,6 +223,7 @@ impl<'ast> From<AstStatement<'ast>> for Statement {
                 Statement::Conditional(ConditionalStatement::from(statement), span)
             }
             AstStatement::Iteration(statement) => Statement::from(statement),
+            AstStatement::Match(statement) => Statement::from(statement),
             AstStatement::Console(console) => Statement::from(console),
             AstStatement::Expression(statement) => Statement::from(statement),
         }
@@ -210,6 +252,13 @@ impl fmt::Display for Statement {
                 }
                 write!(f, "\t}}")
             }
+            Statement::Match(ref expression, ref arms, ref _span) => {
+                write!(f, "match {} {{\n", expression)?;
+                for arm in arms {
+                    write!(f, "\t{}\n", arm)?;
+                }
+                write!(f, "}}")
+            }
             Statement::Console(ref console) => write!(f, "{}", console),
             Statement::Expression(ref expression, ref _span) => write!(f, "{};", expression),
         }
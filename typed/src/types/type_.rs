@@ -15,16 +15,17 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{Expression, Identifier, IntegerType};
-use leo_ast::types::{ArrayType, CircuitType, DataType, TupleType, Type as AstType};
+use leo_ast::types::{ArrayType, ArrayTypeDimensions, CircuitType, DataType, TupleType, Type as AstType};
 use leo_input::types::{
     ArrayType as InputArrayType,
+    CircuitType as InputCircuitType,
     DataType as InputDataType,
     TupleType as InputTupleType,
     Type as InputAstType,
 };
 
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{collections::HashMap, fmt};
 
 /// Explicit type used for defining a variable or expression type
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -38,8 +39,10 @@ pub enum Type {
 
     // Data type wrappers
     Array(Box<Type>, Vec<usize>),
+    GenericArray(Box<Type>, Identifier),
     Tuple(Vec<Type>),
     Circuit(Identifier),
+    Enum(Identifier),
     SelfType,
 }
 
@@ -58,6 +61,135 @@ impl Type {
         false
     }
 
+    pub fn is_enum(&self) -> bool {
+        if let Type::Enum(_) = self {
+            return true;
+        }
+        false
+    }
+
+    /// Returns true if a value of the `other` type can be stored as this type.
+    /// Declared types name enums like circuits, so a circuit type matches an enum type of the same name.
+    pub fn matches(&self, other: &Type) -> bool {
+        match (self, other) {
+            (Type::Circuit(name), Type::Enum(other_name)) | (Type::Enum(name), Type::Circuit(other_name)) => {
+                name.eq(other_name)
+            }
+            (Type::Array(type_, dimensions), Type::Array(other_type, other_dimensions)) => {
+                let (type_, dimensions) = expand_array_type(type_, dimensions);
+                let (other_type, other_dimensions) = expand_array_type(other_type, other_dimensions);
+
+                type_.matches(&other_type) && dimensions.eq(&other_dimensions)
+            }
+            (Type::GenericArray(type_, parameter), Type::GenericArray(other_type, other_parameter)) => {
+                type_.matches(other_type) && parameter.eq(other_parameter)
+            }
+            (Type::Tuple(types), Type::Tuple(other_types)) => {
+                types.len() == other_types.len()
+                    && types
+                        .iter()
+                        .zip(other_types.iter())
+                        .all(|(type_, other_type)| type_.matches(other_type))
+            }
+            (type_, other_type) => type_.eq(other_type),
+        }
+    }
+
+    /// Returns true if the type has an array dimension that is a const generic parameter
+    pub fn is_generic(&self) -> bool {
+        match self {
+            Type::GenericArray(_, _) => true,
+            Type::Array(type_, _) => type_.is_generic(),
+            Type::Tuple(types) => types.iter().any(|type_| type_.is_generic()),
+            _ => false,
+        }
+    }
+
+    /// Returns the type with every known const generic array dimension replaced by its value
+    pub fn resolve_const_parameters(&self, constants: &HashMap<String, usize>) -> Self {
+        match self {
+            Type::GenericArray(type_, parameter) => {
+                let type_ = Box::new(type_.resolve_const_parameters(constants));
+
+                match constants.get(&parameter.name) {
+                    Some(length) => Type::Array(type_, vec![*length]),
+                    None => Type::GenericArray(type_, parameter.clone()),
+                }
+            }
+            Type::Array(type_, dimensions) => {
+                Type::Array(Box::new(type_.resolve_const_parameters(constants)), dimensions.clone())
+            }
+            Type::Tuple(types) => Type::Tuple(
+                types
+                    .iter()
+                    .map(|type_| type_.resolve_const_parameters(constants))
+                    .collect(),
+            ),
+            type_ => type_.clone(),
+        }
+    }
+
+    /// Infers the values of const generic parameters from the type of a given value.
+    /// Returns false if the type of the value does not fit this type.
+    pub fn infer_const_parameters(&self, actual: &Type, constants: &mut HashMap<String, usize>) -> bool {
+        match (self, actual) {
+            (Type::GenericArray(type_, parameter), Type::Array(actual_type, actual_dimensions)) => {
+                let length = actual_dimensions[0];
+
+                if let Some(inferred) = constants.get(&parameter.name) {
+                    if *inferred != length {
+                        return false;
+                    }
+                }
+                constants.insert(parameter.name.clone(), length);
+
+                type_.infer_const_parameters(&actual_type.outer_dimension(actual_dimensions), constants)
+            }
+            (Type::Array(type_, dimensions), Type::Array(actual_type, actual_dimensions)) if self.is_generic() => {
+                if dimensions[0] != actual_dimensions[0] {
+                    return false;
+                }
+
+                type_
+                    .outer_dimension(dimensions)
+                    .infer_const_parameters(&actual_type.outer_dimension(actual_dimensions), constants)
+            }
+            (Type::Tuple(types), Type::Tuple(actual_types)) if self.is_generic() => {
+                types.len() == actual_types.len()
+                    && types
+                        .iter()
+                        .zip(actual_types.iter())
+                        .all(|(type_, actual_type)| type_.infer_const_parameters(actual_type, constants))
+            }
+            (type_, actual_type) => type_.matches(actual_type),
+        }
+    }
+
+    /// Returns this type as written in an input file.
+    pub fn input_type(&self) -> String {
+        match self {
+            Type::Array(element_type, dimensions) => {
+                let element_type = element_type.input_type();
+
+                match dimensions.as_slice() {
+                    [dimension] => format!("[{}; {}]", element_type, dimension),
+                    dimensions => {
+                        let dimensions = dimensions.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+
+                        format!("[{}; ({})]", element_type, dimensions)
+                    }
+                }
+            }
+            Type::Tuple(types) => {
+                let types = types.iter().map(Type::input_type).collect::<Vec<_>>().join(", ");
+
+                format!("({})", types)
+            }
+            Type::Circuit(identifier) | Type::Enum(identifier) => identifier.name.clone(),
+            type_ => type_.to_string(),
+        }
+    }
+
     pub fn match_array_types(&self, other: &Type) -> bool {
         // Check that both `self` and `other` are of type array
         let (type_1, dimensions_1) = match self {
@@ -135,9 +267,15 @@ impl From<DataType> for Type {
 impl<'ast> From<ArrayType<'ast>> for Type {
     fn from(array_type: ArrayType<'ast>) -> Self {
         let element_type = Box::new(Type::from(*array_type.type_));
-        let dimensions = Expression::get_array_dimensions(array_type.dimensions);
 
-        Type::Array(element_type, dimensions)
+        match array_type.dimensions {
+            ArrayTypeDimensions::Dimensions(dimensions) => {
+                Type::Array(element_type, Expression::get_array_dimensions(dimensions))
+            }
+            ArrayTypeDimensions::Const(dimension) => {
+                Type::GenericArray(element_type, Identifier::from(dimension.identifier))
+            }
+        }
     }
 }
 
@@ -198,12 +336,19 @@ impl<'ast> From<InputTupleType<'ast>> for Type {
     }
 }
 
+impl<'ast> From<InputCircuitType<'ast>> for Type {
+    fn from(circuit_type: InputCircuitType<'ast>) -> Self {
+        Type::Circuit(Identifier::from(circuit_type.identifier))
+    }
+}
+
 impl<'ast> From<InputAstType<'ast>> for Type {
     fn from(type_: InputAstType<'ast>) -> Self {
         match type_ {
             InputAstType::Basic(type_) => Type::from(type_),
             InputAstType::Array(type_) => Type::from(type_),
             InputAstType::Tuple(type_) => Type::from(type_),
+            InputAstType::Circuit(type_) => Type::from(type_),
         }
     }
 }
@@ -217,6 +362,7 @@ impl fmt::Display for Type {
             Type::Group => write!(f, "group"),
             Type::IntegerType(ref integer_type) => write!(f, "{}", integer_type),
             Type::Circuit(ref variable) => write!(f, "circuit {}", variable),
+            Type::Enum(ref variable) => write!(f, "enum {}", variable),
             Type::SelfType => write!(f, "SelfType"),
             Type::Array(ref array, ref dimensions) => {
                 let dimensions = dimensions
@@ -226,6 +372,7 @@ impl fmt::Display for Type {
                     .join(", ");
                 write!(f, "[{}; ({})]", *array, dimensions)
             }
+            Type::GenericArray(ref array, ref parameter) => write!(f, "[{}; {}]", *array, parameter),
             Type::Tuple(ref tuple) => {
                 let types = tuple.iter().map(|x| format!("{}", x)).collect::<Vec<_>>().join(", ");
 
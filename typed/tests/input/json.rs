@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_input::{files::JsonFile, LeoInputParser};
+use leo_typed::Input;
+
+fn parse_input(input_string: &str, state_string: &str) -> Input {
+    let mut input = Input::new();
+
+    input.parse_input(LeoInputParser::parse_file(input_string).unwrap()).unwrap();
+    input.parse_state(LeoInputParser::parse_file(state_string).unwrap()).unwrap();
+
+    input
+}
+
+fn expected_json() -> JsonFile {
+    JsonFile::parse(include_str!("expected_input.json")).unwrap()
+}
+
+#[test]
+fn test_input_to_json() {
+    let input = parse_input(include_str!("input.in"), include_str!("input.state"));
+
+    assert_eq!(expected_json(), input.to_json());
+}
+
+#[test]
+fn test_json_to_input() {
+    let json = expected_json();
+
+    let input_string = json.input_file_string().unwrap();
+    let state_string = json.state_file_string().unwrap();
+    let input = parse_input(&input_string, &state_string);
+
+    assert_eq!(json, input.to_json());
+}
+
+#[test]
+fn test_json_string_round_trip() {
+    let json = expected_json();
+
+    let json_string = json.to_json_string().unwrap();
+
+    assert_eq!(json, JsonFile::parse(&json_string).unwrap());
+}
+
+#[test]
+fn test_json_value_mismatch() {
+    let json = JsonFile::parse(r#"{ "main": { "a": { "type": "u8", "value": -1 } } }"#).unwrap();
+
+    let error = json.input_file_string().unwrap_err();
+
+    assert_eq!("E0108", error.code());
+}
+
+#[test]
+fn test_json_array_length() {
+    let json = JsonFile::parse(r#"{ "main": { "a": { "type": "[u8; 2]", "value": [1, 2, 3] } } }"#).unwrap();
+
+    let error = json.input_file_string().unwrap_err();
+
+    assert_eq!("E0108", error.code());
+}
+
+#[test]
+fn test_json_invalid_type() {
+    let json = JsonFile::parse(r#"{ "main": { "a": { "type": "u8; 2", "value": 1 } } }"#).unwrap();
+
+    let error = json.input_file_string().unwrap_err();
+
+    assert_eq!("E0108", error.code());
+}
+
+#[test]
+fn test_json_unknown_section() {
+    let error = JsonFile::parse(r#"{ "constants": {} }"#).unwrap_err();
+
+    assert_eq!("E0107", error.code());
+}